@@ -30,6 +30,7 @@ loading_configuration_step_decoded_chain_spec = Chain-Spezifikation erfolgreich
 loading_networking_stack_title = initialisiere Netzwerk-Stack
 loading_networking_stack_step_checking_node_path = überprüfe Node-Pfad...
 loading_networking_stack_step_creating_node_path = erstelle Node-Pfad...
+loading_networking_stack_step_resetting_node_database = Synchronisierungsmodus oder Knotenspeicher geändert, Knotendatenbank wird entfernt...
 loading_networking_stack_step_node_path_ready = Node-Pfad bereit
 loading_networking_stack_step_preparing = bereite Netzwerk-Stack vor...
 loading_networking_stack_step_reading_keypair = lese Netzwerk-Schlüsselpaar...
@@ -40,8 +41,16 @@ loading_networking_stack_step_created_successfully = Netzwerk-Stack erfolgreich
 loading_consensus_node_title = initialisiere Konsens-Node
 loading_consensus_node_step_creating = erstelle Konsens-Node...
 loading_consensus_node_step_created_successfully = Konsens-Node erfolgreich erstellt
+loading_consensus_node_step_connecting_to_external_node = Verbindung zum externen Knoten unter {$url} wird hergestellt...
+loading_consensus_node_step_connected_to_external_node_successfully = Erfolgreich mit externem Knoten verbunden
 loading_farmer_title = instanziiere Farmer
 loading_farmer_step_initializing = Initialisiere Farms {$index}/{$farms_total}...
+loading_farmer_step_initializing_farm_reading_metadata = Farm {$farm_index}: lese Metadaten ({$time_elapsed} vergangen)
+loading_farmer_step_initializing_farm_allocating = Farm {$farm_index}: reserviere Speicherplatz {$space_allocated}/{$space_total} ({$time_elapsed} vergangen, ~{$time_remaining} verbleibend)
+loading_farmer_step_initializing_farm_allocating_no_eta = Farm {$farm_index}: reserviere Speicherplatz {$space_allocated}/{$space_total} ({$time_elapsed} vergangen)
+loading_farmer_step_initializing_farm_initializing_caches = Farm {$farm_index}: initialisiere Piece-Cache und Plot-Cache ({$time_elapsed} vergangen)
+loading_farmer_step_collecting_plotted_pieces = Sammle bereits geplottete Pieces (das wird einige Zeit dauern)...
+loading_farmer_step_collecting_plotted_pieces_farm = Farm {$farm_index}: {$sectors_collected}/{$sectors_plotted} Sektoren
 loading_farmer_step_created_successfully = Farmer erfolgreich erstellt
 loading_wiping_farmer_data_title = lösche Farmer-Daten
 loading_wiping_farmer_data_step_wiping_farm = lösche Farm-Daten {$index}/{$farms_total} unter {$path}...
@@ -77,17 +86,118 @@ configuration_farm_fixed_size_placeholder = Beispiel: 4T, 2.5TB, 500GiB, etc.
 configuration_farm_fixed_size_tooltip = Größe der Farm in beliebiger Einheit, jeder Speicherplatz über 2 GB ist geeignet.
 configuration_farm_free_percentage_size_placeholder = Beispiel: 100%, 1.1%, etc.
 configuration_farm_free_percentage_size_tooltip = Prozentsatz des freien Speicherplatzes, den diese Farm belegen soll. Jeder Wert über 0 % ist geeignet, aber es sollten mindestens 2 GB freier Speicherplatz auf der Festplatte verbleiben, um Fehler zu vermeiden
+configuration_farm_free_space_warning_threshold_placeholder = Warnung bei wenig Speicher: {$default_threshold}
+configuration_farm_free_space_warning_threshold_tooltip = Freier Speicherplatz auf dem Farm-Datenträger, unterhalb dessen eine Warnung über wenig Speicherplatz angezeigt wird, zum Beispiel 500MiB oder 5GB, leer lassen, um den Standardwert zu verwenden
 configuration_farm_delete = Diese Farm löschen
+configuration_no_farms_node_only = Keine Farmen konfiguriert, nur der Knoten wird ohne Farming ausgeführt
+configuration_no_farms_external_node = Mit einem externen Knoten wird mindestens eine Farm benötigt, sonst läuft nichts
 configuration_advanced = Erweiterte Konfiguration
 configuration_advanced_farmer = Farmer-Konfiguration
 configuration_advanced_farmer_reduce_plotting_cpu_load = CPU-Belastung beim Plotten reduzieren
 configuration_advanced_farmer_reduce_plotting_cpu_load_tooltip = Das initiale Plotten verwendet standardmäßig alle CPU-Kerne. Mit dieser Option wird es jedoch nur die Hälfte der Kerne nutzen, ähnlich wie beim Replotten, wodurch die Systemreaktionsfähigkeit für andere Aufgaben verbessert wird
+configuration_advanced_node = Node-Konfiguration
+configuration_advanced_node_name = Knotenname:
+configuration_advanced_node_name_placeholder = Wird beim ersten Start automatisch erzeugt
+configuration_advanced_node_name_tooltip = Name, den der Knoten im Netzwerk und in der Telemetrie verwendet, bis zu {$max_length} Zeichen
+configuration_advanced_node_telemetry = Telemetrie:
+configuration_advanced_node_telemetry_tooltip = Knotenname, Version und Synchronisationsstatus an öffentliche Telemetrie melden, hilft bei der Überwachung des Netzwerkzustands
+configuration_advanced_node_sync_mode = Synchronisierungsmodus:
+configuration_advanced_node_sync_mode_tooltip = Wie der Knoten die Chain synchronisiert; eine Änderung entfernt die Knotendatenbank und synchronisiert sie von Grund auf neu
+configuration_advanced_node_sync_mode_snap = Snap
+configuration_advanced_node_sync_mode_full = Vollständig
+configuration_advanced_node_sync_mode_snap_explanation = Lädt den aktuellen Zustand und nur die zum Farmen nötigen Blöcke herunter, viel schneller bereit zum Farmen
+configuration_advanced_node_sync_mode_full_explanation = Lädt jeden Block seit dem Genesis-Block herunter und überprüft ihn, dauert viel länger, verlässt sich aber nicht auf den von anderen Knoten bereitgestellten Zustand
+configuration_advanced_node_sync_mode_changed_warning = Die Knotendatenbank wird beim nächsten Start entfernt und erneut synchronisiert
+configuration_advanced_node_pruning = Node-Speicher:
+configuration_advanced_node_pruning_tooltip = Wie viel der Chain-Historie der Node auf dem Laufwerk behält, mehr zu behalten ermöglicht lokale Abfragen historischer Zustände, ein Wechsel zu oder von Archiv erfordert das Löschen und erneute Synchronisieren der Node-Daten
+configuration_advanced_node_pruning_kind_minimum = Minimum (empfohlen)
+configuration_advanced_node_pruning_kind_blocks = Letzte Blöcke behalten
+configuration_advanced_node_pruning_kind_archive = Archiv (alles)
+configuration_advanced_node_pruning_blocks_tooltip = Anzahl der zuletzt behaltenen Blöcke, mindestens {$min_blocks}
+configuration_advanced_node_pruning_estimate = Geschätzter Speicherbedarf: ~{$size}
+configuration_advanced_node_pruning_estimate_archive = Geschätzter Speicherbedarf: wächst mit der Chain um ~{$size} pro Tag
+configuration_advanced_node_pruning_changed_warning = Die Knotendatenbank wird beim nächsten Start entfernt und erneut synchronisiert
+configuration_advanced_node_rpc = JSON-RPC-Server:
+configuration_advanced_node_rpc_tooltip = JSON-RPC-Endpunkt des Knotens für Wallets, Skripte und andere Werkzeuge bereitstellen
+configuration_advanced_node_rpc_listen_on = Abhöradresse:
+configuration_advanced_node_rpc_listen_on_tooltip = IP-Adresse und Port, zum Beispiel 127.0.0.1:9944, 0.0.0.0 verwenden, um auf allen Schnittstellen zu lauschen
+configuration_advanced_node_rpc_unsafe_methods = Unsichere Methoden:
+configuration_advanced_node_rpc_unsafe_methods_tooltip = Methoden erlauben, die das Verhalten des Knotens ändern können, niemals für nicht vertrauenswürdige Netzwerke freigeben
+configuration_advanced_node_external_node = Externer Knoten:
+configuration_advanced_node_external_node_placeholder = Beispiel: {$url}
+configuration_advanced_node_external_node_tooltip = WebSocket-RPC-URL eines vorhandenen Knotens, mit dem gefarmt wird, anstatt einen eingebetteten Knoten auszuführen, leer lassen, um den eingebetteten Knoten zu verwenden
 configuration_advanced_network = Netzwerkkonfiguration
 configuration_advanced_network_default_port_number_tooltip = Der Standardport ist {$port}
+configuration_advanced_network_listen_on = Lauschen auf:
+configuration_advanced_network_listen_on_tooltip = Netzwerkschnittstellen, auf denen eingehende Verbindungen angenommen werden, eine bestimmte Adresse ist auf Servern mit mehreren Netzwerkschnittstellen nützlich
+configuration_advanced_network_listen_on_all = Alle Schnittstellen
+configuration_advanced_network_listen_on_ipv4 = Nur IPv4
+configuration_advanced_network_listen_on_specific = Bestimmte Adresse
+configuration_advanced_network_listen_on_custom = Benutzerdefiniert (Konfigurationsdatei)
+configuration_advanced_network_listen_on_custom_explanation = Es werden Adressen aus der Konfigurationsdatei verwendet, die Ports unten werden ignoriert. Substrate: {$substrate_addresses}; DSN: {$dsn_addresses}
+configuration_advanced_network_listen_on_address_placeholder = 192.168.1.10
 configuration_advanced_network_substrate_port = Substrate (Blockchain) P2P-Port (TCP):
 configuration_advanced_network_subspace_port = Subspace (DSN) P2P-Port (TCP):
-configuration_advanced_network_faster_networking = Schnelles Netzwerk:
-configuration_advanced_network_faster_networking_tooltip = Standardmäßig ist das Netzwerk für Konsumenten-Router optimiert. Wenn du jedoch eine leistungsstärkere Konfiguration hast, kann "Schnelles Netzwerk" die Synchronisationsgeschwindigkeit und andere Prozesse verbessern
+configuration_advanced_network_pick_free_ports = Freie Ports wählen
+configuration_advanced_network_pick_free_ports_tooltip = Beide Ports durch die ersten Ports ab den Standardwerten ersetzen, die von keiner anderen Anwendung verwendet werden
+configuration_advanced_network_error_port_in_use = Port {$port} wird bereits von einer anderen Anwendung verwendet, wählen Sie einen anderen Port
+configuration_advanced_network_error_duplicate_port = Port {$port} kann nicht gleichzeitig von Substrate und DSN verwendet werden, wählen Sie unterschiedliche Ports
+configuration_advanced_network_connections = Verbindungen:
+configuration_advanced_network_connections_tooltip = Begrenzung der Anzahl der Verbindungen; niedrigere Grenzen helfen bei getakteten Verbindungen und schwachen Routern, höhere Grenzen können bei leistungsfähigeren Setups die Synchronisierungsgeschwindigkeit und andere Prozesse verbessern
+configuration_advanced_network_connections_low = Niedrig
+configuration_advanced_network_connections_default = Standard
+configuration_advanced_network_connections_high = Hoch
+configuration_advanced_network_connections_custom = Benutzerdefiniert
+configuration_advanced_network_connections_dsn_in = Eingehende DSN-Verbindungen:
+configuration_advanced_network_connections_dsn_out = Ausgehende DSN-Verbindungen:
+configuration_advanced_network_connections_dsn_pending_in = Ausstehende eingehende DSN-Verbindungen:
+configuration_advanced_network_connections_dsn_pending_out = Ausstehende ausgehende DSN-Verbindungen:
+configuration_advanced_network_connections_substrate_in = Eingehende Substrate-Peers:
+configuration_advanced_network_connections_substrate_out = Ausgehende Substrate-Peers:
+configuration_advanced_network_piece_download = Piece-Downloads:
+configuration_advanced_network_piece_download_tooltip = Wiederholungsrichtlinie und Parallelität der Piece-Downloads für Plotting und Node-Synchronisation
+configuration_advanced_network_piece_download_max_retries = Wiederholungsversuche:
+configuration_advanced_network_piece_download_initial_retry_interval = Anfängliches Wiederholungsintervall (Sekunden):
+configuration_advanced_network_piece_download_max_retry_interval = Maximales Wiederholungsintervall (Sekunden):
+configuration_advanced_network_piece_download_concurrency_multiplier = Parallelitätsfaktor pro ausgehender DSN-Verbindung:
+configuration_advanced_network_peer_multiaddrs_placeholder = /dns/example.com/tcp/30333/p2p/12D3KooW...
+configuration_advanced_network_substrate_bootstrap_nodes = Substrate-Bootstrap-Knoten:
+configuration_advanced_network_substrate_bootstrap_nodes_tooltip = Zusätzliche Substrate-(Blockchain-)Knoten, über die das Netzwerk gefunden wird, kommagetrennte Multiaddrs, die mit /p2p/<Peer-ID> enden
+configuration_advanced_network_substrate_reserved_nodes = Reservierte Substrate-Knoten:
+configuration_advanced_network_substrate_reserved_nodes_tooltip = Substrate-(Blockchain-)Knoten, mit denen immer eine Verbindung gehalten wird, kommagetrennte Multiaddrs, die mit /p2p/<Peer-ID> enden
+configuration_advanced_network_dsn_bootstrap_nodes = DSN-Bootstrap-Knoten:
+configuration_advanced_network_dsn_bootstrap_nodes_tooltip = Zusätzliche Subspace-(DSN-)Knoten, über die das Netzwerk gefunden wird, kommagetrennte Multiaddrs, die mit /p2p/<Peer-ID> enden
+configuration_advanced_network_dsn_reserved_peers = Reservierte DSN-Peers:
+configuration_advanced_network_dsn_reserved_peers_tooltip = Subspace-(DSN-)Peers, mit denen immer eine Verbindung gehalten wird, kommagetrennte Multiaddrs, die mit /p2p/<Peer-ID> enden
+configuration_advanced_network_substrate_external_addresses = Externe Substrate-Adressen:
+configuration_advanced_network_substrate_external_addresses_placeholder = /ip4/203.0.113.1/tcp/30333
+configuration_advanced_network_substrate_external_addresses_tooltip = Öffentlich erreichbare Substrate-(Blockchain-)Adressen, die anderen Peers mitgeteilt werden, zum Beispiel wenn am Router eine Portweiterleitung eingerichtet ist, kommagetrennte Multiaddrs
+configuration_advanced_network_dsn_external_addresses = Externe DSN-Adressen:
+configuration_advanced_network_dsn_external_addresses_placeholder = /ip4/203.0.113.1/tcp/30433
+configuration_advanced_network_dsn_external_addresses_tooltip = Öffentlich erreichbare Subspace-(DSN-)Adressen, die anderen Peers mitgeteilt werden, zum Beispiel wenn am Router eine Portweiterleitung eingerichtet ist, kommagetrennte Multiaddrs
+configuration_advanced_network_daily_download_cap = Tägliches Download-Limit:
+configuration_advanced_network_daily_download_cap_tooltip = Weiches Limit für den Download beider Netzwerke in den letzten 24 Stunden, DSN-Verkehr außer Pieces wird nicht gezählt. Bei Erreichen werden Plotting-Downloads reduziert und DSN-Verbindungen nach einem Neustart verringert. Leer lassen für kein Limit
+configuration_advanced_network_daily_upload_cap = Tägliches Upload-Limit:
+configuration_advanced_network_daily_upload_cap_tooltip = Weiches Limit für den Upload beider Netzwerke in den letzten 24 Stunden, DSN-Verkehr außer Pieces wird nicht gezählt. Bei Erreichen werden DSN-Verbindungen nach einem Neustart verringert. Leer lassen für kein Limit
+configuration_advanced_network_proxy_url = Proxy-URL:
+configuration_advanced_network_proxy_url_tooltip = HTTP-, HTTPS- oder SOCKS5-Proxy für Update-Prüfungen, zum Beispiel http://127.0.0.1:8080 oder socks5h://127.0.0.1:1080 (socks5h löst Hostnamen über den Proxy auf), leer lassen für direkte Verbindung. P2P-Verbindungen von Node und Farmer unterstützen keine Proxys
+configuration_advanced_network_proxy_url_placeholder = http://host:port
+configuration_advanced_network_proxy_username = Proxy-Benutzername:
+configuration_advanced_network_proxy_username_tooltip = Benutzername für die Proxy-Authentifizierung, leer lassen, wenn der Proxy keine Authentifizierung erfordert
+configuration_advanced_network_proxy_username_placeholder = optional
+configuration_advanced_network_proxy_password = Proxy-Passwort:
+configuration_advanced_network_proxy_password_tooltip = Passwort für die Proxy-Authentifizierung, erfordert Benutzernamen, wird in der Konfigurationsdatei als Klartext gespeichert
+configuration_advanced_network_daily_cap_placeholder = Zum Beispiel 50 GB, leer für kein Limit
+configuration_advanced_network_allow_private_addresses = Private Adressen erlauben:
+configuration_advanced_network_allow_private_addresses_tooltip = Verbindung zu Peers mit privaten Adressen herstellen und diese finden, zum Beispiel andere Farmer im selben lokalen Netzwerk
+configuration_advanced_updates = Updates
+configuration_advanced_updates_check = Nach Updates suchen:
+configuration_advanced_updates_check_tooltip = Regelmäßig prüfen, ob eine neue Version von Space Acres verfügbar ist
+configuration_advanced_updates_include_prereleases = Vorabversionen einbeziehen:
+configuration_advanced_updates_include_prereleases_tooltip = Auch über Vorabversionen benachrichtigen, die weniger stabil sein können
+configuration_advanced_updates_source = Update-Quelle:
+configuration_advanced_updates_source_placeholder = GitHub-Releases
+configuration_advanced_updates_source_tooltip = URL oder absoluter Pfad einer JSON-Datei im Format der GitHub-Releases-API, die statt GitHub geprüft wird, nützlich für Tests und Spiegelserver, leer lassen, um GitHub zu verwenden
 configuration_button_add_farm = Farm hinzufügen
 configuration_button_help = Hilfe
 configuration_button_cancel = Abbrechen
@@ -96,6 +206,12 @@ configuration_button_save = Speichern
 configuration_button_start = Start
 configuration_dialog_button_select = Auswählen
 configuration_dialog_button_cancel = Abbrechen
+network_identity_dialog_button_export = Exportieren
+network_identity_dialog_button_import = Importieren
+network_identity_rotate_confirm_heading = Neue Netzwerkidentität generieren?
+network_identity_rotate_confirm_body = Die Peer-ID dieses Knotens ändert sich nach dem Neustart, und Peers, die ihn über die Peer-ID referenzieren, müssen aktualisiert werden. Die vorherige Identität wird in secret_ed25519.<Peer-ID>.bak im Netzwerkverzeichnis der Knotendaten aufbewahrt.
+network_identity_rotate_confirm_button_cancel = Abbrechen
+network_identity_rotate_confirm_button_rotate = Generieren
 
 node_migration_button_cancel = Abbrechen
 node_migration_button_reset = Node zurücksetzen
@@ -128,6 +244,46 @@ running_node_title = {$chain_name} Konsens-Node
 running_node_title_tooltip = Klicken, um im Dateimanager zu öffnen
 running_node_free_disk_space_tooltip = Freier Speicherplatz: {$size} verbleibend
 running_node_connections_tooltip = {$connected_peers}/{$expected_peers} Peers verbunden, klicke hier für Details zu den benötigten P2P-Ports
+running_node_peer_id = Peer-ID: {$peer_id}
+running_node_network_identity_tooltip = Netzwerkidentität
+running_node_network_identity_export = Netzwerkidentität exportieren…
+running_node_network_identity_export_warning = Die exportierte Datei enthält den geheimen Schlüssel dieses Knotens, jeder, der sie besitzt, kann sich im Netzwerk als dieser Knoten ausgeben, halten Sie sie privat
+running_node_network_identity_import = Netzwerkidentität importieren…
+running_node_network_identity_rotate = Neue Netzwerkidentität generieren
+running_node_network_identity_replace_warning = Das Importieren oder Generieren einer Netzwerkidentität ändert die Peer-ID nach dem Neustart, Peers, die diesen Knoten über die Peer-ID referenzieren, müssen aktualisiert werden
+running_node_peers_button = Peers
+running_node_peers_button_tooltip = Verbundene Peers untersuchen, nützlich wenn die Synchronisierung stockt
+running_node_peers_substrate = Peers des Konsensknotens (Substrate): {$count}
+running_node_peers_dsn = DSN-Peers: {$count}
+running_node_known_peers = Cache bekannter Peers
+running_node_known_peers_description = Adressen von DSN-Peers, die zwischen Neustarts für eine schnellere Wiederverbindung gespeichert werden. Leeren Sie ihn, wenn die Wiederverbindung nach einer Änderung der IP-Adresse oder einem Netzwerkwechsel langsam ist, nach einer Woche offline wird er auch automatisch geleert.
+running_node_known_peers_inspect = Prüfen
+running_node_known_peers_clear = Leeren
+running_node_peers_role_authority = Autorität
+running_node_peers_role_full = voll
+running_node_peers_role_light = leicht
+running_node_peers_direction_inbound = eingehend
+running_node_peers_direction_outbound = ausgehend
+running_node_peers_address_unknown = unbekannte Adresse
+running_node_peers_none = Keine Peers verbunden
+running_node_piece_serving = {$pieces} Pieces bereitgestellt, {$upload_rate}/s
+running_node_piece_serving_tooltip =
+    Über DSN an andere Farmer bereitgestellte Daten:
+    Pieces aus dem Cache: {$cache_hits}
+    Pieces aus Plots: {$plot_hits}
+    nicht gefundene Pieces: {$misses}
+    statt eines Pieces gesendete nächste Peers: {$closest_peers_fallbacks}
+    Segment-Header: {$segment_headers}
+    insgesamt hochgeladen: {$bytes_uploaded}
+running_node_bandwidth = Letzte 24 Std.: ↓ {$downloaded} ↑ {$uploaded}
+running_node_bandwidth_tooltip =
+    Letzte Stunde: ↓ {$hour_downloaded} ↑ {$hour_uploaded}
+    Letzte 24 Stunden, Konsensknoten: ↓ {$substrate_downloaded} ↑ {$substrate_uploaded}
+    Letzte 24 Stunden, DSN (nur Pieces, ohne DHT, Gossip und Protokoll-Overhead): ↓ {$dsn_downloaded} ↑ {$dsn_uploaded}
+    Tageslimits: ↓ {$download_cap} ↑ {$upload_cap}
+running_node_bandwidth_no_cap = kein Limit
+running_node_bandwidth_download_cap_reached = Download-Limit erreicht, Plotting-Downloads werden reduziert und DSN-Verbindungen nach einem Neustart verringert
+running_node_bandwidth_upload_cap_reached = Upload-Limit erreicht, DSN-Verbindungen werden nach einem Neustart verringert
 running_node_status_connecting = Verbindung zum Netzwerk wird hergestellt, bester Block #{$block_number}
 running_node_status_syncing_speed_no_eta = , {NUMBER($blocks_per_second, minimumFractionDigits: 2, maximumFractionDigits: 2)} blocks/s
 running_node_status_syncing_speed_hours_eta = , {NUMBER($a_blocks_per_second, minimumFractionDigits: 2, maximumFractionDigits: 2)} blocks/s (~{NUMBER($b_hours_remaining, minimumFractionDigits: 2, maximumFractionDigits: 2)} hours remaining)
@@ -146,6 +302,15 @@ running_farmer_button_pause_plotting = Plotten/Replotten pausieren, bitte beacht
 running_farmer_button_resume_plotting = Plotten fortsetzen
 running_farmer_account_balance_tooltip = Gesamtsaldo des Kontos und gefarmte Coins seit Start der Anwendung, klicken, um Details in Astral anzuzeigen
 running_farmer_piece_cache_sync = Piece-Cache-Synchronisation {NUMBER($percentage, minimumFractionDigits: 2, maximumFractionDigits: 2)}%
+running_farmer_piece_downloads = Heruntergeladene Pieces: {$local} lokal, {$dsn_cache} DSN-Cache, {$archival} Archiv, {$node} Node, {$failed} fehlgeschlagen
+running_farmer_piece_downloads_tooltip =
+    Für Plotting, Piece-Cache-Synchronisation und Node-Synchronisation abgerufene Pieces:
+    aus lokalem Piece-Cache und Plots: {$local}
+    aus DSN-Cache: {$dsn_cache}
+    aus Archivspeicher: {$archival}
+    vom Node: {$node}
+    ungültige Pieces von DSN-Peers: {$invalid}
+    aus keiner Quelle abrufbar: {$failed}
 running_farmer_next_reward_estimate =
     Nächste Reward-Schätzung: {$eta_string ->
         [any_time_now] jederzeit jetzt
@@ -160,7 +325,10 @@ running_farmer_farm_reward_signatures_tooltip = {$successful_signatures}/{$total
 running_farmer_farm_auditing_performance_tooltip = Leistungsüberprüfung: Durchschnittliche Zeit {NUMBER($a_average_time, minimumFractionDigits: 2, maximumFractionDigits: 2)}s, time limit {NUMBER($b_time_timit, minimumFractionDigits: 2, maximumFractionDigits: 2)}s
 running_farmer_farm_proving_performance_tooltip = Nachweis der Leistung: Durchschnittliche Zeit {NUMBER($a_average_time, minimumFractionDigits: 2, maximumFractionDigits: 2)}s, time limit {NUMBER($b_time_timit, minimumFractionDigits: 2, maximumFractionDigits: 2)}s
 running_farmer_farm_non_fatal_error_tooltip = Ein nicht-kritischer Fehler beim Farming ist aufgetreten und wurde behoben, siehe Protokolle für weitere Details: {$error}
+running_farmer_farm_free_disk_space_tooltip = Freier Speicherplatz: {$size} verbleibend
 running_farmer_farm_crashed = Farm abgestürzt: {$error}
+running_farmer_farm_disk_missing = Farm-Laufwerk fehlt, die Farm ist angehalten, bis das Laufwerk wieder verbunden ist
+running_farmer_farm_disk_read_only = Farm-Laufwerk ist schreibgeschützt, die Farm ist angehalten, bis das Laufwerk wieder beschreibbar ist
 running_farmer_farm_plotting_speed =  ({NUMBER($a_sector_time, minimumFractionDigits: 2, maximumFractionDigits: 2)} m/sector, {NUMBER($b_sectors_per_hour, minimumFractionDigits: 2, maximumFractionDigits: 2)} sectors/h)
 running_farmer_farm_plotting_initial =
     {$pausing_state ->
@@ -204,6 +372,8 @@ error_title = Fehler
 error_message = Fehler: {$error}
 error_message_failed_to_send_config_to_backend = Fehler beim Senden der Konfiguration an das Backend: {$error}
 error_message_failed_to_send_pause_plotting_to_backend = Fehler beim Senden der Pause-Anfrage für das Plotten an das Backend: {$error}
+error_message_failed_to_send_network_identity_action_to_backend = Netzwerkidentitätsaktion konnte nicht an das Backend gesendet werden: {$error}
+error_message_failed_to_send_known_peers_action_to_backend = Aktion für bekannte Peers konnte nicht an das Backend gesendet werden: {$error}
 error_button_help_from_community = Hilfe aus der Community
 error_button_reset_node = Node zurücksetzen
 error_button_reset_node_tooltip = Node-Daten löschen und neu vom Netzwerk synchronisieren
@@ -211,6 +381,17 @@ error_button_show_logs = Protokolle anzeigen
 
 new_version_available = Version {$version} verfügbar 🎉
 new_version_available_button_open = Releases-Seite öffnen
+new_version_available_button_release_notes = Versionshinweise anzeigen
+new_version_release_notes_title = Neu in {$version}
+new_version_release_notes_empty = Keine Versionshinweise vorhanden
+new_version_update_button_install = Herunterladen und installieren
+new_version_update_downloading = Version {$version} wird heruntergeladen…
+new_version_update_ready = Version {$version} installieren
+new_version_update_installing = Version {$version} wird installiert…
+new_version_update_confirm_heading = Version {$version} installieren?
+new_version_update_confirm_body = Das Update wurde heruntergeladen und seine Signatur überprüft, Space Acres wird neu gestartet, um die Installation abzuschließen
+new_version_update_confirm_button_cancel = Abbrechen
+new_version_update_confirm_button_install = Installieren und neu starten
 
 main_menu_show_logs = Protokolle im Dateimanager anzeigen
 main_menu_change_configuration = Konfiguration ändern
@@ -221,6 +402,17 @@ main_menu_exit = Beenden
 status_bar_message_configuration_is_invalid = Konfiguration ist ungültig: {$error}
 status_bar_message_restart_is_needed_for_configuration = Ein Neustart der Anwendung ist erforderlich, damit die Konfigurationsänderungen wirksam werden
 status_bar_message_failed_to_save_configuration = Fehler beim Speichern der Konfigurationsänderungen: {$error}
+status_bar_message_network_identity_exported = Netzwerkidentität wurde nach {$path} exportiert, halten Sie diese Datei privat
+status_bar_message_restart_is_needed_for_network_identity = Ein Neustart der Anwendung ist erforderlich, damit die neue Netzwerkidentität mit der Peer-ID {$peer_id} wirksam wird
+status_bar_message_failed_to_update_network_identity = Netzwerkidentität konnte nicht aktualisiert werden: {$error}
+status_bar_message_known_peers_cache = Cache bekannter Peers {$path} belegt {$size}, zuletzt vor {$hours} Stunden aktualisiert
+status_bar_message_known_peers_cache_unknown_age = Cache bekannter Peers {$path} belegt {$size}
+status_bar_message_known_peers_cache_empty = Cache bekannter Peers ist leer
+status_bar_message_known_peers_cache_reset_scheduled = Cache bekannter Peers wird beim nächsten Start geleert
+status_bar_message_restart_is_needed_for_known_peers_cache = Neustart der Anwendung ist erforderlich, um den Cache bekannter Peers zu leeren
+status_bar_message_restart_is_needed_for_bandwidth_cap = Bandbreitenlimit erreicht, Neustart der Anwendung ist erforderlich, um DSN-Verbindungen zu verringern
+status_bar_message_failed_to_manage_known_peers_cache = Cache bekannter Peers konnte nicht verwaltet werden: {$error}
+status_bar_message_failed_to_update = Aktualisierung fehlgeschlagen: {$error}
 status_bar_message_restarted_after_crash = Space Acres wurde nach einem Absturz automatisch neu gestartet. Überprüfe die Anwendungs- und Systemprotokolle für Details
 status_bar_button_migrate = Migrieren
 status_bar_button_ok = Ok
@@ -239,8 +431,16 @@ notification_stopped_with_error = Space Acres wurde mit einem Fehler angehalten
     .body = Ein Fehler ist aufgetreten, der eine Benutzerintervention zur Behebung erfordert
 notification_farm_error = Eine der Farms in Space Acres ist fehlgeschlagen
     .body = Ein Fehler ist aufgetreten, der eine Benutzerintervention zur Behebung erfordert
+notification_farm_disk_missing = Farm-Laufwerk fehlt
+    .body = Die Farm {$path} ist angehalten, bis das Laufwerk wieder verbunden ist, andere Farms farmen weiter
+notification_farm_disk_read_only = Farm-Laufwerk ist schreibgeschützt
+    .body = Die Farm {$path} ist angehalten, bis das Laufwerk wieder beschreibbar ist, andere Farms farmen weiter
+notification_farm_disk_available = Farm-Laufwerk ist wieder verfügbar
+    .body = Die Farm {$path} ist wieder beigetreten und farmt weiter
 notification_node_low_disk_space = Wenig Node-Speicherplatz
     .body = Node-Laufwerk hat nur noch {$free_space} übrig
+notification_farm_low_disk_space = Wenig Farm-Speicherplatz
+    .body = Laufwerk der Farm {$farm_path} hat nur noch {$free_space} übrig
 notification_missed_reward = Signieren der Reward fehlgeschlagen 😞
     .body = Das ist bedauerlich, aber es wird bald eine weitere Gelegenheit geben
 notification_signed_reward_successfully = Neue Reward erfolgreich signiert 🥳
@@ -30,6 +30,7 @@ loading_configuration_step_decoded_chain_spec = Decoded chain specification succ
 loading_networking_stack_title = Initializing networking stack
 loading_networking_stack_step_checking_node_path = Checking node path...
 loading_networking_stack_step_creating_node_path = Creating node path...
+loading_networking_stack_step_resetting_node_database = Sync mode or node storage changed, removing node database...
 loading_networking_stack_step_node_path_ready = Node path ready
 loading_networking_stack_step_preparing = Preparing networking stack...
 loading_networking_stack_step_reading_keypair = Reading network keypair...
@@ -40,8 +41,16 @@ loading_networking_stack_step_created_successfully = Networking stack created su
 loading_consensus_node_title = Initializing consensus node
 loading_consensus_node_step_creating = Creating consensus node...
 loading_consensus_node_step_created_successfully = Consensus node created successfully
+loading_consensus_node_step_connecting_to_external_node = Connecting to external node at {$url}...
+loading_consensus_node_step_connected_to_external_node_successfully = Connected to external node successfully
 loading_farmer_title = Instantiating farmer
 loading_farmer_step_initializing = Initializing farms {$index}/{$farms_total}...
+loading_farmer_step_initializing_farm_reading_metadata = Farm {$farm_index}: reading metadata ({$time_elapsed} elapsed)
+loading_farmer_step_initializing_farm_allocating = Farm {$farm_index}: allocating space {$space_allocated}/{$space_total} ({$time_elapsed} elapsed, ~{$time_remaining} remaining)
+loading_farmer_step_initializing_farm_allocating_no_eta = Farm {$farm_index}: allocating space {$space_allocated}/{$space_total} ({$time_elapsed} elapsed)
+loading_farmer_step_initializing_farm_initializing_caches = Farm {$farm_index}: initializing piece cache and plot cache ({$time_elapsed} elapsed)
+loading_farmer_step_collecting_plotted_pieces = Collecting already plotted pieces (this will take some time)...
+loading_farmer_step_collecting_plotted_pieces_farm = Farm {$farm_index}: {$sectors_collected}/{$sectors_plotted} sectors
 loading_farmer_step_created_successfully = Farmer created successfully
 loading_wiping_farmer_data_title = Wiping farmer data
 loading_wiping_farmer_data_step_wiping_farm = Wiping farm {$index}/{$farms_total} at {$path}...
@@ -77,17 +86,118 @@ configuration_farm_fixed_size_placeholder = Example: 4T, 2.5TB, 500GiB, etc.
 configuration_farm_fixed_size_tooltip = Size of the farm in whichever units you prefer, any amount of space above 2 GB works
 configuration_farm_free_percentage_size_placeholder = Example: 100%, 1.1%, etc.
 configuration_farm_free_percentage_size_tooltip = Percentage of free disk space to occupy by this farm, anything above 0% works, but at least 2 GB of free space should remain on disk to avoid errors
+configuration_farm_free_space_warning_threshold_placeholder = Low space warning: {$default_threshold}
+configuration_farm_free_space_warning_threshold_tooltip = Free space on farm disk below which low disk space warning is shown, for example 500MiB or 5GB, leave empty to use the default
 configuration_farm_delete = Delete this farm
+configuration_no_farms_node_only = No farms configured, only node will run without farming
+configuration_no_farms_external_node = At least one farm is needed with external node, otherwise nothing will run
 configuration_advanced = Advanced configuration
 configuration_advanced_farmer = Farmer configuration
 configuration_advanced_farmer_reduce_plotting_cpu_load = Reduce plotting CPU load
 configuration_advanced_farmer_reduce_plotting_cpu_load_tooltip = Initial plotting uses all CPU cores by default, while with this option it will start using half of the cores like replotting, improving system responsiveness for other tasks
+configuration_advanced_node = Node configuration
+configuration_advanced_node_name = Node name:
+configuration_advanced_node_name_placeholder = Generated automatically on first start
+configuration_advanced_node_name_tooltip = Name node uses on the network and in telemetry, up to {$max_length} characters
+configuration_advanced_node_telemetry = Telemetry:
+configuration_advanced_node_telemetry_tooltip = Report node name, version and sync status to public telemetry, helps to monitor network health
+configuration_advanced_node_sync_mode = Sync mode:
+configuration_advanced_node_sync_mode_tooltip = How node synchronizes the chain, changing it removes node database and syncs it again from scratch
+configuration_advanced_node_sync_mode_snap = Snap
+configuration_advanced_node_sync_mode_full = Full
+configuration_advanced_node_sync_mode_snap_explanation = Downloads recent state and only the blocks necessary for farming, ready to farm much sooner
+configuration_advanced_node_sync_mode_full_explanation = Downloads and verifies every block since genesis, takes much longer, but doesn't rely on state provided by other nodes
+configuration_advanced_node_sync_mode_changed_warning = Node database will be removed and synced again on the next start
+configuration_advanced_node_pruning = Node storage:
+configuration_advanced_node_pruning_tooltip = How much of the chain history node keeps on disk, keeping more allows querying historical state locally, switching to or from archive requires node data to be deleted and synced again
+configuration_advanced_node_pruning_kind_minimum = Minimum (recommended)
+configuration_advanced_node_pruning_kind_blocks = Keep recent blocks
+configuration_advanced_node_pruning_kind_archive = Archive (everything)
+configuration_advanced_node_pruning_blocks_tooltip = Number of most recent blocks to keep, at least {$min_blocks}
+configuration_advanced_node_pruning_estimate = Estimated disk usage: ~{$size}
+configuration_advanced_node_pruning_estimate_archive = Estimated disk usage: grows with the chain by ~{$size} per day
+configuration_advanced_node_pruning_changed_warning = Node database will be removed and synced again on the next start
+configuration_advanced_node_rpc = JSON-RPC server:
+configuration_advanced_node_rpc_tooltip = Expose JSON-RPC endpoint of the node for wallets, scripts and other tools
+configuration_advanced_node_rpc_listen_on = Listen address:
+configuration_advanced_node_rpc_listen_on_tooltip = IP address and port, for example 127.0.0.1:9944, use 0.0.0.0 to listen on all interfaces
+configuration_advanced_node_rpc_unsafe_methods = Unsafe methods:
+configuration_advanced_node_rpc_unsafe_methods_tooltip = Allow methods that can change node behavior, never expose them to untrusted networks
+configuration_advanced_node_external_node = External node:
+configuration_advanced_node_external_node_placeholder = Example: {$url}
+configuration_advanced_node_external_node_tooltip = WebSocket RPC URL of an existing node to farm with instead of running embedded node, leave empty to run embedded node
 configuration_advanced_network = Network configuration
 configuration_advanced_network_default_port_number_tooltip = Default port number is {$port}
+configuration_advanced_network_listen_on = Listen on:
+configuration_advanced_network_listen_on_tooltip = Network interfaces to accept incoming connections on, a specific address is useful on servers with multiple network interfaces
+configuration_advanced_network_listen_on_all = All interfaces
+configuration_advanced_network_listen_on_ipv4 = IPv4 only
+configuration_advanced_network_listen_on_specific = Specific address
+configuration_advanced_network_listen_on_custom = Custom (configuration file)
+configuration_advanced_network_listen_on_custom_explanation = Listen addresses from configuration file are used, ports below are ignored. Substrate: {$substrate_addresses}; DSN: {$dsn_addresses}
+configuration_advanced_network_listen_on_address_placeholder = 192.168.1.10
 configuration_advanced_network_substrate_port = Substrate (blockchain) P2P port (TCP):
 configuration_advanced_network_subspace_port = Subspace (DSN) P2P port (TCP):
-configuration_advanced_network_faster_networking = Faster networking:
-configuration_advanced_network_faster_networking_tooltip = By default networking is optimized for consumer routers, but if you have more powerful setup, faster networking may improve sync speed and other processes
+configuration_advanced_network_pick_free_ports = Pick free ports
+configuration_advanced_network_pick_free_ports_tooltip = Replace both ports with the first ports starting from defaults that are not used by any other application
+configuration_advanced_network_error_port_in_use = Port {$port} is already in use by another application, choose a different port
+configuration_advanced_network_error_duplicate_port = Port {$port} can't be used by both Substrate and DSN, choose different ports
+configuration_advanced_network_connections = Connections:
+configuration_advanced_network_connections_tooltip = Limits on the number of connections, lower limits help with metered connections and weak routers, while higher limits on more powerful setups may improve sync speed and other processes
+configuration_advanced_network_connections_low = Low
+configuration_advanced_network_connections_default = Default
+configuration_advanced_network_connections_high = High
+configuration_advanced_network_connections_custom = Custom
+configuration_advanced_network_connections_dsn_in = DSN incoming connections:
+configuration_advanced_network_connections_dsn_out = DSN outgoing connections:
+configuration_advanced_network_connections_dsn_pending_in = DSN pending incoming connections:
+configuration_advanced_network_connections_dsn_pending_out = DSN pending outgoing connections:
+configuration_advanced_network_connections_substrate_in = Substrate incoming peers:
+configuration_advanced_network_connections_substrate_out = Substrate outgoing peers:
+configuration_advanced_network_piece_download = Piece downloads:
+configuration_advanced_network_piece_download_tooltip = Retry policy and concurrency of piece downloads used by plotting and node sync
+configuration_advanced_network_piece_download_max_retries = Retry attempts:
+configuration_advanced_network_piece_download_initial_retry_interval = Initial retry interval (seconds):
+configuration_advanced_network_piece_download_max_retry_interval = Max retry interval (seconds):
+configuration_advanced_network_piece_download_concurrency_multiplier = Concurrency multiplier per outgoing DSN connection:
+configuration_advanced_network_peer_multiaddrs_placeholder = /dns/example.com/tcp/30333/p2p/12D3KooW...
+configuration_advanced_network_substrate_bootstrap_nodes = Substrate bootstrap nodes:
+configuration_advanced_network_substrate_bootstrap_nodes_tooltip = Additional Substrate (blockchain) nodes to discover the network through, comma-separated multiaddrs ending with /p2p/<peer ID>
+configuration_advanced_network_substrate_reserved_nodes = Substrate reserved nodes:
+configuration_advanced_network_substrate_reserved_nodes_tooltip = Substrate (blockchain) nodes to always stay connected to, comma-separated multiaddrs ending with /p2p/<peer ID>
+configuration_advanced_network_dsn_bootstrap_nodes = DSN bootstrap nodes:
+configuration_advanced_network_dsn_bootstrap_nodes_tooltip = Additional Subspace (DSN) nodes to discover the network through, comma-separated multiaddrs ending with /p2p/<peer ID>
+configuration_advanced_network_dsn_reserved_peers = DSN reserved peers:
+configuration_advanced_network_dsn_reserved_peers_tooltip = Subspace (DSN) peers to always stay connected to, comma-separated multiaddrs ending with /p2p/<peer ID>
+configuration_advanced_network_substrate_external_addresses = Substrate external addresses:
+configuration_advanced_network_substrate_external_addresses_placeholder = /ip4/203.0.113.1/tcp/30333
+configuration_advanced_network_substrate_external_addresses_tooltip = Publicly reachable Substrate (blockchain) addresses to advertise to other peers, for instance when port forwarding is configured on the router, comma-separated multiaddrs
+configuration_advanced_network_dsn_external_addresses = DSN external addresses:
+configuration_advanced_network_dsn_external_addresses_placeholder = /ip4/203.0.113.1/tcp/30433
+configuration_advanced_network_dsn_external_addresses_tooltip = Publicly reachable Subspace (DSN) addresses to advertise to other peers, for instance when port forwarding is configured on the router, comma-separated multiaddrs
+configuration_advanced_network_daily_download_cap = Daily download cap:
+configuration_advanced_network_daily_download_cap_tooltip = Soft cap on download of both networks over the last 24 hours, DSN traffic other than pieces is not counted. Once reached plotting downloads are scaled back and DSN connections are reduced after restart, leave empty for no cap
+configuration_advanced_network_daily_upload_cap = Daily upload cap:
+configuration_advanced_network_daily_upload_cap_tooltip = Soft cap on upload of both networks over the last 24 hours, DSN traffic other than pieces is not counted. Once reached DSN connections are reduced after restart, leave empty for no cap
+configuration_advanced_network_proxy_url = Proxy URL:
+configuration_advanced_network_proxy_url_tooltip = HTTP, HTTPS or SOCKS5 proxy used for update checks, for example http://127.0.0.1:8080 or socks5h://127.0.0.1:1080 (socks5h resolves host names through the proxy), leave empty to connect directly. P2P connections of node and farmer do not support proxies
+configuration_advanced_network_proxy_url_placeholder = http://host:port
+configuration_advanced_network_proxy_username = Proxy username:
+configuration_advanced_network_proxy_username_tooltip = Username for proxy authentication, leave empty if proxy does not require authentication
+configuration_advanced_network_proxy_username_placeholder = optional
+configuration_advanced_network_proxy_password = Proxy password:
+configuration_advanced_network_proxy_password_tooltip = Password for proxy authentication, requires username, stored in configuration file as plain text
+configuration_advanced_network_daily_cap_placeholder = For example 50 GB, empty for no cap
+configuration_advanced_network_allow_private_addresses = Allow private addresses:
+configuration_advanced_network_allow_private_addresses_tooltip = Connect to and discover peers with private addresses, for instance other farmers on the same local network
+configuration_advanced_updates = Updates
+configuration_advanced_updates_check = Check for updates:
+configuration_advanced_updates_check_tooltip = Periodically check whether a new version of Space Acres is available
+configuration_advanced_updates_include_prereleases = Include pre-releases:
+configuration_advanced_updates_include_prereleases_tooltip = Also notify about pre-release versions, which may be less stable
+configuration_advanced_updates_source = Update source:
+configuration_advanced_updates_source_placeholder = GitHub releases
+configuration_advanced_updates_source_tooltip = URL or absolute path of a JSON file in GitHub releases API format to check instead of GitHub, useful for testing and mirrors, leave empty to use GitHub
 configuration_button_add_farm = Add farm
 configuration_button_help = Help
 configuration_button_cancel = Cancel
@@ -96,6 +206,12 @@ configuration_button_save = Save
 configuration_button_start = Start
 configuration_dialog_button_select = Select
 configuration_dialog_button_cancel = Cancel
+network_identity_dialog_button_export = Export
+network_identity_dialog_button_import = Import
+network_identity_rotate_confirm_heading = Generate new network identity?
+network_identity_rotate_confirm_body = Peer ID of this node will change after restart and peers that reference it by peer ID will need to be updated. Previous identity will be kept in secret_ed25519.<peer ID>.bak in network directory of node data.
+network_identity_rotate_confirm_button_cancel = Cancel
+network_identity_rotate_confirm_button_rotate = Generate
 
 node_migration_button_cancel = Cancel
 node_migration_button_reset = Reset Node
@@ -128,6 +244,46 @@ running_node_title = {$chain_name} consensus node
 running_node_title_tooltip = Click to open in file manager
 running_node_free_disk_space_tooltip = Free disk space: {$size} remaining
 running_node_connections_tooltip = {$connected_peers}/{$expected_peers} peers connected, click for details about required P2P ports
+running_node_peer_id = Peer ID: {$peer_id}
+running_node_network_identity_tooltip = Network identity
+running_node_network_identity_export = Export network identity…
+running_node_network_identity_export_warning = Exported file contains secret key of this node, anyone who has it can impersonate this node on the network, keep it private
+running_node_network_identity_import = Import network identity…
+running_node_network_identity_rotate = Generate new network identity
+running_node_network_identity_replace_warning = Importing or generating network identity changes peer ID after restart, peers that reference this node by peer ID will need to be updated
+running_node_peers_button = Peers
+running_node_peers_button_tooltip = Inspect connected peers, useful when sync is stalled
+running_node_peers_substrate = Consensus node (Substrate) peers: {$count}
+running_node_peers_dsn = DSN peers: {$count}
+running_node_known_peers = Known peers cache
+running_node_known_peers_description = Addresses of DSN peers remembered between restarts for faster reconnection. Clear it if reconnecting is slow after IP address change or moving to a different network, it is also cleared automatically after a week offline.
+running_node_known_peers_inspect = Inspect
+running_node_known_peers_clear = Clear
+running_node_peers_role_authority = authority
+running_node_peers_role_full = full
+running_node_peers_role_light = light
+running_node_peers_direction_inbound = inbound
+running_node_peers_direction_outbound = outbound
+running_node_peers_address_unknown = unknown address
+running_node_peers_none = No peers connected
+running_node_piece_serving = Served {$pieces} pieces, {$upload_rate}/s
+running_node_piece_serving_tooltip =
+    Data served to other farmers over DSN:
+    pieces from cache: {$cache_hits}
+    pieces from plots: {$plot_hits}
+    pieces not found: {$misses}
+    closest peers sent instead of a piece: {$closest_peers_fallbacks}
+    segment headers: {$segment_headers}
+    total uploaded: {$bytes_uploaded}
+running_node_bandwidth = Last 24h: ↓ {$downloaded} ↑ {$uploaded}
+running_node_bandwidth_tooltip =
+    Last hour: ↓ {$hour_downloaded} ↑ {$hour_uploaded}
+    Last 24 hours, consensus node: ↓ {$substrate_downloaded} ↑ {$substrate_uploaded}
+    Last 24 hours, DSN (pieces only, without DHT, gossip and protocol overhead): ↓ {$dsn_downloaded} ↑ {$dsn_uploaded}
+    Daily caps: ↓ {$download_cap} ↑ {$upload_cap}
+running_node_bandwidth_no_cap = no cap
+running_node_bandwidth_download_cap_reached = Download cap reached, plotting downloads are scaled back and DSN connections are reduced after restart
+running_node_bandwidth_upload_cap_reached = Upload cap reached, DSN connections are reduced after restart
 running_node_status_connecting = Connecting to the network, best block #{$block_number}
 running_node_status_syncing_speed_no_eta = , {NUMBER($blocks_per_second, minimumFractionDigits: 2, maximumFractionDigits: 2)} blocks/s
 running_node_status_syncing_speed_hours_eta = , {NUMBER($a_blocks_per_second, minimumFractionDigits: 2, maximumFractionDigits: 2)} blocks/s (~{NUMBER($b_hours_remaining, minimumFractionDigits: 2, maximumFractionDigits: 2)} hours remaining)
@@ -146,6 +302,15 @@ running_farmer_button_pause_plotting = Pause plotting/replotting, note that curr
 running_farmer_button_resume_plotting = Resume plotting
 running_farmer_account_balance_tooltip = Total account balance and coins farmed since application started, click to see details in Astral
 running_farmer_piece_cache_sync = Piece cache sync {NUMBER($percentage, minimumFractionDigits: 2, maximumFractionDigits: 2)}%
+running_farmer_piece_downloads = Pieces downloaded: {$local} local, {$dsn_cache} DSN cache, {$archival} archival, {$node} node, {$failed} failed
+running_farmer_piece_downloads_tooltip =
+    Pieces retrieved for plotting, piece cache sync and node sync:
+    from local piece cache and plots: {$local}
+    from DSN cache: {$dsn_cache}
+    from archival storage: {$archival}
+    from node: {$node}
+    invalid pieces received from DSN peers: {$invalid}
+    failed to retrieve from any source: {$failed}
 running_farmer_next_reward_estimate =
     Next reward estimate: {$eta_string ->
         [any_time_now] any time now
@@ -160,7 +325,10 @@ running_farmer_farm_reward_signatures_tooltip = {$successful_signatures}/{$total
 running_farmer_farm_auditing_performance_tooltip = Auditing performance: average time {NUMBER($a_average_time, minimumFractionDigits: 2, maximumFractionDigits: 2)}s, time limit {NUMBER($b_time_timit, minimumFractionDigits: 2, maximumFractionDigits: 2)}s
 running_farmer_farm_proving_performance_tooltip = Proving performance: average time {NUMBER($a_average_time, minimumFractionDigits: 2, maximumFractionDigits: 2)}s, time limit {NUMBER($b_time_timit, minimumFractionDigits: 2, maximumFractionDigits: 2)}s
 running_farmer_farm_non_fatal_error_tooltip = Non-fatal farming error happened and was recovered, see logs for more details: {$error}
+running_farmer_farm_free_disk_space_tooltip = Free disk space: {$size} remaining
 running_farmer_farm_crashed = Farm crashed: {$error}
+running_farmer_farm_disk_missing = Farm disk is missing, farm is stopped until the disk is connected again
+running_farmer_farm_disk_read_only = Farm disk is read-only, farm is stopped until the disk is writable again
 running_farmer_farm_plotting_speed =  ({NUMBER($a_sector_time, minimumFractionDigits: 2, maximumFractionDigits: 2)} m/sector, {NUMBER($b_sectors_per_hour, minimumFractionDigits: 2, maximumFractionDigits: 2)} sectors/h)
 running_farmer_farm_plotting_initial =
     {$pausing_state ->
@@ -204,6 +372,8 @@ error_title = Error
 error_message = Error: {$error}
 error_message_failed_to_send_config_to_backend = Failed to send config to backend: {$error}
 error_message_failed_to_send_pause_plotting_to_backend = Failed to send pause plotting to backend: {$error}
+error_message_failed_to_send_network_identity_action_to_backend = Failed to send network identity action to backend: {$error}
+error_message_failed_to_send_known_peers_action_to_backend = Failed to send known peers action to backend: {$error}
 error_button_help_from_community = Help from community
 error_button_reset_node = Reset node
 error_button_reset_node_tooltip = Wipe node data and sync fresh from the network
@@ -211,6 +381,17 @@ error_button_show_logs = Show logs
 
 new_version_available = Version {$version} available 🎉
 new_version_available_button_open = Open releases page
+new_version_available_button_release_notes = Show release notes
+new_version_release_notes_title = What's new in {$version}
+new_version_release_notes_empty = No release notes provided
+new_version_update_button_install = Download and install
+new_version_update_downloading = Downloading version {$version}…
+new_version_update_ready = Install version {$version}
+new_version_update_installing = Installing version {$version}…
+new_version_update_confirm_heading = Install version {$version}?
+new_version_update_confirm_body = Update was downloaded and its signature verified, Space Acres will restart to finish installation
+new_version_update_confirm_button_cancel = Cancel
+new_version_update_confirm_button_install = Install and restart
 
 main_menu_show_logs = Show logs in file manager
 main_menu_change_configuration = Change configuration
@@ -221,6 +402,17 @@ main_menu_exit = Exit
 status_bar_message_configuration_is_invalid = Configuration is invalid: {$error}
 status_bar_message_restart_is_needed_for_configuration = Application restart is needed for configuration changes to take effect
 status_bar_message_failed_to_save_configuration = Failed to save configuration changes: {$error}
+status_bar_message_network_identity_exported = Network identity was exported to {$path}, keep this file private
+status_bar_message_restart_is_needed_for_network_identity = Application restart is needed for new network identity with peer ID {$peer_id} to take effect
+status_bar_message_failed_to_update_network_identity = Failed to update network identity: {$error}
+status_bar_message_known_peers_cache = Known peers cache {$path} takes {$size}, last updated {$hours} hours ago
+status_bar_message_known_peers_cache_unknown_age = Known peers cache {$path} takes {$size}
+status_bar_message_known_peers_cache_empty = Known peers cache is empty
+status_bar_message_known_peers_cache_reset_scheduled = Known peers cache will be cleared on next start
+status_bar_message_restart_is_needed_for_known_peers_cache = Application restart is needed to clear known peers cache
+status_bar_message_restart_is_needed_for_bandwidth_cap = Bandwidth cap reached, application restart is needed to reduce DSN connections
+status_bar_message_failed_to_manage_known_peers_cache = Failed to manage known peers cache: {$error}
+status_bar_message_failed_to_update = Failed to update: {$error}
 status_bar_message_restarted_after_crash = Space Acres automatically restarted after crash, check application and system logs for details
 status_bar_button_migrate = Migrate
 status_bar_button_ok = Ok
@@ -239,8 +431,16 @@ notification_stopped_with_error = Space Acres stopped with error
     .body = An error happened and requires user intervention to resolve
 notification_farm_error = One of the farms failed in Space Acres
     .body = An error happened and requires user intervention to resolve
+notification_farm_disk_missing = Farm disk is missing
+    .body = Farm {$path} is stopped until the disk is connected again, other farms continue farming
+notification_farm_disk_read_only = Farm disk is read-only
+    .body = Farm {$path} is stopped until the disk is writable again, other farms continue farming
+notification_farm_disk_available = Farm disk is available again
+    .body = Farm {$path} rejoined and continues farming
 notification_node_low_disk_space = Low Node Disk Space
     .body = Node volume has only {$free_space} remaining
+notification_farm_low_disk_space = Low Farm Disk Space
+    .body = Volume of farm {$farm_path} has only {$free_space} remaining
 notification_missed_reward = Reward signing failed 😞
     .body = This is unfortunate, but there will be another chance soon
 notification_signed_reward_successfully = Signed new reward successfully 🥳
@@ -30,6 +30,7 @@ loading_configuration_step_decoded_chain_spec = Especificación de la blockchain
 loading_networking_stack_title = Inicializando pila de red
 loading_networking_stack_step_checking_node_path = Verificando ruta del nodo...
 loading_networking_stack_step_creating_node_path = Creando ruta del nodo...
+loading_networking_stack_step_resetting_node_database = Modo de sincronización o almacenamiento del nodo cambiado, eliminando la base de datos del nodo...
 loading_networking_stack_step_node_path_ready = Ruta del nodo lista
 loading_networking_stack_step_preparing = Preparando pila de red...
 loading_networking_stack_step_reading_keypair = Leyendo claves de red...
@@ -40,8 +41,16 @@ loading_networking_stack_step_created_successfully = Pila de red creada con éxi
 loading_consensus_node_title = Inicializando nodo de consenso
 loading_consensus_node_step_creating = Creando nodo de consenso...
 loading_consensus_node_step_created_successfully = Nodo de consenso creado con éxito
+loading_consensus_node_step_connecting_to_external_node = Conectando al nodo externo en {$url}...
+loading_consensus_node_step_connected_to_external_node_successfully = Conectado al nodo externo con éxito
 loading_farmer_title = Instanciando granjero
 loading_farmer_step_initializing = Inicializando granjas {$index}/{$farms_total}...
+loading_farmer_step_initializing_farm_reading_metadata = Granja {$farm_index}: leyendo metadatos ({$time_elapsed} transcurrido)
+loading_farmer_step_initializing_farm_allocating = Granja {$farm_index}: asignando espacio {$space_allocated}/{$space_total} ({$time_elapsed} transcurrido, ~{$time_remaining} restante)
+loading_farmer_step_initializing_farm_allocating_no_eta = Granja {$farm_index}: asignando espacio {$space_allocated}/{$space_total} ({$time_elapsed} transcurrido)
+loading_farmer_step_initializing_farm_initializing_caches = Granja {$farm_index}: inicializando caché de piezas y caché de plot ({$time_elapsed} transcurrido)
+loading_farmer_step_collecting_plotted_pieces = Recopilando piezas ya ploteadas (esto llevará algún tiempo)...
+loading_farmer_step_collecting_plotted_pieces_farm = Granja {$farm_index}: {$sectors_collected}/{$sectors_plotted} sectores
 loading_farmer_step_created_successfully = Granjero creado con éxito
 loading_wiping_farmer_data_title = Borrando datos del granjero
 loading_wiping_farmer_data_step_wiping_farm = Borrando granja {$index}/{$farms_total} en {$path}...
@@ -77,17 +86,118 @@ configuration_farm_fixed_size_placeholder = Ejemplo: 4T, 2.5TB, 500GiB, etc.
 configuration_farm_fixed_size_tooltip = Tamaño de la granja en las unidades que prefieras, cualquier cantidad de espacio superior a 2 GB funciona
 configuration_farm_free_percentage_size_placeholder = Ejemplo: 100%, 1.1%, etc.
 configuration_farm_free_percentage_size_tooltip = Porcentaje de espacio libre en disco que ocupará esta granja, cualquier valor superior al 0% funciona, pero al menos 2 GB de espacio libre deben permanecer en el disco para evitar errores
+configuration_farm_free_space_warning_threshold_placeholder = Aviso de poco espacio: {$default_threshold}
+configuration_farm_free_space_warning_threshold_tooltip = Espacio libre en el disco de la granja por debajo del cual se muestra un aviso de poco espacio, por ejemplo 500MiB o 5GB, déjelo vacío para usar el valor predeterminado
 configuration_farm_delete = Eliminar esta granja
+configuration_no_farms_node_only = No hay granjas configuradas, solo se ejecutará el nodo sin cultivar
+configuration_no_farms_external_node = Se necesita al menos una granja con un nodo externo, de lo contrario no se ejecutará nada
 configuration_advanced = Configuración avanzada
 configuration_advanced_farmer = Configuración del granjero
 configuration_advanced_farmer_reduce_plotting_cpu_load = Reducir carga de CPU durante la creación de parcelas
 configuration_advanced_farmer_reduce_plotting_cpu_load_tooltip = La creación inicial de parcelas utiliza todos los núcleos de la CPU por defecto, mientras que con esta opción comenzará a usar la mitad de los núcleos como en el sustitución de parcelas, mejorando la capacidad de respuesta del sistema para otras tareas
+configuration_advanced_node = Configuración del nodo
+configuration_advanced_node_name = Nombre del nodo:
+configuration_advanced_node_name_placeholder = Se genera automáticamente en el primer inicio
+configuration_advanced_node_name_tooltip = Nombre que usa el nodo en la red y en la telemetría, hasta {$max_length} caracteres
+configuration_advanced_node_telemetry = Telemetría:
+configuration_advanced_node_telemetry_tooltip = Informar el nombre, la versión y el estado de sincronización del nodo a la telemetría pública, ayuda a supervisar la salud de la red
+configuration_advanced_node_sync_mode = Modo de sincronización:
+configuration_advanced_node_sync_mode_tooltip = Cómo sincroniza el nodo la cadena, cambiarlo elimina la base de datos del nodo y la sincroniza de nuevo desde cero
+configuration_advanced_node_sync_mode_snap = Snap
+configuration_advanced_node_sync_mode_full = Completa
+configuration_advanced_node_sync_mode_snap_explanation = Descarga el estado reciente y solo los bloques necesarios para farmear, listo para farmear mucho antes
+configuration_advanced_node_sync_mode_full_explanation = Descarga y verifica cada bloque desde el génesis, tarda mucho más, pero no depende del estado proporcionado por otros nodos
+configuration_advanced_node_sync_mode_changed_warning = La base de datos del nodo se eliminará y se sincronizará de nuevo en el próximo inicio
+configuration_advanced_node_pruning = Almacenamiento del nodo:
+configuration_advanced_node_pruning_tooltip = Cuánto historial de la cadena guarda el nodo en disco, guardar más permite consultar el estado histórico localmente, cambiar a o desde archivo requiere borrar los datos del nodo y volver a sincronizar
+configuration_advanced_node_pruning_kind_minimum = Mínimo (recomendado)
+configuration_advanced_node_pruning_kind_blocks = Guardar bloques recientes
+configuration_advanced_node_pruning_kind_archive = Archivo (todo)
+configuration_advanced_node_pruning_blocks_tooltip = Número de bloques más recientes a guardar, al menos {$min_blocks}
+configuration_advanced_node_pruning_estimate = Uso de disco estimado: ~{$size}
+configuration_advanced_node_pruning_estimate_archive = Uso de disco estimado: crece con la cadena ~{$size} por día
+configuration_advanced_node_pruning_changed_warning = La base de datos del nodo se eliminará y se sincronizará de nuevo en el próximo inicio
+configuration_advanced_node_rpc = Servidor JSON-RPC:
+configuration_advanced_node_rpc_tooltip = Exponer el endpoint JSON-RPC del nodo para billeteras, scripts y otras herramientas
+configuration_advanced_node_rpc_listen_on = Dirección de escucha:
+configuration_advanced_node_rpc_listen_on_tooltip = Dirección IP y puerto, por ejemplo 127.0.0.1:9944, use 0.0.0.0 para escuchar en todas las interfaces
+configuration_advanced_node_rpc_unsafe_methods = Métodos inseguros:
+configuration_advanced_node_rpc_unsafe_methods_tooltip = Permitir métodos que pueden cambiar el comportamiento del nodo, nunca los exponga a redes no confiables
+configuration_advanced_node_external_node = Nodo externo:
+configuration_advanced_node_external_node_placeholder = Ejemplo: {$url}
+configuration_advanced_node_external_node_tooltip = URL RPC WebSocket de un nodo existente con el que cultivar en lugar de ejecutar el nodo integrado, deje vacío para ejecutar el nodo integrado
 configuration_advanced_network = Configuración de red
 configuration_advanced_network_default_port_number_tooltip = El número de puerto predeterminado es {$port}
+configuration_advanced_network_listen_on = Escuchar en:
+configuration_advanced_network_listen_on_tooltip = Interfaces de red en las que aceptar conexiones entrantes, una dirección específica es útil en servidores con varias interfaces de red
+configuration_advanced_network_listen_on_all = Todas las interfaces
+configuration_advanced_network_listen_on_ipv4 = Solo IPv4
+configuration_advanced_network_listen_on_specific = Dirección específica
+configuration_advanced_network_listen_on_custom = Personalizado (archivo de configuración)
+configuration_advanced_network_listen_on_custom_explanation = Se usan las direcciones del archivo de configuración, los puertos de abajo se ignoran. Substrate: {$substrate_addresses}; DSN: {$dsn_addresses}
+configuration_advanced_network_listen_on_address_placeholder = 192.168.1.10
 configuration_advanced_network_substrate_port = Puerto P2P de Substrate (blockchain) (TCP):
 configuration_advanced_network_subspace_port = Puerto P2P de Subspace (DSN) (TCP):
-configuration_advanced_network_faster_networking = Red más rápida:
-configuration_advanced_network_faster_networking_tooltip = Por defecto, la red está optimizada para routers convencionales, pero si tienes una configuración más potente, una red más rápida puede mejorar la velocidad de sincronización y otros procesos
+configuration_advanced_network_pick_free_ports = Elegir puertos libres
+configuration_advanced_network_pick_free_ports_tooltip = Reemplazar ambos puertos por los primeros puertos a partir de los predeterminados que no usa ninguna otra aplicación
+configuration_advanced_network_error_port_in_use = El puerto {$port} ya está en uso por otra aplicación, elija un puerto diferente
+configuration_advanced_network_error_duplicate_port = El puerto {$port} no puede ser usado por Substrate y DSN a la vez, elija puertos diferentes
+configuration_advanced_network_connections = Conexiones:
+configuration_advanced_network_connections_tooltip = Límites del número de conexiones, los límites bajos ayudan con conexiones medidas y routers débiles, mientras que los límites altos en equipos más potentes pueden mejorar la velocidad de sincronización y otros procesos
+configuration_advanced_network_connections_low = Bajo
+configuration_advanced_network_connections_default = Predeterminado
+configuration_advanced_network_connections_high = Alto
+configuration_advanced_network_connections_custom = Personalizado
+configuration_advanced_network_connections_dsn_in = Conexiones entrantes de DSN:
+configuration_advanced_network_connections_dsn_out = Conexiones salientes de DSN:
+configuration_advanced_network_connections_dsn_pending_in = Conexiones entrantes pendientes de DSN:
+configuration_advanced_network_connections_dsn_pending_out = Conexiones salientes pendientes de DSN:
+configuration_advanced_network_connections_substrate_in = Pares entrantes de Substrate:
+configuration_advanced_network_connections_substrate_out = Pares salientes de Substrate:
+configuration_advanced_network_piece_download = Descargas de piezas:
+configuration_advanced_network_piece_download_tooltip = Política de reintentos y concurrencia de las descargas de piezas usadas por el plotting y la sincronización del nodo
+configuration_advanced_network_piece_download_max_retries = Reintentos:
+configuration_advanced_network_piece_download_initial_retry_interval = Intervalo inicial entre reintentos (segundos):
+configuration_advanced_network_piece_download_max_retry_interval = Intervalo máximo entre reintentos (segundos):
+configuration_advanced_network_piece_download_concurrency_multiplier = Multiplicador de concurrencia por conexión DSN saliente:
+configuration_advanced_network_peer_multiaddrs_placeholder = /dns/example.com/tcp/30333/p2p/12D3KooW...
+configuration_advanced_network_substrate_bootstrap_nodes = Nodos de arranque de Substrate:
+configuration_advanced_network_substrate_bootstrap_nodes_tooltip = Nodos adicionales de Substrate (blockchain) para descubrir la red, multiaddrs separadas por comas que terminan en /p2p/<ID de par>
+configuration_advanced_network_substrate_reserved_nodes = Nodos reservados de Substrate:
+configuration_advanced_network_substrate_reserved_nodes_tooltip = Nodos de Substrate (blockchain) con los que mantener siempre la conexión, multiaddrs separadas por comas que terminan en /p2p/<ID de par>
+configuration_advanced_network_dsn_bootstrap_nodes = Nodos de arranque de DSN:
+configuration_advanced_network_dsn_bootstrap_nodes_tooltip = Nodos adicionales de Subspace (DSN) para descubrir la red, multiaddrs separadas por comas que terminan en /p2p/<ID de par>
+configuration_advanced_network_dsn_reserved_peers = Pares reservados de DSN:
+configuration_advanced_network_dsn_reserved_peers_tooltip = Pares de Subspace (DSN) con los que mantener siempre la conexión, multiaddrs separadas por comas que terminan en /p2p/<ID de par>
+configuration_advanced_network_substrate_external_addresses = Direcciones externas de Substrate:
+configuration_advanced_network_substrate_external_addresses_placeholder = /ip4/203.0.113.1/tcp/30333
+configuration_advanced_network_substrate_external_addresses_tooltip = Direcciones públicamente accesibles de Substrate (blockchain) para anunciar a otros pares, por ejemplo cuando hay reenvío de puertos configurado en el router, multiaddrs separadas por comas
+configuration_advanced_network_dsn_external_addresses = Direcciones externas de DSN:
+configuration_advanced_network_dsn_external_addresses_placeholder = /ip4/203.0.113.1/tcp/30433
+configuration_advanced_network_dsn_external_addresses_tooltip = Direcciones públicamente accesibles de Subspace (DSN) para anunciar a otros pares, por ejemplo cuando hay reenvío de puertos configurado en el router, multiaddrs separadas por comas
+configuration_advanced_network_daily_download_cap = Límite diario de descarga:
+configuration_advanced_network_daily_download_cap_tooltip = Límite flexible de descarga de ambas redes en las últimas 24 horas, el tráfico DSN distinto de las piezas no se cuenta. Al alcanzarlo se reducen las descargas de plotting y las conexiones DSN tras reiniciar. Déjelo vacío para no tener límite
+configuration_advanced_network_daily_upload_cap = Límite diario de subida:
+configuration_advanced_network_daily_upload_cap_tooltip = Límite flexible de subida de ambas redes en las últimas 24 horas, el tráfico DSN distinto de las piezas no se cuenta. Al alcanzarlo las conexiones DSN se reducen tras reiniciar. Déjelo vacío para no tener límite
+configuration_advanced_network_proxy_url = URL del proxy:
+configuration_advanced_network_proxy_url_tooltip = Proxy HTTP, HTTPS o SOCKS5 usado para comprobar actualizaciones, por ejemplo http://127.0.0.1:8080 o socks5h://127.0.0.1:1080 (socks5h resuelve los nombres de host a través del proxy), déjelo vacío para conectarse directamente. Las conexiones P2P del nodo y del farmer no admiten proxies
+configuration_advanced_network_proxy_url_placeholder = http://host:puerto
+configuration_advanced_network_proxy_username = Usuario del proxy:
+configuration_advanced_network_proxy_username_tooltip = Usuario para la autenticación del proxy, déjelo vacío si el proxy no requiere autenticación
+configuration_advanced_network_proxy_username_placeholder = opcional
+configuration_advanced_network_proxy_password = Contraseña del proxy:
+configuration_advanced_network_proxy_password_tooltip = Contraseña para la autenticación del proxy, requiere usuario, se guarda en el archivo de configuración como texto plano
+configuration_advanced_network_daily_cap_placeholder = Por ejemplo 50 GB, vacío para sin límite
+configuration_advanced_network_allow_private_addresses = Permitir direcciones privadas:
+configuration_advanced_network_allow_private_addresses_tooltip = Conectarse y descubrir pares con direcciones privadas, por ejemplo otros farmers en la misma red local
+configuration_advanced_updates = Actualizaciones
+configuration_advanced_updates_check = Buscar actualizaciones:
+configuration_advanced_updates_check_tooltip = Comprobar periódicamente si hay una nueva versión de Space Acres disponible
+configuration_advanced_updates_include_prereleases = Incluir versiones preliminares:
+configuration_advanced_updates_include_prereleases_tooltip = Notificar también sobre versiones preliminares, que pueden ser menos estables
+configuration_advanced_updates_source = Fuente de actualizaciones:
+configuration_advanced_updates_source_placeholder = Versiones de GitHub
+configuration_advanced_updates_source_tooltip = URL o ruta absoluta de un archivo JSON en formato de la API de versiones de GitHub para comprobar en lugar de GitHub, útil para pruebas y espejos, déjelo vacío para usar GitHub
 configuration_button_add_farm = Agregar granja
 configuration_button_help = Ayuda
 configuration_button_cancel = Cancelar
@@ -96,6 +206,12 @@ configuration_button_save = Guardar
 configuration_button_start = Iniciar
 configuration_dialog_button_select = Seleccionar
 configuration_dialog_button_cancel = Cancelar
+network_identity_dialog_button_export = Exportar
+network_identity_dialog_button_import = Importar
+network_identity_rotate_confirm_heading = ¿Generar una nueva identidad de red?
+network_identity_rotate_confirm_body = El ID de par de este nodo cambiará tras el reinicio y los pares que lo referencian por ID de par tendrán que actualizarse. La identidad anterior se conservará en secret_ed25519.<ID de par>.bak en el directorio de red de los datos del nodo.
+network_identity_rotate_confirm_button_cancel = Cancelar
+network_identity_rotate_confirm_button_rotate = Generar
 
 node_migration_button_cancel = Cancelar
 node_migration_button_reset = Reiniciar nodo
@@ -128,6 +244,46 @@ running_node_title = {$chain_name} nodo de consenso
 running_node_title_tooltip = Abrir sistema de archivos
 running_node_free_disk_space_tooltip = Espacio libre en disco: {$size} restante
 running_node_connections_tooltip = {$connected_peers}/{$expected_peers} pares conectados, clique para ver más detalles de los puertos P2P necesarios
+running_node_peer_id = ID de par: {$peer_id}
+running_node_network_identity_tooltip = Identidad de red
+running_node_network_identity_export = Exportar identidad de red…
+running_node_network_identity_export_warning = El archivo exportado contiene la clave secreta de este nodo, cualquiera que lo tenga puede suplantar a este nodo en la red, manténgalo privado
+running_node_network_identity_import = Importar identidad de red…
+running_node_network_identity_rotate = Generar nueva identidad de red
+running_node_network_identity_replace_warning = Importar o generar una identidad de red cambia el ID de par tras reiniciar, los pares que hacen referencia a este nodo por su ID de par deberán actualizarse
+running_node_peers_button = Pares
+running_node_peers_button_tooltip = Inspeccionar pares conectados, útil cuando la sincronización se detiene
+running_node_peers_substrate = Pares del nodo de consenso (Substrate): {$count}
+running_node_peers_dsn = Pares DSN: {$count}
+running_node_known_peers = Caché de pares conocidos
+running_node_known_peers_description = Direcciones de pares DSN recordadas entre reinicios para reconectar más rápido. Vacíela si la reconexión es lenta tras un cambio de dirección IP o de red, también se vacía automáticamente tras una semana sin conexión.
+running_node_known_peers_inspect = Inspeccionar
+running_node_known_peers_clear = Vaciar
+running_node_peers_role_authority = autoridad
+running_node_peers_role_full = completo
+running_node_peers_role_light = ligero
+running_node_peers_direction_inbound = entrante
+running_node_peers_direction_outbound = saliente
+running_node_peers_address_unknown = dirección desconocida
+running_node_peers_none = No hay pares conectados
+running_node_piece_serving = {$pieces} piezas servidas, {$upload_rate}/s
+running_node_piece_serving_tooltip =
+    Datos servidos a otros farmers a través de DSN:
+    piezas desde la caché: {$cache_hits}
+    piezas desde los plots: {$plot_hits}
+    piezas no encontradas: {$misses}
+    pares más cercanos enviados en lugar de una pieza: {$closest_peers_fallbacks}
+    encabezados de segmento: {$segment_headers}
+    total subido: {$bytes_uploaded}
+running_node_bandwidth = Últimas 24 h: ↓ {$downloaded} ↑ {$uploaded}
+running_node_bandwidth_tooltip =
+    Última hora: ↓ {$hour_downloaded} ↑ {$hour_uploaded}
+    Últimas 24 horas, nodo de consenso: ↓ {$substrate_downloaded} ↑ {$substrate_uploaded}
+    Últimas 24 horas, DSN (solo piezas, sin DHT, gossip ni sobrecarga de protocolo): ↓ {$dsn_downloaded} ↑ {$dsn_uploaded}
+    Límites diarios: ↓ {$download_cap} ↑ {$upload_cap}
+running_node_bandwidth_no_cap = sin límite
+running_node_bandwidth_download_cap_reached = Límite de descarga alcanzado, las descargas de plotting se reducen y las conexiones DSN se reducirán tras reiniciar
+running_node_bandwidth_upload_cap_reached = Límite de subida alcanzado, las conexiones DSN se reducirán tras reiniciar
 running_node_status_connecting = Conectando a la red, mejor bloque #{$block_number}
 running_node_status_syncing_speed_no_eta = , {NUMBER($blocks_per_second, minimumFractionDigits: 2, maximumFractionDigits: 2)} bloques/s
 running_node_status_syncing_speed_hours_eta = , {NUMBER($a_blocks_per_second, minimumFractionDigits: 2, maximumFractionDigits: 2)} bloques/s (~{NUMBER($b_hours_remaining, minimumFractionDigits: 2, maximumFractionDigits: 2)} horas restantes)
@@ -146,6 +302,15 @@ running_farmer_button_pause_plotting = Pausar creación y sustitución de parcel
 running_farmer_button_resume_plotting = Reanudar la creación de la parcela
 running_farmer_account_balance_tooltip = Balance total de la cuenta y monedas granjeadas desde que la aplicación se inició, clica para ver más detalles en Astral
 running_farmer_piece_cache_sync = Caché sincronizada {NUMBER($percentage, minimumFractionDigits: 2, maximumFractionDigits: 2)}%
+running_farmer_piece_downloads = Piezas descargadas: {$local} locales, {$dsn_cache} caché DSN, {$archival} archivo, {$node} nodo, {$failed} fallidas
+running_farmer_piece_downloads_tooltip =
+    Piezas obtenidas para plotting, sincronización de la caché de piezas y sincronización del nodo:
+    desde la caché de piezas local y los plots: {$local}
+    desde la caché DSN: {$dsn_cache}
+    desde el almacenamiento de archivo: {$archival}
+    desde el nodo: {$node}
+    piezas inválidas recibidas de pares DSN: {$invalid}
+    no obtenidas de ninguna fuente: {$failed}
 running_farmer_next_reward_estimate =
     Próxima recompensa estimada para: {$eta_string ->
         [any_time_now] En cualquier momento
@@ -160,7 +325,10 @@ running_farmer_farm_reward_signatures_tooltip = {$successful_signatures}/{$total
 running_farmer_farm_auditing_performance_tooltip = Auditando eficiencia: tiempo medio {NUMBER($a_average_time, minimumFractionDigits: 2, maximumFractionDigits: 2)}s, tiempo límite {NUMBER($b_time_timit, minimumFractionDigits: 2, maximumFractionDigits: 2)}s
 running_farmer_farm_proving_performance_tooltip = Demostrando eficiencia: tiempo medio {NUMBER($a_average_time, minimumFractionDigits: 2, maximumFractionDigits: 2)}s, tiempo límite {NUMBER($b_time_timit, minimumFractionDigits: 2, maximumFractionDigits: 2)}s
 running_farmer_farm_non_fatal_error_tooltip = Ha ocurrido un error pero se ha conseguido recuperar, mira la traza para más información: {$error}
+running_farmer_farm_free_disk_space_tooltip = Espacio libre en disco: {$size} restante
 running_farmer_farm_crashed = Ha ocurrido un error en la granja que no se ha conseguido recuperar: {$error}
+running_farmer_farm_disk_missing = Falta el disco de la granja, la granja está detenida hasta que el disco se vuelva a conectar
+running_farmer_farm_disk_read_only = El disco de la granja es de solo lectura, la granja está detenida hasta que se pueda volver a escribir en el disco
 running_farmer_farm_plotting_speed =  ({NUMBER($a_sector_time, minimumFractionDigits: 2, maximumFractionDigits: 2)} m/sector, {NUMBER($b_sectors_per_hour, minimumFractionDigits: 2, maximumFractionDigits: 2)} sectores/h)
 running_farmer_farm_plotting_initial =
     {$pausing_state ->
@@ -204,6 +372,8 @@ error_title = Error
 error_message = Error: {$error}
 error_message_failed_to_send_config_to_backend = Fallo al enviar la configuración al backend: {$error}
 error_message_failed_to_send_pause_plotting_to_backend = Fallo al enviar la pausa de la granja al backend: {$error}
+error_message_failed_to_send_network_identity_action_to_backend = No se pudo enviar la acción de identidad de red al backend: {$error}
+error_message_failed_to_send_known_peers_action_to_backend = No se pudo enviar la acción de pares conocidos al backend: {$error}
 error_button_help_from_community = Ayuda de la comunidad
 error_button_reset_node = Reiniciar nodo
 error_button_reset_node_tooltip = Borrar datos del nodo y sincronizar de nuevo desde la red
@@ -211,6 +381,17 @@ error_button_show_logs = Mira la traza
 
 new_version_available = Versión {$version} disponible 🎉
 new_version_available_button_open = Abrir página de actualizaciones
+new_version_available_button_release_notes = Mostrar notas de la versión
+new_version_release_notes_title = Novedades de {$version}
+new_version_release_notes_empty = No se proporcionaron notas de la versión
+new_version_update_button_install = Descargar e instalar
+new_version_update_downloading = Descargando la versión {$version}…
+new_version_update_ready = Instalar la versión {$version}
+new_version_update_installing = Instalando la versión {$version}…
+new_version_update_confirm_heading = ¿Instalar la versión {$version}?
+new_version_update_confirm_body = La actualización se descargó y se verificó su firma, Space Acres se reiniciará para completar la instalación
+new_version_update_confirm_button_cancel = Cancelar
+new_version_update_confirm_button_install = Instalar y reiniciar
 
 main_menu_show_logs = Mira la traza en el sistema de archivos
 main_menu_change_configuration = Cambiar configuración
@@ -221,6 +402,17 @@ main_menu_exit = Salir
 status_bar_message_configuration_is_invalid = La configuración es invalida: {$error}
 status_bar_message_restart_is_needed_for_configuration = La aplicación necesita reiniciarse para que los cambios tengan efecto
 status_bar_message_failed_to_save_configuration = Fallo al guardar los cambios: {$error}
+status_bar_message_network_identity_exported = La identidad de red se exportó a {$path}, mantenga este archivo privado
+status_bar_message_restart_is_needed_for_network_identity = Es necesario reiniciar la aplicación para que la nueva identidad de red con ID de par {$peer_id} surta efecto
+status_bar_message_failed_to_update_network_identity = No se pudo actualizar la identidad de red: {$error}
+status_bar_message_known_peers_cache = La caché de pares conocidos {$path} ocupa {$size}, actualizada hace {$hours} horas
+status_bar_message_known_peers_cache_unknown_age = La caché de pares conocidos {$path} ocupa {$size}
+status_bar_message_known_peers_cache_empty = La caché de pares conocidos está vacía
+status_bar_message_known_peers_cache_reset_scheduled = La caché de pares conocidos se vaciará en el próximo inicio
+status_bar_message_restart_is_needed_for_known_peers_cache = Es necesario reiniciar la aplicación para vaciar la caché de pares conocidos
+status_bar_message_restart_is_needed_for_bandwidth_cap = Límite de ancho de banda alcanzado, es necesario reiniciar la aplicación para reducir las conexiones DSN
+status_bar_message_failed_to_manage_known_peers_cache = No se pudo gestionar la caché de pares conocidos: {$error}
+status_bar_message_failed_to_update = No se pudo actualizar: {$error}
 status_bar_message_restarted_after_crash = Space Acres se ha reiniciado automáticamente después de un error, mira la traza de la aplicación y del sistema para más detalles.
 status_bar_button_migrate = Migrar
 status_bar_button_ok = Vale
@@ -239,8 +431,16 @@ notification_stopped_with_error = Space Acres se detuvo con un error
     .body = Ocurrió un error y se requiere la intervención del usuario para resolverlo
 notification_farm_error = Una de las granjas falló en Space Acres
     .body = Ocurrió un error y se requiere la intervención del usuario para resolverlo
+notification_farm_disk_missing = Falta el disco de la granja
+    .body = La granja {$path} está detenida hasta que el disco se vuelva a conectar, las demás granjas siguen funcionando
+notification_farm_disk_read_only = El disco de la granja es de solo lectura
+    .body = La granja {$path} está detenida hasta que se pueda volver a escribir en el disco, las demás granjas siguen funcionando
+notification_farm_disk_available = El disco de la granja vuelve a estar disponible
+    .body = La granja {$path} se ha reincorporado y sigue funcionando
 notification_node_low_disk_space = Poco espacio en disco del nodo
     .body = El volumen del nodo sólo tiene {$free_space} restante
+notification_farm_low_disk_space = Poco espacio en disco de la granja
+    .body = El volumen de la granja {$farm_path} sólo tiene {$free_space} restante
 notification_missed_reward = Falló la firma de la recompensa 😞
     .body = Esto es desafortunado, pero habrá otra oportunidad pronto
 notification_signed_reward_successfully = Nueva recompensa firmada con éxito 🥳
@@ -30,6 +30,7 @@ loading_configuration_step_decoded_chain_spec = Spécification de la blockchain
 loading_networking_stack_title = Initialisation de la pile réseau
 loading_networking_stack_step_checking_node_path = Vérification du chemin des données de la blockchain...
 loading_networking_stack_step_creating_node_path = Création du dossier des données de la blockchain...
+loading_networking_stack_step_resetting_node_database = Mode de synchronisation ou stockage du nœud changé, suppression de la base de données du nœud...
 loading_networking_stack_step_node_path_ready = Le dossier des données de la blockchain est prêt
 loading_networking_stack_step_preparing = Préparation de la pile réseau...
 loading_networking_stack_step_reading_keypair = Lecture de la paire de clés réseau...
@@ -40,8 +41,16 @@ loading_networking_stack_step_created_successfully = Pile réseau créée avec s
 loading_consensus_node_title = Initialisation du nœud de consensus
 loading_consensus_node_step_creating = Création du nœud de consensus...
 loading_consensus_node_step_created_successfully = Nœud de consensus créé avec succès
+loading_consensus_node_step_connecting_to_external_node = Connexion au nœud externe à {$url}...
+loading_consensus_node_step_connected_to_external_node_successfully = Connecté au nœud externe avec succès
 loading_farmer_title = Création de la ferme
 loading_farmer_step_initializing = Initialisation de la ferme {$index}/{$farms_total}...
+loading_farmer_step_initializing_farm_reading_metadata = Ferme {$farm_index} : lecture des métadonnées ({$time_elapsed} écoulé)
+loading_farmer_step_initializing_farm_allocating = Ferme {$farm_index} : allocation de l'espace {$space_allocated}/{$space_total} ({$time_elapsed} écoulé, ~{$time_remaining} restant)
+loading_farmer_step_initializing_farm_allocating_no_eta = Ferme {$farm_index} : allocation de l'espace {$space_allocated}/{$space_total} ({$time_elapsed} écoulé)
+loading_farmer_step_initializing_farm_initializing_caches = Ferme {$farm_index} : initialisation du cache de pièces et du cache de plot ({$time_elapsed} écoulé)
+loading_farmer_step_collecting_plotted_pieces = Collecte des pièces déjà tracées (cela prendra un certain temps)...
+loading_farmer_step_collecting_plotted_pieces_farm = Ferme {$farm_index} : {$sectors_collected}/{$sectors_plotted} secteurs
 loading_farmer_step_created_successfully = Ferme créée avec succès
 loading_wiping_farmer_data_title = Suppression des données de la ferme
 loading_wiping_farmer_data_step_wiping_farm = Suppression de la ferme {$index}/{$farms_total} dans {$path}...
@@ -77,17 +86,118 @@ configuration_farm_fixed_size_placeholder = Exemple : 4T, 2,5To, 500Gio, etc.
 configuration_farm_fixed_size_tooltip = Taille de la ferme en fonction des unités que vous préférez. Toute taille supérieure à 2 Go convient
 configuration_farm_free_percentage_size_placeholder = Exemple : 100%, 1,1%, etc.
 configuration_farm_free_percentage_size_tooltip = Pourcentage de l'espace disque libre à occuper par cette ferme. Tout ce qui dépasse 0 % fonctionne, mais il est conseillé de laisser au moins 2 Go d'espace libre sur le disque pour éviter les erreurs
+configuration_farm_free_space_warning_threshold_placeholder = Alerte d'espace faible : {$default_threshold}
+configuration_farm_free_space_warning_threshold_tooltip = Espace libre sur le disque de la ferme en dessous duquel une alerte d'espace faible est affichée, par exemple 500MiB ou 5GB, laissez vide pour utiliser la valeur par défaut
 configuration_farm_delete = Supprimer cette ferme
+configuration_no_farms_node_only = Aucune ferme configurée, seul le nœud fonctionnera sans farming
+configuration_no_farms_external_node = Au moins une ferme est nécessaire avec un nœud externe, sinon rien ne fonctionnera
 configuration_advanced = Configuration avancée
 configuration_advanced_farmer = Configuration de la ferme
 configuration_advanced_farmer_reduce_plotting_cpu_load = Réduire la charge du processeur pendant le plotting
 configuration_advanced_farmer_reduce_plotting_cpu_load_tooltip = Le plotting initial utilise tous les cœurs du processeur par défaut. Cette option réduit la charge à la moitié des cœurs, ce qui permet d'utiliser l'ordinateur pour d'autres tâches
+configuration_advanced_node = Configuration du nœud
+configuration_advanced_node_name = Nom du nœud :
+configuration_advanced_node_name_placeholder = Généré automatiquement au premier démarrage
+configuration_advanced_node_name_tooltip = Nom utilisé par le nœud sur le réseau et dans la télémétrie, jusqu'à {$max_length} caractères
+configuration_advanced_node_telemetry = Télémétrie :
+configuration_advanced_node_telemetry_tooltip = Signaler le nom, la version et l'état de synchronisation du nœud à la télémétrie publique, aide à surveiller la santé du réseau
+configuration_advanced_node_sync_mode = Mode de synchronisation :
+configuration_advanced_node_sync_mode_tooltip = Comment le nœud synchronise la chaîne, le changer supprime la base de données du nœud et la resynchronise depuis le début
+configuration_advanced_node_sync_mode_snap = Snap
+configuration_advanced_node_sync_mode_full = Complète
+configuration_advanced_node_sync_mode_snap_explanation = Télécharge l'état récent et uniquement les blocs nécessaires au farming, prêt à farmer bien plus tôt
+configuration_advanced_node_sync_mode_full_explanation = Télécharge et vérifie chaque bloc depuis la genèse, prend beaucoup plus de temps, mais ne dépend pas de l'état fourni par d'autres nœuds
+configuration_advanced_node_sync_mode_changed_warning = La base de données du nœud sera supprimée et resynchronisée au prochain démarrage
+configuration_advanced_node_pruning = Stockage du nœud :
+configuration_advanced_node_pruning_tooltip = Quelle part de l'historique de la chaîne le nœud conserve sur le disque, en conserver davantage permet d'interroger l'état historique localement, passer au mode archive ou en sortir nécessite de supprimer les données du nœud et de resynchroniser
+configuration_advanced_node_pruning_kind_minimum = Minimum (recommandé)
+configuration_advanced_node_pruning_kind_blocks = Conserver les blocs récents
+configuration_advanced_node_pruning_kind_archive = Archive (tout)
+configuration_advanced_node_pruning_blocks_tooltip = Nombre de blocs les plus récents à conserver, au moins {$min_blocks}
+configuration_advanced_node_pruning_estimate = Utilisation disque estimée : ~{$size}
+configuration_advanced_node_pruning_estimate_archive = Utilisation disque estimée : augmente avec la chaîne d'environ {$size} par jour
+configuration_advanced_node_pruning_changed_warning = La base de données du nœud sera supprimée et resynchronisée au prochain démarrage
+configuration_advanced_node_rpc = Serveur JSON-RPC :
+configuration_advanced_node_rpc_tooltip = Exposer le point de terminaison JSON-RPC du nœud pour les portefeuilles, scripts et autres outils
+configuration_advanced_node_rpc_listen_on = Adresse d'écoute :
+configuration_advanced_node_rpc_listen_on_tooltip = Adresse IP et port, par exemple 127.0.0.1:9944, utilisez 0.0.0.0 pour écouter sur toutes les interfaces
+configuration_advanced_node_rpc_unsafe_methods = Méthodes non sécurisées :
+configuration_advanced_node_rpc_unsafe_methods_tooltip = Autoriser les méthodes pouvant modifier le comportement du nœud, ne jamais les exposer à des réseaux non fiables
+configuration_advanced_node_external_node = Nœud externe :
+configuration_advanced_node_external_node_placeholder = Exemple : {$url}
+configuration_advanced_node_external_node_tooltip = URL RPC WebSocket d'un nœud existant avec lequel farmer au lieu d'exécuter le nœud intégré, laisser vide pour exécuter le nœud intégré
 configuration_advanced_network = Configuration réseau
 configuration_advanced_network_default_port_number_tooltip = Le numéro de port par défaut est {$port}
+configuration_advanced_network_listen_on = Écouter sur :
+configuration_advanced_network_listen_on_tooltip = Interfaces réseau sur lesquelles accepter les connexions entrantes, une adresse spécifique est utile sur les serveurs avec plusieurs interfaces réseau
+configuration_advanced_network_listen_on_all = Toutes les interfaces
+configuration_advanced_network_listen_on_ipv4 = IPv4 uniquement
+configuration_advanced_network_listen_on_specific = Adresse spécifique
+configuration_advanced_network_listen_on_custom = Personnalisé (fichier de configuration)
+configuration_advanced_network_listen_on_custom_explanation = Les adresses du fichier de configuration sont utilisées, les ports ci-dessous sont ignorés. Substrate : {$substrate_addresses} ; DSN : {$dsn_addresses}
+configuration_advanced_network_listen_on_address_placeholder = 192.168.1.10
 configuration_advanced_network_substrate_port = Port P2P Substrate (blockchain) (TCP) :
 configuration_advanced_network_subspace_port = Port P2P Subspace (DSN) (TCP) :
-configuration_advanced_network_faster_networking = Réseau rapide :
-configuration_advanced_network_faster_networking_tooltip = Par défaut, les paramètres réseau sont optimisés pour les routeurs domestiques. Si vous disposez d'un équipement plus performant, cette option peut améliorer la vitesse de synchronisation et d'autres processus
+configuration_advanced_network_pick_free_ports = Choisir des ports libres
+configuration_advanced_network_pick_free_ports_tooltip = Remplacer les deux ports par les premiers ports à partir des valeurs par défaut qui ne sont utilisés par aucune autre application
+configuration_advanced_network_error_port_in_use = Le port {$port} est déjà utilisé par une autre application, choisissez un autre port
+configuration_advanced_network_error_duplicate_port = Le port {$port} ne peut pas être utilisé à la fois par Substrate et DSN, choisissez des ports différents
+configuration_advanced_network_connections = Connexions :
+configuration_advanced_network_connections_tooltip = Limites du nombre de connexions, des limites basses aident avec les connexions limitées et les routeurs peu puissants, tandis que des limites élevées sur des configurations plus puissantes peuvent améliorer la vitesse de synchronisation et d'autres processus
+configuration_advanced_network_connections_low = Basse
+configuration_advanced_network_connections_default = Par défaut
+configuration_advanced_network_connections_high = Haute
+configuration_advanced_network_connections_custom = Personnalisée
+configuration_advanced_network_connections_dsn_in = Connexions DSN entrantes :
+configuration_advanced_network_connections_dsn_out = Connexions DSN sortantes :
+configuration_advanced_network_connections_dsn_pending_in = Connexions DSN entrantes en attente :
+configuration_advanced_network_connections_dsn_pending_out = Connexions DSN sortantes en attente :
+configuration_advanced_network_connections_substrate_in = Pairs Substrate entrants :
+configuration_advanced_network_connections_substrate_out = Pairs Substrate sortants :
+configuration_advanced_network_piece_download = Téléchargements de pièces :
+configuration_advanced_network_piece_download_tooltip = Politique de nouvelles tentatives et concurrence des téléchargements de pièces utilisés par le plotting et la synchronisation du nœud
+configuration_advanced_network_piece_download_max_retries = Nouvelles tentatives :
+configuration_advanced_network_piece_download_initial_retry_interval = Intervalle initial entre tentatives (secondes) :
+configuration_advanced_network_piece_download_max_retry_interval = Intervalle maximal entre tentatives (secondes) :
+configuration_advanced_network_piece_download_concurrency_multiplier = Multiplicateur de concurrence par connexion DSN sortante :
+configuration_advanced_network_peer_multiaddrs_placeholder = /dns/example.com/tcp/30333/p2p/12D3KooW...
+configuration_advanced_network_substrate_bootstrap_nodes = Nœuds d'amorçage Substrate :
+configuration_advanced_network_substrate_bootstrap_nodes_tooltip = Nœuds Substrate (blockchain) supplémentaires pour découvrir le réseau, multiaddrs séparées par des virgules se terminant par /p2p/<ID du pair>
+configuration_advanced_network_substrate_reserved_nodes = Nœuds réservés Substrate :
+configuration_advanced_network_substrate_reserved_nodes_tooltip = Nœuds Substrate (blockchain) auxquels rester toujours connecté, multiaddrs séparées par des virgules se terminant par /p2p/<ID du pair>
+configuration_advanced_network_dsn_bootstrap_nodes = Nœuds d'amorçage DSN :
+configuration_advanced_network_dsn_bootstrap_nodes_tooltip = Nœuds Subspace (DSN) supplémentaires pour découvrir le réseau, multiaddrs séparées par des virgules se terminant par /p2p/<ID du pair>
+configuration_advanced_network_dsn_reserved_peers = Pairs réservés DSN :
+configuration_advanced_network_dsn_reserved_peers_tooltip = Pairs Subspace (DSN) auxquels rester toujours connecté, multiaddrs séparées par des virgules se terminant par /p2p/<ID du pair>
+configuration_advanced_network_substrate_external_addresses = Adresses externes Substrate :
+configuration_advanced_network_substrate_external_addresses_placeholder = /ip4/203.0.113.1/tcp/30333
+configuration_advanced_network_substrate_external_addresses_tooltip = Adresses Substrate (blockchain) accessibles publiquement à annoncer aux autres pairs, par exemple lorsque la redirection de port est configurée sur le routeur, multiaddrs séparées par des virgules
+configuration_advanced_network_dsn_external_addresses = Adresses externes DSN :
+configuration_advanced_network_dsn_external_addresses_placeholder = /ip4/203.0.113.1/tcp/30433
+configuration_advanced_network_dsn_external_addresses_tooltip = Adresses Subspace (DSN) accessibles publiquement à annoncer aux autres pairs, par exemple lorsque la redirection de port est configurée sur le routeur, multiaddrs séparées par des virgules
+configuration_advanced_network_daily_download_cap = Plafond quotidien de téléchargement :
+configuration_advanced_network_daily_download_cap_tooltip = Plafond souple de téléchargement des deux réseaux sur les dernières 24 heures, le trafic DSN autre que les pièces n'est pas compté. Une fois atteint, les téléchargements de plotting sont réduits et les connexions DSN le sont après redémarrage. Laissez vide pour aucun plafond
+configuration_advanced_network_daily_upload_cap = Plafond quotidien d'envoi :
+configuration_advanced_network_daily_upload_cap_tooltip = Plafond souple d'envoi des deux réseaux sur les dernières 24 heures, le trafic DSN autre que les pièces n'est pas compté. Une fois atteint, les connexions DSN sont réduites après redémarrage. Laissez vide pour aucun plafond
+configuration_advanced_network_proxy_url = URL du proxy :
+configuration_advanced_network_proxy_url_tooltip = Proxy HTTP, HTTPS ou SOCKS5 utilisé pour la vérification des mises à jour, par exemple http://127.0.0.1:8080 ou socks5h://127.0.0.1:1080 (socks5h résout les noms d'hôte via le proxy), laisser vide pour une connexion directe. Les connexions P2P du nœud et du farmer ne prennent pas en charge les proxys
+configuration_advanced_network_proxy_url_placeholder = http://hôte:port
+configuration_advanced_network_proxy_username = Nom d'utilisateur du proxy :
+configuration_advanced_network_proxy_username_tooltip = Nom d'utilisateur pour l'authentification du proxy, laisser vide si le proxy ne requiert pas d'authentification
+configuration_advanced_network_proxy_username_placeholder = facultatif
+configuration_advanced_network_proxy_password = Mot de passe du proxy :
+configuration_advanced_network_proxy_password_tooltip = Mot de passe pour l'authentification du proxy, nécessite un nom d'utilisateur, stocké en clair dans le fichier de configuration
+configuration_advanced_network_daily_cap_placeholder = Par exemple 50 GB, vide pour aucun plafond
+configuration_advanced_network_allow_private_addresses = Autoriser les adresses privées :
+configuration_advanced_network_allow_private_addresses_tooltip = Se connecter et découvrir des pairs avec des adresses privées, par exemple d'autres farmers sur le même réseau local
+configuration_advanced_updates = Mises à jour
+configuration_advanced_updates_check = Rechercher des mises à jour :
+configuration_advanced_updates_check_tooltip = Vérifier périodiquement si une nouvelle version de Space Acres est disponible
+configuration_advanced_updates_include_prereleases = Inclure les préversions :
+configuration_advanced_updates_include_prereleases_tooltip = Notifier également les préversions, qui peuvent être moins stables
+configuration_advanced_updates_source = Source des mises à jour :
+configuration_advanced_updates_source_placeholder = Versions GitHub
+configuration_advanced_updates_source_tooltip = URL ou chemin absolu d'un fichier JSON au format de l'API des versions GitHub à vérifier à la place de GitHub, utile pour les tests et les miroirs, laisser vide pour utiliser GitHub
 configuration_button_add_farm = Ajouter une ferme
 configuration_button_help = Aide
 configuration_button_cancel = Annuler
@@ -96,6 +206,12 @@ configuration_button_save = Sauvegarder
 configuration_button_start = Démarrer
 configuration_dialog_button_select = Sélectionner
 configuration_dialog_button_cancel = Annuler
+network_identity_dialog_button_export = Exporter
+network_identity_dialog_button_import = Importer
+network_identity_rotate_confirm_heading = Générer une nouvelle identité réseau ?
+network_identity_rotate_confirm_body = L'ID de pair de ce nœud changera après le redémarrage et les pairs qui le référencent par ID de pair devront être mis à jour. L'identité précédente sera conservée dans secret_ed25519.<ID de pair>.bak dans le répertoire réseau des données du nœud.
+network_identity_rotate_confirm_button_cancel = Annuler
+network_identity_rotate_confirm_button_rotate = Générer
 
 node_migration_button_cancel = Annuler
 node_migration_button_reset = Réinitialiser le nœud
@@ -128,6 +244,46 @@ running_node_title = {$chain_name} nœud de la blockchain
 running_node_title_tooltip = Cliquez pour ouvrir dans le gestionnaire de fichiers
 running_node_free_disk_space_tooltip = Espace disque libre restant : {$size}
 running_node_connections_tooltip = {$connected_peers}/{$expected_peers} pairs connectées, cliquez pour plus de détails sur les ports P2P requis
+running_node_peer_id = ID de pair : {$peer_id}
+running_node_network_identity_tooltip = Identité réseau
+running_node_network_identity_export = Exporter l'identité réseau…
+running_node_network_identity_export_warning = Le fichier exporté contient la clé secrète de ce nœud, toute personne qui le possède peut usurper l'identité de ce nœud sur le réseau, gardez-le privé
+running_node_network_identity_import = Importer l'identité réseau…
+running_node_network_identity_rotate = Générer une nouvelle identité réseau
+running_node_network_identity_replace_warning = L'importation ou la génération d'une identité réseau change l'ID de pair après le redémarrage, les pairs qui référencent ce nœud par son ID de pair devront être mis à jour
+running_node_peers_button = Pairs
+running_node_peers_button_tooltip = Inspecter les pairs connectés, utile lorsque la synchronisation est bloquée
+running_node_peers_substrate = Pairs du nœud de consensus (Substrate) : {$count}
+running_node_peers_dsn = Pairs DSN : {$count}
+running_node_known_peers = Cache des pairs connus
+running_node_known_peers_description = Adresses des pairs DSN mémorisées entre les redémarrages pour une reconnexion plus rapide. Videz-le si la reconnexion est lente après un changement d'adresse IP ou de réseau, il est aussi vidé automatiquement après une semaine hors ligne.
+running_node_known_peers_inspect = Inspecter
+running_node_known_peers_clear = Vider
+running_node_peers_role_authority = autorité
+running_node_peers_role_full = complet
+running_node_peers_role_light = léger
+running_node_peers_direction_inbound = entrant
+running_node_peers_direction_outbound = sortant
+running_node_peers_address_unknown = adresse inconnue
+running_node_peers_none = Aucun pair connecté
+running_node_piece_serving = {$pieces} pièces servies, {$upload_rate}/s
+running_node_piece_serving_tooltip =
+    Données servies aux autres farmers via le DSN :
+    pièces depuis le cache : {$cache_hits}
+    pièces depuis les plots : {$plot_hits}
+    pièces introuvables : {$misses}
+    pairs les plus proches envoyés à la place d'une pièce : {$closest_peers_fallbacks}
+    en-têtes de segment : {$segment_headers}
+    total envoyé : {$bytes_uploaded}
+running_node_bandwidth = Dernières 24 h : ↓ {$downloaded} ↑ {$uploaded}
+running_node_bandwidth_tooltip =
+    Dernière heure : ↓ {$hour_downloaded} ↑ {$hour_uploaded}
+    Dernières 24 heures, nœud de consensus : ↓ {$substrate_downloaded} ↑ {$substrate_uploaded}
+    Dernières 24 heures, DSN (pièces uniquement, sans DHT, gossip ni surcoût protocolaire) : ↓ {$dsn_downloaded} ↑ {$dsn_uploaded}
+    Plafonds quotidiens : ↓ {$download_cap} ↑ {$upload_cap}
+running_node_bandwidth_no_cap = aucun plafond
+running_node_bandwidth_download_cap_reached = Plafond de téléchargement atteint, les téléchargements de plotting sont réduits et les connexions DSN le seront après redémarrage
+running_node_bandwidth_upload_cap_reached = Plafond d'envoi atteint, les connexions DSN seront réduites après redémarrage
 running_node_status_connecting = Connexion au réseau, meilleur bloc #{$block_number}
 running_node_status_syncing_speed_no_eta = , {NUMBER($blocks_per_second, minimumFractionDigits: 2, maximumFractionDigits: 2)} blocs/s
 running_node_status_syncing_speed_hours_eta = , {NUMBER($a_blocks_per_second, minimumFractionDigits: 2, maximumFractionDigits: 2)} blocs/s (~{NUMBER($b_hours_remaining, minimumFractionDigits: 2, maximumFractionDigits: 2)} heures restantes)
@@ -146,6 +302,15 @@ running_farmer_button_pause_plotting = Suspendre le plotting/replotting. Notez q
 running_farmer_button_resume_plotting = Reprendre le plotting
 running_farmer_account_balance_tooltip = Solde total et pièces gagnées depuis le lancement de l'application. Cliquez pour voir les détails dans Astral
 running_farmer_piece_cache_sync = Synchronisation du cache de morceaux à {NUMBER($percentage, minimumFractionDigits: 2, maximumFractionDigits: 2)}%
+running_farmer_piece_downloads = Pièces téléchargées : {$local} locales, {$dsn_cache} cache DSN, {$archival} archive, {$node} nœud, {$failed} en échec
+running_farmer_piece_downloads_tooltip =
+    Pièces récupérées pour le plotting, la synchronisation du cache de pièces et la synchronisation du nœud :
+    depuis le cache de pièces local et les plots : {$local}
+    depuis le cache DSN : {$dsn_cache}
+    depuis le stockage d'archive : {$archival}
+    depuis le nœud : {$node}
+    pièces invalides reçues de pairs DSN : {$invalid}
+    impossibles à récupérer depuis aucune source : {$failed}
 running_farmer_next_reward_estimate =
     Prochaine récompense : {$eta_string ->
         [any_time_now] à tout moment
@@ -160,7 +325,10 @@ running_farmer_farm_reward_signatures_tooltip = {$successful_signatures}/{$total
 running_farmer_farm_auditing_performance_tooltip = Performance de l'audit : temps moyen {NUMBER($a_average_time, minimumFractionDigits: 2, maximumFractionDigits: 2)}s, limite de temps {NUMBER($b_time_timit, minimumFractionDigits: 2, maximumFractionDigits: 2)}s
 running_farmer_farm_proving_performance_tooltip = Performance de la preuve : temps moyen {NUMBER($a_average_time, minimumFractionDigits: 2, maximumFractionDigits: 2)}s, limite de temps {NUMBER($b_time_timit, minimumFractionDigits: 2, maximumFractionDigits: 2)}s
 running_farmer_farm_non_fatal_error_tooltip = Une erreur est survenue lors du farming, mais elle a été corrigée. Consultez le journal pour plus de détails : {$error}
+running_farmer_farm_free_disk_space_tooltip = Espace disque libre restant : {$size}
 running_farmer_farm_crashed = Le farming a échoué : {$error}
+running_farmer_farm_disk_missing = Le disque de la ferme est introuvable, la ferme est arrêtée jusqu'à ce que le disque soit reconnecté
+running_farmer_farm_disk_read_only = Le disque de la ferme est en lecture seule, la ferme est arrêtée jusqu'à ce que le disque soit de nouveau accessible en écriture
 running_farmer_farm_plotting_speed = ({NUMBER($a_sector_time, minimumFractionDigits: 2, maximumFractionDigits: 2)} min/secteur, {NUMBER($b_sectors_per_hour, minimumFractionDigits: 2, maximumFractionDigits: 2)} secteur/heure)
 running_farmer_farm_plotting_initial =
     {$pausing_state ->
@@ -204,6 +372,8 @@ error_title = Erreur
 error_message = Erreur : {$error}
 error_message_failed_to_send_config_to_backend = Échec de l'envoi de la configuration au système interne : {$error}
 error_message_failed_to_send_pause_plotting_to_backend = Échec de l'envoi de la mise en pause du plotting au système interne : {$error}
+error_message_failed_to_send_network_identity_action_to_backend = Échec de l'envoi de l'action d'identité réseau au backend : {$error}
+error_message_failed_to_send_known_peers_action_to_backend = Échec de l'envoi de l'action des pairs connus au backend : {$error}
 error_button_help_from_community = Aide de la communauté
 error_button_reset_node = Réinitialiser le nœud
 error_button_reset_node_tooltip = Effacer les données du nœud et resynchroniser depuis le réseau
@@ -211,6 +381,17 @@ error_button_show_logs = Voir le journal
 
 new_version_available = Nouvelle version {$version} disponible 🎉
 new_version_available_button_open = Aller aux versions
+new_version_available_button_release_notes = Afficher les notes de version
+new_version_release_notes_title = Nouveautés de {$version}
+new_version_release_notes_empty = Aucune note de version fournie
+new_version_update_button_install = Télécharger et installer
+new_version_update_downloading = Téléchargement de la version {$version}…
+new_version_update_ready = Installer la version {$version}
+new_version_update_installing = Installation de la version {$version}…
+new_version_update_confirm_heading = Installer la version {$version} ?
+new_version_update_confirm_body = La mise à jour a été téléchargée et sa signature vérifiée, Space Acres va redémarrer pour terminer l'installation
+new_version_update_confirm_button_cancel = Annuler
+new_version_update_confirm_button_install = Installer et redémarrer
 
 main_menu_show_logs = Voir le journal dans le gestionnaire de fichiers
 main_menu_change_configuration = Modifier la configuration
@@ -221,6 +402,17 @@ main_menu_exit = Quitter
 status_bar_message_configuration_is_invalid = La configuration est invalide : {$error}
 status_bar_message_restart_is_needed_for_configuration = Redémarrez l'application pour que les modifications de configuration prennent effet
 status_bar_message_failed_to_save_configuration = Échec de la sauvegarde de la configuration : {$error}
+status_bar_message_network_identity_exported = L'identité réseau a été exportée vers {$path}, gardez ce fichier privé
+status_bar_message_restart_is_needed_for_network_identity = Un redémarrage de l'application est nécessaire pour que la nouvelle identité réseau avec l'ID de pair {$peer_id} prenne effet
+status_bar_message_failed_to_update_network_identity = Échec de la mise à jour de l'identité réseau : {$error}
+status_bar_message_known_peers_cache = Le cache des pairs connus {$path} occupe {$size}, mis à jour il y a {$hours} heures
+status_bar_message_known_peers_cache_unknown_age = Le cache des pairs connus {$path} occupe {$size}
+status_bar_message_known_peers_cache_empty = Le cache des pairs connus est vide
+status_bar_message_known_peers_cache_reset_scheduled = Le cache des pairs connus sera vidé au prochain démarrage
+status_bar_message_restart_is_needed_for_known_peers_cache = Un redémarrage de l'application est nécessaire pour vider le cache des pairs connus
+status_bar_message_restart_is_needed_for_bandwidth_cap = Plafond de bande passante atteint, un redémarrage de l'application est nécessaire pour réduire les connexions DSN
+status_bar_message_failed_to_manage_known_peers_cache = Échec de la gestion du cache des pairs connus : {$error}
+status_bar_message_failed_to_update = Échec de la mise à jour : {$error}
 status_bar_message_restarted_after_crash = Space Acres s'est automatiquement redémarré après un crash. Consultez l'application et le journal système pour plus de détails
 status_bar_button_migrate = Migrer
 status_bar_button_ok = OK
@@ -239,8 +431,16 @@ notification_stopped_with_error = Space Acres s'est arrêté avec une erreur
     .body = Une erreur est survenue et nécessite une intervention de l'utilisateur pour la résoudre
 notification_farm_error = L'une des fermes de Space Acres a rencontré une erreur
     .body = Une erreur est survenue et nécessite une intervention de l'utilisateur pour la résoudre
+notification_farm_disk_missing = Disque de la ferme introuvable
+    .body = La ferme {$path} est arrêtée jusqu'à ce que le disque soit reconnecté, les autres fermes continuent le farming
+notification_farm_disk_read_only = Disque de la ferme en lecture seule
+    .body = La ferme {$path} est arrêtée jusqu'à ce que le disque soit de nouveau accessible en écriture, les autres fermes continuent le farming
+notification_farm_disk_available = Le disque de la ferme est de nouveau disponible
+    .body = La ferme {$path} a repris le farming
 notification_node_low_disk_space = Espace disque faible sur le nœud
     .body = Le volume du nœud ne dispose plus que de {$free_space}
+notification_farm_low_disk_space = Espace disque faible sur la ferme
+    .body = Le volume de la ferme {$farm_path} ne dispose plus que de {$free_space}
 notification_missed_reward = Échec de la signature de la récompense 😞
     .body = C'est regrettable, mais il y aura bientôt une autre opportunité
 notification_signed_reward_successfully = Nouvelle récompense signée avec succès 🥳
@@ -30,6 +30,7 @@ loading_configuration_step_decoded_chain_spec = Спецификация бло
 loading_networking_stack_title = Инициализация сетевого стека
 loading_networking_stack_step_checking_node_path = Проверка пути к папке с данными блокчейна...
 loading_networking_stack_step_creating_node_path = Создание папки с данными блокчейна...
+loading_networking_stack_step_resetting_node_database = Режим синхронизации или хранилище узла изменены, удаление базы данных узла...
 loading_networking_stack_step_node_path_ready = Папка с данными блокчейна подготовлена
 loading_networking_stack_step_preparing = Подготовка сетевого стека...
 loading_networking_stack_step_reading_keypair = Чтение сетевой ключевой пары...
@@ -40,8 +41,16 @@ loading_networking_stack_step_created_successfully = Сетевой стек у
 loading_consensus_node_title = Инициализация узла консенсуса
 loading_consensus_node_step_creating = Создание узла консенсуса...
 loading_consensus_node_step_created_successfully = Узел консенсуса успешно создана
+loading_consensus_node_step_connecting_to_external_node = Подключение к внешнему узлу {$url}...
+loading_consensus_node_step_connected_to_external_node_successfully = Успешно подключено к внешнему узлу
 loading_farmer_title = Создание фермы
 loading_farmer_step_initializing = Инициализация фермы {$index}/{$farms_total}...
+loading_farmer_step_initializing_farm_reading_metadata = Ферма {$farm_index}: чтение метаданных (прошло {$time_elapsed})
+loading_farmer_step_initializing_farm_allocating = Ферма {$farm_index}: выделение места {$space_allocated}/{$space_total} (прошло {$time_elapsed}, осталось ~{$time_remaining})
+loading_farmer_step_initializing_farm_allocating_no_eta = Ферма {$farm_index}: выделение места {$space_allocated}/{$space_total} (прошло {$time_elapsed})
+loading_farmer_step_initializing_farm_initializing_caches = Ферма {$farm_index}: инициализация кэша частей и кэша плота (прошло {$time_elapsed})
+loading_farmer_step_collecting_plotted_pieces = Сбор уже засеянных частей (это займёт некоторое время)...
+loading_farmer_step_collecting_plotted_pieces_farm = Ферма {$farm_index}: {$sectors_collected}/{$sectors_plotted} секторов
 loading_farmer_step_created_successfully = Ферма создана успешно
 loading_wiping_farmer_data_title = Удаление данных фермы
 loading_wiping_farmer_data_step_wiping_farm = Удаление фермы {$index}/{$farms_total} в {$path}...
@@ -77,17 +86,118 @@ configuration_farm_fixed_size_placeholder = Пример: 4T, 2.5TB, 500GiB, и
 configuration_farm_fixed_size_tooltip = Размер фермы в зависимости от того, какие единицы измерения вы предпочитаете. Подойдет любой размер свыше 2Гб
 configuration_farm_free_percentage_size_placeholder = Пример: 100%, 1.1%, и т.д.
 configuration_farm_free_percentage_size_tooltip = Процент свободного места на диске, занимаемого фермой. Будет работать от 0%, но на диске должно оставаться не менее 2Гб свободного места для исключения ошибок.
+configuration_farm_free_space_warning_threshold_placeholder = Предупреждение о нехватке места: {$default_threshold}
+configuration_farm_free_space_warning_threshold_tooltip = Свободное место на диске фермы, ниже которого показывается предупреждение о нехватке места, например 500MiB или 5GB, оставьте пустым, чтобы использовать значение по умолчанию
 configuration_farm_delete = Удалить эту ферму
+configuration_no_farms_node_only = Фермы не настроены, будет работать только узел без фарминга
+configuration_no_farms_external_node = С внешним узлом нужна хотя бы одна ферма, иначе ничего не будет запущено
 configuration_advanced = Расширенная конфигурация
 configuration_advanced_farmer = Конфигурация фермы
 configuration_advanced_farmer_reduce_plotting_cpu_load = Уменьшить нагрузку на процессор при плоттинге
 configuration_advanced_farmer_reduce_plotting_cpu_load_tooltip = Первичный плоттинг использует все ядра процессора по умолчанию. При использовании этой опции, для плоттинга используется половина ядер процессора, что позволит использовать компьютер для выполнения других задач
+configuration_advanced_node = Конфигурация узла
+configuration_advanced_node_name = Имя узла:
+configuration_advanced_node_name_placeholder = Генерируется автоматически при первом запуске
+configuration_advanced_node_name_tooltip = Имя узла в сети и телеметрии, до {$max_length} символов
+configuration_advanced_node_telemetry = Телеметрия:
+configuration_advanced_node_telemetry_tooltip = Отправлять имя, версию и статус синхронизации узла в публичную телеметрию, помогает отслеживать состояние сети
+configuration_advanced_node_sync_mode = Режим синхронизации:
+configuration_advanced_node_sync_mode_tooltip = Как узел синхронизирует цепочку, изменение удаляет базу данных узла и синхронизирует её заново
+configuration_advanced_node_sync_mode_snap = Snap
+configuration_advanced_node_sync_mode_full = Полная
+configuration_advanced_node_sync_mode_snap_explanation = Загружает недавнее состояние и только блоки, необходимые для фарминга, готов к фармингу гораздо быстрее
+configuration_advanced_node_sync_mode_full_explanation = Загружает и проверяет каждый блок с генезиса, занимает гораздо больше времени, но не полагается на состояние, предоставленное другими узлами
+configuration_advanced_node_sync_mode_changed_warning = База данных узла будет удалена и синхронизирована заново при следующем запуске
+configuration_advanced_node_pruning = Хранилище узла:
+configuration_advanced_node_pruning_tooltip = Какую часть истории цепочки узел хранит на диске, хранение большего объёма позволяет запрашивать историческое состояние локально, переход в архивный режим или из него требует удаления данных узла и повторной синхронизации
+configuration_advanced_node_pruning_kind_minimum = Минимум (рекомендуется)
+configuration_advanced_node_pruning_kind_blocks = Хранить последние блоки
+configuration_advanced_node_pruning_kind_archive = Архив (всё)
+configuration_advanced_node_pruning_blocks_tooltip = Количество последних блоков для хранения, не менее {$min_blocks}
+configuration_advanced_node_pruning_estimate = Ожидаемое использование диска: ~{$size}
+configuration_advanced_node_pruning_estimate_archive = Ожидаемое использование диска: растёт вместе с цепочкой на ~{$size} в день
+configuration_advanced_node_pruning_changed_warning = База данных узла будет удалена и синхронизирована заново при следующем запуске
+configuration_advanced_node_rpc = Сервер JSON-RPC:
+configuration_advanced_node_rpc_tooltip = Открыть JSON-RPC доступ к узлу для кошельков, скриптов и других инструментов
+configuration_advanced_node_rpc_listen_on = Адрес прослушивания:
+configuration_advanced_node_rpc_listen_on_tooltip = IP-адрес и порт, например 127.0.0.1:9944, используйте 0.0.0.0 для прослушивания на всех интерфейсах
+configuration_advanced_node_rpc_unsafe_methods = Небезопасные методы:
+configuration_advanced_node_rpc_unsafe_methods_tooltip = Разрешить методы, изменяющие поведение узла, никогда не открывайте их для недоверенных сетей
+configuration_advanced_node_external_node = Внешний узел:
+configuration_advanced_node_external_node_placeholder = Пример: {$url}
+configuration_advanced_node_external_node_tooltip = WebSocket RPC URL существующего узла для фарминга вместо встроенного узла, оставьте пустым для запуска встроенного узла
 configuration_advanced_network = Конфигурация сети
 configuration_advanced_network_default_port_number_tooltip = Номер порта по умолчанию - {$port}
+configuration_advanced_network_listen_on = Прослушивать:
+configuration_advanced_network_listen_on_tooltip = Сетевые интерфейсы для приёма входящих соединений, конкретный адрес полезен на серверах с несколькими сетевыми интерфейсами
+configuration_advanced_network_listen_on_all = Все интерфейсы
+configuration_advanced_network_listen_on_ipv4 = Только IPv4
+configuration_advanced_network_listen_on_specific = Конкретный адрес
+configuration_advanced_network_listen_on_custom = Пользовательский (файл конфигурации)
+configuration_advanced_network_listen_on_custom_explanation = Используются адреса из файла конфигурации, порты ниже игнорируются. Substrate: {$substrate_addresses}; DSN: {$dsn_addresses}
+configuration_advanced_network_listen_on_address_placeholder = 192.168.1.10
 configuration_advanced_network_substrate_port = Substrate (блокчейн) P2P порт (TCP):
 configuration_advanced_network_subspace_port = Subspace (DSN) P2P порт (TCP):
-configuration_advanced_network_faster_networking = Быстрая сеть:
-configuration_advanced_network_faster_networking_tooltip = По умолчанию сетевые настройки оптимизированы для домашних маршрутизаторов. Если у вас более мощное оборудование, данная опция может улучшить скорость синхронизации и другие процессы
+configuration_advanced_network_pick_free_ports = Выбрать свободные порты
+configuration_advanced_network_pick_free_ports_tooltip = Заменить оба порта первыми портами, начиная со стандартных, которые не используются другими приложениями
+configuration_advanced_network_error_port_in_use = Порт {$port} уже используется другим приложением, выберите другой порт
+configuration_advanced_network_error_duplicate_port = Порт {$port} не может использоваться одновременно Substrate и DSN, выберите разные порты
+configuration_advanced_network_connections = Соединения:
+configuration_advanced_network_connections_tooltip = Ограничения количества соединений, низкие ограничения помогают при лимитированном подключении и слабых роутерах, а высокие ограничения на более мощных системах могут улучшить скорость синхронизации и другие процессы
+configuration_advanced_network_connections_low = Низкие
+configuration_advanced_network_connections_default = По умолчанию
+configuration_advanced_network_connections_high = Высокие
+configuration_advanced_network_connections_custom = Пользовательские
+configuration_advanced_network_connections_dsn_in = Входящие соединения DSN:
+configuration_advanced_network_connections_dsn_out = Исходящие соединения DSN:
+configuration_advanced_network_connections_dsn_pending_in = Ожидающие входящие соединения DSN:
+configuration_advanced_network_connections_dsn_pending_out = Ожидающие исходящие соединения DSN:
+configuration_advanced_network_connections_substrate_in = Входящие пиры Substrate:
+configuration_advanced_network_connections_substrate_out = Исходящие пиры Substrate:
+configuration_advanced_network_piece_download = Загрузка частей:
+configuration_advanced_network_piece_download_tooltip = Политика повторов и параллельность загрузки частей для плоттинга и синхронизации узла
+configuration_advanced_network_piece_download_max_retries = Число повторов:
+configuration_advanced_network_piece_download_initial_retry_interval = Начальный интервал между повторами (секунды):
+configuration_advanced_network_piece_download_max_retry_interval = Максимальный интервал между повторами (секунды):
+configuration_advanced_network_piece_download_concurrency_multiplier = Множитель параллельности на исходящее соединение DSN:
+configuration_advanced_network_peer_multiaddrs_placeholder = /dns/example.com/tcp/30333/p2p/12D3KooW...
+configuration_advanced_network_substrate_bootstrap_nodes = Начальные узлы Substrate:
+configuration_advanced_network_substrate_bootstrap_nodes_tooltip = Дополнительные узлы Substrate (блокчейн) для обнаружения сети, multiaddr через запятую, заканчивающиеся на /p2p/<ID пира>
+configuration_advanced_network_substrate_reserved_nodes = Зарезервированные узлы Substrate:
+configuration_advanced_network_substrate_reserved_nodes_tooltip = Узлы Substrate (блокчейн), с которыми всегда поддерживается соединение, multiaddr через запятую, заканчивающиеся на /p2p/<ID пира>
+configuration_advanced_network_dsn_bootstrap_nodes = Начальные узлы DSN:
+configuration_advanced_network_dsn_bootstrap_nodes_tooltip = Дополнительные узлы Subspace (DSN) для обнаружения сети, multiaddr через запятую, заканчивающиеся на /p2p/<ID пира>
+configuration_advanced_network_dsn_reserved_peers = Зарезервированные пиры DSN:
+configuration_advanced_network_dsn_reserved_peers_tooltip = Пиры Subspace (DSN), с которыми всегда поддерживается соединение, multiaddr через запятую, заканчивающиеся на /p2p/<ID пира>
+configuration_advanced_network_substrate_external_addresses = Внешние адреса Substrate:
+configuration_advanced_network_substrate_external_addresses_placeholder = /ip4/203.0.113.1/tcp/30333
+configuration_advanced_network_substrate_external_addresses_tooltip = Публично доступные адреса Substrate (блокчейн), сообщаемые другим пирам, например при настроенном пробросе портов на роутере, multiaddr через запятую
+configuration_advanced_network_dsn_external_addresses = Внешние адреса DSN:
+configuration_advanced_network_dsn_external_addresses_placeholder = /ip4/203.0.113.1/tcp/30433
+configuration_advanced_network_dsn_external_addresses_tooltip = Публично доступные адреса Subspace (DSN), сообщаемые другим пирам, например при настроенном пробросе портов на роутере, multiaddr через запятую
+configuration_advanced_network_daily_download_cap = Суточный лимит загрузки:
+configuration_advanced_network_daily_download_cap_tooltip = Мягкий лимит загрузки обеих сетей за последние 24 часа, трафик DSN помимо частей не учитывается. При его достижении загрузки для плоттинга сокращаются, а соединения DSN сокращаются после перезапуска. Оставьте пустым, чтобы не ограничивать
+configuration_advanced_network_daily_upload_cap = Суточный лимит отдачи:
+configuration_advanced_network_daily_upload_cap_tooltip = Мягкий лимит отдачи обеих сетей за последние 24 часа, трафик DSN помимо частей не учитывается. При его достижении соединения DSN сокращаются после перезапуска. Оставьте пустым, чтобы не ограничивать
+configuration_advanced_network_proxy_url = URL прокси:
+configuration_advanced_network_proxy_url_tooltip = HTTP, HTTPS или SOCKS5 прокси для проверки обновлений, например http://127.0.0.1:8080 или socks5h://127.0.0.1:1080 (socks5h разрешает имена хостов через прокси), оставьте пустым для прямого подключения. P2P-соединения узла и фармера не поддерживают прокси
+configuration_advanced_network_proxy_url_placeholder = http://хост:порт
+configuration_advanced_network_proxy_username = Имя пользователя прокси:
+configuration_advanced_network_proxy_username_tooltip = Имя пользователя для аутентификации на прокси, оставьте пустым, если прокси не требует аутентификации
+configuration_advanced_network_proxy_username_placeholder = необязательно
+configuration_advanced_network_proxy_password = Пароль прокси:
+configuration_advanced_network_proxy_password_tooltip = Пароль для аутентификации на прокси, требует имя пользователя, хранится в файле конфигурации в открытом виде
+configuration_advanced_network_daily_cap_placeholder = Например, 50 GB, пусто — без лимита
+configuration_advanced_network_allow_private_addresses = Разрешить частные адреса:
+configuration_advanced_network_allow_private_addresses_tooltip = Подключаться к пирам с частными адресами и обнаруживать их, например других фармеров в той же локальной сети
+configuration_advanced_updates = Обновления
+configuration_advanced_updates_check = Проверять обновления:
+configuration_advanced_updates_check_tooltip = Периодически проверять, доступна ли новая версия Space Acres
+configuration_advanced_updates_include_prereleases = Включать предварительные версии:
+configuration_advanced_updates_include_prereleases_tooltip = Также уведомлять о предварительных версиях, которые могут быть менее стабильными
+configuration_advanced_updates_source = Источник обновлений:
+configuration_advanced_updates_source_placeholder = Релизы GitHub
+configuration_advanced_updates_source_tooltip = URL или абсолютный путь к JSON-файлу в формате API релизов GitHub для проверки вместо GitHub, полезно для тестирования и зеркал, оставьте пустым для использования GitHub
 configuration_button_add_farm = Добавить ферму
 configuration_button_help = Помощь
 configuration_button_cancel = Отмена
@@ -96,6 +206,12 @@ configuration_button_save = Сохранить
 configuration_button_start = Старт
 configuration_dialog_button_select = Выбрать
 configuration_dialog_button_cancel = Отмена
+network_identity_dialog_button_export = Экспортировать
+network_identity_dialog_button_import = Импортировать
+network_identity_rotate_confirm_heading = Сгенерировать новую сетевую идентичность?
+network_identity_rotate_confirm_body = Peer ID этого узла изменится после перезапуска, и пиров, ссылающихся на него по peer ID, нужно будет обновить. Предыдущая идентичность будет сохранена в secret_ed25519.<peer ID>.bak в сетевом каталоге данных узла.
+network_identity_rotate_confirm_button_cancel = Отмена
+network_identity_rotate_confirm_button_rotate = Сгенерировать
 
 node_migration_button_cancel = Отмена
 node_migration_button_reset = Сбросить узел
@@ -128,6 +244,46 @@ running_node_title = Узел блокчейна {$chain_name}
 running_node_title_tooltip = Нажмите, чтобы открыть в файловом менеджере
 running_node_free_disk_space_tooltip = Осталось свободного места на диске: {$size}
 running_node_connections_tooltip = {$connected_peers}/{$expected_peers} подключенных пиров, нажмите для подробностей про нужные P2P порты
+running_node_peer_id = ID пира: {$peer_id}
+running_node_network_identity_tooltip = Сетевая идентичность
+running_node_network_identity_export = Экспортировать сетевую идентичность…
+running_node_network_identity_export_warning = Экспортированный файл содержит секретный ключ этого узла, любой, у кого он есть, может выдавать себя за этот узел в сети, храните его в тайне
+running_node_network_identity_import = Импортировать сетевую идентичность…
+running_node_network_identity_rotate = Сгенерировать новую сетевую идентичность
+running_node_network_identity_replace_warning = Импорт или генерация сетевой идентичности изменяет ID пира после перезапуска, пиры, ссылающиеся на этот узел по ID пира, потребуют обновления
+running_node_peers_button = Пиры
+running_node_peers_button_tooltip = Просмотреть подключённые пиры, полезно при остановке синхронизации
+running_node_peers_substrate = Пиры узла консенсуса (Substrate): {$count}
+running_node_peers_dsn = Пиры DSN: {$count}
+running_node_known_peers = Кэш известных пиров
+running_node_known_peers_description = Адреса пиров DSN, сохраняемые между перезапусками для более быстрого переподключения. Очистите его, если переподключение медленное после смены IP-адреса или сети, он также очищается автоматически после недели офлайн.
+running_node_known_peers_inspect = Просмотреть
+running_node_known_peers_clear = Очистить
+running_node_peers_role_authority = авторитет
+running_node_peers_role_full = полный
+running_node_peers_role_light = лёгкий
+running_node_peers_direction_inbound = входящий
+running_node_peers_direction_outbound = исходящий
+running_node_peers_address_unknown = неизвестный адрес
+running_node_peers_none = Нет подключённых пиров
+running_node_piece_serving = Отдано частей: {$pieces}, {$upload_rate}/с
+running_node_piece_serving_tooltip =
+    Данные, отданные другим фермерам через DSN:
+    части из кэша: {$cache_hits}
+    части из плотов: {$plot_hits}
+    части не найдены: {$misses}
+    отправлены ближайшие пиры вместо части: {$closest_peers_fallbacks}
+    заголовки сегментов: {$segment_headers}
+    всего отдано: {$bytes_uploaded}
+running_node_bandwidth = За 24 ч: ↓ {$downloaded} ↑ {$uploaded}
+running_node_bandwidth_tooltip =
+    За последний час: ↓ {$hour_downloaded} ↑ {$hour_uploaded}
+    За 24 часа, узел консенсуса: ↓ {$substrate_downloaded} ↑ {$substrate_uploaded}
+    За 24 часа, DSN (только части, без DHT, gossip и служебного трафика протоколов): ↓ {$dsn_downloaded} ↑ {$dsn_uploaded}
+    Суточные лимиты: ↓ {$download_cap} ↑ {$upload_cap}
+running_node_bandwidth_no_cap = без лимита
+running_node_bandwidth_download_cap_reached = Достигнут лимит загрузки, загрузки для плоттинга сокращены, соединения DSN будут сокращены после перезапуска
+running_node_bandwidth_upload_cap_reached = Достигнут лимит отдачи, соединения DSN будут сокращены после перезапуска
 running_node_status_connecting = Подключение к сети, лучший блок #{$block_number}
 running_node_status_syncing_speed_no_eta = , {NUMBER($blocks_per_second, minimumFractionDigits: 2, maximumFractionDigits: 2)} блоков/сек
 running_node_status_syncing_speed_hours_eta = , {NUMBER($a_blocks_per_second, minimumFractionDigits: 2, maximumFractionDigits: 2)} блоков/сек (~{NUMBER($b_hours_remaining, minimumFractionDigits: 2, maximumFractionDigits: 2)} часов осталось)
@@ -146,6 +302,15 @@ running_farmer_button_pause_plotting = Приостановить плоттин
 running_farmer_button_resume_plotting = Продолжить плоттинг
 running_farmer_account_balance_tooltip = Общий баланс и монеты, заработанные с момента запуска приложения. Нажмите, чтобы увидеть подробности в Astral
 running_farmer_piece_cache_sync = Синхронизация фрагментов кэша {NUMBER($percentage, minimumFractionDigits: 2, maximumFractionDigits: 2)}%
+running_farmer_piece_downloads = Загружено частей: {$local} локально, {$dsn_cache} кэш DSN, {$archival} архив, {$node} узел, {$failed} с ошибкой
+running_farmer_piece_downloads_tooltip =
+    Части, полученные для плоттинга, синхронизации кэша частей и синхронизации узла:
+    из локального кэша и плотов: {$local}
+    из кэша DSN: {$dsn_cache}
+    из архивного хранилища: {$archival}
+    от узла: {$node}
+    некорректные части от пиров DSN: {$invalid}
+    не удалось получить ни из одного источника: {$failed}
 running_farmer_next_reward_estimate =
     Следующее вознаграждение:{$eta_string ->
         [any_time_now] с минуты на минуту
@@ -160,7 +325,10 @@ running_farmer_farm_reward_signatures_tooltip = {$successful_signatures}/{$total
 running_farmer_farm_auditing_performance_tooltip = Эффективность аудита: среднее время {NUMBER($a_average_time, minimumFractionDigits: 2, maximumFractionDigits: 2)}s, лимит времени {NUMBER($b_time_timit, minimumFractionDigits: 2, maximumFractionDigits: 2)}s
 running_farmer_farm_proving_performance_tooltip = Эффективность подтверждения: среднее время {NUMBER($a_average_time, minimumFractionDigits: 2, maximumFractionDigits: 2)}s, лимит времени {NUMBER($b_time_timit, minimumFractionDigits: 2, maximumFractionDigits: 2)}s
 running_farmer_farm_non_fatal_error_tooltip = При фарминге произошла ошибка, которая была устранена. Более подробную информацию смотрите в журнале: {$error}
+running_farmer_farm_free_disk_space_tooltip = Осталось свободного места на диске: {$size}
 running_farmer_farm_crashed = Фарм сломался: {$error}
+running_farmer_farm_disk_missing = Диск фермы отсутствует, ферма остановлена до повторного подключения диска
+running_farmer_farm_disk_read_only = Диск фермы доступен только для чтения, ферма остановлена, пока запись на диск снова не станет возможной
 running_farmer_farm_plotting_speed =  ({NUMBER($a_sector_time, minimumFractionDigits: 2, maximumFractionDigits: 2)} мин/сектор, {NUMBER($b_sectors_per_hour, minimumFractionDigits: 2, maximumFractionDigits: 2)} сектор/час)
 running_farmer_farm_plotting_initial =
     {$pausing_state ->
@@ -204,6 +372,8 @@ error_title = Ошибка
 error_message = Ошибка: {$error}
 error_message_failed_to_send_config_to_backend = Не удалось отправить конфигурацию: {$error}
 error_message_failed_to_send_pause_plotting_to_backend = Не удалось приостановить плоттинг: {$error}
+error_message_failed_to_send_network_identity_action_to_backend = Не удалось отправить действие с сетевой идентичностью в бэкенд: {$error}
+error_message_failed_to_send_known_peers_action_to_backend = Не удалось отправить действие с известными пирами в бэкенд: {$error}
 error_button_help_from_community = Помощь от сообщества
 error_button_reset_node = Сбросить узел
 error_button_reset_node_tooltip = Удалить данные узла и синхронизировать заново из сети
@@ -211,6 +381,17 @@ error_button_show_logs = Показать журнал
 
 new_version_available = Доступна новая версия {$version} 🎉
 new_version_available_button_open = Перейти к релизам
+new_version_available_button_release_notes = Показать список изменений
+new_version_release_notes_title = Что нового в {$version}
+new_version_release_notes_empty = Список изменений не предоставлен
+new_version_update_button_install = Скачать и установить
+new_version_update_downloading = Загрузка версии {$version}…
+new_version_update_ready = Установить версию {$version}
+new_version_update_installing = Установка версии {$version}…
+new_version_update_confirm_heading = Установить версию {$version}?
+new_version_update_confirm_body = Обновление загружено, его подпись проверена, Space Acres перезапустится для завершения установки
+new_version_update_confirm_button_cancel = Отмена
+new_version_update_confirm_button_install = Установить и перезапустить
 
 main_menu_show_logs = Показать журнал в файловом менеджере
 main_menu_change_configuration = Изменить конфигурацию
@@ -221,6 +402,17 @@ main_menu_exit = Выход
 status_bar_message_configuration_is_invalid = Неверная конфигурация: {$error}
 status_bar_message_restart_is_needed_for_configuration = Перезапустите приложение, чтобы изменения конфигурации вступили в силу
 status_bar_message_failed_to_save_configuration = Не удалось сохранить изменения конфигурации: {$error}
+status_bar_message_network_identity_exported = Сетевая идентичность экспортирована в {$path}, храните этот файл в тайне
+status_bar_message_restart_is_needed_for_network_identity = Для вступления в силу новой сетевой идентичности с ID пира {$peer_id} необходим перезапуск приложения
+status_bar_message_failed_to_update_network_identity = Не удалось обновить сетевую идентичность: {$error}
+status_bar_message_known_peers_cache = Кэш известных пиров {$path} занимает {$size}, обновлён {$hours} ч. назад
+status_bar_message_known_peers_cache_unknown_age = Кэш известных пиров {$path} занимает {$size}
+status_bar_message_known_peers_cache_empty = Кэш известных пиров пуст
+status_bar_message_known_peers_cache_reset_scheduled = Кэш известных пиров будет очищен при следующем запуске
+status_bar_message_restart_is_needed_for_known_peers_cache = Для очистки кэша известных пиров необходим перезапуск приложения
+status_bar_message_restart_is_needed_for_bandwidth_cap = Достигнут лимит трафика, для сокращения соединений DSN необходим перезапуск приложения
+status_bar_message_failed_to_manage_known_peers_cache = Не удалось управлять кэшем известных пиров: {$error}
+status_bar_message_failed_to_update = Не удалось обновить: {$error}
 status_bar_message_restarted_after_crash = Space Acres автоматически перезапускается после сбоя. Подробности можно найти в приложении и системном журнале
 status_bar_button_migrate = Перенести
 status_bar_button_ok = Ok
@@ -239,8 +431,16 @@ notification_stopped_with_error = Space Acres остановился с ошиб
     .body = Произошла ошибка, для устранения которой требуется вмешательство пользователя
 notification_farm_error = Одна из ферм сломалась в Space Acres
     .body = Произошла ошибка, для устранения которой требуется вмешательство пользователя
+notification_farm_disk_missing = Диск фермы отсутствует
+    .body = Ферма {$path} остановлена до повторного подключения диска, остальные фермы продолжают работу
+notification_farm_disk_read_only = Диск фермы доступен только для чтения
+    .body = Ферма {$path} остановлена, пока запись на диск снова не станет возможной, остальные фермы продолжают работу
+notification_farm_disk_available = Диск фермы снова доступен
+    .body = Ферма {$path} снова подключена и продолжает работу
 notification_node_low_disk_space = Мало места на диске узла
     .body = На томе узла осталось только {$free_space}
+notification_farm_low_disk_space = Мало места на диске фермы
+    .body = На томе фермы {$farm_path} осталось только {$free_space}
 notification_missed_reward = Не удалось подписать вознаграждение 😞
     .body = Это досадно, но скоро представится еще один шанс
 notification_signed_reward_successfully = Успешно подписано новое вознаграждение 🥳
@@ -32,6 +32,7 @@ loading_configuration_step_decoded_chain_spec = Uspešno dekodirana specifikacij
 loading_networking_stack_title = Inicijalizacija mrežnog steka
 loading_networking_stack_step_checking_node_path = Provera putanje čvora...
 loading_networking_stack_step_creating_node_path = Kreiranje putanje čvora...
+loading_networking_stack_step_resetting_node_database = Režim sinhronizacije ili skladište čvora promenjeni, uklanjanje baze podataka čvora...
 loading_networking_stack_step_node_path_ready = Putanja čvora je spremna
 loading_networking_stack_step_preparing = Priprema mrežnog steka...
 loading_networking_stack_step_reading_keypair = Čitanje mrežnog para ključeva...
@@ -42,8 +43,16 @@ loading_networking_stack_step_created_successfully = Mrežni stek je uspešno kr
 loading_consensus_node_title = Inicijalizacija konsenzus čvora
 loading_consensus_node_step_creating = Kreiranje konsenzus čvora...
 loading_consensus_node_step_created_successfully = Konsenzus čvor uspešno kreiran
+loading_consensus_node_step_connecting_to_external_node = Povezivanje sa spoljnim čvorom na {$url}...
+loading_consensus_node_step_connected_to_external_node_successfully = Uspešno povezano sa spoljnim čvorom
 loading_farmer_title = Inicijalizacija farmera
 loading_farmer_step_initializing = Inicijalizacija farmi {$index}/{$farms_total}...
+loading_farmer_step_initializing_farm_reading_metadata = Farma {$farm_index}: čitanje metapodataka (proteklo {$time_elapsed})
+loading_farmer_step_initializing_farm_allocating = Farma {$farm_index}: zauzimanje prostora {$space_allocated}/{$space_total} (proteklo {$time_elapsed}, preostalo ~{$time_remaining})
+loading_farmer_step_initializing_farm_allocating_no_eta = Farma {$farm_index}: zauzimanje prostora {$space_allocated}/{$space_total} (proteklo {$time_elapsed})
+loading_farmer_step_initializing_farm_initializing_caches = Farma {$farm_index}: inicijalizacija keša delova i keša plota (proteklo {$time_elapsed})
+loading_farmer_step_collecting_plotted_pieces = Prikupljanje već plotovanih delova (ovo će potrajati)...
+loading_farmer_step_collecting_plotted_pieces_farm = Farma {$farm_index}: {$sectors_collected}/{$sectors_plotted} sektora
 loading_farmer_step_created_successfully = Farmer uspešno kreiran
 loading_wiping_farmer_data_title = Brisanje podataka farmera
 loading_wiping_farmer_data_step_wiping_farm = Brisanje farme {$index}/{$farms_total} na {$path}...
@@ -79,17 +88,118 @@ configuration_farm_fixed_size_placeholder = Primer: 4T, 2.5TB, 500GiB itd.
 configuration_farm_fixed_size_tooltip = Veličina farme u jedinicama koje preferirate, bilo koja količina prostora iznad 2 GB funkcioniše
 configuration_farm_free_percentage_size_placeholder = Primer: 100%, 1.1%, itd.
 configuration_farm_free_percentage_size_tooltip = Procenat slobodnog prostora koji ova farma zauzima, sve preko 0% funkcioniše, ostavite minimum 2GB prostora da izbegnete greške
+configuration_farm_free_space_warning_threshold_placeholder = Upozorenje o malo prostora: {$default_threshold}
+configuration_farm_free_space_warning_threshold_tooltip = Slobodan prostor na disku farme ispod kog se prikazuje upozorenje o malo prostora, na primer 500MiB ili 5GB, ostavite prazno da biste koristili podrazumevanu vrednost
 configuration_farm_delete = Obriši ovu farmu
+configuration_no_farms_node_only = Nijedna farma nije konfigurisana, radiće samo čvor bez farmovanja
+configuration_no_farms_external_node = Sa spoljnim čvorom potrebna je bar jedna farma, inače ništa neće raditi
 configuration_advanced = Napredna konfiguracija
 configuration_advanced_farmer = Konfiguracija farmera
 configuration_advanced_farmer_reduce_plotting_cpu_load = Smanjeno opterećenje procesora
 configuration_advanced_farmer_reduce_plotting_cpu_load_tooltip = Inicijalno plotovanje koristi sva jegra na procesoru, dok će sa ovom opcijom koristiti jednu polovinu dostupnih jezgra, ovo poboljšava odaziv i performanse ostalih zadataka
+configuration_advanced_node = Konfiguracija čvora
+configuration_advanced_node_name = Ime čvora:
+configuration_advanced_node_name_placeholder = Generiše se automatski pri prvom pokretanju
+configuration_advanced_node_name_tooltip = Ime koje čvor koristi na mreži i u telemetriji, do {$max_length} karaktera
+configuration_advanced_node_telemetry = Telemetrija:
+configuration_advanced_node_telemetry_tooltip = Prijavljuj ime, verziju i status sinhronizacije čvora javnoj telemetriji, pomaže u praćenju stanja mreže
+configuration_advanced_node_sync_mode = Režim sinhronizacije:
+configuration_advanced_node_sync_mode_tooltip = Kako čvor sinhronizuje lanac, promena uklanja bazu podataka čvora i sinhronizuje je ponovo od početka
+configuration_advanced_node_sync_mode_snap = Snap
+configuration_advanced_node_sync_mode_full = Potpuna
+configuration_advanced_node_sync_mode_snap_explanation = Preuzima nedavno stanje i samo blokove neophodne za farmovanje, spreman za farmovanje mnogo ranije
+configuration_advanced_node_sync_mode_full_explanation = Preuzima i proverava svaki blok od geneze, traje mnogo duže, ali se ne oslanja na stanje koje pružaju drugi čvorovi
+configuration_advanced_node_sync_mode_changed_warning = Baza podataka čvora će biti uklonjena i ponovo sinhronizovana pri sledećem pokretanju
+configuration_advanced_node_pruning = Skladište čvora:
+configuration_advanced_node_pruning_tooltip = Koliko istorije lanca čvor čuva na disku, čuvanje više omogućava lokalno pretraživanje istorijskog stanja, prelazak na arhivu ili sa nje zahteva brisanje podataka čvora i ponovnu sinhronizaciju
+configuration_advanced_node_pruning_kind_minimum = Minimum (preporučeno)
+configuration_advanced_node_pruning_kind_blocks = Čuvaj poslednje blokove
+configuration_advanced_node_pruning_kind_archive = Arhiva (sve)
+configuration_advanced_node_pruning_blocks_tooltip = Broj poslednjih blokova koji se čuvaju, najmanje {$min_blocks}
+configuration_advanced_node_pruning_estimate = Procenjena upotreba diska: ~{$size}
+configuration_advanced_node_pruning_estimate_archive = Procenjena upotreba diska: raste sa lancem za ~{$size} dnevno
+configuration_advanced_node_pruning_changed_warning = Baza podataka čvora će biti uklonjena i ponovo sinhronizovana pri sledećem pokretanju
+configuration_advanced_node_rpc = JSON-RPC server:
+configuration_advanced_node_rpc_tooltip = Izloži JSON-RPC krajnju tačku čvora za novčanike, skripte i druge alate
+configuration_advanced_node_rpc_listen_on = Adresa za osluškivanje:
+configuration_advanced_node_rpc_listen_on_tooltip = IP adresa i port, na primer 127.0.0.1:9944, koristite 0.0.0.0 za osluškivanje na svim interfejsima
+configuration_advanced_node_rpc_unsafe_methods = Nebezbedne metode:
+configuration_advanced_node_rpc_unsafe_methods_tooltip = Dozvoli metode koje mogu promeniti ponašanje čvora, nikada ih ne izlažite nepouzdanim mrežama
+configuration_advanced_node_external_node = Spoljni čvor:
+configuration_advanced_node_external_node_placeholder = Primer: {$url}
+configuration_advanced_node_external_node_tooltip = WebSocket RPC URL postojećeg čvora za farmovanje umesto pokretanja ugrađenog čvora, ostavite prazno za pokretanje ugrađenog čvora
 configuration_advanced_network = Konfiguracija mreže
 configuration_advanced_network_default_port_number_tooltip = Podrazumevani broj porta je {$port}
+configuration_advanced_network_listen_on = Osluškuj na:
+configuration_advanced_network_listen_on_tooltip = Mrežni interfejsi na kojima se prihvataju dolazne veze, određena adresa je korisna na serverima sa više mrežnih interfejsa
+configuration_advanced_network_listen_on_all = Svi interfejsi
+configuration_advanced_network_listen_on_ipv4 = Samo IPv4
+configuration_advanced_network_listen_on_specific = Određena adresa
+configuration_advanced_network_listen_on_custom = Prilagođeno (konfiguraciona datoteka)
+configuration_advanced_network_listen_on_custom_explanation = Koriste se adrese iz konfiguracione datoteke, portovi ispod se zanemaruju. Substrate: {$substrate_addresses}; DSN: {$dsn_addresses}
+configuration_advanced_network_listen_on_address_placeholder = 192.168.1.10
 configuration_advanced_network_substrate_port = Substrate (blockchain) P2P port (TCP):
 configuration_advanced_network_subspace_port = Subspace (DSN) P2P port (TCP):
-configuration_advanced_network_faster_networking = Brže umrežavanje:
-configuration_advanced_network_faster_networking_tooltip = Podrazumevano, umrežavanje je optimizovano za kućne rutere, ali ako imate jaču opremu, brže umrežavanje može poboljšati brzinu sinhronizacije i druge procese
+configuration_advanced_network_pick_free_ports = Izaberi slobodne portove
+configuration_advanced_network_pick_free_ports_tooltip = Zameni oba porta prvim portovima počevši od podrazumevanih koje ne koristi nijedna druga aplikacija
+configuration_advanced_network_error_port_in_use = Port {$port} već koristi druga aplikacija, izaberite drugi port
+configuration_advanced_network_error_duplicate_port = Port {$port} ne mogu istovremeno koristiti Substrate i DSN, izaberite različite portove
+configuration_advanced_network_connections = Veze:
+configuration_advanced_network_connections_tooltip = Ograničenja broja veza, niža ograničenja pomažu kod merenih veza i slabih rutera, dok viša ograničenja na jačim sistemima mogu poboljšati brzinu sinhronizacije i druge procese
+configuration_advanced_network_connections_low = Niska
+configuration_advanced_network_connections_default = Podrazumevana
+configuration_advanced_network_connections_high = Visoka
+configuration_advanced_network_connections_custom = Prilagođena
+configuration_advanced_network_connections_dsn_in = DSN dolazne veze:
+configuration_advanced_network_connections_dsn_out = DSN odlazne veze:
+configuration_advanced_network_connections_dsn_pending_in = DSN dolazne veze na čekanju:
+configuration_advanced_network_connections_dsn_pending_out = DSN odlazne veze na čekanju:
+configuration_advanced_network_connections_substrate_in = Substrate dolazni peer-ovi:
+configuration_advanced_network_connections_substrate_out = Substrate odlazni peer-ovi:
+configuration_advanced_network_piece_download = Preuzimanje delova:
+configuration_advanced_network_piece_download_tooltip = Politika ponovnih pokušaja i paralelnost preuzimanja delova za plotovanje i sinhronizaciju čvora
+configuration_advanced_network_piece_download_max_retries = Broj ponovnih pokušaja:
+configuration_advanced_network_piece_download_initial_retry_interval = Početni interval između pokušaja (sekunde):
+configuration_advanced_network_piece_download_max_retry_interval = Maksimalni interval između pokušaja (sekunde):
+configuration_advanced_network_piece_download_concurrency_multiplier = Množilac paralelnosti po odlaznoj DSN vezi:
+configuration_advanced_network_peer_multiaddrs_placeholder = /dns/example.com/tcp/30333/p2p/12D3KooW...
+configuration_advanced_network_substrate_bootstrap_nodes = Substrate početni čvorovi:
+configuration_advanced_network_substrate_bootstrap_nodes_tooltip = Dodatni Substrate (blokčejn) čvorovi za otkrivanje mreže, multiaddr adrese odvojene zarezom koje se završavaju sa /p2p/<ID peer-a>
+configuration_advanced_network_substrate_reserved_nodes = Substrate rezervisani čvorovi:
+configuration_advanced_network_substrate_reserved_nodes_tooltip = Substrate (blokčejn) čvorovi sa kojima se uvek održava veza, multiaddr adrese odvojene zarezom koje se završavaju sa /p2p/<ID peer-a>
+configuration_advanced_network_dsn_bootstrap_nodes = DSN početni čvorovi:
+configuration_advanced_network_dsn_bootstrap_nodes_tooltip = Dodatni Subspace (DSN) čvorovi za otkrivanje mreže, multiaddr adrese odvojene zarezom koje se završavaju sa /p2p/<ID peer-a>
+configuration_advanced_network_dsn_reserved_peers = DSN rezervisani peer-ovi:
+configuration_advanced_network_dsn_reserved_peers_tooltip = Subspace (DSN) peer-ovi sa kojima se uvek održava veza, multiaddr adrese odvojene zarezom koje se završavaju sa /p2p/<ID peer-a>
+configuration_advanced_network_substrate_external_addresses = Substrate spoljne adrese:
+configuration_advanced_network_substrate_external_addresses_placeholder = /ip4/203.0.113.1/tcp/30333
+configuration_advanced_network_substrate_external_addresses_tooltip = Javno dostupne Substrate (blokčejn) adrese koje se oglašavaju drugim peer-ovima, na primer kada je na ruteru podešeno prosleđivanje portova, multiaddr adrese odvojene zarezom
+configuration_advanced_network_dsn_external_addresses = DSN spoljne adrese:
+configuration_advanced_network_dsn_external_addresses_placeholder = /ip4/203.0.113.1/tcp/30433
+configuration_advanced_network_dsn_external_addresses_tooltip = Javno dostupne Subspace (DSN) adrese koje se oglašavaju drugim peer-ovima, na primer kada je na ruteru podešeno prosleđivanje portova, multiaddr adrese odvojene zarezom
+configuration_advanced_network_daily_download_cap = Dnevno ograničenje preuzimanja:
+configuration_advanced_network_daily_download_cap_tooltip = Meko ograničenje preuzimanja obe mreže u poslednja 24 sata, DSN saobraćaj osim delova se ne računa. Kada se dostigne, preuzimanja za plotovanje se smanjuju, a DSN veze nakon ponovnog pokretanja. Ostavite prazno za bez ograničenja
+configuration_advanced_network_daily_upload_cap = Dnevno ograničenje otpremanja:
+configuration_advanced_network_daily_upload_cap_tooltip = Meko ograničenje otpremanja obe mreže u poslednja 24 sata, DSN saobraćaj osim delova se ne računa. Kada se dostigne, DSN veze se smanjuju nakon ponovnog pokretanja. Ostavite prazno za bez ograničenja
+configuration_advanced_network_proxy_url = URL proksija:
+configuration_advanced_network_proxy_url_tooltip = HTTP, HTTPS ili SOCKS5 proksi za proveru ažuriranja, na primer http://127.0.0.1:8080 ili socks5h://127.0.0.1:1080 (socks5h razrešava imena hostova preko proksija), ostavite prazno za direktnu vezu. P2P veze noda i farmera ne podržavaju proksije
+configuration_advanced_network_proxy_url_placeholder = http://host:port
+configuration_advanced_network_proxy_username = Korisničko ime proksija:
+configuration_advanced_network_proxy_username_tooltip = Korisničko ime za autentifikaciju na proksiju, ostavite prazno ako proksi ne zahteva autentifikaciju
+configuration_advanced_network_proxy_username_placeholder = opciono
+configuration_advanced_network_proxy_password = Lozinka proksija:
+configuration_advanced_network_proxy_password_tooltip = Lozinka za autentifikaciju na proksiju, zahteva korisničko ime, čuva se u konfiguracionom fajlu kao običan tekst
+configuration_advanced_network_daily_cap_placeholder = Na primer 50 GB, prazno za bez ograničenja
+configuration_advanced_network_allow_private_addresses = Dozvoli privatne adrese:
+configuration_advanced_network_allow_private_addresses_tooltip = Povezivanje i otkrivanje peer-ova sa privatnim adresama, na primer drugih farmera na istoj lokalnoj mreži
+configuration_advanced_updates = Ažuriranja
+configuration_advanced_updates_check = Proveravaj ažuriranja:
+configuration_advanced_updates_check_tooltip = Povremeno proveravaj da li je dostupna nova verzija Space Acres-a
+configuration_advanced_updates_include_prereleases = Uključi pred-izdanja:
+configuration_advanced_updates_include_prereleases_tooltip = Obaveštavaj i o pred-izdanjima, koja mogu biti manje stabilna
+configuration_advanced_updates_source = Izvor ažuriranja:
+configuration_advanced_updates_source_placeholder = GitHub izdanja
+configuration_advanced_updates_source_tooltip = URL ili apsolutna putanja JSON fajla u formatu GitHub API-ja za izdanja koji se proverava umesto GitHub-a, korisno za testiranje i mirror servere, ostavite prazno za korišćenje GitHub-a
 configuration_button_add_farm = Dodaj farmu
 configuration_button_help = Pomoć
 configuration_button_cancel = Otkaži
@@ -98,6 +208,12 @@ configuration_button_save = Sačuvaj
 configuration_button_start = Pokreni
 configuration_dialog_button_select = Izaberi
 configuration_dialog_button_cancel = Otkaži
+network_identity_dialog_button_export = Izvezi
+network_identity_dialog_button_import = Uvezi
+network_identity_rotate_confirm_heading = Generisati novi mrežni identitet?
+network_identity_rotate_confirm_body = Peer ID ovog čvora će se promeniti nakon ponovnog pokretanja i peer-ovi koji ga referenciraju po peer ID-u moraće da se ažuriraju. Prethodni identitet biće sačuvan u secret_ed25519.<peer ID>.bak u mrežnom direktorijumu podataka čvora.
+network_identity_rotate_confirm_button_cancel = Otkaži
+network_identity_rotate_confirm_button_rotate = Generiši
 
 node_migration_button_cancel = Otkaži
 node_migration_button_reset = Resetuj čvor
@@ -131,6 +247,46 @@ running_node_title_tooltip = Kliknite da otvorite u upravitelju datotekama
 # TODO: Translate
 running_node_free_disk_space_tooltip = Slobodan prostor na disku: preostalo {$size}
 running_node_connections_tooltip = {$connected_peers}/{$expected_peers} peers connected, click for details about required P2P ports
+running_node_peer_id = ID peer-a: {$peer_id}
+running_node_network_identity_tooltip = Mrežni identitet
+running_node_network_identity_export = Izvezi mrežni identitet…
+running_node_network_identity_export_warning = Izvezena datoteka sadrži tajni ključ ovog čvora, svako ko je ima može da se predstavlja kao ovaj čvor na mreži, čuvajte je privatno
+running_node_network_identity_import = Uvezi mrežni identitet…
+running_node_network_identity_rotate = Generiši novi mrežni identitet
+running_node_network_identity_replace_warning = Uvoz ili generisanje mrežnog identiteta menja ID peer-a nakon ponovnog pokretanja, peer-ovi koji referenciraju ovaj čvor po ID-u peer-a moraće da budu ažurirani
+running_node_peers_button = Peer-ovi
+running_node_peers_button_tooltip = Pregledaj povezane peer-ove, korisno kada sinhronizacija zastane
+running_node_peers_substrate = Peer-ovi čvora konsenzusa (Substrate): {$count}
+running_node_peers_dsn = DSN peer-ovi: {$count}
+running_node_known_peers = Keš poznatih peer-ova
+running_node_known_peers_description = Adrese DSN peer-ova zapamćene između ponovnih pokretanja radi bržeg ponovnog povezivanja. Obrišite ga ako je ponovno povezivanje sporo nakon promene IP adrese ili mreže, takođe se automatski briše nakon nedelju dana van mreže.
+running_node_known_peers_inspect = Pregledaj
+running_node_known_peers_clear = Obriši
+running_node_peers_role_authority = autoritet
+running_node_peers_role_full = pun
+running_node_peers_role_light = lak
+running_node_peers_direction_inbound = dolazni
+running_node_peers_direction_outbound = odlazni
+running_node_peers_address_unknown = nepoznata adresa
+running_node_peers_none = Nema povezanih peer-ova
+running_node_piece_serving = Posluženo delova: {$pieces}, {$upload_rate}/s
+running_node_piece_serving_tooltip =
+    Podaci posluženi drugim farmerima preko DSN-a:
+    delovi iz keša: {$cache_hits}
+    delovi iz plotova: {$plot_hits}
+    delovi nisu pronađeni: {$misses}
+    najbliži peer-ovi poslati umesto dela: {$closest_peers_fallbacks}
+    zaglavlja segmenata: {$segment_headers}
+    ukupno otpremljeno: {$bytes_uploaded}
+running_node_bandwidth = Poslednja 24 h: ↓ {$downloaded} ↑ {$uploaded}
+running_node_bandwidth_tooltip =
+    Poslednji sat: ↓ {$hour_downloaded} ↑ {$hour_uploaded}
+    Poslednja 24 sata, čvor konsenzusa: ↓ {$substrate_downloaded} ↑ {$substrate_uploaded}
+    Poslednja 24 sata, DSN (samo delovi, bez DHT-a, gossip-a i režijskog saobraćaja protokola): ↓ {$dsn_downloaded} ↑ {$dsn_uploaded}
+    Dnevna ograničenja: ↓ {$download_cap} ↑ {$upload_cap}
+running_node_bandwidth_no_cap = bez ograničenja
+running_node_bandwidth_download_cap_reached = Dostignuto ograničenje preuzimanja, preuzimanja za plotovanje su smanjena, a DSN veze će biti smanjene nakon ponovnog pokretanja
+running_node_bandwidth_upload_cap_reached = Dostignuto ograničenje otpremanja, DSN veze će biti smanjene nakon ponovnog pokretanja
 running_node_status_connecting = Povezivanje sa mrežom, najbolji blok #{$block_number}
 running_node_status_syncing_speed_no_eta = , {NUMBER($blocks_per_second, minimumFractionDigits: 2, maximumFractionDigits: 2)} blokova/s
 running_node_status_syncing_speed_hours_eta = , {NUMBER($a_blocks_per_second, minimumFractionDigits: 2, maximumFractionDigits: 2)} blokova/s (~{NUMBER($b_hours_remaining, minimumFractionDigits: 2, maximumFractionDigits: 2)} sati preostalo)
@@ -149,6 +305,15 @@ running_farmer_button_pause_plotting = Pauziraj plotovanje/preplotovanje, imajte
 running_farmer_button_resume_plotting = Nastavi plotovanje
 running_farmer_account_balance_tooltip = Ukupni saldo i kovanice zarđene od početka rada aplikacije, kliknite da vidite detalje u Astral
 running_farmer_piece_cache_sync = Sinhronizacija delova keša {NUMBER($percentage, minimumFractionDigits: 2, maximumFractionDigits: 2)}%
+running_farmer_piece_downloads = Preuzeti delovi: {$local} lokalno, {$dsn_cache} DSN keš, {$archival} arhiva, {$node} čvor, {$failed} neuspešno
+running_farmer_piece_downloads_tooltip =
+    Delovi preuzeti za plotovanje, sinhronizaciju keša delova i sinhronizaciju čvora:
+    iz lokalnog keša delova i plotova: {$local}
+    iz DSN keša: {$dsn_cache}
+    iz arhivskog skladišta: {$archival}
+    od čvora: {$node}
+    nevažeći delovi primljeni od DSN peer-ova: {$invalid}
+    nije moguće preuzeti ni iz jednog izvora: {$failed}
 running_farmer_next_reward_estimate =
     Sledeća procena nagrade: {$eta_string ->
         [any_time_now] bilo kada
@@ -163,7 +328,10 @@ running_farmer_farm_reward_signatures_tooltip = {$successful_signatures}/{$total
 running_farmer_farm_auditing_performance_tooltip = Provera performansi: prosečno vreme {NUMBER($a_average_time, minimumFractionDigits: 2, maximumFractionDigits: 2)}s, vremensko ograničenje {NUMBER($b_time_timit, minimumFractionDigits: 2, maximumFractionDigits: 2)}s
 running_farmer_farm_proving_performance_tooltip = Dokazivanje performansi: prosečno vreme {NUMBER($a_average_time, minimumFractionDigits: 2, maximumFractionDigits: 2)}s, vremensko ograničenje {NUMBER($b_time_timit, minimumFractionDigits: 2, maximumFractionDigits: 2)}s
 running_farmer_farm_non_fatal_error_tooltip = Dogodila se ne-fatalna greška u farmovanju i uspešno je ispravljena, pogledajte dnevnik za više detalja: {$error}
+running_farmer_farm_free_disk_space_tooltip = Slobodan prostor na disku: preostalo {$size}
 running_farmer_farm_crashed = Farma se srušila: {$error}
+running_farmer_farm_disk_missing = Disk farme nedostaje, farma je zaustavljena dok se disk ponovo ne poveže
+running_farmer_farm_disk_read_only = Disk farme je samo za čitanje, farma je zaustavljena dok upis na disk ponovo ne bude moguć
 running_farmer_farm_plotting_speed =  ({NUMBER($a_sector_time, minimumFractionDigits: 2, maximumFractionDigits: 2)} m/sektoru, {NUMBER($b_sectors_per_hour, minimumFractionDigits: 2, maximumFractionDigits: 2)} sektora/h)
 running_farmer_farm_plotting_initial =
     {$pausing_state ->
@@ -207,6 +375,8 @@ error_title = Greška
 error_message = Greška: {$error}
 error_message_failed_to_send_config_to_backend = Nije uspelo slanje konfiguracije na backend: {$error}
 error_message_failed_to_send_pause_plotting_to_backend = Nije uspelo slanje pauze plotovanja na backend: {$error}
+error_message_failed_to_send_network_identity_action_to_backend = Slanje akcije mrežnog identiteta backend-u nije uspelo: {$error}
+error_message_failed_to_send_known_peers_action_to_backend = Slanje akcije poznatih peer-ova backend-u nije uspelo: {$error}
 error_button_help_from_community = Pomoć zajednice
 error_button_reset_node = Resetuj čvor
 error_button_reset_node_tooltip = Obriši podatke čvora i ponovo sinhronizuj sa mreže
@@ -214,6 +384,17 @@ error_button_show_logs = Prikaz dnevnika
 
 new_version_available = Dostupna je nova verzija {$version} 🎉
 new_version_available_button_open = Otvori stranicu sa izdanjima
+new_version_available_button_release_notes = Prikaži beleške o izdanju
+new_version_release_notes_title = Šta je novo u {$version}
+new_version_release_notes_empty = Beleške o izdanju nisu dostupne
+new_version_update_button_install = Preuzmi i instaliraj
+new_version_update_downloading = Preuzimanje verzije {$version}…
+new_version_update_ready = Instaliraj verziju {$version}
+new_version_update_installing = Instaliranje verzije {$version}…
+new_version_update_confirm_heading = Instalirati verziju {$version}?
+new_version_update_confirm_body = Ažuriranje je preuzeto i njegov potpis je proveren, Space Acres će se ponovo pokrenuti da završi instalaciju
+new_version_update_confirm_button_cancel = Otkaži
+new_version_update_confirm_button_install = Instaliraj i ponovo pokreni
 
 main_menu_show_logs = Prikaz dnevnika u upravitelju datotekama
 main_menu_change_configuration = Promeni konfiguraciju
@@ -224,6 +405,17 @@ main_menu_exit = Izlaz
 status_bar_message_configuration_is_invalid = Konfiguracija je nevažeća: {$error}
 status_bar_message_restart_is_needed_for_configuration = Potreban je restart aplikacije za primenu promena u konfiguraciji
 status_bar_message_failed_to_save_configuration = Nije uspelo čuvanje promena konfiguracije: {$error}
+status_bar_message_network_identity_exported = Mrežni identitet je izvezen u {$path}, čuvajte ovu datoteku privatno
+status_bar_message_restart_is_needed_for_network_identity = Potrebno je ponovno pokretanje aplikacije da bi novi mrežni identitet sa ID-om peer-a {$peer_id} stupio na snagu
+status_bar_message_failed_to_update_network_identity = Ažuriranje mrežnog identiteta nije uspelo: {$error}
+status_bar_message_known_peers_cache = Keš poznatih peer-ova {$path} zauzima {$size}, poslednji put ažuriran pre {$hours} sati
+status_bar_message_known_peers_cache_unknown_age = Keš poznatih peer-ova {$path} zauzima {$size}
+status_bar_message_known_peers_cache_empty = Keš poznatih peer-ova je prazan
+status_bar_message_known_peers_cache_reset_scheduled = Keš poznatih peer-ova biće obrisan pri sledećem pokretanju
+status_bar_message_restart_is_needed_for_known_peers_cache = Potrebno je ponovo pokrenuti aplikaciju da bi se obrisao keš poznatih peer-ova
+status_bar_message_restart_is_needed_for_bandwidth_cap = Dostignuto ograničenje protoka, potrebno je ponovo pokrenuti aplikaciju da bi se smanjile DSN veze
+status_bar_message_failed_to_manage_known_peers_cache = Upravljanje kešom poznatih peer-ova nije uspelo: {$error}
+status_bar_message_failed_to_update = Ažuriranje nije uspelo: {$error}
 status_bar_message_restarted_after_crash = Space Acres se automatski restartovao nakon kraha, proveri dnevnik aplikacije za više informacija
 status_bar_button_migrate = Migriraj
 status_bar_button_ok = U redu
@@ -243,8 +435,16 @@ notification_stopped_with_error = Space Acres je zaustavljen zbog greške
     .body = Došlo je do greške koja zahteva intervenciju korisnika za rešavanje
 notification_farm_error = Jedna od farmi u Space Acresu nije uspela
     .body = Došlo je do greške koja zahteva intervenciju korisnika za rešavanje
+notification_farm_disk_missing = Disk farme nedostaje
+    .body = Farma {$path} je zaustavljena dok se disk ponovo ne poveže, ostale farme nastavljaju sa radom
+notification_farm_disk_read_only = Disk farme je samo za čitanje
+    .body = Farma {$path} je zaustavljena dok upis na disk ponovo ne bude moguć, ostale farme nastavljaju sa radom
+notification_farm_disk_available = Disk farme je ponovo dostupan
+    .body = Farma {$path} je ponovo priključena i nastavlja sa radom
 notification_node_low_disk_space = Malo prostora na disku čvora
     .body = Na volumenu čvora je preostalo samo {$free_space}
+notification_farm_low_disk_space = Malo prostora na disku farme
+    .body = Na volumenu farme {$farm_path} je preostalo samo {$free_space}
 notification_missed_reward = Potpisivanje nagrade nije uspelo 😞
     .body = To je nesreća, ali biće još prilika uskoro
 notification_signed_reward_successfully = Uspešno potpisana nova nagrada 🥳
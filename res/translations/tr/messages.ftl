@@ -30,6 +30,7 @@ loading_configuration_step_decoded_chain_spec = Ağ özellikleri başarıyla ç
 loading_networking_stack_title = Ağ yığını başlatılıyor
 loading_networking_stack_step_checking_node_path = Node dosya yolu kontrol ediliyor...
 loading_networking_stack_step_creating_node_path = Node dosya yolu oluşturuluyor...
+loading_networking_stack_step_resetting_node_database = Senkronizasyon modu veya düğüm depolaması değiştirildi, düğüm veritabanı kaldırılıyor...
 loading_networking_stack_step_node_path_ready = Node dosya yolu hazır
 loading_networking_stack_step_preparing = Ağ yapısı hazırlanıyor...
 loading_networking_stack_step_reading_keypair = Ağ anahtar çifti okunuyor...
@@ -40,8 +41,16 @@ loading_networking_stack_step_created_successfully = Ağ yığını başarılı
 loading_consensus_node_title = Konsensüs Node'u Başlatılıyor
 loading_consensus_node_step_creating = Konsensüs Node'u oluşturuluyor...
 loading_consensus_node_step_created_successfully = Konsensüs Node'u başarıyla oluşturuldu
+loading_consensus_node_step_connecting_to_external_node = {$url} adresindeki harici düğüme bağlanılıyor...
+loading_consensus_node_step_connected_to_external_node_successfully = Harici düğüme başarıyla bağlanıldı
 loading_farmer_title = Çiftçi başlatılıyor
 loading_farmer_step_initializing = Çiftlikler Başlatılıyor {$index}/{$farms_total}...
+loading_farmer_step_initializing_farm_reading_metadata = Çiftlik {$farm_index}: meta veriler okunuyor ({$time_elapsed} geçti)
+loading_farmer_step_initializing_farm_allocating = Çiftlik {$farm_index}: alan ayrılıyor {$space_allocated}/{$space_total} ({$time_elapsed} geçti, ~{$time_remaining} kaldı)
+loading_farmer_step_initializing_farm_allocating_no_eta = Çiftlik {$farm_index}: alan ayrılıyor {$space_allocated}/{$space_total} ({$time_elapsed} geçti)
+loading_farmer_step_initializing_farm_initializing_caches = Çiftlik {$farm_index}: parça önbelleği ve plot önbelleği başlatılıyor ({$time_elapsed} geçti)
+loading_farmer_step_collecting_plotted_pieces = Önceden plotlanmış parçalar toplanıyor (bu biraz zaman alacak)...
+loading_farmer_step_collecting_plotted_pieces_farm = Çiftlik {$farm_index}: {$sectors_collected}/{$sectors_plotted} sektör
 loading_farmer_step_created_successfully = Çiftlikler başarıyla oluşturuldu
 loading_wiping_farmer_data_title = Çiftçi Verileri Temizleniyor
 loading_wiping_farmer_data_step_wiping_farm = Çiftlikler {$index}/{$farms_total} konumunda temizleniyor {$path}...
@@ -77,17 +86,118 @@ configuration_farm_fixed_size_placeholder = Örnek: 4T, 2.5TB, 500G, vb.
 configuration_farm_fixed_size_tooltip = Çiftlik boyutunu istediğiniz birimle girin. 2 GB üzerinde herhangi bir alan uygundur
 configuration_farm_free_percentage_size_placeholder = Örnek: 100%, 1.1%, vb.
 configuration_farm_free_percentage_size_tooltip = Bu çiftliğin kaplayacağı boş disk alanının yüzdesi 0%'dan büyük bir değer olmalıdır, ancak hataları önlemek için disk üzerinde en az 2 GB boş alan kalmalıdır
+configuration_farm_free_space_warning_threshold_placeholder = Düşük alan uyarısı: {$default_threshold}
+configuration_farm_free_space_warning_threshold_tooltip = Altında düşük disk alanı uyarısı gösterilen çiftlik diskindeki boş alan, örneğin 500MiB veya 5GB, varsayılanı kullanmak için boş bırakın
 configuration_farm_delete = Bu çiftliği sil
+configuration_no_farms_node_only = Yapılandırılmış çiftlik yok, yalnızca düğüm farming olmadan çalışacak
+configuration_no_farms_external_node = Harici düğümle en az bir çiftlik gerekir, aksi halde hiçbir şey çalışmaz
 configuration_advanced = Gelişmiş Konfigürasyon
 configuration_advanced_farmer = Çiftçi Konfigürasyonu
 configuration_advanced_farmer_reduce_plotting_cpu_load = Çizim CPU yükünü azalt
 configuration_advanced_farmer_reduce_plotting_cpu_load_tooltip = Başlangıç çizimi varsayılan olarak tüm CPU çekirdeklerini kullanır. Bu seçenek etkinleştirildiğinde, yeniden çizimde olduğu gibi sadece çekirdeklerin yarısını kullanır. Bu, diğer görevler için sistemin daha duyarlı olmasını sağlar
+configuration_advanced_node = Node yapılandırması
+configuration_advanced_node_name = Düğüm adı:
+configuration_advanced_node_name_placeholder = İlk başlangıçta otomatik olarak oluşturulur
+configuration_advanced_node_name_tooltip = Düğümün ağda ve telemetride kullandığı ad, en fazla {$max_length} karakter
+configuration_advanced_node_telemetry = Telemetri:
+configuration_advanced_node_telemetry_tooltip = Düğüm adını, sürümünü ve senkronizasyon durumunu genel telemetriye bildir, ağ sağlığını izlemeye yardımcı olur
+configuration_advanced_node_sync_mode = Senkronizasyon modu:
+configuration_advanced_node_sync_mode_tooltip = Düğümün zinciri nasıl senkronize ettiği, değiştirmek düğüm veritabanını kaldırır ve sıfırdan yeniden senkronize eder
+configuration_advanced_node_sync_mode_snap = Snap
+configuration_advanced_node_sync_mode_full = Tam
+configuration_advanced_node_sync_mode_snap_explanation = Son durumu ve yalnızca farming için gerekli blokları indirir, farming'e çok daha erken hazır olur
+configuration_advanced_node_sync_mode_full_explanation = Genesis'ten bu yana her bloğu indirir ve doğrular, çok daha uzun sürer, ancak diğer düğümlerin sağladığı duruma güvenmez
+configuration_advanced_node_sync_mode_changed_warning = Düğüm veritabanı bir sonraki başlatmada kaldırılacak ve yeniden senkronize edilecek
+configuration_advanced_node_pruning = Node depolaması:
+configuration_advanced_node_pruning_tooltip = Node'un zincir geçmişinin ne kadarını diskte tuttuğu, daha fazlasını tutmak geçmiş durumu yerel olarak sorgulamaya olanak tanır, arşiv moduna geçmek veya arşivden çıkmak node verilerinin silinip yeniden senkronize edilmesini gerektirir
+configuration_advanced_node_pruning_kind_minimum = Minimum (önerilen)
+configuration_advanced_node_pruning_kind_blocks = Son blokları tut
+configuration_advanced_node_pruning_kind_archive = Arşiv (her şey)
+configuration_advanced_node_pruning_blocks_tooltip = Tutulacak en son blok sayısı, en az {$min_blocks}
+configuration_advanced_node_pruning_estimate = Tahmini disk kullanımı: ~{$size}
+configuration_advanced_node_pruning_estimate_archive = Tahmini disk kullanımı: zincirle birlikte günde ~{$size} artar
+configuration_advanced_node_pruning_changed_warning = Düğüm veritabanı bir sonraki başlatmada kaldırılacak ve yeniden senkronize edilecek
+configuration_advanced_node_rpc = JSON-RPC sunucusu:
+configuration_advanced_node_rpc_tooltip = Düğümün JSON-RPC uç noktasını cüzdanlar, betikler ve diğer araçlar için aç
+configuration_advanced_node_rpc_listen_on = Dinleme adresi:
+configuration_advanced_node_rpc_listen_on_tooltip = IP adresi ve port, örneğin 127.0.0.1:9944, tüm arayüzlerde dinlemek için 0.0.0.0 kullanın
+configuration_advanced_node_rpc_unsafe_methods = Güvensiz yöntemler:
+configuration_advanced_node_rpc_unsafe_methods_tooltip = Düğüm davranışını değiştirebilen yöntemlere izin ver, bunları asla güvenilmeyen ağlara açmayın
+configuration_advanced_node_external_node = Harici düğüm:
+configuration_advanced_node_external_node_placeholder = Örnek: {$url}
+configuration_advanced_node_external_node_tooltip = Gömülü düğüm yerine farming yapılacak mevcut bir düğümün WebSocket RPC URL'si, gömülü düğümü çalıştırmak için boş bırakın
 configuration_advanced_network = Ağ konfigürasyonu
 configuration_advanced_network_default_port_number_tooltip = Varsayılan port numarası {$port}
+configuration_advanced_network_listen_on = Dinlenecek adres:
+configuration_advanced_network_listen_on_tooltip = Gelen bağlantıların kabul edileceği ağ arayüzleri, belirli bir adres birden fazla ağ arayüzüne sahip sunucularda kullanışlıdır
+configuration_advanced_network_listen_on_all = Tüm arayüzler
+configuration_advanced_network_listen_on_ipv4 = Yalnızca IPv4
+configuration_advanced_network_listen_on_specific = Belirli adres
+configuration_advanced_network_listen_on_custom = Özel (yapılandırma dosyası)
+configuration_advanced_network_listen_on_custom_explanation = Yapılandırma dosyasındaki adresler kullanılıyor, aşağıdaki portlar yok sayılıyor. Substrate: {$substrate_addresses}; DSN: {$dsn_addresses}
+configuration_advanced_network_listen_on_address_placeholder = 192.168.1.10
 configuration_advanced_network_substrate_port = Substrate (blok zinciri) P2P portu (TCP):
 configuration_advanced_network_subspace_port = Subspace (DSN) P2P portu (TCP):
-configuration_advanced_network_faster_networking = Daha Hızlı Ağ:
-configuration_advanced_network_faster_networking_tooltip = Varsayılan olarak ağ, tüketici yönlendiricilerine göre optimize edilmiştir. Ancak daha güçlü bir kurulumunuz varsa, daha hızlı ağ seçeneği senkronizasyon hızını ve diğer süreçleri iyileştirebilir
+configuration_advanced_network_pick_free_ports = Boş portları seç
+configuration_advanced_network_pick_free_ports_tooltip = Her iki portu da varsayılanlardan başlayarak başka hiçbir uygulama tarafından kullanılmayan ilk portlarla değiştir
+configuration_advanced_network_error_port_in_use = {$port} portu zaten başka bir uygulama tarafından kullanılıyor, farklı bir port seçin
+configuration_advanced_network_error_duplicate_port = {$port} portu hem Substrate hem de DSN tarafından kullanılamaz, farklı portlar seçin
+configuration_advanced_network_connections = Bağlantılar:
+configuration_advanced_network_connections_tooltip = Bağlantı sayısı sınırları, düşük sınırlar kotalı bağlantılarda ve zayıf yönlendiricilerde yardımcı olur, daha güçlü kurulumlarda yüksek sınırlar senkronizasyon hızını ve diğer süreçleri iyileştirebilir
+configuration_advanced_network_connections_low = Düşük
+configuration_advanced_network_connections_default = Varsayılan
+configuration_advanced_network_connections_high = Yüksek
+configuration_advanced_network_connections_custom = Özel
+configuration_advanced_network_connections_dsn_in = DSN gelen bağlantılar:
+configuration_advanced_network_connections_dsn_out = DSN giden bağlantılar:
+configuration_advanced_network_connections_dsn_pending_in = DSN bekleyen gelen bağlantılar:
+configuration_advanced_network_connections_dsn_pending_out = DSN bekleyen giden bağlantılar:
+configuration_advanced_network_connections_substrate_in = Substrate gelen eşler:
+configuration_advanced_network_connections_substrate_out = Substrate giden eşler:
+configuration_advanced_network_piece_download = Parça indirmeleri:
+configuration_advanced_network_piece_download_tooltip = Plotlama ve düğüm senkronizasyonu için parça indirmelerinin yeniden deneme politikası ve eşzamanlılığı
+configuration_advanced_network_piece_download_max_retries = Yeniden deneme sayısı:
+configuration_advanced_network_piece_download_initial_retry_interval = İlk yeniden deneme aralığı (saniye):
+configuration_advanced_network_piece_download_max_retry_interval = En fazla yeniden deneme aralığı (saniye):
+configuration_advanced_network_piece_download_concurrency_multiplier = Giden DSN bağlantısı başına eşzamanlılık çarpanı:
+configuration_advanced_network_peer_multiaddrs_placeholder = /dns/example.com/tcp/30333/p2p/12D3KooW...
+configuration_advanced_network_substrate_bootstrap_nodes = Substrate önyükleme düğümleri:
+configuration_advanced_network_substrate_bootstrap_nodes_tooltip = Ağı keşfetmek için ek Substrate (blok zinciri) düğümleri, /p2p/<eş kimliği> ile biten virgülle ayrılmış multiaddr'lar
+configuration_advanced_network_substrate_reserved_nodes = Substrate ayrılmış düğümleri:
+configuration_advanced_network_substrate_reserved_nodes_tooltip = Her zaman bağlı kalınacak Substrate (blok zinciri) düğümleri, /p2p/<eş kimliği> ile biten virgülle ayrılmış multiaddr'lar
+configuration_advanced_network_dsn_bootstrap_nodes = DSN önyükleme düğümleri:
+configuration_advanced_network_dsn_bootstrap_nodes_tooltip = Ağı keşfetmek için ek Subspace (DSN) düğümleri, /p2p/<eş kimliği> ile biten virgülle ayrılmış multiaddr'lar
+configuration_advanced_network_dsn_reserved_peers = DSN ayrılmış eşleri:
+configuration_advanced_network_dsn_reserved_peers_tooltip = Her zaman bağlı kalınacak Subspace (DSN) eşleri, /p2p/<eş kimliği> ile biten virgülle ayrılmış multiaddr'lar
+configuration_advanced_network_substrate_external_addresses = Substrate harici adresleri:
+configuration_advanced_network_substrate_external_addresses_placeholder = /ip4/203.0.113.1/tcp/30333
+configuration_advanced_network_substrate_external_addresses_tooltip = Diğer eşlere duyurulacak, herkese açık erişilebilir Substrate (blok zinciri) adresleri, örneğin yönlendiricide port yönlendirme yapılandırıldığında, virgülle ayrılmış multiaddr'lar
+configuration_advanced_network_dsn_external_addresses = DSN harici adresleri:
+configuration_advanced_network_dsn_external_addresses_placeholder = /ip4/203.0.113.1/tcp/30433
+configuration_advanced_network_dsn_external_addresses_tooltip = Diğer eşlere duyurulacak, herkese açık erişilebilir Subspace (DSN) adresleri, örneğin yönlendiricide port yönlendirme yapılandırıldığında, virgülle ayrılmış multiaddr'lar
+configuration_advanced_network_daily_download_cap = Günlük indirme sınırı:
+configuration_advanced_network_daily_download_cap_tooltip = Son 24 saatte her iki ağın indirmesi için esnek sınır, parçalar dışındaki DSN trafiği sayılmaz. Ulaşıldığında plotting indirmeleri azaltılır ve DSN bağlantıları yeniden başlatmadan sonra azaltılır. Sınır olmaması için boş bırakın
+configuration_advanced_network_daily_upload_cap = Günlük yükleme sınırı:
+configuration_advanced_network_daily_upload_cap_tooltip = Son 24 saatte her iki ağın yüklemesi için esnek sınır, parçalar dışındaki DSN trafiği sayılmaz. Ulaşıldığında DSN bağlantıları yeniden başlatmadan sonra azaltılır. Sınır olmaması için boş bırakın
+configuration_advanced_network_proxy_url = Proxy URL'si:
+configuration_advanced_network_proxy_url_tooltip = Güncelleme kontrolleri için kullanılan HTTP, HTTPS veya SOCKS5 proxy, örneğin http://127.0.0.1:8080 veya socks5h://127.0.0.1:1080 (socks5h ana bilgisayar adlarını proxy üzerinden çözer), doğrudan bağlanmak için boş bırakın. Düğüm ve çiftçinin P2P bağlantıları proxy desteklemez
+configuration_advanced_network_proxy_url_placeholder = http://sunucu:port
+configuration_advanced_network_proxy_username = Proxy kullanıcı adı:
+configuration_advanced_network_proxy_username_tooltip = Proxy kimlik doğrulaması için kullanıcı adı, proxy kimlik doğrulama gerektirmiyorsa boş bırakın
+configuration_advanced_network_proxy_username_placeholder = isteğe bağlı
+configuration_advanced_network_proxy_password = Proxy şifresi:
+configuration_advanced_network_proxy_password_tooltip = Proxy kimlik doğrulaması için şifre, kullanıcı adı gerektirir, yapılandırma dosyasında düz metin olarak saklanır
+configuration_advanced_network_daily_cap_placeholder = Örneğin 50 GB, sınır yoksa boş
+configuration_advanced_network_allow_private_addresses = Özel adreslere izin ver:
+configuration_advanced_network_allow_private_addresses_tooltip = Özel adreslere sahip eşlere bağlan ve onları keşfet, örneğin aynı yerel ağdaki diğer çiftçiler
+configuration_advanced_updates = Güncellemeler
+configuration_advanced_updates_check = Güncellemeleri kontrol et:
+configuration_advanced_updates_check_tooltip = Space Acres'ın yeni bir sürümünün mevcut olup olmadığını düzenli olarak kontrol et
+configuration_advanced_updates_include_prereleases = Ön sürümleri dahil et:
+configuration_advanced_updates_include_prereleases_tooltip = Daha az kararlı olabilecek ön sürümler hakkında da bildirim gönder
+configuration_advanced_updates_source = Güncelleme kaynağı:
+configuration_advanced_updates_source_placeholder = GitHub sürümleri
+configuration_advanced_updates_source_tooltip = GitHub yerine kontrol edilecek, GitHub sürüm API'si biçimindeki JSON dosyasının URL'si veya mutlak yolu, test ve yansı sunucuları için kullanışlıdır, GitHub'ı kullanmak için boş bırakın
 configuration_button_add_farm = Çiftlik Ekle
 configuration_button_help = Yardım
 configuration_button_cancel = İptal
@@ -96,6 +206,12 @@ configuration_button_save = Kaydet
 configuration_button_start = Başlat
 configuration_dialog_button_select = Seç
 configuration_dialog_button_cancel = İptal
+network_identity_dialog_button_export = Dışa aktar
+network_identity_dialog_button_import = İçe aktar
+network_identity_rotate_confirm_heading = Yeni ağ kimliği oluşturulsun mu?
+network_identity_rotate_confirm_body = Bu düğümün eş kimliği yeniden başlatmadan sonra değişecek ve ona eş kimliğiyle başvuran eşlerin güncellenmesi gerekecek. Önceki kimlik, düğüm verilerinin ağ dizinindeki secret_ed25519.<eş kimliği>.bak dosyasında saklanacak.
+network_identity_rotate_confirm_button_cancel = İptal
+network_identity_rotate_confirm_button_rotate = Oluştur
 
 node_migration_button_cancel = İptal
 node_migration_button_reset = Node'u Sıfırla
@@ -128,6 +244,46 @@ running_node_title = {$chain_name} konsensüs düğümü
 running_node_title_tooltip = Dosya yöneticisinde açmak için tıklayın
 running_node_free_disk_space_tooltip = Boş disk alanı: {$size} kaldı
 running_node_connections_tooltip = {$connected_peers}/{$expected_peers} peersler bağlı, gerekli P2P portları hakkında detaylar için tıklayın
+running_node_peer_id = Eş kimliği: {$peer_id}
+running_node_network_identity_tooltip = Ağ kimliği
+running_node_network_identity_export = Ağ kimliğini dışa aktar…
+running_node_network_identity_export_warning = Dışa aktarılan dosya bu düğümün gizli anahtarını içerir, ona sahip olan herkes ağda bu düğümün kimliğine bürünebilir, gizli tutun
+running_node_network_identity_import = Ağ kimliğini içe aktar…
+running_node_network_identity_rotate = Yeni ağ kimliği oluştur
+running_node_network_identity_replace_warning = Ağ kimliğini içe aktarmak veya oluşturmak yeniden başlatmadan sonra eş kimliğini değiştirir, bu düğüme eş kimliği ile başvuran eşlerin güncellenmesi gerekecektir
+running_node_peers_button = Eşler
+running_node_peers_button_tooltip = Bağlı eşleri incele, senkronizasyon durduğunda kullanışlıdır
+running_node_peers_substrate = Konsensüs düğümü (Substrate) eşleri: {$count}
+running_node_peers_dsn = DSN eşleri: {$count}
+running_node_known_peers = Bilinen eşler önbelleği
+running_node_known_peers_description = Daha hızlı yeniden bağlanmak için yeniden başlatmalar arasında hatırlanan DSN eş adresleri. IP adresi veya ağ değişikliğinden sonra yeniden bağlanma yavaşsa temizleyin, bir hafta çevrimdışı kaldıktan sonra otomatik olarak da temizlenir.
+running_node_known_peers_inspect = İncele
+running_node_known_peers_clear = Temizle
+running_node_peers_role_authority = otorite
+running_node_peers_role_full = tam
+running_node_peers_role_light = hafif
+running_node_peers_direction_inbound = gelen
+running_node_peers_direction_outbound = giden
+running_node_peers_address_unknown = bilinmeyen adres
+running_node_peers_none = Bağlı eş yok
+running_node_piece_serving = {$pieces} parça sunuldu, {$upload_rate}/s
+running_node_piece_serving_tooltip =
+    DSN üzerinden diğer çiftçilere sunulan veriler:
+    önbellekten parçalar: {$cache_hits}
+    plotlardan parçalar: {$plot_hits}
+    bulunamayan parçalar: {$misses}
+    parça yerine gönderilen en yakın eşler: {$closest_peers_fallbacks}
+    segment başlıkları: {$segment_headers}
+    toplam yüklenen: {$bytes_uploaded}
+running_node_bandwidth = Son 24 saat: ↓ {$downloaded} ↑ {$uploaded}
+running_node_bandwidth_tooltip =
+    Son saat: ↓ {$hour_downloaded} ↑ {$hour_uploaded}
+    Son 24 saat, konsensüs düğümü: ↓ {$substrate_downloaded} ↑ {$substrate_uploaded}
+    Son 24 saat, DSN (yalnızca parçalar, DHT, gossip ve protokol ek yükü hariç): ↓ {$dsn_downloaded} ↑ {$dsn_uploaded}
+    Günlük sınırlar: ↓ {$download_cap} ↑ {$upload_cap}
+running_node_bandwidth_no_cap = sınır yok
+running_node_bandwidth_download_cap_reached = İndirme sınırına ulaşıldı, plotting indirmeleri azaltıldı, DSN bağlantıları yeniden başlatmadan sonra azaltılacak
+running_node_bandwidth_upload_cap_reached = Yükleme sınırına ulaşıldı, DSN bağlantıları yeniden başlatmadan sonra azaltılacak
 running_node_status_connecting = Ağa bağlanılıyor, en iyi blok #{$block_number}
 running_node_status_syncing_speed_no_eta = , {NUMBER($blocks_per_second, minimumFractionDigits: 2, maximumFractionDigits: 2)} blok/sn
 running_node_status_syncing_speed_hours_eta = , {NUMBER($a_blocks_per_second, minimumFractionDigits: 2, maximumFractionDigits: 2)} blok/sn (~{NUMBER($b_hours_remaining, minimumFractionDigits: 2, maximumFractionDigits: 2)} saat kaldı)
@@ -146,6 +302,15 @@ running_farmer_button_pause_plotting = Alan oluşturmayı/yeni veri alanı hazı
 running_farmer_button_resume_plotting = Alan oluşturmaya devam et
 running_farmer_account_balance_tooltip = Uygulama başlatıldığından beri toplam hesap bakiyesi ve üretilen coinler, detayları Astral'de görmek için tıklayın
 running_farmer_piece_cache_sync = Parça önbelleği senkronizasyonu {NUMBER($percentage, minimumFractionDigits: 2, maximumFractionDigits: 2)}%
+running_farmer_piece_downloads = İndirilen parçalar: {$local} yerel, {$dsn_cache} DSN önbelleği, {$archival} arşiv, {$node} düğüm, {$failed} başarısız
+running_farmer_piece_downloads_tooltip =
+    Plotlama, parça önbelleği senkronizasyonu ve düğüm senkronizasyonu için alınan parçalar:
+    yerel parça önbelleği ve plotlardan: {$local}
+    DSN önbelleğinden: {$dsn_cache}
+    arşiv depolamasından: {$archival}
+    düğümden: {$node}
+    DSN eşlerinden alınan geçersiz parçalar: {$invalid}
+    hiçbir kaynaktan alınamayan: {$failed}
 running_farmer_next_reward_estimate =
     Bir sonraki ödül tahmini: {$eta_string ->
         [any_time_now] hemen şimdi
@@ -160,7 +325,10 @@ running_farmer_farm_reward_signatures_tooltip = {$successful_signatures}/{$total
 running_farmer_farm_auditing_performance_tooltip = Denetim performansı: ortalama süre {NUMBER($a_average_time, minimumFractionDigits: 2, maximumFractionDigits: 2)}sn, zaman limiti {NUMBER($b_time_timit, minimumFractionDigits: 2, maximumFractionDigits: 2)}sn
 running_farmer_farm_proving_performance_tooltip = Kanıt performansı: ortalama süre {NUMBER($a_average_time, minimumFractionDigits: 2, maximumFractionDigits: 2)}sn, zaman limiti {NUMBER($b_time_timit, minimumFractionDigits: 2, maximumFractionDigits: 2)}sn
 running_farmer_farm_non_fatal_error_tooltip = Riskli olmayan bir çiftçilik hatası oluştu ve düzeltildi, daha fazla detay için loglara bakın: {$error}
+running_farmer_farm_free_disk_space_tooltip = Boş disk alanı: {$size} kaldı
 running_farmer_farm_crashed = Çiftlik çöktü: {$error}
+running_farmer_farm_disk_missing = Çiftlik diski bulunamadı, disk yeniden bağlanana kadar çiftlik durduruldu
+running_farmer_farm_disk_read_only = Çiftlik diski salt okunur, disk yeniden yazılabilir olana kadar çiftlik durduruldu
 running_farmer_farm_plotting_speed = ({NUMBER($a_sector_time, minimumFractionDigits: 2, maximumFractionDigits: 2)} dakika/sektör, {NUMBER($b_sectors_per_hour, minimumFractionDigits: 2, maximumFractionDigits: 2)} sektörler/saat)
 running_farmer_farm_plotting_initial =
     {$pausing_state ->
@@ -204,6 +372,8 @@ error_title = Hata
 error_message = Hata: {$error}
 error_message_failed_to_send_config_to_backend = Konfigürasyon verileri arka uca iletilemedi: {$error}
 error_message_failed_to_send_pause_plotting_to_backend = Çizimi duraklatmayı arka uca göndermek başarısız oldu: {$error}
+error_message_failed_to_send_network_identity_action_to_backend = Ağ kimliği eylemi arka uca gönderilemedi: {$error}
+error_message_failed_to_send_known_peers_action_to_backend = Bilinen eşler eylemi arka uca gönderilemedi: {$error}
 error_button_help_from_community = Topluluktan yardım iste
 error_button_reset_node = Node'u sıfırla
 error_button_reset_node_tooltip = Node verilerini sil ve ağdan sıfırdan senkronize et
@@ -211,6 +381,17 @@ error_button_show_logs = Logları göster
 
 new_version_available = Sürüm {$version} mevcut 🎉
 new_version_available_button_open = Sürümler sayfasını aç
+new_version_available_button_release_notes = Sürüm notlarını göster
+new_version_release_notes_title = {$version} sürümündeki yenilikler
+new_version_release_notes_empty = Sürüm notu sağlanmadı
+new_version_update_button_install = İndir ve yükle
+new_version_update_downloading = {$version} sürümü indiriliyor…
+new_version_update_ready = {$version} sürümünü yükle
+new_version_update_installing = {$version} sürümü yükleniyor…
+new_version_update_confirm_heading = {$version} sürümü yüklensin mi?
+new_version_update_confirm_body = Güncelleme indirildi ve imzası doğrulandı, kurulumu tamamlamak için Space Acres yeniden başlatılacak
+new_version_update_confirm_button_cancel = İptal
+new_version_update_confirm_button_install = Yükle ve yeniden başlat
 
 main_menu_show_logs = Logları dosya yöneticisinde göster
 main_menu_change_configuration = Konfigürasyonu değiştir
@@ -221,6 +402,17 @@ main_menu_exit = Çıkış
 status_bar_message_configuration_is_invalid = Konfigürasyon geçersiz: {$error}
 status_bar_message_restart_is_needed_for_configuration = Konfigürasyon değişikliklerinin etkili olması için uygulamanın yeniden başlatılması gerekiyor
 status_bar_message_failed_to_save_configuration = Konfigürasyon değişiklikleri kaydedilemedi: {$error}
+status_bar_message_network_identity_exported = Ağ kimliği {$path} konumuna dışa aktarıldı, bu dosyayı gizli tutun
+status_bar_message_restart_is_needed_for_network_identity = {$peer_id} eş kimliğine sahip yeni ağ kimliğinin geçerli olması için uygulamanın yeniden başlatılması gerekiyor
+status_bar_message_failed_to_update_network_identity = Ağ kimliği güncellenemedi: {$error}
+status_bar_message_known_peers_cache = Bilinen eşler önbelleği {$path} {$size} yer kaplıyor, en son {$hours} saat önce güncellendi
+status_bar_message_known_peers_cache_unknown_age = Bilinen eşler önbelleği {$path} {$size} yer kaplıyor
+status_bar_message_known_peers_cache_empty = Bilinen eşler önbelleği boş
+status_bar_message_known_peers_cache_reset_scheduled = Bilinen eşler önbelleği bir sonraki başlangıçta temizlenecek
+status_bar_message_restart_is_needed_for_known_peers_cache = Bilinen eşler önbelleğini temizlemek için uygulamanın yeniden başlatılması gerekiyor
+status_bar_message_restart_is_needed_for_bandwidth_cap = Bant genişliği sınırına ulaşıldı, DSN bağlantılarını azaltmak için uygulamanın yeniden başlatılması gerekiyor
+status_bar_message_failed_to_manage_known_peers_cache = Bilinen eşler önbelleği yönetilemedi: {$error}
+status_bar_message_failed_to_update = Güncelleme başarısız oldu: {$error}
 status_bar_message_restarted_after_crash = Space Acres çökme sonrası otomatik olarak yeniden başlatıldı, ayrıntılar için uygulama ve sistem loglarını kontrol edin
 status_bar_button_migrate = Taşı
 status_bar_button_ok = Tamam
@@ -239,8 +431,16 @@ notification_stopped_with_error = Space Acres bir hata nedeniyle durdu
     .body = Bir hata meydana geldi ve çözüm için kullanıcı müdahalesi gerekiyor
 notification_farm_error = Space Acres içerisindeki çiftliklerden biri başarısız oldu
     .body = Bir hata meydana geldi ve çözüm için kullanıcı müdahalesi gerekiyor
+notification_farm_disk_missing = Çiftlik diski bulunamadı
+    .body = {$path} çiftliği disk yeniden bağlanana kadar durduruldu, diğer çiftlikler çalışmaya devam ediyor
+notification_farm_disk_read_only = Çiftlik diski salt okunur
+    .body = {$path} çiftliği disk yeniden yazılabilir olana kadar durduruldu, diğer çiftlikler çalışmaya devam ediyor
+notification_farm_disk_available = Çiftlik diski yeniden kullanılabilir
+    .body = {$path} çiftliği yeniden katıldı ve çalışmaya devam ediyor
 notification_node_low_disk_space = Düşük Node Disk Alanı
     .body = Node disk biriminde sadece {$free_space} kaldı
+notification_farm_low_disk_space = Düşük Çiftlik Disk Alanı
+    .body = {$farm_path} çiftliğinin disk biriminde sadece {$free_space} kaldı
 notification_missed_reward = Ödül imzalama başarısız oldu 😞
     .body = Bu üzücü bir durum, ancak yakında başka bir şansınız olacak
 notification_signed_reward_successfully = Yeni ödül başarıyla imzalandı 🥳
@@ -30,6 +30,7 @@ loading_configuration_step_decoded_chain_spec = Специфікацію мер
 loading_networking_stack_title = Ініціалізація мережевого стеку
 loading_networking_stack_step_checking_node_path = Перевірка шляху до вузла...
 loading_networking_stack_step_creating_node_path = Створення шляху до вузла...
+loading_networking_stack_step_resetting_node_database = Режим синхронізації або сховище вузла змінено, видалення бази даних вузла...
 loading_networking_stack_step_node_path_ready = Шлях до вузла готовий
 loading_networking_stack_step_preparing = Підготовка мережевого стеку...
 loading_networking_stack_step_reading_keypair = Зчитування мережевої пари ключів...
@@ -40,8 +41,16 @@ loading_networking_stack_step_created_successfully = Стек мережі ус
 loading_consensus_node_title = Ініціалізація вузла консенсусу
 loading_consensus_node_step_creating = Створення вузла консенсусу...
 loading_consensus_node_step_created_successfully = Вузол консенсусу успішно створено
+loading_consensus_node_step_connecting_to_external_node = Підключення до зовнішнього вузла {$url}...
+loading_consensus_node_step_connected_to_external_node_successfully = Успішно підключено до зовнішнього вузла
 loading_farmer_title = Створення фармера
 loading_farmer_step_initializing = Створення ферми {$index}/{$farms_total}...
+loading_farmer_step_initializing_farm_reading_metadata = Ферма {$farm_index}: читання метаданих (минуло {$time_elapsed})
+loading_farmer_step_initializing_farm_allocating = Ферма {$farm_index}: виділення місця {$space_allocated}/{$space_total} (минуло {$time_elapsed}, залишилось ~{$time_remaining})
+loading_farmer_step_initializing_farm_allocating_no_eta = Ферма {$farm_index}: виділення місця {$space_allocated}/{$space_total} (минуло {$time_elapsed})
+loading_farmer_step_initializing_farm_initializing_caches = Ферма {$farm_index}: ініціалізація кешу частин і кешу плоту (минуло {$time_elapsed})
+loading_farmer_step_collecting_plotted_pieces = Збір вже засіяних частин (це займе деякий час)...
+loading_farmer_step_collecting_plotted_pieces_farm = Ферма {$farm_index}: {$sectors_collected}/{$sectors_plotted} секторів
 loading_farmer_step_created_successfully = Фармера успішно створено
 loading_wiping_farmer_data_title = Видалення даних фармера
 loading_wiping_farmer_data_step_wiping_farm = Видалення ферми {$index}/{$farms_total} at {$path}...
@@ -77,17 +86,118 @@ configuration_farm_fixed_size_placeholder = Приклад: 4T, 2.5TB, 500GiB, 
 configuration_farm_fixed_size_tooltip = Розмір ферми в будь-яких одиницях, яким ви віддаєте перевагу, будь-яка кількість простору понад 2 ГБ підійде
 configuration_farm_free_percentage_size_placeholder = Приклад: 100%, 1.1%, і тд.
 configuration_farm_free_percentage_size_tooltip = Відсоток вільного дискового простору, який займатиме ця ферма. Будь-яке значення понад 0% підійде, але на диску повинно залишатися принаймні 2 ГБ вільного місця, щоб уникнути помилок.
+configuration_farm_free_space_warning_threshold_placeholder = Попередження про брак місця: {$default_threshold}
+configuration_farm_free_space_warning_threshold_tooltip = Вільне місце на диску ферми, нижче якого показується попередження про брак місця, наприклад 500MiB або 5GB, залиште порожнім, щоб використати значення за замовчуванням
 configuration_farm_delete = Видалити ферму
+configuration_no_farms_node_only = Ферми не налаштовані, працюватиме лише вузол без фармінгу
+configuration_no_farms_external_node = З зовнішнім вузлом потрібна хоча б одна ферма, інакше нічого не буде запущено
 configuration_advanced = Розширені конфігурації
 configuration_advanced_farmer = Конфігурації Фармера
 configuration_advanced_farmer_reduce_plotting_cpu_load = Зменшити навантаження на процесор при плотингу
 configuration_advanced_farmer_reduce_plotting_cpu_load_tooltip = Початковий плотинг за замовчуванням використовує всі ядра процесора, тоді як з цією опцією він почне використовувати половину ядер як під час реплотингу, що покращить реагування системи для інших завдань
+configuration_advanced_node = Конфігурація вузла
+configuration_advanced_node_name = Ім'я вузла:
+configuration_advanced_node_name_placeholder = Генерується автоматично під час першого запуску
+configuration_advanced_node_name_tooltip = Ім'я вузла в мережі та телеметрії, до {$max_length} символів
+configuration_advanced_node_telemetry = Телеметрія:
+configuration_advanced_node_telemetry_tooltip = Надсилати ім'я, версію та статус синхронізації вузла до публічної телеметрії, допомагає відстежувати стан мережі
+configuration_advanced_node_sync_mode = Режим синхронізації:
+configuration_advanced_node_sync_mode_tooltip = Як вузол синхронізує ланцюг, зміна видаляє базу даних вузла і синхронізує її заново
+configuration_advanced_node_sync_mode_snap = Snap
+configuration_advanced_node_sync_mode_full = Повна
+configuration_advanced_node_sync_mode_snap_explanation = Завантажує недавній стан і лише блоки, необхідні для фармінгу, готовий до фармінгу значно швидше
+configuration_advanced_node_sync_mode_full_explanation = Завантажує і перевіряє кожен блок з генезису, займає значно більше часу, але не покладається на стан, наданий іншими вузлами
+configuration_advanced_node_sync_mode_changed_warning = База даних вузла буде видалена і синхронізована заново під час наступного запуску
+configuration_advanced_node_pruning = Сховище вузла:
+configuration_advanced_node_pruning_tooltip = Яку частину історії ланцюга вузол зберігає на диску, зберігання більшого обсягу дозволяє запитувати історичний стан локально, перехід в архівний режим або з нього потребує видалення даних вузла та повторної синхронізації
+configuration_advanced_node_pruning_kind_minimum = Мінімум (рекомендовано)
+configuration_advanced_node_pruning_kind_blocks = Зберігати останні блоки
+configuration_advanced_node_pruning_kind_archive = Архів (усе)
+configuration_advanced_node_pruning_blocks_tooltip = Кількість останніх блоків для зберігання, щонайменше {$min_blocks}
+configuration_advanced_node_pruning_estimate = Очікуване використання диска: ~{$size}
+configuration_advanced_node_pruning_estimate_archive = Очікуване використання диска: зростає разом з ланцюгом на ~{$size} на день
+configuration_advanced_node_pruning_changed_warning = База даних вузла буде видалена і синхронізована заново під час наступного запуску
+configuration_advanced_node_rpc = Сервер JSON-RPC:
+configuration_advanced_node_rpc_tooltip = Відкрити JSON-RPC доступ до вузла для гаманців, скриптів та інших інструментів
+configuration_advanced_node_rpc_listen_on = Адреса прослуховування:
+configuration_advanced_node_rpc_listen_on_tooltip = IP-адреса та порт, наприклад 127.0.0.1:9944, використовуйте 0.0.0.0 для прослуховування на всіх інтерфейсах
+configuration_advanced_node_rpc_unsafe_methods = Небезпечні методи:
+configuration_advanced_node_rpc_unsafe_methods_tooltip = Дозволити методи, що змінюють поведінку вузла, ніколи не відкривайте їх для недовірених мереж
+configuration_advanced_node_external_node = Зовнішній вузол:
+configuration_advanced_node_external_node_placeholder = Приклад: {$url}
+configuration_advanced_node_external_node_tooltip = WebSocket RPC URL наявного вузла для фармінгу замість вбудованого вузла, залиште порожнім для запуску вбудованого вузла
 configuration_advanced_network = Налаштування мережі
 configuration_advanced_network_default_port_number_tooltip = Порт за замовчуванням: {$port}
+configuration_advanced_network_listen_on = Прослуховувати:
+configuration_advanced_network_listen_on_tooltip = Мережеві інтерфейси для прийому вхідних з'єднань, конкретна адреса корисна на серверах з кількома мережевими інтерфейсами
+configuration_advanced_network_listen_on_all = Усі інтерфейси
+configuration_advanced_network_listen_on_ipv4 = Лише IPv4
+configuration_advanced_network_listen_on_specific = Конкретна адреса
+configuration_advanced_network_listen_on_custom = Користувацькі (файл конфігурації)
+configuration_advanced_network_listen_on_custom_explanation = Використовуються адреси з файлу конфігурації, порти нижче ігноруються. Substrate: {$substrate_addresses}; DSN: {$dsn_addresses}
+configuration_advanced_network_listen_on_address_placeholder = 192.168.1.10
 configuration_advanced_network_substrate_port = Substrate (вузол) P2P порт (TCP):
 configuration_advanced_network_subspace_port = Subspace (DSN) P2P порт (TCP):
-configuration_advanced_network_faster_networking = Швидша мережа:
-configuration_advanced_network_faster_networking_tooltip = За замовчуванням мережа оптимізована для споживчих маршрутизаторів, але якщо у вас є більш потужна конфігурація, швидша мережа може покращити швидкість синхронізації та інші процеси
+configuration_advanced_network_pick_free_ports = Вибрати вільні порти
+configuration_advanced_network_pick_free_ports_tooltip = Замінити обидва порти першими портами, починаючи зі стандартних, які не використовуються іншими застосунками
+configuration_advanced_network_error_port_in_use = Порт {$port} вже використовується іншим застосунком, виберіть інший порт
+configuration_advanced_network_error_duplicate_port = Порт {$port} не може використовуватися одночасно Substrate і DSN, виберіть різні порти
+configuration_advanced_network_connections = З'єднання:
+configuration_advanced_network_connections_tooltip = Обмеження кількості з'єднань, низькі обмеження допомагають при лімітованому підключенні та слабких роутерах, а високі обмеження на потужніших системах можуть покращити швидкість синхронізації та інші процеси
+configuration_advanced_network_connections_low = Низькі
+configuration_advanced_network_connections_default = За замовчуванням
+configuration_advanced_network_connections_high = Високі
+configuration_advanced_network_connections_custom = Користувацькі
+configuration_advanced_network_connections_dsn_in = Вхідні з'єднання DSN:
+configuration_advanced_network_connections_dsn_out = Вихідні з'єднання DSN:
+configuration_advanced_network_connections_dsn_pending_in = Очікувані вхідні з'єднання DSN:
+configuration_advanced_network_connections_dsn_pending_out = Очікувані вихідні з'єднання DSN:
+configuration_advanced_network_connections_substrate_in = Вхідні піри Substrate:
+configuration_advanced_network_connections_substrate_out = Вихідні піри Substrate:
+configuration_advanced_network_piece_download = Завантаження частин:
+configuration_advanced_network_piece_download_tooltip = Політика повторів і паралельність завантаження частин для плотингу та синхронізації вузла
+configuration_advanced_network_piece_download_max_retries = Кількість повторів:
+configuration_advanced_network_piece_download_initial_retry_interval = Початковий інтервал між повторами (секунди):
+configuration_advanced_network_piece_download_max_retry_interval = Максимальний інтервал між повторами (секунди):
+configuration_advanced_network_piece_download_concurrency_multiplier = Множник паралельності на вихідне з'єднання DSN:
+configuration_advanced_network_peer_multiaddrs_placeholder = /dns/example.com/tcp/30333/p2p/12D3KooW...
+configuration_advanced_network_substrate_bootstrap_nodes = Початкові вузли Substrate:
+configuration_advanced_network_substrate_bootstrap_nodes_tooltip = Додаткові вузли Substrate (блокчейн) для виявлення мережі, multiaddr через кому, що закінчуються на /p2p/<ID піра>
+configuration_advanced_network_substrate_reserved_nodes = Зарезервовані вузли Substrate:
+configuration_advanced_network_substrate_reserved_nodes_tooltip = Вузли Substrate (блокчейн), з якими завжди підтримується з'єднання, multiaddr через кому, що закінчуються на /p2p/<ID піра>
+configuration_advanced_network_dsn_bootstrap_nodes = Початкові вузли DSN:
+configuration_advanced_network_dsn_bootstrap_nodes_tooltip = Додаткові вузли Subspace (DSN) для виявлення мережі, multiaddr через кому, що закінчуються на /p2p/<ID піра>
+configuration_advanced_network_dsn_reserved_peers = Зарезервовані піри DSN:
+configuration_advanced_network_dsn_reserved_peers_tooltip = Піри Subspace (DSN), з якими завжди підтримується з'єднання, multiaddr через кому, що закінчуються на /p2p/<ID піра>
+configuration_advanced_network_substrate_external_addresses = Зовнішні адреси Substrate:
+configuration_advanced_network_substrate_external_addresses_placeholder = /ip4/203.0.113.1/tcp/30333
+configuration_advanced_network_substrate_external_addresses_tooltip = Публічно доступні адреси Substrate (блокчейн), що повідомляються іншим пірам, наприклад при налаштованому перенаправленні портів на роутері, multiaddr через кому
+configuration_advanced_network_dsn_external_addresses = Зовнішні адреси DSN:
+configuration_advanced_network_dsn_external_addresses_placeholder = /ip4/203.0.113.1/tcp/30433
+configuration_advanced_network_dsn_external_addresses_tooltip = Публічно доступні адреси Subspace (DSN), що повідомляються іншим пірам, наприклад при налаштованому перенаправленні портів на роутері, multiaddr через кому
+configuration_advanced_network_daily_download_cap = Добовий ліміт завантаження:
+configuration_advanced_network_daily_download_cap_tooltip = М'який ліміт завантаження обох мереж за останні 24 години, трафік DSN окрім частин не враховується. Після його досягнення завантаження для плотингу скорочуються, а з'єднання DSN скорочуються після перезапуску. Залиште порожнім, щоб не обмежувати
+configuration_advanced_network_daily_upload_cap = Добовий ліміт віддачі:
+configuration_advanced_network_daily_upload_cap_tooltip = М'який ліміт віддачі обох мереж за останні 24 години, трафік DSN окрім частин не враховується. Після його досягнення з'єднання DSN скорочуються після перезапуску. Залиште порожнім, щоб не обмежувати
+configuration_advanced_network_proxy_url = URL проксі:
+configuration_advanced_network_proxy_url_tooltip = HTTP, HTTPS або SOCKS5 проксі для перевірки оновлень, наприклад http://127.0.0.1:8080 або socks5h://127.0.0.1:1080 (socks5h розв'язує імена хостів через проксі), залиште порожнім для прямого з'єднання. P2P-з'єднання вузла та фармера не підтримують проксі
+configuration_advanced_network_proxy_url_placeholder = http://хост:порт
+configuration_advanced_network_proxy_username = Ім'я користувача проксі:
+configuration_advanced_network_proxy_username_tooltip = Ім'я користувача для автентифікації на проксі, залиште порожнім, якщо проксі не вимагає автентифікації
+configuration_advanced_network_proxy_username_placeholder = необов'язково
+configuration_advanced_network_proxy_password = Пароль проксі:
+configuration_advanced_network_proxy_password_tooltip = Пароль для автентифікації на проксі, потребує імені користувача, зберігається у файлі конфігурації у відкритому вигляді
+configuration_advanced_network_daily_cap_placeholder = Наприклад, 50 GB, порожньо — без ліміту
+configuration_advanced_network_allow_private_addresses = Дозволити приватні адреси:
+configuration_advanced_network_allow_private_addresses_tooltip = Підключатися до пірів з приватними адресами та виявляти їх, наприклад інших фармерів у тій самій локальній мережі
+configuration_advanced_updates = Оновлення
+configuration_advanced_updates_check = Перевіряти оновлення:
+configuration_advanced_updates_check_tooltip = Періодично перевіряти, чи доступна нова версія Space Acres
+configuration_advanced_updates_include_prereleases = Включати попередні версії:
+configuration_advanced_updates_include_prereleases_tooltip = Також повідомляти про попередні версії, які можуть бути менш стабільними
+configuration_advanced_updates_source = Джерело оновлень:
+configuration_advanced_updates_source_placeholder = Релізи GitHub
+configuration_advanced_updates_source_tooltip = URL або абсолютний шлях до JSON-файлу у форматі API релізів GitHub для перевірки замість GitHub, корисно для тестування та дзеркал, залиште порожнім для використання GitHub
 configuration_button_add_farm = Додати ферму
 configuration_button_help = Допомога
 configuration_button_cancel = Скасувати
@@ -96,6 +206,12 @@ configuration_button_save = Зберегти
 configuration_button_start = Розпочати
 configuration_dialog_button_select = Обрати
 configuration_dialog_button_cancel = Скасувати
+network_identity_dialog_button_export = Експортувати
+network_identity_dialog_button_import = Імпортувати
+network_identity_rotate_confirm_heading = Згенерувати нову мережеву ідентичність?
+network_identity_rotate_confirm_body = Peer ID цього вузла зміниться після перезапуску, і пірів, які посилаються на нього за peer ID, потрібно буде оновити. Попередня ідентичність буде збережена в secret_ed25519.<peer ID>.bak у мережевому каталозі даних вузла.
+network_identity_rotate_confirm_button_cancel = Скасувати
+network_identity_rotate_confirm_button_rotate = Згенерувати
 
 node_migration_button_cancel = Скасувати
 node_migration_button_reset = Скинути вузол
@@ -128,6 +244,46 @@ running_node_title = {$chain_name} вузол консенсусу
 running_node_title_tooltip = Натисніть щоб відкрити в файловому менеджері
 running_node_free_disk_space_tooltip = Вільний дисковий простір: {$size} remaining
 running_node_connections_tooltip = {$connected_peers}/{$expected_peers} пірів підключено, натисніть для деталей про необхідні P2P порти
+running_node_peer_id = ID піра: {$peer_id}
+running_node_network_identity_tooltip = Мережева ідентичність
+running_node_network_identity_export = Експортувати мережеву ідентичність…
+running_node_network_identity_export_warning = Експортований файл містить секретний ключ цього вузла, будь-хто, хто його має, може видавати себе за цей вузол у мережі, зберігайте його в таємниці
+running_node_network_identity_import = Імпортувати мережеву ідентичність…
+running_node_network_identity_rotate = Згенерувати нову мережеву ідентичність
+running_node_network_identity_replace_warning = Імпорт або генерація мережевої ідентичності змінює ID піра після перезапуску, піри, що посилаються на цей вузол за ID піра, потребуватимуть оновлення
+running_node_peers_button = Піри
+running_node_peers_button_tooltip = Переглянути підключені піри, корисно при зупинці синхронізації
+running_node_peers_substrate = Піри вузла консенсусу (Substrate): {$count}
+running_node_peers_dsn = Піри DSN: {$count}
+running_node_known_peers = Кеш відомих пірів
+running_node_known_peers_description = Адреси пірів DSN, що зберігаються між перезапусками для швидшого перепідключення. Очистіть його, якщо перепідключення повільне після зміни IP-адреси або мережі, він також очищується автоматично після тижня офлайн.
+running_node_known_peers_inspect = Переглянути
+running_node_known_peers_clear = Очистити
+running_node_peers_role_authority = авторитет
+running_node_peers_role_full = повний
+running_node_peers_role_light = легкий
+running_node_peers_direction_inbound = вхідний
+running_node_peers_direction_outbound = вихідний
+running_node_peers_address_unknown = невідома адреса
+running_node_peers_none = Немає підключених пірів
+running_node_piece_serving = Віддано частин: {$pieces}, {$upload_rate}/с
+running_node_piece_serving_tooltip =
+    Дані, віддані іншим фермерам через DSN:
+    частини з кешу: {$cache_hits}
+    частини з плотів: {$plot_hits}
+    частини не знайдено: {$misses}
+    надіслано найближчих пірів замість частини: {$closest_peers_fallbacks}
+    заголовки сегментів: {$segment_headers}
+    всього віддано: {$bytes_uploaded}
+running_node_bandwidth = За 24 год: ↓ {$downloaded} ↑ {$uploaded}
+running_node_bandwidth_tooltip =
+    За останню годину: ↓ {$hour_downloaded} ↑ {$hour_uploaded}
+    За 24 години, вузол консенсусу: ↓ {$substrate_downloaded} ↑ {$substrate_uploaded}
+    За 24 години, DSN (лише частини, без DHT, gossip та службового трафіку протоколів): ↓ {$dsn_downloaded} ↑ {$dsn_uploaded}
+    Добові ліміти: ↓ {$download_cap} ↑ {$upload_cap}
+running_node_bandwidth_no_cap = без ліміту
+running_node_bandwidth_download_cap_reached = Досягнуто ліміт завантаження, завантаження для плотингу скорочено, з'єднання DSN буде скорочено після перезапуску
+running_node_bandwidth_upload_cap_reached = Досягнуто ліміт віддачі, з'єднання DSN буде скорочено після перезапуску
 running_node_status_connecting = Підключення до мережі, кращий блок #{$block_number}
 running_node_status_syncing_speed_no_eta = , {NUMBER($blocks_per_second, minimumFractionDigits: 2, maximumFractionDigits: 2)} блоки/с
 running_node_status_syncing_speed_hours_eta = , {NUMBER($a_blocks_per_second, minimumFractionDigits: 2, maximumFractionDigits: 2)} блоки/с (~{NUMBER($b_hours_remaining, minimumFractionDigits: 2, maximumFractionDigits: 2)} годин залишилось)
@@ -146,6 +302,15 @@ running_farmer_button_pause_plotting = Призупення плотингу/р
 running_farmer_button_resume_plotting = Продовжити плоттинг
 running_farmer_account_balance_tooltip = Загальний баланс рахунку та монет, зароблених з моменту запуску програми, натисніть, щоб побачити деталі в Astral
 running_farmer_piece_cache_sync = Синхронізація фрагментів кешу {NUMBER($percentage, minimumFractionDigits: 2, maximumFractionDigits: 2)}%
+running_farmer_piece_downloads = Завантажено частин: {$local} локально, {$dsn_cache} кеш DSN, {$archival} архів, {$node} вузол, {$failed} з помилкою
+running_farmer_piece_downloads_tooltip =
+    Частини, отримані для плотингу, синхронізації кешу частин та синхронізації вузла:
+    з локального кешу та плотів: {$local}
+    з кешу DSN: {$dsn_cache}
+    з архівного сховища: {$archival}
+    від вузла: {$node}
+    некоректні частини від пірів DSN: {$invalid}
+    не вдалося отримати з жодного джерела: {$failed}
 running_farmer_next_reward_estimate =
     Наступна винагорода: {$eta_string ->
         [any_time_now] у будь-який момент
@@ -160,7 +325,10 @@ running_farmer_farm_reward_signatures_tooltip = {$successful_signatures}/{$total
 running_farmer_farm_auditing_performance_tooltip = Аудит ефективності: середній час {NUMBER($a_average_time, minimumFractionDigits: 2, maximumFractionDigits: 2)}с, ліміт часу {NUMBER($b_time_timit, minimumFractionDigits: 2, maximumFractionDigits: 2)}с
 running_farmer_farm_proving_performance_tooltip = Підтвердження ефективності: середній час {NUMBER($a_average_time, minimumFractionDigits: 2, maximumFractionDigits: 2)}с, ліміт часу {NUMBER($b_time_timit, minimumFractionDigits: 2, maximumFractionDigits: 2)}с
 running_farmer_farm_non_fatal_error_tooltip = При фармінгу сталася помилка яка була усунена. Перегляньте журнали для отримання додаткової інформації: {$error}
+running_farmer_farm_free_disk_space_tooltip = Вільний дисковий простір: залишилось {$size}
 running_farmer_farm_crashed = Ферма аварійно завершила роботу: {$error}
+running_farmer_farm_disk_missing = Диск ферми відсутній, ферму зупинено до повторного підключення диска
+running_farmer_farm_disk_read_only = Диск ферми доступний лише для читання, ферму зупинено, доки запис на диск знову не стане можливим
 running_farmer_farm_plotting_speed =  ({NUMBER($a_sector_time, minimumFractionDigits: 2, maximumFractionDigits: 2)} хв/сектор, {NUMBER($b_sectors_per_hour, minimumFractionDigits: 2, maximumFractionDigits: 2)} сектори/г)
 running_farmer_farm_plotting_initial =
     {$pausing_state ->
@@ -204,6 +372,8 @@ error_title = Помилка
 error_message = Помилка: {$error}
 error_message_failed_to_send_config_to_backend = Не вдалося надіслати конфігурацію: {$error}
 error_message_failed_to_send_pause_plotting_to_backend = Не вдалось призупинити плотинг: {$error}
+error_message_failed_to_send_network_identity_action_to_backend = Не вдалося надіслати дію з мережевою ідентичністю до бекенду: {$error}
+error_message_failed_to_send_known_peers_action_to_backend = Не вдалося надіслати дію з відомими пірами до бекенду: {$error}
 error_button_help_from_community = Допомога від спільноти
 error_button_reset_node = Скинути вузол
 error_button_reset_node_tooltip = Видалити дані вузла та синхронізувати заново з мережі
@@ -211,6 +381,17 @@ error_button_show_logs = Показати журнал
 
 new_version_available = Версія {$version} доступна 🎉
 new_version_available_button_open = Перейти до релізів
+new_version_available_button_release_notes = Показати список змін
+new_version_release_notes_title = Що нового в {$version}
+new_version_release_notes_empty = Список змін не надано
+new_version_update_button_install = Завантажити та встановити
+new_version_update_downloading = Завантаження версії {$version}…
+new_version_update_ready = Встановити версію {$version}
+new_version_update_installing = Встановлення версії {$version}…
+new_version_update_confirm_heading = Встановити версію {$version}?
+new_version_update_confirm_body = Оновлення завантажено, його підпис перевірено, Space Acres перезапуститься для завершення встановлення
+new_version_update_confirm_button_cancel = Скасувати
+new_version_update_confirm_button_install = Встановити та перезапустити
 
 main_menu_show_logs = Показати журнал у файловому менеджері
 main_menu_change_configuration = Змінити конфігурацію
@@ -221,6 +402,17 @@ main_menu_exit = Вийти
 status_bar_message_configuration_is_invalid = Конфігурація недійсна: {$error}
 status_bar_message_restart_is_needed_for_configuration = Для того щоб зміни конфігурації вступили в силу, потрібен перезапуск програми
 status_bar_message_failed_to_save_configuration = Не вдалося зберегти зміни конфігурації: {$error}
+status_bar_message_network_identity_exported = Мережеву ідентичність експортовано до {$path}, зберігайте цей файл у таємниці
+status_bar_message_restart_is_needed_for_network_identity = Для набуття чинності новою мережевою ідентичністю з ID піра {$peer_id} потрібен перезапуск застосунку
+status_bar_message_failed_to_update_network_identity = Не вдалося оновити мережеву ідентичність: {$error}
+status_bar_message_known_peers_cache = Кеш відомих пірів {$path} займає {$size}, оновлено {$hours} год. тому
+status_bar_message_known_peers_cache_unknown_age = Кеш відомих пірів {$path} займає {$size}
+status_bar_message_known_peers_cache_empty = Кеш відомих пірів порожній
+status_bar_message_known_peers_cache_reset_scheduled = Кеш відомих пірів буде очищено під час наступного запуску
+status_bar_message_restart_is_needed_for_known_peers_cache = Для очищення кешу відомих пірів потрібен перезапуск застосунку
+status_bar_message_restart_is_needed_for_bandwidth_cap = Досягнуто ліміт трафіку, для скорочення з'єднань DSN потрібен перезапуск застосунку
+status_bar_message_failed_to_manage_known_peers_cache = Не вдалося керувати кешем відомих пірів: {$error}
+status_bar_message_failed_to_update = Не вдалося оновити: {$error}
 status_bar_message_restarted_after_crash = Space Acres автоматично перезапустилася після неочікуваної помилки, перевірте журнали програми та системи для отримання деталей
 status_bar_button_migrate = Перенести
 status_bar_button_ok = Ок
@@ -239,8 +431,16 @@ notification_stopped_with_error = Space Acres зупинилася з помил
     .body = Сталася помилка яка вимагає втручання користувача для її вирішення
 notification_farm_error = Одна з ферм зазнала невдачі в Space Acres
     .body = Сталася помилка яка вимагає втручання користувача для її вирішення
+notification_farm_disk_missing = Диск ферми відсутній
+    .body = Ферму {$path} зупинено до повторного підключення диска, інші ферми продовжують роботу
+notification_farm_disk_read_only = Диск ферми доступний лише для читання
+    .body = Ферму {$path} зупинено, доки запис на диск знову не стане можливим, інші ферми продовжують роботу
+notification_farm_disk_available = Диск ферми знову доступний
+    .body = Ферму {$path} знову підключено, вона продовжує роботу
 notification_node_low_disk_space = Мало вільного простору на диску вузла
     .body = На томі вузла залишилось лише {$free_space}
+notification_farm_low_disk_space = Мало вільного простору на диску ферми
+    .body = На томі ферми {$farm_path} залишилось лише {$free_space}
 notification_missed_reward = Підписання винагороди не вдалося 😞
     .body = Це прикро, але найближчим часом буде інша можливість
 notification_signed_reward_successfully = Успішно підписано нову винагороду 🥳
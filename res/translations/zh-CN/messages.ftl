@@ -30,6 +30,7 @@ loading_configuration_step_decoded_chain_spec = chain spec 解析成功
 loading_networking_stack_title = 初始化网络工作栈
 loading_networking_stack_step_checking_node_path = 检查节点目录...
 loading_networking_stack_step_creating_node_path = 创建节点目录...
+loading_networking_stack_step_resetting_node_database = 同步模式或节点存储已更改，正在删除节点数据库...
 loading_networking_stack_step_node_path_ready = 节点目录准备完毕
 loading_networking_stack_step_preparing = 准备网络工作栈...
 loading_networking_stack_step_reading_keypair = 读取网络密钥对...
@@ -40,8 +41,16 @@ loading_networking_stack_step_created_successfully = 网络工作栈创建成功
 loading_consensus_node_title = 初始化共识节点
 loading_consensus_node_step_creating = 创建共识节点...
 loading_consensus_node_step_created_successfully = 共识节点创建成功
+loading_consensus_node_step_connecting_to_external_node = 正在连接位于 {$url} 的外部节点...
+loading_consensus_node_step_connected_to_external_node_successfully = 已成功连接到外部节点
 loading_farmer_title = 实例化农民
 loading_farmer_step_initializing = 初始化农场 {$index}/{$farms_total}...
+loading_farmer_step_initializing_farm_reading_metadata = 农场 {$farm_index}：正在读取元数据（已用时 {$time_elapsed}）
+loading_farmer_step_initializing_farm_allocating = 农场 {$farm_index}：正在分配空间 {$space_allocated}/{$space_total}（已用时 {$time_elapsed}，剩余约 {$time_remaining}）
+loading_farmer_step_initializing_farm_allocating_no_eta = 农场 {$farm_index}：正在分配空间 {$space_allocated}/{$space_total}（已用时 {$time_elapsed}）
+loading_farmer_step_initializing_farm_initializing_caches = 农场 {$farm_index}：正在初始化片段缓存和绘图缓存（已用时 {$time_elapsed}）
+loading_farmer_step_collecting_plotted_pieces = 正在收集已绘制的片段（这需要一些时间）...
+loading_farmer_step_collecting_plotted_pieces_farm = 农场 {$farm_index}：{$sectors_collected}/{$sectors_plotted} 个扇区
 loading_farmer_step_created_successfully = 农民创建成功
 loading_wiping_farmer_data_title = 擦除农民数据
 loading_wiping_farmer_data_step_wiping_farm = 擦除农场 {$index}/{$farms_total} at {$path}...
@@ -77,17 +86,118 @@ configuration_farm_fixed_size_placeholder = 示例: 4T, 2.5TB, 500GiB, 等.
 configuration_farm_fixed_size_tooltip = 农场单元的大小，可以使用任何大于 2GB 的值
 configuration_farm_free_percentage_size_placeholder = 示例: 100%, 1.1%, 等.
 configuration_farm_free_percentage_size_tooltip = 用于该农场的磁盘大小百分比，可以指定任意大小，但需保留最少2GB的剩余空间避免出现问题
+configuration_farm_free_space_warning_threshold_placeholder = 空间不足警告：{$default_threshold}
+configuration_farm_free_space_warning_threshold_tooltip = 农场磁盘剩余空间低于此值时显示空间不足警告，例如 500MiB 或 5GB，留空则使用默认值
 configuration_farm_delete = 删除这个农场
+configuration_no_farms_node_only = 未配置农场，将仅运行节点而不进行耕种
+configuration_no_farms_external_node = 使用外部节点时至少需要一个农场，否则不会运行任何内容
 configuration_advanced = 高级配置
 configuration_advanced_farmer = 农民配置
 configuration_advanced_farmer_reduce_plotting_cpu_load = 降低绘图时CPU负载
 configuration_advanced_farmer_reduce_plotting_cpu_load_tooltip = 初次绘图默认会使用所有CPU核心，这个选项可以在重新绘图时只占用50%的CPU来使系统响应其他任务更加流畅
+configuration_advanced_node = 节点配置
+configuration_advanced_node_name = 节点名称：
+configuration_advanced_node_name_placeholder = 首次启动时自动生成
+configuration_advanced_node_name_tooltip = 节点在网络和遥测中使用的名称，最多 {$max_length} 个字符
+configuration_advanced_node_telemetry = 遥测：
+configuration_advanced_node_telemetry_tooltip = 向公共遥测报告节点名称、版本和同步状态，有助于监控网络健康
+configuration_advanced_node_sync_mode = 同步模式：
+configuration_advanced_node_sync_mode_tooltip = 节点同步链的方式，更改后将删除节点数据库并从头重新同步
+configuration_advanced_node_sync_mode_snap = 快照
+configuration_advanced_node_sync_mode_full = 完整
+configuration_advanced_node_sync_mode_snap_explanation = 下载最新状态以及耕种所需的区块，能更快开始耕种
+configuration_advanced_node_sync_mode_full_explanation = 下载并验证自创世以来的每个区块，耗时更长，但不依赖其他节点提供的状态
+configuration_advanced_node_sync_mode_changed_warning = 节点数据库将在下次启动时被删除并重新同步
+configuration_advanced_node_pruning = 节点存储:
+configuration_advanced_node_pruning_tooltip = 节点在磁盘上保留多少链历史，保留更多可在本地查询历史状态，切换到归档模式或从归档模式切换需要删除节点数据并重新同步
+configuration_advanced_node_pruning_kind_minimum = 最小 (推荐)
+configuration_advanced_node_pruning_kind_blocks = 保留最近的区块
+configuration_advanced_node_pruning_kind_archive = 归档 (全部)
+configuration_advanced_node_pruning_blocks_tooltip = 保留的最近区块数量，至少 {$min_blocks}
+configuration_advanced_node_pruning_estimate = 预计磁盘占用: ~{$size}
+configuration_advanced_node_pruning_estimate_archive = 预计磁盘占用: 随链增长，每天约 {$size}
+configuration_advanced_node_pruning_changed_warning = 节点数据库将在下次启动时被删除并重新同步
+configuration_advanced_node_rpc = JSON-RPC 服务器：
+configuration_advanced_node_rpc_tooltip = 为钱包、脚本和其他工具开放节点的 JSON-RPC 端点
+configuration_advanced_node_rpc_listen_on = 监听地址：
+configuration_advanced_node_rpc_listen_on_tooltip = IP 地址和端口，例如 127.0.0.1:9944，使用 0.0.0.0 在所有接口上监听
+configuration_advanced_node_rpc_unsafe_methods = 不安全方法：
+configuration_advanced_node_rpc_unsafe_methods_tooltip = 允许可能改变节点行为的方法，切勿将其暴露给不受信任的网络
+configuration_advanced_node_external_node = 外部节点：
+configuration_advanced_node_external_node_placeholder = 示例：{$url}
+configuration_advanced_node_external_node_tooltip = 用于耕种的现有节点的 WebSocket RPC URL，以代替运行内置节点，留空则运行内置节点
 configuration_advanced_network = 网络配置
 configuration_advanced_network_default_port_number_tooltip = 默认端口是 {$port}
+configuration_advanced_network_listen_on = 监听：
+configuration_advanced_network_listen_on_tooltip = 接受入站连接的网络接口，在具有多个网络接口的服务器上指定地址很有用
+configuration_advanced_network_listen_on_all = 所有接口
+configuration_advanced_network_listen_on_ipv4 = 仅 IPv4
+configuration_advanced_network_listen_on_specific = 指定地址
+configuration_advanced_network_listen_on_custom = 自定义（配置文件）
+configuration_advanced_network_listen_on_custom_explanation = 使用配置文件中的监听地址，忽略下方的端口。Substrate：{$substrate_addresses}；DSN：{$dsn_addresses}
+configuration_advanced_network_listen_on_address_placeholder = 192.168.1.10
 configuration_advanced_network_substrate_port = Substrate (blockchain) P2P 端口 (TCP):
 configuration_advanced_network_subspace_port = Subspace (DSN) P2P 端口 (TCP):
-configuration_advanced_network_faster_networking = 快速网络:
-configuration_advanced_network_faster_networking_tooltip = 默认的网络配置已为消费级路由优化，但如果你有高性能的配置，快速网络设置可能提升节点同步速度和其他流程
+configuration_advanced_network_pick_free_ports = 选择空闲端口
+configuration_advanced_network_pick_free_ports_tooltip = 将两个端口替换为从默认端口开始、未被任何其他应用程序使用的第一个端口
+configuration_advanced_network_error_port_in_use = 端口 {$port} 已被其他应用程序占用，请选择其他端口
+configuration_advanced_network_error_duplicate_port = 端口 {$port} 不能同时被 Substrate 和 DSN 使用，请选择不同的端口
+configuration_advanced_network_connections = 连接：
+configuration_advanced_network_connections_tooltip = 连接数量限制，较低的限制适用于按流量计费的网络和性能较弱的路由器，而在性能更强的设备上较高的限制可能提升同步速度和其他流程
+configuration_advanced_network_connections_low = 低
+configuration_advanced_network_connections_default = 默认
+configuration_advanced_network_connections_high = 高
+configuration_advanced_network_connections_custom = 自定义
+configuration_advanced_network_connections_dsn_in = DSN 入站连接：
+configuration_advanced_network_connections_dsn_out = DSN 出站连接：
+configuration_advanced_network_connections_dsn_pending_in = DSN 待处理入站连接：
+configuration_advanced_network_connections_dsn_pending_out = DSN 待处理出站连接：
+configuration_advanced_network_connections_substrate_in = Substrate 入站节点：
+configuration_advanced_network_connections_substrate_out = Substrate 出站节点：
+configuration_advanced_network_piece_download = 分片下载：
+configuration_advanced_network_piece_download_tooltip = 用于绘图和节点同步的分片下载重试策略与并发度
+configuration_advanced_network_piece_download_max_retries = 重试次数：
+configuration_advanced_network_piece_download_initial_retry_interval = 初始重试间隔（秒）：
+configuration_advanced_network_piece_download_max_retry_interval = 最大重试间隔（秒）：
+configuration_advanced_network_piece_download_concurrency_multiplier = 每个出站 DSN 连接的并发倍数：
+configuration_advanced_network_peer_multiaddrs_placeholder = /dns/example.com/tcp/30333/p2p/12D3KooW...
+configuration_advanced_network_substrate_bootstrap_nodes = Substrate 引导节点：
+configuration_advanced_network_substrate_bootstrap_nodes_tooltip = 用于发现网络的额外 Substrate（区块链）节点，以逗号分隔、以 /p2p/<节点 ID> 结尾的 multiaddr
+configuration_advanced_network_substrate_reserved_nodes = Substrate 保留节点：
+configuration_advanced_network_substrate_reserved_nodes_tooltip = 始终保持连接的 Substrate（区块链）节点，以逗号分隔、以 /p2p/<节点 ID> 结尾的 multiaddr
+configuration_advanced_network_dsn_bootstrap_nodes = DSN 引导节点：
+configuration_advanced_network_dsn_bootstrap_nodes_tooltip = 用于发现网络的额外 Subspace（DSN）节点，以逗号分隔、以 /p2p/<节点 ID> 结尾的 multiaddr
+configuration_advanced_network_dsn_reserved_peers = DSN 保留节点：
+configuration_advanced_network_dsn_reserved_peers_tooltip = 始终保持连接的 Subspace（DSN）节点，以逗号分隔、以 /p2p/<节点 ID> 结尾的 multiaddr
+configuration_advanced_network_substrate_external_addresses = Substrate 外部地址：
+configuration_advanced_network_substrate_external_addresses_placeholder = /ip4/203.0.113.1/tcp/30333
+configuration_advanced_network_substrate_external_addresses_tooltip = 向其他节点公布的可公开访问的 Substrate（区块链）地址，例如在路由器上配置了端口转发时，以逗号分隔的 multiaddr
+configuration_advanced_network_dsn_external_addresses = DSN 外部地址：
+configuration_advanced_network_dsn_external_addresses_placeholder = /ip4/203.0.113.1/tcp/30433
+configuration_advanced_network_dsn_external_addresses_tooltip = 向其他节点公布的可公开访问的 Subspace（DSN）地址，例如在路由器上配置了端口转发时，以逗号分隔的 multiaddr
+configuration_advanced_network_daily_download_cap = 每日下载上限：
+configuration_advanced_network_daily_download_cap_tooltip = 两个网络在过去 24 小时内下载量的软上限，不计入分片以外的 DSN 流量。达到后将减少 plot 下载，DSN 连接在重启后减少，留空表示无上限
+configuration_advanced_network_daily_upload_cap = 每日上传上限：
+configuration_advanced_network_daily_upload_cap_tooltip = 两个网络在过去 24 小时内上传量的软上限，不计入分片以外的 DSN 流量。达到后 DSN 连接将在重启后减少，留空表示无上限
+configuration_advanced_network_proxy_url = 代理 URL：
+configuration_advanced_network_proxy_url_tooltip = 用于检查更新的 HTTP、HTTPS 或 SOCKS5 代理，例如 http://127.0.0.1:8080 或 socks5h://127.0.0.1:1080（socks5h 通过代理解析主机名），留空则直接连接。节点和农民的 P2P 连接不支持代理
+configuration_advanced_network_proxy_url_placeholder = http://主机:端口
+configuration_advanced_network_proxy_username = 代理用户名：
+configuration_advanced_network_proxy_username_tooltip = 代理认证的用户名，如果代理不需要认证请留空
+configuration_advanced_network_proxy_username_placeholder = 可选
+configuration_advanced_network_proxy_password = 代理密码：
+configuration_advanced_network_proxy_password_tooltip = 代理认证的密码，需要用户名，以明文形式保存在配置文件中
+configuration_advanced_network_daily_cap_placeholder = 例如 50 GB，留空表示无上限
+configuration_advanced_network_allow_private_addresses = 允许私有地址：
+configuration_advanced_network_allow_private_addresses_tooltip = 连接并发现使用私有地址的节点，例如同一局域网中的其他耕种者
+configuration_advanced_updates = 更新
+configuration_advanced_updates_check = 检查更新：
+configuration_advanced_updates_check_tooltip = 定期检查是否有新版本的 Space Acres 可用
+configuration_advanced_updates_include_prereleases = 包含预发布版本：
+configuration_advanced_updates_include_prereleases_tooltip = 同时通知可能不太稳定的预发布版本
+configuration_advanced_updates_source = 更新源：
+configuration_advanced_updates_source_placeholder = GitHub 发布
+configuration_advanced_updates_source_tooltip = 用于代替 GitHub 检查的 GitHub 发布 API 格式 JSON 文件的 URL 或绝对路径，适用于测试和镜像，留空则使用 GitHub
 configuration_button_add_farm = 新增农场
 configuration_button_help = 帮助
 configuration_button_cancel = 取消
@@ -96,6 +206,12 @@ configuration_button_save = 保存
 configuration_button_start = 开始
 configuration_dialog_button_select = 选择
 configuration_dialog_button_cancel = 取消
+network_identity_dialog_button_export = 导出
+network_identity_dialog_button_import = 导入
+network_identity_rotate_confirm_heading = 生成新的网络身份？
+network_identity_rotate_confirm_body = 重启后此节点的对等节点 ID 将会改变，通过对等节点 ID 引用它的节点需要更新。之前的身份将保存在节点数据网络目录中的 secret_ed25519.<对等节点 ID>.bak 文件中。
+network_identity_rotate_confirm_button_cancel = 取消
+network_identity_rotate_confirm_button_rotate = 生成
 
 node_migration_button_cancel = 取消
 node_migration_button_reset = 重置节点
@@ -128,6 +244,46 @@ running_node_title = {$chain_name} 共识节点
 running_node_title_tooltip = 在文件管理器中打开
 running_node_free_disk_space_tooltip = 空闲磁盘大小: {$size}
 running_node_connections_tooltip = {$connected_peers}/{$expected_peers} 节点已连接, 点击查看所需 P2P 端口
+running_node_peer_id = 节点 ID：{$peer_id}
+running_node_network_identity_tooltip = 网络身份
+running_node_network_identity_export = 导出网络身份…
+running_node_network_identity_export_warning = 导出的文件包含此节点的私钥，任何拥有它的人都可以在网络上冒充此节点，请妥善保管
+running_node_network_identity_import = 导入网络身份…
+running_node_network_identity_rotate = 生成新的网络身份
+running_node_network_identity_replace_warning = 导入或生成网络身份会在重启后更改节点 ID，通过节点 ID 引用此节点的对等节点需要更新
+running_node_peers_button = 对等节点
+running_node_peers_button_tooltip = 查看已连接的对等节点，在同步停滞时很有用
+running_node_peers_substrate = 共识节点 (Substrate) 对等节点：{$count}
+running_node_peers_dsn = DSN 对等节点：{$count}
+running_node_known_peers = 已知节点缓存
+running_node_known_peers_description = 在重启之间记住的 DSN 节点地址，用于更快地重新连接。如果在 IP 地址变化或切换网络后重新连接缓慢，请清除它，离线一周后也会自动清除。
+running_node_known_peers_inspect = 查看
+running_node_known_peers_clear = 清除
+running_node_peers_role_authority = 权威
+running_node_peers_role_full = 完整
+running_node_peers_role_light = 轻量
+running_node_peers_direction_inbound = 入站
+running_node_peers_direction_outbound = 出站
+running_node_peers_address_unknown = 未知地址
+running_node_peers_none = 没有已连接的对等节点
+running_node_piece_serving = 已提供 {$pieces} 个分片，{$upload_rate}/s
+running_node_piece_serving_tooltip =
+    通过 DSN 向其他农民提供的数据：
+    来自缓存的分片：{$cache_hits}
+    来自 plot 的分片：{$plot_hits}
+    未找到的分片：{$misses}
+    以最近对等节点代替分片：{$closest_peers_fallbacks}
+    段头：{$segment_headers}
+    总上传量：{$bytes_uploaded}
+running_node_bandwidth = 过去 24 小时：↓ {$downloaded} ↑ {$uploaded}
+running_node_bandwidth_tooltip =
+    过去一小时：↓ {$hour_downloaded} ↑ {$hour_uploaded}
+    过去 24 小时，共识节点：↓ {$substrate_downloaded} ↑ {$substrate_uploaded}
+    过去 24 小时，DSN（仅分片，不含 DHT、gossip 和协议开销）：↓ {$dsn_downloaded} ↑ {$dsn_uploaded}
+    每日上限：↓ {$download_cap} ↑ {$upload_cap}
+running_node_bandwidth_no_cap = 无上限
+running_node_bandwidth_download_cap_reached = 已达到下载上限，plot 下载已减少，DSN 连接将在重启后减少
+running_node_bandwidth_upload_cap_reached = 已达到上传上限，DSN 连接将在重启后减少
 running_node_status_connecting = 连接网络中，最新区块 #{$block_number}
 running_node_status_syncing_speed_no_eta = , {NUMBER($blocks_per_second, minimumFractionDigits: 2, maximumFractionDigits: 2)} blocks/s
 running_node_status_syncing_speed_hours_eta = , {NUMBER($a_blocks_per_second, minimumFractionDigits: 2, maximumFractionDigits: 2)} blocks/s (预计 ~{NUMBER($b_hours_remaining, minimumFractionDigits: 2, maximumFractionDigits: 2)} 小时)
@@ -146,6 +302,15 @@ running_farmer_button_pause_plotting = 暂停绘图/重新绘图，当前的编
 running_farmer_button_resume_plotting = 继续绘图
 running_farmer_account_balance_tooltip = 自启动以来耕种到的总奖励币，点击在 Astral 中查看更多详细信息
 running_farmer_piece_cache_sync = Piece缓存同步 {NUMBER($percentage, minimumFractionDigits: 2, maximumFractionDigits: 2)}%
+running_farmer_piece_downloads = 已下载分片：本地 {$local}，DSN 缓存 {$dsn_cache}，归档 {$archival}，节点 {$node}，失败 {$failed}
+running_farmer_piece_downloads_tooltip =
+    为绘图、分片缓存同步和节点同步获取的分片：
+    来自本地分片缓存和绘图：{$local}
+    来自 DSN 缓存：{$dsn_cache}
+    来自归档存储：{$archival}
+    来自节点：{$node}
+    从 DSN 节点收到的无效分片：{$invalid}
+    无法从任何来源获取：{$failed}
 running_farmer_next_reward_estimate =
     下次奖励预计: {$eta_string ->
         [any_time_now] 即将发生
@@ -160,7 +325,10 @@ running_farmer_farm_reward_signatures_tooltip = {$successful_signatures}/{$total
 running_farmer_farm_auditing_performance_tooltip = 审计性能: 平均时长 {NUMBER($a_average_time, minimumFractionDigits: 2, maximumFractionDigits: 2)}秒, 时间限制 {NUMBER($b_time_timit, minimumFractionDigits: 2, maximumFractionDigits: 2)}秒
 running_farmer_farm_proving_performance_tooltip = 证明性能: 平均时长 {NUMBER($a_average_time, minimumFractionDigits: 2, maximumFractionDigits: 2)}秒, 时间限制 {NUMBER($b_time_timit, minimumFractionDigits: 2, maximumFractionDigits: 2)}秒
 running_farmer_farm_non_fatal_error_tooltip = 非致命错误发生并已经恢复，在日志中查看更多信息: {$error}
+running_farmer_farm_free_disk_space_tooltip = 空闲磁盘大小: {$size}
 running_farmer_farm_crashed = 农场崩溃: {$error}
+running_farmer_farm_disk_missing = 农场磁盘丢失，农场已停止，直到磁盘重新连接
+running_farmer_farm_disk_read_only = 农场磁盘为只读，农场已停止，直到磁盘重新可写
 running_farmer_farm_plotting_speed =  ({NUMBER($a_sector_time, minimumFractionDigits: 2, maximumFractionDigits: 2)} 分钟/扇区, {NUMBER($b_sectors_per_hour, minimumFractionDigits: 2, maximumFractionDigits: 2)} 扇区/小时)
 running_farmer_farm_plotting_initial =
     {$pausing_state ->
@@ -204,6 +372,8 @@ error_title = 错误
 error_message = 错误: {$error}
 error_message_failed_to_send_config_to_backend = 发送到后端过程出错: {$error}
 error_message_failed_to_send_pause_plotting_to_backend = 发送暂停任务到后端出错: {$error}
+error_message_failed_to_send_network_identity_action_to_backend = 无法将网络身份操作发送到后端：{$error}
+error_message_failed_to_send_known_peers_action_to_backend = 无法将已知节点操作发送到后端：{$error}
 error_button_help_from_community = 获得社区支持
 error_button_reset_node = 重置节点
 error_button_reset_node_tooltip = 清除节点数据并从网络重新同步
@@ -211,6 +381,17 @@ error_button_show_logs = 打开日志
 
 new_version_available = 版本 {$version} 可用 🎉
 new_version_available_button_open = 打开版本发布页面
+new_version_available_button_release_notes = 显示发布说明
+new_version_release_notes_title = {$version} 中的新功能
+new_version_release_notes_empty = 未提供发布说明
+new_version_update_button_install = 下载并安装
+new_version_update_downloading = 正在下载版本 {$version}…
+new_version_update_ready = 安装版本 {$version}
+new_version_update_installing = 正在安装版本 {$version}…
+new_version_update_confirm_heading = 安装版本 {$version}？
+new_version_update_confirm_body = 更新已下载并验证了签名，Space Acres 将重新启动以完成安装
+new_version_update_confirm_button_cancel = 取消
+new_version_update_confirm_button_install = 安装并重启
 
 main_menu_show_logs = 在文件管理器中打开日志
 main_menu_change_configuration = 修改配置
@@ -221,6 +402,17 @@ main_menu_exit = 退出
 status_bar_message_configuration_is_invalid = 配置不可用: {$error}
 status_bar_message_restart_is_needed_for_configuration = 重启以使配置修改生效
 status_bar_message_failed_to_save_configuration = 保存配置修改失败: {$error}
+status_bar_message_network_identity_exported = 网络身份已导出到 {$path}，请妥善保管此文件
+status_bar_message_restart_is_needed_for_network_identity = 需要重启应用程序才能使节点 ID 为 {$peer_id} 的新网络身份生效
+status_bar_message_failed_to_update_network_identity = 无法更新网络身份：{$error}
+status_bar_message_known_peers_cache = 已知节点缓存 {$path} 占用 {$size}，上次更新于 {$hours} 小时前
+status_bar_message_known_peers_cache_unknown_age = 已知节点缓存 {$path} 占用 {$size}
+status_bar_message_known_peers_cache_empty = 已知节点缓存为空
+status_bar_message_known_peers_cache_reset_scheduled = 已知节点缓存将在下次启动时清除
+status_bar_message_restart_is_needed_for_known_peers_cache = 需要重启应用以清除已知节点缓存
+status_bar_message_restart_is_needed_for_bandwidth_cap = 已达到带宽上限，需要重启应用以减少 DSN 连接
+status_bar_message_failed_to_manage_known_peers_cache = 无法管理已知节点缓存：{$error}
+status_bar_message_failed_to_update = 更新失败：{$error}
 status_bar_message_restarted_after_crash = Space Acres在崩溃后自动重启，请在日志中查看详细信息
 status_bar_button_migrate = 迁移
 status_bar_button_ok = 正常
@@ -239,8 +431,16 @@ notification_stopped_with_error = Space Acres由于错误暂停
     .body = 出现一个错误，需要手动解决
 notification_farm_error = 一个Space Acres农场出错
     .body = 出现一个错误，需要手动解决
+notification_farm_disk_missing = 农场磁盘丢失
+    .body = 农场 {$path} 已停止，直到磁盘重新连接，其他农场继续耕种
+notification_farm_disk_read_only = 农场磁盘为只读
+    .body = 农场 {$path} 已停止，直到磁盘重新可写，其他农场继续耕种
+notification_farm_disk_available = 农场磁盘已恢复可用
+    .body = 农场 {$path} 已重新加入并继续耕种
 notification_node_low_disk_space = 节点磁盘空间不足
     .body = 节点卷仅剩余 {$free_space}
+notification_farm_low_disk_space = 农场磁盘空间不足
+    .body = 农场 {$farm_path} 所在卷仅剩余 {$free_space}
 notification_missed_reward = 签署奖励失败 😞
     .body = 很不幸，但很快就会有下一次机会
 notification_signed_reward_successfully = 成功签署一份奖励 🥳
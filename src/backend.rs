@@ -1,32 +1,52 @@
 // TODO: Make these modules private
+pub mod bandwidth;
 pub mod config;
 pub mod farmer;
-mod networking;
+pub mod networking;
 pub mod node;
 mod utils;
 
-use crate::backend::config::{Config, ConfigError, RawConfig};
+use crate::backend::bandwidth::{BandwidthTracker, BandwidthUsage, DownloadThrottle, Traffic};
+use crate::backend::config::{
+    Config, ConfigError, NetworkConfiguration, NetworkConnections, NodeConfiguration, NodeSyncMode,
+    RawConfig,
+};
 use crate::backend::farmer::maybe_node_client::MaybeNodeClient;
+use crate::backend::farmer::piece_downloads::{CountingPieceGetter, PieceDownloadCounters};
 use crate::backend::farmer::{
-    CACHE_PERCENTAGE, DiskFarm, Farmer, FarmerAction, FarmerNotification, FarmerOptions,
-    InitialFarmState,
+    CACHE_PERCENTAGE, DiskFarm, FarmInitializationProgress, Farmer, FarmerAction,
+    FarmerNotification, FarmerOptions, InitialFarmState, PlottedPiecesCollectionProgress,
+};
+use crate::backend::networking::{
+    ConnectedDsnPeers, CountingPieceValidator, DsnPeer, KnownPeersCacheInfo, NetworkOptions,
+    PieceServingCounters, PieceServingStats, create_network, known_peers_cache_info,
+    reset_known_peers_cache_if_needed, schedule_known_peers_cache_reset,
+};
+use crate::backend::node::external_node::{
+    ExternalNode, ExternalNodeConnectionError, connect_to_external_node,
 };
-use crate::backend::networking::{NetworkOptions, create_network};
 use crate::backend::node::{
     BlockImportedNotification, ChainInfo, ChainSpec, ConsensusNode, ConsensusNodeCreationError,
-    GENESIS_HASH, SyncState, dsn_bootstrap_nodes,
+    GENESIS_HASH, PEERS_DETAILS_EVENT_INTERVAL, SubstratePeer, SyncState, dsn_bootstrap_nodes,
+    generate_node_name,
 };
+use crate::backend::utils::{HandlerFn, parse_multiaddrs};
 use async_lock::{RwLock as AsyncRwLock, Semaphore};
 use backoff::ExponentialBackoff;
+use event_listener_primitives::HandlerId;
 use future::FutureExt;
 use futures::channel::mpsc;
 use futures::{SinkExt, StreamExt, future, select};
+use parking_lot::Mutex;
 use sc_subspace_chain_specs::MAINNET_CHAIN_SPEC;
+use serde::{Deserialize, Serialize};
 use sp_consensus_subspace::ChainConstants;
+use std::io;
 use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 use std::num::NonZeroU8;
 use std::path::{Path, PathBuf};
 use std::pin::pin;
+use std::sync::atomic::{AtomicU16, Ordering};
 use std::sync::{Arc, Weak};
 use std::time::Duration;
 use subspace_core_primitives::{BlockNumber, PublicKey};
@@ -37,9 +57,9 @@ use subspace_farmer::farmer_piece_getter::piece_validator::SegmentCommitmentPiec
 use subspace_farmer::farmer_piece_getter::{DsnCacheRetryPolicy, FarmerPieceGetter};
 use subspace_farmer::single_disk_farm::SingleDiskFarm;
 use subspace_kzg::Kzg;
-use subspace_networking::libp2p::Multiaddr;
 use subspace_networking::libp2p::identity::ed25519::{Keypair, SecretKey};
 use subspace_networking::libp2p::multiaddr::Protocol;
+use subspace_networking::libp2p::{Multiaddr, PeerId};
 use subspace_networking::utils::piece_provider::PieceProvider;
 use subspace_networking::{Node, NodeRunner};
 use subspace_process::run_future_in_dedicated_thread;
@@ -48,18 +68,25 @@ use tokio::fs;
 use tokio::fs::OpenOptions;
 use tokio::io::AsyncWriteExt;
 use tokio::runtime::Handle;
-use tracing::{Instrument, error, info_span, warn};
+use tokio::time::MissedTickBehavior;
+use tracing::{Instrument, error, info, info_span, warn};
 
 pub type FarmIndex = u8;
 
-/// Get piece retry attempts number.
-const PIECE_GETTER_MAX_RETRIES: u16 = 7;
-/// Defines initial duration between get_piece calls.
-const GET_PIECE_INITIAL_INTERVAL: Duration = Duration::from_secs(5);
-/// Defines max duration between get_piece calls.
-const GET_PIECE_MAX_INTERVAL: Duration = Duration::from_secs(40);
-/// Multiplier on top of outgoing connections number for piece downloading purposes
-const PIECE_PROVIDER_MULTIPLIER: usize = 10;
+/// File in node path that stores settings node database was created with
+pub const NODE_DATABASE_SETTINGS_FILE: &str = "database_settings.json";
+/// File in network directory of node path that stores network identity secret key
+const NETWORK_KEYPAIR_FILE: &str = "secret_ed25519";
+/// Suffix of the file next to network identity secret key that stores the previous one after it
+/// was replaced, prefixed with peer ID of the previous identity such that backups of different
+/// identities don't overwrite each other
+const NETWORK_KEYPAIR_BACKUP_SUFFIX: &str = ".bak";
+/// Interval between DSN piece serving statistics updates
+const PIECE_SERVING_STATS_INTERVAL: Duration = Duration::from_secs(5);
+/// Interval between piece download stats updates
+const PIECE_DOWNLOAD_STATS_INTERVAL: Duration = Duration::from_secs(5);
+/// Interval between bandwidth usage updates, which are also persisted on disk
+const BANDWIDTH_USAGE_INTERVAL: Duration = Duration::from_secs(10);
 
 /// Major steps in application loading progress
 #[derive(Debug, Clone)]
@@ -76,6 +103,7 @@ pub enum LoadingStep {
     DecodedChainSpecificationSuccessfully,
     CheckingNodePath,
     CreatingNodePath,
+    ResettingNodeDatabase,
     NodePathReady,
     PreparingNetworkingStack,
     ReadingNetworkKeypair,
@@ -85,13 +113,30 @@ pub enum LoadingStep {
     NetworkingStackCreatedSuccessfully,
     CreatingConsensusNode,
     ConsensusNodeCreatedSuccessfully,
+    ConnectingToExternalNode {
+        url: String,
+    },
+    ConnectedToExternalNodeSuccessfully,
     InitializingFarms {
         farms_total: u16,
     },
+    FarmInitializationProgress {
+        farm_index: FarmIndex,
+        /// Number of farms that finished initialization so far, farms initialize concurrently
+        farms_initialized: u16,
+        farms_total: u16,
+        progress: FarmInitializationProgress,
+    },
     FarmInitialized {
         farm_index: FarmIndex,
+        /// Number of farms that finished initialization so far, including this one
+        farms_initialized: u16,
         farms_total: u16,
     },
+    CollectingPlottedPieces {
+        /// Progress of each farm, indexed by farm index
+        farms: Vec<PlottedPiecesCollectionProgress>,
+    },
     FarmerCreatedSuccessfully,
     WipingFarm {
         farm_index: FarmIndex,
@@ -118,6 +163,7 @@ impl LoadingStep {
             LoadingStep::DecodedChainSpecificationSuccessfully => 7.0,
             LoadingStep::CheckingNodePath => 9.0,
             LoadingStep::CreatingNodePath => 10.0,
+            LoadingStep::ResettingNodeDatabase => 10.0,
             LoadingStep::NodePathReady => 11.0,
             LoadingStep::PreparingNetworkingStack => 13.0,
             LoadingStep::ReadingNetworkKeypair => 15.0,
@@ -127,11 +173,34 @@ impl LoadingStep {
             LoadingStep::NetworkingStackCreatedSuccessfully => 20.0,
             LoadingStep::CreatingConsensusNode => 20.0,
             LoadingStep::ConsensusNodeCreatedSuccessfully => 40.0,
+            LoadingStep::ConnectingToExternalNode { .. } => 20.0,
+            LoadingStep::ConnectedToExternalNodeSuccessfully => 40.0,
             LoadingStep::InitializingFarms { .. } => 40.0,
-            LoadingStep::FarmInitialized {
-                farm_index,
+            LoadingStep::FarmInitializationProgress {
+                farms_initialized,
+                farms_total,
+                ..
+            }
+            | LoadingStep::FarmInitialized {
+                farms_initialized,
                 farms_total,
-            } => 40.0 + 60.0 / *farms_total as f32 * (f32::from(*farm_index) + 1.0),
+                ..
+            } => 40.0 + 50.0 / *farms_total as f32 * f32::from(*farms_initialized),
+            LoadingStep::CollectingPlottedPieces { farms } => {
+                let (collected_sectors_count, plotted_sectors_count) =
+                    farms.iter().fold((0, 0), |(collected, plotted), farm| {
+                        (
+                            collected + u64::from(farm.collected_sectors_count),
+                            plotted + u64::from(farm.plotted_sectors_count),
+                        )
+                    });
+
+                if plotted_sectors_count == 0 {
+                    90.0
+                } else {
+                    90.0 + 10.0 * collected_sectors_count as f32 / plotted_sectors_count as f32
+                }
+            }
             LoadingStep::FarmerCreatedSuccessfully => 100.0,
             LoadingStep::WipingFarm {
                 farm_index,
@@ -145,10 +214,92 @@ impl LoadingStep {
     }
 }
 
+/// Consensus node farmer is connected to
+#[derive(Debug)]
+#[allow(clippy::large_enum_variant)]
+enum ConsensusChainNode {
+    /// Node embedded into the application
+    Embedded(ConsensusNode),
+    /// External node connected to over RPC
+    External(ExternalNode),
+}
+
+impl ConsensusChainNode {
+    async fn run(self, reward_address: &PublicKey) -> anyhow::Result<()> {
+        match self {
+            Self::Embedded(consensus_node) => Ok(consensus_node.run(reward_address).await?),
+            Self::External(external_node) => external_node.run(reward_address).await,
+        }
+    }
+
+    async fn best_block_number(&self) -> BlockNumber {
+        match self {
+            Self::Embedded(consensus_node) => consensus_node.best_block_number(),
+            Self::External(external_node) => external_node.best_block_number().await,
+        }
+    }
+
+    async fn account_balance(&self, account: &PublicKey) -> Balance {
+        match self {
+            Self::Embedded(consensus_node) => consensus_node.account_balance(account),
+            Self::External(external_node) => external_node.account_balance(account).await,
+        }
+    }
+
+    fn chain_info(&self) -> &ChainInfo {
+        match self {
+            Self::Embedded(consensus_node) => consensus_node.chain_info(),
+            Self::External(external_node) => external_node.chain_info(),
+        }
+    }
+
+    fn chain_constants(&self) -> &ChainConstants {
+        match self {
+            Self::Embedded(consensus_node) => consensus_node.chain_constants(),
+            Self::External(external_node) => external_node.chain_constants(),
+        }
+    }
+
+    fn on_sync_state_change(&self, callback: HandlerFn<SyncState>) -> HandlerId {
+        match self {
+            Self::Embedded(consensus_node) => consensus_node.on_sync_state_change(callback),
+            Self::External(external_node) => external_node.on_sync_state_change(callback),
+        }
+    }
+
+    fn on_connected_peers_change(&self, callback: HandlerFn<u32>) -> HandlerId {
+        match self {
+            Self::Embedded(consensus_node) => consensus_node.on_connected_peers_change(callback),
+            Self::External(external_node) => external_node.on_connected_peers_change(callback),
+        }
+    }
+
+    fn on_peers_update(&self, callback: HandlerFn<Vec<SubstratePeer>>) -> HandlerId {
+        match self {
+            Self::Embedded(consensus_node) => consensus_node.on_peers_update(callback),
+            Self::External(external_node) => external_node.on_peers_update(callback),
+        }
+    }
+
+    fn on_network_traffic_update(&self, callback: HandlerFn<Traffic>) -> HandlerId {
+        match self {
+            Self::Embedded(consensus_node) => consensus_node.on_network_traffic_update(callback),
+            Self::External(external_node) => external_node.on_network_traffic_update(callback),
+        }
+    }
+
+    fn on_block_imported(&self, callback: HandlerFn<BlockImportedNotification>) -> HandlerId {
+        match self {
+            Self::Embedded(consensus_node) => consensus_node.on_block_imported(callback),
+            Self::External(external_node) => external_node.on_block_imported(callback),
+        }
+    }
+}
+
 #[derive(Debug)]
 #[allow(clippy::large_enum_variant)]
 enum LoadedConsensusChainNode {
-    Compatible(ConsensusNode),
+    Compatible(ConsensusChainNode),
     Incompatible { compatible_chain: String },
 }
 
@@ -156,9 +307,31 @@ enum LoadedConsensusChainNode {
 pub enum NodeNotification {
     SyncStateUpdate(SyncState),
     ConnectedPeersUpdate(u32),
+    SubstratePeersUpdate(Vec<SubstratePeer>),
+    DsnPeersUpdate(Vec<DsnPeer>),
+    PieceServingStatsUpdate(PieceServingStats),
+    BandwidthUsageUpdate(BandwidthUsage),
     BlockImported(BlockImportedNotification),
 }
 
+/// Successful result of [`NetworkIdentityAction`]
+#[derive(Debug)]
+pub enum NetworkIdentityUpdate {
+    /// Network identity was exported to specified path
+    Exported { path: PathBuf },
+    /// Network identity was replaced, takes effect after restart
+    Replaced { peer_id: PeerId },
+}
+
+/// Successful result of [`KnownPeersAction`]
+#[derive(Debug)]
+pub enum KnownPeersUpdate {
+    /// Known peers cache details, `None` if there is no cache yet
+    Inspected(Option<KnownPeersCacheInfo>),
+    /// Known peers cache will be cleared on next start
+    ResetScheduled,
+}
+
 /// Notification messages send from backend about its operation
 #[derive(Debug)]
 #[allow(clippy::large_enum_variant)]
@@ -176,9 +349,13 @@ pub enum BackendNotification {
         error: ConfigError,
     },
     ConfigSaveResult(anyhow::Result<()>),
+    NetworkIdentityActionResult(anyhow::Result<NetworkIdentityUpdate>),
+    KnownPeersActionResult(anyhow::Result<KnownPeersUpdate>),
     Running {
         config: Config,
         raw_config: RawConfig,
+        /// Peer ID of both consensus node and DSN
+        peer_id: PeerId,
         best_block_number: BlockNumber,
         reward_address_balance: Balance,
         initial_farm_states: Vec<InitialFarmState>,
@@ -188,6 +365,8 @@ pub enum BackendNotification {
     },
     Node(NodeNotification),
     Farmer(FarmerNotification<FarmIndex>),
+    /// Bandwidth cap was reached while running, DSN connections are only reduced after restart
+    BandwidthCapReached,
     Stopped {
         /// Error in case stopped due to error
         error: Option<anyhow::Error>,
@@ -205,15 +384,47 @@ pub enum BackendAction {
     NewConfig { raw_config: RawConfig },
     /// Farmer action
     Farmer(FarmerAction),
+    /// Network identity action
+    NetworkIdentity(NetworkIdentityAction),
+    /// Known peers cache action
+    KnownPeers(KnownPeersAction),
+}
+
+/// Actions for network identity management, identity is shared by consensus node and DSN
+#[derive(Debug)]
+pub enum NetworkIdentityAction {
+    /// Export network identity secret key to a file
+    Export { path: PathBuf },
+    /// Replace network identity with secret key imported from a file
+    Import { path: PathBuf },
+    /// Replace network identity with newly generated one
+    Rotate,
+}
+
+/// Actions for DSN known peers cache management
+#[derive(Debug)]
+pub enum KnownPeersAction {
+    /// Get details about known peers cache
+    Inspect,
+    /// Clear known peers cache on next start
+    Clear,
 }
 
 struct LoadedBackend {
     config: Config,
     raw_config: RawConfig,
+    peer_id: PeerId,
     config_file_path: PathBuf,
-    consensus_node: ConsensusNode,
+    consensus_node: ConsensusChainNode,
     farmer: Farmer<FarmIndex>,
     node_runner: NodeRunner,
+    piece_serving_counters: Arc<PieceServingCounters>,
+    connected_dsn_peers: Arc<ConnectedDsnPeers>,
+    bandwidth_tracker: BandwidthTracker,
+    /// DSN connection limits were reduced on start due to reached bandwidth cap
+    dsn_connections_reduced: bool,
+    download_throttle: DownloadThrottle,
+    piece_download_counters: Arc<PieceDownloadCounters>,
 }
 
 #[allow(clippy::large_enum_variant)]
@@ -242,8 +453,6 @@ pub async fn create(
                 return;
             }
 
-            // Remove suppression once we have more actions for backend
-            #[allow(clippy::never_loop)]
             while let Some(backend_action) = backend_action_receiver.next().await {
                 match backend_action {
                     BackendAction::NewConfig { raw_config } => {
@@ -274,6 +483,19 @@ pub async fn create(
                             "Farmer action is not expected before initialization, ignored"
                         );
                     }
+                    BackendAction::NetworkIdentity(network_identity_action) => {
+                        warn!(
+                            ?network_identity_action,
+                            "Network identity action is not expected before initialization, \
+                            ignored"
+                        );
+                    }
+                    BackendAction::KnownPeers(known_peers_action) => {
+                        warn!(
+                            ?known_peers_action,
+                            "Known peers action is not expected before initialization, ignored"
+                        );
+                    }
                 }
             }
 
@@ -323,21 +545,61 @@ pub async fn create(
 async fn load(
     notifications_sender: &mut mpsc::Sender<BackendNotification>,
 ) -> anyhow::Result<Option<BackendLoadingResult>> {
-    let (config_file_path, Some(raw_config)) = load_configuration(notifications_sender).await?
+    let (config_file_path, Some(mut raw_config)) = load_configuration(notifications_sender).await?
     else {
         return Ok(None);
     };
 
-    let Some(config) = check_configuration(&raw_config, notifications_sender).await? else {
+    let Some(mut config) = check_configuration(&raw_config, notifications_sender).await? else {
         return Ok(None);
     };
 
+    if config.node.name.is_none() {
+        // Node name is generated once and persisted, such that it doesn't change between restarts
+        let node_name = generate_node_name();
+        raw_config.node_mut().name.replace(node_name.clone());
+        config.node.name.replace(node_name);
+
+        raw_config
+            .write_to_path(&config_file_path)
+            .await
+            .map_err(|error| {
+                anyhow::anyhow!(
+                    "Failed to write config to \"{}\": {}",
+                    config_file_path.display(),
+                    error
+                )
+            })?;
+    }
+
     let chain_spec = load_chain_specification(notifications_sender).await?;
 
     preparing_node_path(&config.node_path, notifications_sender).await?;
 
+    if config.node.external_node_rpc_url.is_none() {
+        reset_node_database_on_settings_change(
+            &config.node_path,
+            NodeDatabaseSettings {
+                sync_mode: config.node.sync_mode,
+                archive: config.node.pruning.is_archive(),
+            },
+            notifications_sender,
+        )
+        .await?;
+    }
+
     let plotted_pieces = Arc::new(AsyncRwLock::new(PlottedPieces::default()));
 
+    let bandwidth_tracker =
+        BandwidthTracker::open(&config.node_path.join("network"), config.bandwidth_caps).await;
+    let bandwidth_usage = bandwidth_tracker.usage();
+    let reduce_dsn_connections =
+        bandwidth_usage.download_cap_reached() || bandwidth_usage.upload_cap_reached();
+
+    let piece_download_counters = Arc::new(PieceDownloadCounters::new(
+        config.network.piece_download.max_retries,
+    ));
+
     let (
         maybe_node_client,
         node,
@@ -346,23 +608,37 @@ async fn load(
         farmer_cache,
         farmer_cache_worker,
         out_connections,
+        piece_serving_counters,
+        connected_dsn_peers,
     ) = create_networking_stack(
         &config,
         GENESIS_HASH.to_string(),
         &chain_spec,
         Arc::downgrade(&plotted_pieces),
+        reduce_dsn_connections,
+        Arc::clone(&piece_download_counters),
         notifications_sender,
     )
     .await?;
 
     let kzg = Kzg::new();
+    let piece_download = config.network.piece_download;
+    let piece_provider_permits =
+        out_connections as usize * usize::from(piece_download.concurrency_multiplier);
+    let piece_provider_semaphore = Arc::new(Semaphore::new(piece_provider_permits));
     let piece_provider = PieceProvider::new(
         node.clone(),
-        SegmentCommitmentPieceValidator::new(node.clone(), maybe_node_client.clone(), kzg.clone()),
-        Arc::new(Semaphore::new(
-            out_connections as usize * PIECE_PROVIDER_MULTIPLIER,
-        )),
+        CountingPieceValidator::new(
+            SegmentCommitmentPieceValidator::new(
+                node.clone(),
+                maybe_node_client.clone(),
+                kzg.clone(),
+            ),
+            Arc::clone(&piece_download_counters),
+        ),
+        Arc::clone(&piece_provider_semaphore),
     );
+    let download_throttle = DownloadThrottle::new(piece_provider_semaphore, piece_provider_permits);
 
     let piece_getter = FarmerPieceGetter::new(
         piece_provider,
@@ -370,10 +646,10 @@ async fn load(
         maybe_node_client.clone(),
         Arc::clone(&plotted_pieces),
         DsnCacheRetryPolicy {
-            max_retries: PIECE_GETTER_MAX_RETRIES,
+            max_retries: piece_download.max_retries,
             backoff: ExponentialBackoff {
-                initial_interval: GET_PIECE_INITIAL_INTERVAL,
-                max_interval: GET_PIECE_MAX_INTERVAL,
+                initial_interval: Duration::from_secs(piece_download.initial_retry_interval),
+                max_interval: Duration::from_secs(piece_download.max_retry_interval),
                 // Try until we get a valid piece
                 max_elapsed_time: None,
                 multiplier: 1.75,
@@ -385,9 +661,13 @@ async fn load(
     let create_consensus_node_fut = create_consensus_node(
         &network_keypair,
         config.node_path.clone(),
-        config.network.substrate_port,
+        &config.network,
+        &config.node,
         chain_spec,
-        Arc::new(piece_getter.clone()),
+        Arc::new(CountingPieceGetter::new(
+            piece_getter.clone(),
+            Arc::clone(&piece_download_counters),
+        )),
         node.clone(),
         &maybe_node_client,
         notifications_sender,
@@ -411,6 +691,7 @@ async fn load(
         kzg,
         config.reduce_plotting_cpu_load,
         piece_getter,
+        Arc::clone(&piece_download_counters),
         notifications_sender,
     )
     .await?;
@@ -418,10 +699,17 @@ async fn load(
     Ok(Some(BackendLoadingResult::Success(LoadedBackend {
         config,
         raw_config,
+        peer_id: network_peer_id(&network_keypair),
         config_file_path,
         consensus_node,
         farmer,
         node_runner,
+        piece_serving_counters,
+        connected_dsn_peers,
+        bandwidth_tracker,
+        dsn_connections_reduced: reduce_dsn_connections,
+        download_throttle,
+        piece_download_counters,
     })))
 }
 
@@ -433,10 +721,17 @@ async fn run(
     let LoadedBackend {
         config,
         raw_config,
+        peer_id,
         config_file_path,
         consensus_node,
         farmer,
         mut node_runner,
+        piece_serving_counters,
+        connected_dsn_peers,
+        mut bandwidth_tracker,
+        mut dsn_connections_reduced,
+        mut download_throttle,
+        piece_download_counters,
     } = loaded_backend;
     let networking_fut = run_future_in_dedicated_thread(
         {
@@ -449,12 +744,14 @@ async fn run(
     )?;
 
     let reward_address = config.reward_address;
+    let network_path = config.node_path.join("network");
     notifications_sender
         .send(BackendNotification::Running {
             config,
             raw_config,
-            best_block_number: consensus_node.best_block_number(),
-            reward_address_balance: consensus_node.account_balance(&reward_address),
+            peer_id,
+            best_block_number: consensus_node.best_block_number().await,
+            reward_address_balance: consensus_node.account_balance(&reward_address).await,
             initial_farm_states: farmer.initial_farm_states().to_vec(),
             cache_percentage: CACHE_PERCENTAGE,
             chain_info: consensus_node.chain_info().clone(),
@@ -502,6 +799,34 @@ async fn run(
             }
         })
     });
+    let _on_peers_update_handler_id = consensus_node.on_peers_update({
+        let notifications_sender = notifications_sender.clone();
+
+        Arc::new(move |peers| {
+            let notification = NodeNotification::SubstratePeersUpdate(peers.clone());
+
+            let mut notifications_sender = notifications_sender.clone();
+
+            if let Err(error) = notifications_sender
+                .try_send(BackendNotification::Node(notification))
+                .or_else(|error| {
+                    tokio::task::block_in_place(|| {
+                        Handle::current().block_on(notifications_sender.send(error.into_inner()))
+                    })
+                })
+            {
+                warn!(%error, "Failed to send peers update backend notification");
+            }
+        })
+    });
+    let substrate_traffic = Arc::new(Mutex::new(Traffic::default()));
+    let _on_network_traffic_update_handler_id = consensus_node.on_network_traffic_update({
+        let substrate_traffic = Arc::clone(&substrate_traffic);
+
+        Arc::new(move |&traffic| {
+            *substrate_traffic.lock() = traffic;
+        })
+    });
     let _on_imported_block_handler_id = consensus_node.on_block_imported({
         let notifications_sender = notifications_sender.clone();
 
@@ -548,12 +873,139 @@ async fn run(
     let networking_fut = networking_fut;
     let consensus_node_fut = consensus_node.run(&reward_address);
     let farmer_fut = farmer.run();
+    let dsn_peers_update_fut = {
+        let mut notifications_sender = notifications_sender.clone();
+
+        async move {
+            let mut peers_details_interval = tokio::time::interval(PEERS_DETAILS_EVENT_INTERVAL);
+            peers_details_interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+            loop {
+                peers_details_interval.tick().await;
+
+                if let Err(error) = notifications_sender
+                    .send(BackendNotification::Node(NodeNotification::DsnPeersUpdate(
+                        connected_dsn_peers.peers(),
+                    )))
+                    .await
+                {
+                    warn!(%error, "Failed to send DSN peers update backend notification");
+                }
+            }
+        }
+    };
+    let bandwidth_usage_fut = {
+        let mut notifications_sender = notifications_sender.clone();
+        let piece_serving_counters = Arc::clone(&piece_serving_counters);
+        let piece_download_counters = Arc::clone(&piece_download_counters);
+
+        async move {
+            let mut bandwidth_usage_interval = tokio::time::interval(BANDWIDTH_USAGE_INTERVAL);
+            bandwidth_usage_interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+            loop {
+                bandwidth_usage_interval.tick().await;
+
+                let substrate_total = *substrate_traffic.lock();
+                let dsn_total = Traffic {
+                    downloaded: piece_download_counters.bytes_downloaded(),
+                    uploaded: piece_serving_counters.stats().bytes_uploaded,
+                };
+                bandwidth_tracker.record(substrate_total, dsn_total);
+
+                let bandwidth_usage = bandwidth_tracker.usage();
+                download_throttle.set_throttled(bandwidth_usage.download_cap_reached());
+
+                // DSN connection limits can't be changed while networking is running, so restart
+                // is requested once cap is first reached
+                if !dsn_connections_reduced
+                    && (bandwidth_usage.download_cap_reached()
+                        || bandwidth_usage.upload_cap_reached())
+                {
+                    dsn_connections_reduced = true;
+
+                    if let Err(error) = notifications_sender
+                        .send(BackendNotification::BandwidthCapReached)
+                        .await
+                    {
+                        warn!(%error, "Failed to send bandwidth cap reached backend notification");
+                    }
+                }
+
+                if let Err(error) = bandwidth_tracker.persist().await {
+                    warn!(%error, "Failed to persist bandwidth usage");
+                }
+
+                if let Err(error) = notifications_sender
+                    .send(BackendNotification::Node(
+                        NodeNotification::BandwidthUsageUpdate(bandwidth_usage),
+                    ))
+                    .await
+                {
+                    warn!(%error, "Failed to send bandwidth usage backend notification");
+                }
+            }
+        }
+    };
+    let piece_serving_stats_fut = {
+        let mut notifications_sender = notifications_sender.clone();
+
+        async move {
+            let mut piece_serving_stats_interval =
+                tokio::time::interval(PIECE_SERVING_STATS_INTERVAL);
+            piece_serving_stats_interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+            loop {
+                piece_serving_stats_interval.tick().await;
+
+                // Sent even if unchanged, such that frontend can derive upload rate from it
+                if let Err(error) = notifications_sender
+                    .send(BackendNotification::Node(
+                        NodeNotification::PieceServingStatsUpdate(piece_serving_counters.stats()),
+                    ))
+                    .await
+                {
+                    warn!(%error, "Failed to send piece serving stats backend notification");
+                }
+            }
+        }
+    };
+    let piece_download_stats_fut = {
+        let mut notifications_sender = notifications_sender.clone();
+
+        async move {
+            let mut piece_download_stats_interval =
+                tokio::time::interval(PIECE_DOWNLOAD_STATS_INTERVAL);
+            piece_download_stats_interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+            let mut last_piece_download_stats = None;
+
+            loop {
+                piece_download_stats_interval.tick().await;
+
+                let piece_download_stats = piece_download_counters.stats();
+                if last_piece_download_stats == Some(piece_download_stats) {
+                    continue;
+                }
+                last_piece_download_stats.replace(piece_download_stats);
+
+                if let Err(error) = notifications_sender
+                    .send(BackendNotification::Farmer(
+                        FarmerNotification::PieceDownloadStatsUpdate(piece_download_stats),
+                    ))
+                    .await
+                {
+                    warn!(%error, "Failed to send piece download stats backend notification");
+                }
+            }
+        }
+    };
     let process_backend_actions_fut = {
         let mut notifications_sender = notifications_sender.clone();
 
         async move {
             process_backend_actions(
                 &config_file_path,
+                &network_path,
                 backend_action_receiver,
                 &mut farmer_action_sender,
                 &mut notifications_sender,
@@ -565,6 +1017,10 @@ async fn run(
     let networking_fut = pin!(networking_fut);
     let consensus_node_fut = pin!(consensus_node_fut);
     let farmer_fut = pin!(farmer_fut);
+    let dsn_peers_update_fut = pin!(dsn_peers_update_fut);
+    let piece_serving_stats_fut = pin!(piece_serving_stats_fut);
+    let bandwidth_usage_fut = pin!(bandwidth_usage_fut);
+    let piece_download_stats_fut = pin!(piece_download_stats_fut);
     let process_backend_actions_fut = pin!(process_backend_actions_fut);
 
     let result: anyhow::Result<()> = select! {
@@ -577,6 +1033,18 @@ async fn run(
         result = farmer_fut.fuse() => {
             result.map_err(|error| anyhow::anyhow!("Farm exited: {error}"))
         }
+        _ = dsn_peers_update_fut.fuse() => {
+            Ok(())
+        }
+        _ = piece_serving_stats_fut.fuse() => {
+            Ok(())
+        }
+        _ = bandwidth_usage_fut.fuse() => {
+            Ok(())
+        }
+        _ = piece_download_stats_fut.fuse() => {
+            Ok(())
+        }
         _ = process_backend_actions_fut.fuse() => {
             Ok(())
         }
@@ -714,11 +1182,108 @@ async fn preparing_node_path(
     Ok(())
 }
 
+/// Settings node database was created with that can't be changed without removing the database
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct NodeDatabaseSettings {
+    /// Snap sync is only possible from an empty database and database synced with snap sync
+    /// doesn't have history necessary for full sync
+    sync_mode: NodeSyncMode,
+    /// Substrate stores state pruning mode in the database and refuses to start if archive mode
+    /// doesn't match it
+    archive: bool,
+}
+
+/// Node database is removed whenever any of [`NodeDatabaseSettings`] changes
+async fn reset_node_database_on_settings_change(
+    node_path: &Path,
+    settings: NodeDatabaseSettings,
+    notifications_sender: &mut mpsc::Sender<BackendNotification>,
+) -> anyhow::Result<()> {
+    let db_path = node_path.join("db");
+    // Stored next to the database directory, such that it doesn't interfere with Substrate
+    let settings_file_path = node_path.join(NODE_DATABASE_SETTINGS_FILE);
+    let db_exists = fs::try_exists(&db_path).await.unwrap_or(true);
+
+    let previous_settings = match fs::read_to_string(&settings_file_path).await {
+        Ok(contents) => Some(serde_json::from_str::<NodeDatabaseSettings>(&contents)),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => None,
+        Err(error) => {
+            return Err(anyhow::anyhow!(
+                "Failed to read node database settings from \"{}\": {error}",
+                settings_file_path.display()
+            ));
+        }
+    };
+
+    let reset = match previous_settings {
+        Some(Ok(previous_settings)) if previous_settings == settings => {
+            return Ok(());
+        }
+        Some(Ok(previous_settings)) => {
+            info!(
+                ?previous_settings,
+                ?settings,
+                "Node database settings changed, removing node database"
+            );
+            true
+        }
+        Some(Err(error)) => {
+            // Unlike missing file, settings database was created with are unknown and can't be
+            // assumed to match current ones
+            warn!(
+                %error,
+                ?settings,
+                "Failed to parse node database settings, removing node database"
+            );
+            true
+        }
+        None => false,
+    };
+
+    if db_exists {
+        if reset {
+            notifications_sender
+                .send(BackendNotification::Loading(
+                    LoadingStep::ResettingNodeDatabase,
+                ))
+                .await?;
+
+            fs::remove_dir_all(&db_path).await.map_err(|error| {
+                anyhow::anyhow!(
+                    "Failed to remove node database at \"{}\": {error}",
+                    db_path.display()
+                )
+            })?;
+        } else {
+            // Database was created before settings were recorded, it was created with settings
+            // from the current configuration since they were not possible to change before
+            info!(
+                ?settings,
+                "Node database settings are not known, recording current settings"
+            );
+        }
+    }
+
+    fs::write(&settings_file_path, serde_json::to_string(&settings)?)
+        .await
+        .map_err(|error| {
+            anyhow::anyhow!(
+                "Failed to write node database settings to \"{}\": {error}",
+                settings_file_path.display()
+            )
+        })?;
+
+    Ok(())
+}
+
 async fn create_networking_stack(
     config: &Config,
     protocol_prefix: String,
     chain_spec: &ChainSpec,
     weak_plotted_pieces: Weak<AsyncRwLock<PlottedPieces<FarmIndex>>>,
+    reduce_dsn_connections: bool,
+    piece_download_counters: Arc<PieceDownloadCounters>,
     notifications_sender: &mut mpsc::Sender<BackendNotification>,
 ) -> anyhow::Result<(
     MaybeNodeClient,
@@ -728,6 +1293,8 @@ async fn create_networking_stack(
     FarmerCache,
     FarmerCacheWorker<MaybeNodeClient>,
     u32,
+    Arc<PieceServingCounters>,
+    Arc<ConnectedDsnPeers>,
 )> {
     notifications_sender
         .send(BackendNotification::Loading(
@@ -735,10 +1302,15 @@ async fn create_networking_stack(
         ))
         .await?;
 
-    let bootstrap_nodes = dsn_bootstrap_nodes(chain_spec)?;
+    let mut bootstrap_nodes = dsn_bootstrap_nodes(chain_spec)?;
+    bootstrap_nodes.extend(parse_multiaddrs::<Multiaddr>(
+        &config.network.dsn_bootstrap_nodes,
+    ));
+    let reserved_peers = parse_multiaddrs::<Multiaddr>(&config.network.dsn_reserved_peers);
+    let external_addresses = parse_multiaddrs::<Multiaddr>(&config.network.dsn_external_addresses);
 
     let network_path = config.node_path.join("network");
-    let keypair_path = network_path.join("secret_ed25519");
+    let keypair_path = network_path.join(NETWORK_KEYPAIR_FILE);
     let keypair_exists = fs::try_exists(&keypair_path).await.map_err(|error| {
         anyhow::anyhow!(
             "Keypair path \"{}\" doesn't exist and can't be created: {error:?}",
@@ -753,15 +1325,7 @@ async fn create_networking_stack(
             ))
             .await?;
 
-        let mut secret_bytes = fs::read(&keypair_path).await.map_err(|error| {
-            anyhow::anyhow!(
-                "Failed to read keypair from \"{}\": {error:?}",
-                keypair_path.display()
-            )
-        })?;
-        let secret_key = SecretKey::try_from_bytes(&mut secret_bytes)?;
-
-        Keypair::from(secret_key)
+        read_network_keypair(&keypair_path).await?
     } else {
         notifications_sender
             .send(BackendNotification::Loading(
@@ -791,21 +1355,7 @@ async fn create_networking_stack(
             })?;
         }
 
-        let mut options = OpenOptions::new();
-        options.write(true).truncate(true).create(true);
-        #[cfg(unix)]
-        options.mode(0o600);
-        options
-            .open(&keypair_path)
-            .await?
-            .write_all(network_keypair.secret().as_ref())
-            .await
-            .map_err(|error| {
-                anyhow::anyhow!(
-                    "Failed to write keypair to \"{}\": {error:?}",
-                    keypair_path.display()
-                )
-            })?;
+        write_network_keypair(&keypair_path, &network_keypair).await?;
 
         network_keypair
     };
@@ -816,33 +1366,63 @@ async fn create_networking_stack(
         ))
         .await?;
 
-    let mut network_options = NetworkOptions {
-        keypair: network_keypair.clone(),
-        bootstrap_nodes,
-        listen_on: vec![
+    if let Err(error) = reset_known_peers_cache_if_needed(&network_path).await {
+        warn!(%error, "Failed to reset known peers cache");
+    }
+
+    let listen_on = if config.network.dsn_listen_on.is_empty() {
+        vec![
             Multiaddr::from(IpAddr::V4(Ipv4Addr::UNSPECIFIED))
                 .with(Protocol::Tcp(config.network.subspace_port)),
             Multiaddr::from(IpAddr::V6(Ipv6Addr::UNSPECIFIED))
                 .with(Protocol::Tcp(config.network.subspace_port)),
-        ],
-        ..NetworkOptions::default()
+        ]
+    } else {
+        parse_multiaddrs(&config.network.dsn_listen_on)
     };
-    if config.network.faster_networking {
-        network_options.in_connections = 500;
-        network_options.out_connections = 500;
-        network_options.pending_in_connections = 500;
-        network_options.pending_out_connections = 500;
+    let mut connection_limits = config.network.connections.limits();
+    if reduce_dsn_connections {
+        info!("Bandwidth cap is reached, scaling back DSN connections");
+
+        let low_limits = NetworkConnections::Low.limits();
+        connection_limits.dsn_in_connections = connection_limits
+            .dsn_in_connections
+            .min(low_limits.dsn_in_connections);
+        connection_limits.dsn_out_connections = connection_limits
+            .dsn_out_connections
+            .min(low_limits.dsn_out_connections);
+        connection_limits.dsn_pending_in_connections = connection_limits
+            .dsn_pending_in_connections
+            .min(low_limits.dsn_pending_in_connections);
+        connection_limits.dsn_pending_out_connections = connection_limits
+            .dsn_pending_out_connections
+            .min(low_limits.dsn_pending_out_connections);
     }
+    let network_options = NetworkOptions {
+        keypair: network_keypair.clone(),
+        bootstrap_nodes,
+        reserved_peers,
+        external_addresses,
+        enable_private_ips: config.network.allow_private_addresses,
+        listen_on,
+        in_connections: connection_limits.dsn_in_connections,
+        out_connections: connection_limits.dsn_out_connections,
+        pending_in_connections: connection_limits.dsn_pending_in_connections,
+        pending_out_connections: connection_limits.dsn_pending_out_connections,
+        ..NetworkOptions::default()
+    };
     let out_connections = network_options.out_connections;
-    let maybe_node_client = MaybeNodeClient::default();
+    let maybe_node_client = MaybeNodeClient::new(piece_download_counters);
 
     let (farmer_cache, farmer_cache_worker) = FarmerCache::new(
         maybe_node_client.clone(),
-        subspace_networking::libp2p::identity::PublicKey::from(network_keypair.public())
-            .to_peer_id(),
+        network_peer_id(&network_keypair),
         None,
     );
 
+    let piece_serving_counters = Arc::<PieceServingCounters>::default();
+    let connected_dsn_peers = Arc::<ConnectedDsnPeers>::default();
+
     let (node, node_runner) = create_network(
         protocol_prefix,
         &network_path,
@@ -850,6 +1430,8 @@ async fn create_networking_stack(
         weak_plotted_pieces,
         maybe_node_client.clone(),
         farmer_cache.clone(),
+        Arc::clone(&piece_serving_counters),
+        Arc::clone(&connected_dsn_peers),
     )?;
 
     notifications_sender
@@ -866,6 +1448,8 @@ async fn create_networking_stack(
         farmer_cache,
         farmer_cache_worker,
         out_connections,
+        piece_serving_counters,
+        connected_dsn_peers,
     ))
 }
 
@@ -873,13 +1457,24 @@ async fn create_networking_stack(
 async fn create_consensus_node(
     network_keypair: &Keypair,
     node_path: PathBuf,
-    substrate_port: u16,
+    network_configuration: &NetworkConfiguration,
+    node_configuration: &NodeConfiguration,
     chain_spec: ChainSpec,
     piece_getter: Arc<dyn PieceGetter + Send + Sync + 'static>,
     node: Node,
     maybe_node_client: &MaybeNodeClient,
     notifications_sender: &mut mpsc::Sender<BackendNotification>,
 ) -> anyhow::Result<LoadedConsensusChainNode> {
+    if let Some(url) = &node_configuration.external_node_rpc_url {
+        return connect_to_external_node_rpc(
+            url,
+            &chain_spec,
+            maybe_node_client,
+            notifications_sender,
+        )
+        .await;
+    }
+
     notifications_sender
         .send(BackendNotification::Loading(
             LoadingStep::CreatingConsensusNode,
@@ -889,7 +1484,8 @@ async fn create_consensus_node(
     let create_consensus_node_fut = node::create_consensus_node(
         network_keypair,
         node_path,
-        substrate_port,
+        network_configuration,
+        node_configuration,
         chain_spec,
         piece_getter,
         node,
@@ -911,7 +1507,46 @@ async fn create_consensus_node(
         ))
         .await?;
 
-    Ok(LoadedConsensusChainNode::Compatible(consensus_node))
+    Ok(LoadedConsensusChainNode::Compatible(
+        ConsensusChainNode::Embedded(consensus_node),
+    ))
+}
+
+async fn connect_to_external_node_rpc(
+    url: &str,
+    chain_spec: &ChainSpec,
+    maybe_node_client: &MaybeNodeClient,
+    notifications_sender: &mut mpsc::Sender<BackendNotification>,
+) -> anyhow::Result<LoadedConsensusChainNode> {
+    notifications_sender
+        .send(BackendNotification::Loading(
+            LoadingStep::ConnectingToExternalNode {
+                url: url.to_string(),
+            },
+        ))
+        .await?;
+
+    let external_node = match connect_to_external_node(url, chain_spec, maybe_node_client).await {
+        Ok(external_node) => external_node,
+        Err(ExternalNodeConnectionError::IncompatibleChain { compatible_chain }) => {
+            return Ok(LoadedConsensusChainNode::Incompatible { compatible_chain });
+        }
+        Err(error) => {
+            return Err(anyhow::anyhow!(
+                "Failed to connect to external node at {url}: {error}"
+            ));
+        }
+    };
+
+    notifications_sender
+        .send(BackendNotification::Loading(
+            LoadingStep::ConnectedToExternalNodeSuccessfully,
+        ))
+        .await?;
+
+    Ok(LoadedConsensusChainNode::Compatible(
+        ConsensusChainNode::External(external_node),
+    ))
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -926,12 +1561,14 @@ async fn create_farmer(
     reduce_plotting_cpu_load: bool,
     piece_getter: FarmerPieceGetter<
         FarmIndex,
-        SegmentCommitmentPieceValidator<MaybeNodeClient>,
+        CountingPieceValidator<SegmentCommitmentPieceValidator<MaybeNodeClient>>,
         MaybeNodeClient,
     >,
+    piece_download_counters: Arc<PieceDownloadCounters>,
     notifications_sender: &mut mpsc::Sender<BackendNotification>,
 ) -> anyhow::Result<Farmer<FarmIndex>> {
     let farms_total = disk_farms.len() as u16;
+    let farms_initialized = &AtomicU16::new(0);
 
     notifications_sender
         .send(BackendNotification::Loading(
@@ -948,15 +1585,47 @@ async fn create_farmer(
         farmer_cache_worker,
         kzg,
         piece_getter,
+        piece_download_counters,
+        on_farm_initialization_progress: {
+            let notifications_sender = &*notifications_sender;
+
+            move |farm_index, progress| {
+                let mut notifications_sender = notifications_sender.clone();
+
+                if let Err(error) = notifications_sender
+                    .try_send(BackendNotification::Loading(
+                        LoadingStep::FarmInitializationProgress {
+                            farm_index,
+                            farms_initialized: farms_initialized.load(Ordering::Acquire),
+                            farms_total,
+                            progress,
+                        },
+                    ))
+                    .or_else(|error| {
+                        tokio::task::block_in_place(|| {
+                            Handle::current()
+                                .block_on(notifications_sender.send(error.into_inner()))
+                        })
+                    })
+                {
+                    warn!(
+                        %error,
+                        "Failed to send farm initialization progress backend notification"
+                    );
+                }
+            }
+        },
         on_farm_initialized: {
             let notifications_sender = &*notifications_sender;
 
             move |farm_index| {
                 let mut notifications_sender = notifications_sender.clone();
+                let farms_initialized = farms_initialized.fetch_add(1, Ordering::AcqRel) + 1;
 
                 if let Err(error) = notifications_sender
                     .try_send(BackendNotification::Loading(LoadingStep::FarmInitialized {
                         farm_index,
+                        farms_initialized,
                         farms_total,
                     }))
                     .or_else(|error| {
@@ -970,6 +1639,32 @@ async fn create_farmer(
                 }
             }
         },
+        on_plotted_pieces_collection_progress: {
+            let notifications_sender = &*notifications_sender;
+
+            move |farms: &[PlottedPiecesCollectionProgress]| {
+                let mut notifications_sender = notifications_sender.clone();
+
+                if let Err(error) = notifications_sender
+                    .try_send(BackendNotification::Loading(
+                        LoadingStep::CollectingPlottedPieces {
+                            farms: farms.to_vec(),
+                        },
+                    ))
+                    .or_else(|error| {
+                        tokio::task::block_in_place(|| {
+                            Handle::current()
+                                .block_on(notifications_sender.send(error.into_inner()))
+                        })
+                    })
+                {
+                    warn!(
+                        %error,
+                        "Failed to send plotted pieces collection progress backend notification"
+                    );
+                }
+            }
+        },
         reduce_plotting_cpu_load,
     };
 
@@ -986,6 +1681,7 @@ async fn create_farmer(
 
 async fn process_backend_actions(
     config_file_path: &Path,
+    network_path: &Path,
     backend_action_receiver: &mut mpsc::Receiver<BackendAction>,
     farmer_action_sender: &mut mpsc::Sender<FarmerAction>,
     notifications_sender: &mut mpsc::Sender<BackendNotification>,
@@ -1015,10 +1711,189 @@ async fn process_backend_actions(
                     error!(%error, "Failed to forward farmer action");
                 }
             }
+            BackendAction::NetworkIdentity(network_identity_action) => {
+                let result = process_network_identity_action(
+                    &network_path.join(NETWORK_KEYPAIR_FILE),
+                    network_identity_action,
+                )
+                .await;
+                if let Err(error) = notifications_sender
+                    .send(BackendNotification::NetworkIdentityActionResult(result))
+                    .await
+                {
+                    error!(%error, "Failed to send network identity action result notification");
+                }
+            }
+            BackendAction::KnownPeers(known_peers_action) => {
+                let result = process_known_peers_action(network_path, known_peers_action).await;
+                if let Err(error) = notifications_sender
+                    .send(BackendNotification::KnownPeersActionResult(result))
+                    .await
+                {
+                    error!(%error, "Failed to send known peers action result notification");
+                }
+            }
         }
     }
 }
 
+/// Changes to the network identity are written to disk and only take effect after restart
+async fn process_network_identity_action(
+    network_keypair_path: &Path,
+    network_identity_action: NetworkIdentityAction,
+) -> anyhow::Result<NetworkIdentityUpdate> {
+    match network_identity_action {
+        NetworkIdentityAction::Export { path } => {
+            let network_keypair = read_network_keypair(network_keypair_path).await?;
+            write_network_keypair(&path, &network_keypair).await?;
+
+            info!(path = %path.display(), "Exported network identity");
+
+            Ok(NetworkIdentityUpdate::Exported { path })
+        }
+        NetworkIdentityAction::Import { path } => {
+            let network_keypair = read_network_keypair(&path).await?;
+            replace_network_keypair(network_keypair_path, &network_keypair).await?;
+
+            let peer_id = network_peer_id(&network_keypair);
+            info!(path = %path.display(), %peer_id, "Imported network identity");
+
+            Ok(NetworkIdentityUpdate::Replaced { peer_id })
+        }
+        NetworkIdentityAction::Rotate => {
+            let network_keypair = Keypair::generate();
+            replace_network_keypair(network_keypair_path, &network_keypair).await?;
+
+            let peer_id = network_peer_id(&network_keypair);
+            info!(%peer_id, "Generated new network identity");
+
+            Ok(NetworkIdentityUpdate::Replaced { peer_id })
+        }
+    }
+}
+
+async fn process_known_peers_action(
+    network_path: &Path,
+    known_peers_action: KnownPeersAction,
+) -> anyhow::Result<KnownPeersUpdate> {
+    match known_peers_action {
+        KnownPeersAction::Inspect => {
+            let known_peers_cache_info =
+                known_peers_cache_info(network_path)
+                    .await
+                    .map_err(|error| {
+                        anyhow::anyhow!(
+                            "Failed to inspect known peers cache in \"{}\": {error}",
+                            network_path.display()
+                        )
+                    })?;
+
+            Ok(KnownPeersUpdate::Inspected(known_peers_cache_info))
+        }
+        KnownPeersAction::Clear => {
+            schedule_known_peers_cache_reset(network_path)
+                .await
+                .map_err(|error| {
+                    anyhow::anyhow!(
+                        "Failed to schedule known peers cache reset in \"{}\": {error}",
+                        network_path.display()
+                    )
+                })?;
+
+            info!("Known peers cache will be cleared on next start");
+
+            Ok(KnownPeersUpdate::ResetScheduled)
+        }
+    }
+}
+
+fn network_peer_id(network_keypair: &Keypair) -> PeerId {
+    subspace_networking::libp2p::identity::PublicKey::from(network_keypair.public()).to_peer_id()
+}
+
+async fn read_network_keypair(keypair_path: &Path) -> anyhow::Result<Keypair> {
+    let mut secret_bytes = fs::read(keypair_path).await.map_err(|error| {
+        anyhow::anyhow!(
+            "Failed to read keypair from \"{}\": {error:?}",
+            keypair_path.display()
+        )
+    })?;
+    let secret_key = SecretKey::try_from_bytes(&mut secret_bytes).map_err(|error| {
+        anyhow::anyhow!("Invalid keypair in \"{}\": {error}", keypair_path.display())
+    })?;
+
+    Ok(Keypair::from(secret_key))
+}
+
+/// Replace network identity, keeping the previous one next to it in case it is needed again
+async fn replace_network_keypair(
+    keypair_path: &Path,
+    network_keypair: &Keypair,
+) -> anyhow::Result<()> {
+    let previous_network_keypair = read_network_keypair(keypair_path).await?;
+    let previous_peer_id = network_peer_id(&previous_network_keypair);
+
+    let mut backup_path = keypair_path.as_os_str().to_os_string();
+    backup_path.push(format!(
+        ".{previous_peer_id}{NETWORK_KEYPAIR_BACKUP_SUFFIX}"
+    ));
+    let backup_path = PathBuf::from(backup_path);
+
+    write_network_keypair(&backup_path, &previous_network_keypair).await?;
+
+    info!(
+        path = %backup_path.display(),
+        peer_id = %previous_peer_id,
+        "Previous network identity backed up"
+    );
+
+    write_network_keypair(keypair_path, network_keypair).await
+}
+
+/// Keypair is written to a temporary file first and then renamed, such that existing keypair is
+/// never left partially written
+async fn write_network_keypair(
+    keypair_path: &Path,
+    network_keypair: &Keypair,
+) -> anyhow::Result<()> {
+    let mut tmp_path = keypair_path.as_os_str().to_os_string();
+    tmp_path.push(".tmp");
+    let tmp_path = PathBuf::from(tmp_path);
+
+    let mut options = OpenOptions::new();
+    options.write(true).truncate(true).create(true);
+    #[cfg(unix)]
+    options.mode(0o600);
+    let mut file = options.open(&tmp_path).await.map_err(|error| {
+        anyhow::anyhow!(
+            "Failed to open keypair file \"{}\": {error:?}",
+            tmp_path.display()
+        )
+    })?;
+    let result: io::Result<()> = try {
+        file.write_all(network_keypair.secret().as_ref()).await?;
+        file.sync_all().await?;
+    };
+    drop(file);
+    if let Err(error) = result {
+        let _ = fs::remove_file(&tmp_path).await;
+
+        return Err(anyhow::anyhow!(
+            "Failed to write keypair to \"{}\": {error:?}",
+            tmp_path.display()
+        ));
+    }
+
+    fs::rename(&tmp_path, keypair_path).await.map_err(|error| {
+        anyhow::anyhow!(
+            "Failed to move keypair to \"{}\": {error:?}",
+            keypair_path.display()
+        )
+    })?;
+
+    Ok(())
+}
+
 pub async fn wipe(
     raw_config: &RawConfig,
     notifications_sender: &mut mpsc::Sender<BackendNotification>,
@@ -1096,6 +1971,17 @@ pub async fn wipe(
             }
         }
 
+        let path = path.join(NODE_DATABASE_SETTINGS_FILE);
+        if let Err(error) = fs::remove_file(&path).await
+            && error.kind() != io::ErrorKind::NotFound
+        {
+            notifications_sender
+                .send(BackendNotification::IrrecoverableError {
+                    error: anyhow::anyhow!("Failed to remove {}: {error}", path.display()),
+                })
+                .await?;
+        }
+
         notifications_sender
             .send(BackendNotification::Loading(
                 LoadingStep::WipedNodeSuccessfully,
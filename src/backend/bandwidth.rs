@@ -0,0 +1,227 @@
+use crate::backend::config::BandwidthCaps;
+use async_lock::{Semaphore, SemaphoreGuardArc};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::fs;
+use tracing::warn;
+
+/// File in network directory of node path that stores bandwidth usage of the last 24 hours
+const BANDWIDTH_USAGE_FILE: &str = "bandwidth_usage.json";
+/// Number of hourly entries that make up a day
+const HOURS_PER_DAY: u64 = 24;
+/// Divisor of piece downloads concurrency applied once download cap is reached
+const THROTTLED_DOWNLOADS_DIVISOR: usize = 10;
+
+/// Bytes transferred in each direction
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Traffic {
+    pub downloaded: u64,
+    pub uploaded: u64,
+}
+
+impl Traffic {
+    fn saturating_add(self, other: Self) -> Self {
+        Self {
+            downloaded: self.downloaded.saturating_add(other.downloaded),
+            uploaded: self.uploaded.saturating_add(other.uploaded),
+        }
+    }
+
+    fn saturating_sub(self, other: Self) -> Self {
+        Self {
+            downloaded: self.downloaded.saturating_sub(other.downloaded),
+            uploaded: self.uploaded.saturating_sub(other.uploaded),
+        }
+    }
+}
+
+/// Traffic of both networks during one hour
+#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct HourlyTraffic {
+    /// Hours since Unix epoch
+    hour: u64,
+    substrate: Traffic,
+    dsn: Traffic,
+}
+
+/// Bandwidth usage of both networks
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct BandwidthUsage {
+    /// Traffic of both networks during the current hour
+    pub hour: Traffic,
+    /// Traffic of consensus node (Substrate) during the last 24 hours
+    pub substrate_day: Traffic,
+    /// Traffic of DSN during the last 24 hours
+    pub dsn_day: Traffic,
+    pub caps: BandwidthCaps,
+}
+
+impl BandwidthUsage {
+    /// Traffic of both networks during the last 24 hours
+    pub fn day(&self) -> Traffic {
+        self.substrate_day.saturating_add(self.dsn_day)
+    }
+
+    pub fn download_cap_reached(&self) -> bool {
+        self.caps
+            .download
+            .is_some_and(|cap| self.day().downloaded >= cap)
+    }
+
+    pub fn upload_cap_reached(&self) -> bool {
+        self.caps
+            .upload
+            .is_some_and(|cap| self.day().uploaded >= cap)
+    }
+}
+
+/// Accumulates traffic from cumulative byte counters of both networks into hourly entries that are
+/// persisted on disk, such that daily caps survive application restarts
+#[derive(Debug)]
+pub(super) struct BandwidthTracker {
+    path: PathBuf,
+    caps: BandwidthCaps,
+    hours: VecDeque<HourlyTraffic>,
+    last_substrate_total: Traffic,
+    last_dsn_total: Traffic,
+}
+
+impl BandwidthTracker {
+    /// Open tracker with usage history stored in network directory, history is discarded if it
+    /// can't be read
+    pub(super) async fn open(network_path: &Path, caps: BandwidthCaps) -> Self {
+        let path = network_path.join(BANDWIDTH_USAGE_FILE);
+
+        let hours = match fs::read_to_string(&path).await {
+            Ok(contents) => serde_json::from_str::<VecDeque<HourlyTraffic>>(&contents)
+                .unwrap_or_else(|error| {
+                    warn!(%error, path = %path.display(), "Failed to decode bandwidth usage");
+                    VecDeque::new()
+                }),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => VecDeque::new(),
+            Err(error) => {
+                warn!(%error, path = %path.display(), "Failed to read bandwidth usage");
+                VecDeque::new()
+            }
+        };
+
+        let mut tracker = Self {
+            path,
+            caps,
+            hours,
+            last_substrate_total: Traffic::default(),
+            last_dsn_total: Traffic::default(),
+        };
+        tracker.prune(current_hour());
+
+        tracker
+    }
+
+    /// Record cumulative traffic counters of both networks since application start
+    pub(super) fn record(&mut self, substrate_total: Traffic, dsn_total: Traffic) {
+        let substrate = substrate_total.saturating_sub(self.last_substrate_total);
+        let dsn = dsn_total.saturating_sub(self.last_dsn_total);
+        self.last_substrate_total = substrate_total;
+        self.last_dsn_total = dsn_total;
+
+        let hour = current_hour();
+        self.prune(hour);
+
+        match self.hours.back_mut() {
+            Some(hourly_traffic) if hourly_traffic.hour == hour => {
+                hourly_traffic.substrate = hourly_traffic.substrate.saturating_add(substrate);
+                hourly_traffic.dsn = hourly_traffic.dsn.saturating_add(dsn);
+            }
+            _ => {
+                self.hours.push_back(HourlyTraffic {
+                    hour,
+                    substrate,
+                    dsn,
+                });
+            }
+        }
+    }
+
+    pub(super) fn usage(&self) -> BandwidthUsage {
+        let hour = current_hour();
+
+        self.hours.iter().fold(
+            BandwidthUsage {
+                caps: self.caps,
+                ..BandwidthUsage::default()
+            },
+            |mut usage, hourly_traffic| {
+                if hourly_traffic.hour == hour {
+                    usage.hour = hourly_traffic.substrate.saturating_add(hourly_traffic.dsn);
+                }
+                usage.substrate_day = usage.substrate_day.saturating_add(hourly_traffic.substrate);
+                usage.dsn_day = usage.dsn_day.saturating_add(hourly_traffic.dsn);
+
+                usage
+            },
+        )
+    }
+
+    pub(super) async fn persist(&self) -> io::Result<()> {
+        let contents = serde_json::to_string(&self.hours).map_err(io::Error::other)?;
+
+        fs::write(&self.path, contents).await
+    }
+
+    /// Remove entries that are older than 24 hours
+    fn prune(&mut self, hour: u64) {
+        while let Some(hourly_traffic) = self.hours.front()
+            && hourly_traffic.hour + HOURS_PER_DAY <= hour
+        {
+            self.hours.pop_front();
+        }
+    }
+}
+
+/// Scales back concurrent piece downloads by holding most of piece provider's permits
+#[derive(Debug)]
+pub(super) struct DownloadThrottle {
+    semaphore: Arc<Semaphore>,
+    permits_to_hold: usize,
+    held_permits: Vec<SemaphoreGuardArc>,
+}
+
+impl DownloadThrottle {
+    pub(super) fn new(semaphore: Arc<Semaphore>, permits: usize) -> Self {
+        Self {
+            semaphore,
+            permits_to_hold: permits - (permits / THROTTLED_DOWNLOADS_DIVISOR).max(1),
+            held_permits: Vec::new(),
+        }
+    }
+
+    /// Permits are taken as they become available, so this needs to be called periodically while
+    /// throttled
+    pub(super) fn set_throttled(&mut self, throttled: bool) {
+        if !throttled {
+            self.held_permits.clear();
+            return;
+        }
+
+        while self.held_permits.len() < self.permits_to_hold {
+            let Some(permit) = self.semaphore.try_acquire_arc() else {
+                break;
+            };
+            self.held_permits.push(permit);
+        }
+    }
+}
+
+fn current_hour() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+        / 3600
+}
@@ -1,23 +1,33 @@
 use crate::backend::farmer::{CACHE_PERCENTAGE, DiskFarm};
+use crate::backend::node::{IN_PEERS, MIN_STATE_PRUNING, NODE_NAME_MAX_LENGTH, OUT_PEERS};
 use bytesize::ByteSize;
+use reqwest::{Proxy, Url};
 use serde::{Deserialize, Serialize};
-use std::io;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, TcpListener};
+use std::num::NonZeroU32;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
-use subspace_core_primitives::PublicKey;
+use std::{io, mem};
+use subspace_core_primitives::{BlockNumber, PublicKey};
 use subspace_farmer::single_disk_farm::SingleDiskFarm;
 use subspace_farmer::utils::ss58::{Ss58ParsingError, parse_ss58_reward_address};
+use subspace_networking::libp2p::Multiaddr;
+use subspace_networking::libp2p::multiaddr::Protocol;
 use tokio::io::AsyncWriteExt;
 use tokio::task;
 use tracing::warn;
 
 const DEFAULT_SUBSTRATE_PORT: u16 = 30333;
 const DEFAULT_SUBSPACE_PORT: u16 = 30433;
+const DEFAULT_RPC_PORT: u16 = 9944;
 pub const MIN_FARM_SIZE: u64 = ByteSize::gb(2).as_u64();
 /// Marginal difference in farm size that will not trigger resizing
 const FARM_SIZE_DIFF_MARGIN: u64 = ByteSize::gib(5).as_u64();
 /// Margin for farm size allocation relatively to available space
 const FARM_SIZE_ALLOCATION_MARGIN: u64 = ByteSize::gib(2).as_u64();
+/// Free space on farm disk below which warning must be shown unless configured otherwise, below
+/// [`FARM_SIZE_ALLOCATION_MARGIN`] such that freshly allocated farm doesn't trigger it right away
+pub const DEFAULT_FARM_FREE_SPACE_WARNING_THRESHOLD: u64 = ByteSize::gib(1).as_u64();
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -25,6 +35,10 @@ pub struct Farm {
     pub path: PathBuf,
     /// Could be absolute value or percentage of free disk space (when ends with `%`)
     pub size: String,
+    /// Free space on farm disk below which warning is shown, defaults to
+    /// [`DEFAULT_FARM_FREE_SPACE_WARNING_THRESHOLD`]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub free_space_warning_threshold: Option<String>,
 }
 
 /// Configuration error
@@ -45,12 +59,236 @@ pub enum RawConfigError {
     FailedToDeserialize(serde_json::Error),
 }
 
+/// Limits on the number of connections on both networks
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectionLimits {
+    /// Max established incoming DSN connections
+    pub dsn_in_connections: u32,
+    /// Max established outgoing DSN connections
+    pub dsn_out_connections: u32,
+    /// Max pending incoming DSN connections
+    pub dsn_pending_in_connections: u32,
+    /// Max pending outgoing DSN connections
+    pub dsn_pending_out_connections: u32,
+    /// Max incoming Substrate peers
+    pub substrate_in_peers: u32,
+    /// Max outgoing Substrate peers
+    pub substrate_out_peers: u32,
+}
+
+/// Soft caps on traffic of both networks over the last 24 hours, in bytes
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct BandwidthCaps {
+    /// Once reached, plotting downloads and DSN connections are scaled back
+    pub download: Option<u64>,
+    /// Once reached, serving pieces to other DSN peers is paused
+    pub upload: Option<u64>,
+}
+
+/// Piece download retry policy and concurrency, used by plotting and DSN sync
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PieceDownloadConfiguration {
+    /// Piece download retry attempts
+    pub max_retries: u16,
+    /// Initial interval between piece download retries in seconds
+    pub initial_retry_interval: u64,
+    /// Max interval between piece download retries in seconds
+    pub max_retry_interval: u64,
+    /// Multiplier on top of outgoing DSN connections for concurrent piece downloads
+    pub concurrency_multiplier: u16,
+}
+
+impl Default for PieceDownloadConfiguration {
+    fn default() -> Self {
+        Self {
+            max_retries: 7,
+            initial_retry_interval: 5,
+            max_retry_interval: 40,
+            concurrency_multiplier: 10,
+        }
+    }
+}
+
+impl PieceDownloadConfiguration {
+    pub fn is_valid(&self) -> bool {
+        self.initial_retry_interval > 0
+            && self.initial_retry_interval <= self.max_retry_interval
+            && self.concurrency_multiplier > 0
+    }
+}
+
+/// Proxy for outgoing HTTP requests, peer-to-peer connections of both networks don't support
+/// proxies
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProxyConfiguration {
+    /// Proxy URL, for example `http://proxy.example.com:8080` or `socks5://127.0.0.1:1080`
+    pub url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+}
+
+impl ProxyConfiguration {
+    /// Whether provided proxy URL is a valid HTTP, HTTPS or SOCKS5 URL
+    pub fn is_valid_url(url: &str) -> bool {
+        Url::parse(url).is_ok_and(|url| {
+            matches!(url.scheme(), "http" | "https" | "socks5" | "socks5h")
+                && url.host_str().is_some()
+        })
+    }
+
+    /// Proxy for `reqwest` clients with basic authentication, if configured
+    pub fn reqwest_proxy(&self) -> reqwest::Result<Proxy> {
+        let proxy = Proxy::all(&self.url)?;
+
+        Ok(match &self.username {
+            Some(username) => {
+                proxy.basic_auth(username, self.password.as_deref().unwrap_or_default())
+            }
+            None => proxy,
+        })
+    }
+}
+
+/// New version checks
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdatesConfiguration {
+    /// Whether to periodically check for new versions
+    #[serde(default = "UpdatesConfiguration::default_enabled")]
+    pub enabled: bool,
+    /// Whether to consider pre-releases as new versions
+    #[serde(default)]
+    pub include_prereleases: bool,
+    /// HTTP(S) URL or absolute path of a JSON file in GitHub releases API format to check instead
+    /// of GitHub, either a single release or a list of releases
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+}
+
+impl Default for UpdatesConfiguration {
+    fn default() -> Self {
+        Self {
+            enabled: Self::default_enabled(),
+            include_prereleases: false,
+            source: None,
+        }
+    }
+}
+
+impl UpdatesConfiguration {
+    fn default_enabled() -> bool {
+        true
+    }
+
+    /// Whether provided update source is an absolute file path or a valid HTTP or HTTPS URL
+    pub fn is_valid_source(source: &str) -> bool {
+        // Check path first, on Windows `C:\...` is a valid URL with `c` scheme
+        Path::new(source).is_absolute()
+            || Url::parse(source).is_ok_and(|url| {
+                matches!(url.scheme(), "http" | "https") && url.host_str().is_some()
+            })
+    }
+}
+
+/// Connection limits preset
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum NetworkConnections {
+    /// For metered connections and weak consumer routers
+    Low,
+    /// Optimized for typical consumer routers
+    #[default]
+    Default,
+    /// For more powerful setups, may improve sync speed and other processes
+    High,
+    /// Explicitly configured limits
+    Custom(ConnectionLimits),
+}
+
+impl NetworkConnections {
+    pub fn limits(&self) -> ConnectionLimits {
+        match self {
+            Self::Low => ConnectionLimits {
+                dsn_in_connections: 100,
+                dsn_out_connections: 50,
+                dsn_pending_in_connections: 50,
+                dsn_pending_out_connections: 50,
+                substrate_in_peers: IN_PEERS / 2,
+                substrate_out_peers: OUT_PEERS,
+            },
+            Self::Default => ConnectionLimits {
+                dsn_in_connections: 300,
+                dsn_out_connections: 100,
+                dsn_pending_in_connections: 100,
+                dsn_pending_out_connections: 100,
+                substrate_in_peers: IN_PEERS,
+                substrate_out_peers: OUT_PEERS,
+            },
+            Self::High => ConnectionLimits {
+                dsn_in_connections: 500,
+                dsn_out_connections: 500,
+                dsn_pending_in_connections: 500,
+                dsn_pending_out_connections: 500,
+                substrate_in_peers: IN_PEERS * 2,
+                substrate_out_peers: OUT_PEERS * 2,
+            },
+            Self::Custom(limits) => *limits,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct NetworkConfiguration {
     pub substrate_port: u16,
     pub subspace_port: u16,
-    #[serde(default)]
+    /// Replaced by [`Self::connections`], `true` is migrated to [`NetworkConnections::High`] when
+    /// config is read
+    #[serde(default, skip_serializing)]
     pub faster_networking: bool,
+    #[serde(default)]
+    pub connections: NetworkConnections,
+    /// Substrate bootstrap nodes in addition to those from chain specification
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub substrate_bootstrap_nodes: Vec<String>,
+    /// Substrate nodes to always stay connected to
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub substrate_reserved_nodes: Vec<String>,
+    /// DSN bootstrap nodes in addition to those from chain specification
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dsn_bootstrap_nodes: Vec<String>,
+    /// DSN peers to always stay connected to
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dsn_reserved_peers: Vec<String>,
+    /// Publicly reachable Substrate addresses to advertise, for instance with port forwarding
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub substrate_external_addresses: Vec<String>,
+    /// Publicly reachable DSN addresses to advertise, for instance with port forwarding
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dsn_external_addresses: Vec<String>,
+    /// Allow connecting to and discovering peers with private addresses, useful on LAN
+    #[serde(default)]
+    pub allow_private_addresses: bool,
+    /// Substrate addresses to listen on, all interfaces with [`Self::substrate_port`] if empty
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub substrate_listen_on: Vec<String>,
+    /// DSN addresses to listen on, all interfaces with [`Self::subspace_port`] if empty
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dsn_listen_on: Vec<String>,
+    /// Soft cap on download over the last 24 hours, no cap if not set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub daily_download_cap: Option<String>,
+    /// Soft cap on upload over the last 24 hours, no cap if not set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub daily_upload_cap: Option<String>,
+    #[serde(default)]
+    pub piece_download: PieceDownloadConfiguration,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<ProxyConfiguration>,
 }
 
 impl Default for NetworkConfiguration {
@@ -59,8 +297,306 @@ impl Default for NetworkConfiguration {
             substrate_port: DEFAULT_SUBSTRATE_PORT,
             subspace_port: DEFAULT_SUBSPACE_PORT,
             faster_networking: false,
+            connections: NetworkConnections::default(),
+            substrate_bootstrap_nodes: Vec::new(),
+            substrate_reserved_nodes: Vec::new(),
+            dsn_bootstrap_nodes: Vec::new(),
+            dsn_reserved_peers: Vec::new(),
+            substrate_external_addresses: Vec::new(),
+            dsn_external_addresses: Vec::new(),
+            allow_private_addresses: false,
+            substrate_listen_on: Vec::new(),
+            dsn_listen_on: Vec::new(),
+            daily_download_cap: None,
+            daily_upload_cap: None,
+            piece_download: PieceDownloadConfiguration::default(),
+            proxy: None,
+        }
+    }
+}
+
+impl NetworkConfiguration {
+    /// TCP addresses that are listened on with provided listen multiaddrs, all interfaces with
+    /// provided port are listened on when there are none
+    pub fn tcp_listen_addresses(listen_on: &[String], port: u16) -> Vec<SocketAddr> {
+        if listen_on.is_empty() {
+            return vec![
+                SocketAddr::from((Ipv4Addr::UNSPECIFIED, port)),
+                SocketAddr::from((Ipv6Addr::UNSPECIFIED, port)),
+            ];
+        }
+
+        listen_on
+            .iter()
+            .filter_map(|multiaddr| {
+                let mut protocols = multiaddr.parse::<Multiaddr>().ok()?.into_iter();
+                let ip = match protocols.next()? {
+                    Protocol::Ip4(ip) => IpAddr::V4(ip),
+                    Protocol::Ip6(ip) => IpAddr::V6(ip),
+                    _ => {
+                        return None;
+                    }
+                };
+                let Protocol::Tcp(port) = protocols.next()? else {
+                    return None;
+                };
+
+                Some(SocketAddr::new(ip, port))
+            })
+            .collect()
+    }
+
+    /// Whether TCP address is not used by another application
+    pub fn is_address_available(address: SocketAddr) -> bool {
+        // Port `0` means random port will be picked by OS
+        if address.port() == 0 {
+            return true;
+        }
+
+        match TcpListener::bind(address) {
+            Ok(_listener) => true,
+            // Other errors (like IPv6 not being supported) are not caused by other applications
+            Err(error) => error.kind() != io::ErrorKind::AddrInUse,
         }
     }
+
+    /// Port that is used by both Substrate and DSN listen addresses, if any
+    pub fn find_duplicate_port(
+        substrate_addresses: &[SocketAddr],
+        dsn_addresses: &[SocketAddr],
+    ) -> Option<u16> {
+        substrate_addresses
+            .iter()
+            .map(SocketAddr::port)
+            .filter(|&port| port != 0)
+            .find(|&port| dsn_addresses.iter().any(|address| address.port() == port))
+    }
+
+    /// Pick two different TCP ports that are currently not used by any application on provided
+    /// IP address (all interfaces if `None`).
+    ///
+    /// Ports are probed upward from default ports rather than picked randomly, such that they
+    /// don't collide with ephemeral ports of outgoing connections and are suitable for port
+    /// forwarding.
+    pub fn pick_free_ports(listen_ip: Option<IpAddr>) -> Option<(u16, u16)> {
+        let is_port_available = |port: u16| match listen_ip {
+            Some(ip) => Self::is_address_available(SocketAddr::new(ip, port)),
+            None => Self::tcp_listen_addresses(&[], port)
+                .into_iter()
+                .all(Self::is_address_available),
+        };
+
+        let substrate_port =
+            (DEFAULT_SUBSTRATE_PORT..=u16::MAX).find(|&port| is_port_available(port))?;
+        let subspace_port = (DEFAULT_SUBSPACE_PORT..=u16::MAX)
+            .find(|&port| port != substrate_port && is_port_available(port))?;
+
+        Some((substrate_port, subspace_port))
+    }
+
+    /// Parse configured bandwidth caps
+    pub fn bandwidth_caps(&self) -> Result<BandwidthCaps, ConfigError> {
+        let parse_cap = |cap: &Option<String>| {
+            cap.as_ref()
+                .map(|cap| {
+                    ByteSize::from_str(cap)
+                        .map(|cap| cap.as_u64())
+                        .map_err(|error| ConfigError::InvalidSizeFormat {
+                            size: cap.clone(),
+                            error,
+                        })
+                })
+                .transpose()
+        };
+
+        Ok(BandwidthCaps {
+            download: parse_cap(&self.daily_download_cap)?,
+            upload: parse_cap(&self.daily_upload_cap)?,
+        })
+    }
+
+    /// Whether provided string is a valid multiaddr
+    pub fn is_valid_multiaddr(multiaddr: &str) -> bool {
+        multiaddr.parse::<Multiaddr>().is_ok()
+    }
+
+    /// Whether provided string is a valid multiaddr that ends with peer ID
+    pub fn is_valid_peer_multiaddr(multiaddr: &str) -> bool {
+        multiaddr
+            .parse::<Multiaddr>()
+            .is_ok_and(|multiaddr| matches!(multiaddr.iter().last(), Some(Protocol::P2p(_))))
+    }
+
+    /// All configured bootstrap nodes and reserved peers of both networks
+    fn peer_multiaddrs(&self) -> impl Iterator<Item = &String> {
+        self.substrate_bootstrap_nodes
+            .iter()
+            .chain(&self.substrate_reserved_nodes)
+            .chain(&self.dsn_bootstrap_nodes)
+            .chain(&self.dsn_reserved_peers)
+    }
+
+    /// All configured listen addresses of both networks
+    fn listen_addresses(&self) -> impl Iterator<Item = &String> {
+        self.substrate_listen_on.iter().chain(&self.dsn_listen_on)
+    }
+
+    /// All configured external addresses of both networks
+    fn external_addresses(&self) -> impl Iterator<Item = &String> {
+        self.substrate_external_addresses
+            .iter()
+            .chain(&self.dsn_external_addresses)
+    }
+}
+
+/// How much of the chain history node keeps on disk
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum NodePruning {
+    /// Keep the minimum that is necessary for farming
+    #[default]
+    Minimum,
+    /// Keep state and blocks of this many most recent blocks, can't be lower than
+    /// [`MIN_STATE_PRUNING`]
+    Blocks(BlockNumber),
+    /// Keep state and blocks of the whole chain
+    Archive,
+}
+
+impl NodePruning {
+    /// Whether node keeps state of the whole chain
+    pub fn is_archive(self) -> bool {
+        matches!(self, Self::Archive)
+    }
+}
+
+/// How node synchronizes the chain when starting with an empty database
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum NodeSyncMode {
+    /// Download recent state and only the blocks necessary for farming, much faster
+    #[default]
+    Snap,
+    /// Download and verify every block since genesis, much slower
+    Full,
+}
+
+impl NodeSyncMode {
+    pub fn all() -> [NodeSyncMode; std::mem::variant_count::<NodeSyncMode>()] {
+        [Self::Snap, Self::Full]
+    }
+}
+
+/// Set of JSON-RPC methods exposed by the node
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RpcMethods {
+    /// Only methods that are safe to expose publicly
+    #[default]
+    Safe,
+    /// All methods, including those that change node behavior
+    Unsafe,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcConfiguration {
+    /// Whether JSON-RPC server is enabled
+    #[serde(default)]
+    pub enabled: bool,
+    /// Address to listen on, localhost by default
+    #[serde(default = "RpcConfiguration::default_listen_on")]
+    pub listen_on: SocketAddr,
+    #[serde(default)]
+    pub methods: RpcMethods,
+    /// Allowed CORS origins, `*` allows any origin
+    #[serde(default = "RpcConfiguration::default_cors")]
+    pub cors: Vec<String>,
+    /// Maximum number of calls per minute per connection, unlimited if not set
+    #[serde(default)]
+    pub rate_limit: Option<NonZeroU32>,
+}
+
+impl Default for RpcConfiguration {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            listen_on: Self::default_listen_on(),
+            methods: RpcMethods::default(),
+            cors: Self::default_cors(),
+            rate_limit: None,
+        }
+    }
+}
+
+impl RpcConfiguration {
+    fn default_listen_on() -> SocketAddr {
+        SocketAddr::from((Ipv4Addr::LOCALHOST, DEFAULT_RPC_PORT))
+    }
+
+    /// Substrate's default, allows local origins only
+    fn default_cors() -> Vec<String> {
+        [
+            "http://localhost:*",
+            "http://127.0.0.1:*",
+            "https://localhost:*",
+            "https://127.0.0.1:*",
+        ]
+        .into_iter()
+        .map(String::from)
+        .collect()
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeConfiguration {
+    /// Node name, generated randomly on first start if not set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Whether node reports to telemetry endpoints from chain specification
+    #[serde(default = "NodeConfiguration::default_telemetry")]
+    pub telemetry: bool,
+    #[serde(default)]
+    pub sync_mode: NodeSyncMode,
+    #[serde(default)]
+    pub pruning: NodePruning,
+    #[serde(default)]
+    pub rpc: RpcConfiguration,
+    /// WebSocket RPC URL of an external node to farm with instead of running embedded node
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_node_rpc_url: Option<String>,
+}
+
+impl Default for NodeConfiguration {
+    fn default() -> Self {
+        Self {
+            name: None,
+            telemetry: Self::default_telemetry(),
+            sync_mode: NodeSyncMode::default(),
+            pruning: NodePruning::default(),
+            rpc: RpcConfiguration::default(),
+            external_node_rpc_url: None,
+        }
+    }
+}
+
+impl NodeConfiguration {
+    fn default_telemetry() -> bool {
+        true
+    }
+
+    /// Whether provided node name is not empty and fits into [`NODE_NAME_MAX_LENGTH`]
+    pub fn is_valid_node_name(name: &str) -> bool {
+        !name.trim().is_empty() && name.chars().count() < NODE_NAME_MAX_LENGTH
+    }
+
+    /// Whether provided external node RPC URL looks like a valid WebSocket URL
+    pub fn is_valid_external_node_rpc_url(url: &str) -> bool {
+        url.strip_prefix("ws://")
+            .or_else(|| url.strip_prefix("wss://"))
+            .is_some_and(|rest| !rest.is_empty())
+    }
 }
 
 // TODO: This config is not necessarily valid, probably combine with valid config
@@ -77,6 +613,10 @@ pub enum RawConfig {
         reduce_plotting_cpu_load: bool,
         #[serde(default)]
         network: NetworkConfiguration,
+        #[serde(default)]
+        node: NodeConfiguration,
+        #[serde(default)]
+        updates: UpdatesConfiguration,
     },
 }
 
@@ -88,6 +628,8 @@ impl Default for RawConfig {
             farms: Vec::new(),
             reduce_plotting_cpu_load: false,
             network: NetworkConfiguration::default(),
+            node: NodeConfiguration::default(),
+            updates: UpdatesConfiguration::default(),
         }
     }
 }
@@ -116,7 +658,10 @@ impl RawConfig {
     pub async fn read_from_path(config_file_path: &Path) -> Result<Option<Self>, RawConfigError> {
         match tokio::fs::read_to_string(config_file_path).await {
             Ok(config) => serde_json::from_str::<Self>(&config)
-                .map(Some)
+                .map(|mut config| {
+                    config.migrate();
+                    Some(config)
+                })
                 .map_err(RawConfigError::FailedToDeserialize),
             Err(error) => {
                 if error.kind() == io::ErrorKind::NotFound {
@@ -128,6 +673,16 @@ impl RawConfig {
         }
     }
 
+    /// Migrate deprecated options, such that they are not lost when config is written back
+    fn migrate(&mut self) {
+        let Self::V0 { network, .. } = self;
+        if mem::take(&mut network.faster_networking)
+            && network.connections == NetworkConnections::Default
+        {
+            network.connections = NetworkConnections::High;
+        }
+    }
+
     pub async fn write_to_path(&self, config_file_path: &Path) -> io::Result<()> {
         let mut options = tokio::fs::OpenOptions::new();
         options.write(true).truncate(true).create(true);
@@ -167,9 +722,24 @@ impl RawConfig {
         *reduce_plotting_cpu_load
     }
 
-    pub fn network(&self) -> NetworkConfiguration {
+    pub fn network(&self) -> &NetworkConfiguration {
         let Self::V0 { network, .. } = self;
-        *network
+        network
+    }
+
+    pub fn node(&self) -> &NodeConfiguration {
+        let Self::V0 { node, .. } = self;
+        node
+    }
+
+    pub fn node_mut(&mut self) -> &mut NodeConfiguration {
+        let Self::V0 { node, .. } = self;
+        node
+    }
+
+    pub fn updates(&self) -> &UpdatesConfiguration {
+        let Self::V0 { updates, .. } = self;
+        updates
     }
 
     pub fn set_node_path(&mut self, new_path: PathBuf) {
@@ -196,6 +766,60 @@ pub enum ConfigError {
     /// Invalid size format
     #[error("Invalid size format \"{size}\": {error}")]
     InvalidSizeFormat { size: String, error: String },
+    /// Invalid farm free space warning threshold
+    #[error("Free space warning threshold of farm at \"{path}\" must be above zero")]
+    InvalidFreeSpaceWarningThreshold { path: String },
+    /// Too few blocks to keep with node pruning
+    #[error("Node must keep at least {min_blocks} blocks, {blocks} blocks is not enough")]
+    InvalidNodePruning {
+        blocks: BlockNumber,
+        min_blocks: BlockNumber,
+    },
+    /// Invalid node name
+    #[error(
+        "Invalid node name \"{name}\", must not be empty and shorter than {max_length} characters"
+    )]
+    InvalidNodeName { name: String, max_length: usize },
+    /// Invalid external node RPC URL
+    #[error("Invalid external node RPC URL \"{url}\", must start with ws:// or wss://")]
+    InvalidExternalNodeRpcUrl { url: String },
+    /// External node without farms, neither node nor farmer would run
+    #[error("At least one farm is required when external node is used")]
+    ExternalNodeWithoutFarms,
+    /// Invalid bootstrap node or reserved peer address
+    #[error("Invalid peer address \"{multiaddr}\", must be a multiaddr ending with /p2p/<peer ID>")]
+    InvalidPeerMultiaddr { multiaddr: String },
+    /// Invalid connection limits
+    #[error(
+        "Invalid connection limits, there must be at least one outgoing connection on each network"
+    )]
+    InvalidConnectionLimits,
+    /// Invalid piece download configuration
+    #[error(
+        "Invalid piece download configuration, retry intervals must be above zero with initial \
+        interval not exceeding max interval and concurrency multiplier must be above zero"
+    )]
+    InvalidPieceDownloadConfiguration,
+    /// Invalid proxy URL
+    #[error("Invalid proxy URL \"{url}\", must be an HTTP, HTTPS or SOCKS5 URL")]
+    InvalidProxyUrl { url: String },
+    /// Invalid update source
+    #[error(
+        "Invalid update source \"{update_source}\", must be an HTTP or HTTPS URL or an absolute path"
+    )]
+    InvalidUpdateSource { update_source: String },
+    /// Port is already in use
+    #[error("Port {port} is already in use by another application")]
+    PortInUse { port: u16 },
+    /// Same port is used by both Substrate and DSN
+    #[error("Port {port} can't be used by both Substrate and DSN")]
+    DuplicatePort { port: u16 },
+    /// Invalid listen address
+    #[error("Invalid listen address \"{multiaddr}\", must be a valid multiaddr")]
+    InvalidListenAddress { multiaddr: String },
+    /// Invalid external address
+    #[error("Invalid external address \"{multiaddr}\", must be a valid multiaddr")]
+    InvalidExternalAddress { multiaddr: String },
 }
 
 #[derive(Debug, Clone)]
@@ -205,6 +829,8 @@ pub struct Config {
     pub farms: Vec<DiskFarm>,
     pub reduce_plotting_cpu_load: bool,
     pub network: NetworkConfiguration,
+    pub bandwidth_caps: BandwidthCaps,
+    pub node: NodeConfiguration,
 }
 
 impl Config {
@@ -223,6 +849,109 @@ impl Config {
         let node_path = raw_config.node_path().clone();
         check_path(node_path.clone()).await?;
 
+        let node = raw_config.node().clone();
+        if let NodePruning::Blocks(blocks) = node.pruning
+            && blocks < MIN_STATE_PRUNING
+        {
+            return Err(ConfigError::InvalidNodePruning {
+                blocks,
+                min_blocks: MIN_STATE_PRUNING,
+            });
+        }
+        if let Some(name) = &node.name
+            && !NodeConfiguration::is_valid_node_name(name)
+        {
+            return Err(ConfigError::InvalidNodeName {
+                name: name.clone(),
+                max_length: NODE_NAME_MAX_LENGTH,
+            });
+        }
+        if let Some(url) = &node.external_node_rpc_url
+            && !NodeConfiguration::is_valid_external_node_rpc_url(url)
+        {
+            return Err(ConfigError::InvalidExternalNodeRpcUrl { url: url.clone() });
+        }
+        if node.external_node_rpc_url.is_some() && raw_config.farms().is_empty() {
+            return Err(ConfigError::ExternalNodeWithoutFarms);
+        }
+
+        let network = raw_config.network().clone();
+        let connection_limits = network.connections.limits();
+        if connection_limits.dsn_out_connections == 0 || connection_limits.substrate_out_peers == 0
+        {
+            return Err(ConfigError::InvalidConnectionLimits);
+        }
+        let bandwidth_caps = network.bandwidth_caps()?;
+        if !network.piece_download.is_valid() {
+            return Err(ConfigError::InvalidPieceDownloadConfiguration);
+        }
+        if let Some(proxy) = &network.proxy
+            && !ProxyConfiguration::is_valid_url(&proxy.url)
+        {
+            return Err(ConfigError::InvalidProxyUrl {
+                url: proxy.url.clone(),
+            });
+        }
+        if let Some(update_source) = &raw_config.updates().source
+            && !UpdatesConfiguration::is_valid_source(update_source)
+        {
+            return Err(ConfigError::InvalidUpdateSource {
+                update_source: update_source.clone(),
+            });
+        }
+        if let Some(multiaddr) = network
+            .peer_multiaddrs()
+            .find(|multiaddr| !NetworkConfiguration::is_valid_peer_multiaddr(multiaddr))
+        {
+            return Err(ConfigError::InvalidPeerMultiaddr {
+                multiaddr: multiaddr.clone(),
+            });
+        }
+        if let Some(multiaddr) = network
+            .listen_addresses()
+            .find(|multiaddr| !NetworkConfiguration::is_valid_multiaddr(multiaddr))
+        {
+            return Err(ConfigError::InvalidListenAddress {
+                multiaddr: multiaddr.clone(),
+            });
+        }
+        if let Some(multiaddr) = network
+            .external_addresses()
+            .find(|multiaddr| !NetworkConfiguration::is_valid_multiaddr(multiaddr))
+        {
+            return Err(ConfigError::InvalidExternalAddress {
+                multiaddr: multiaddr.clone(),
+            });
+        }
+
+        // Substrate port is not used when farming with external node
+        let substrate_addresses = if node.external_node_rpc_url.is_none() {
+            NetworkConfiguration::tcp_listen_addresses(
+                &network.substrate_listen_on,
+                network.substrate_port,
+            )
+        } else {
+            Vec::new()
+        };
+        let dsn_addresses = NetworkConfiguration::tcp_listen_addresses(
+            &network.dsn_listen_on,
+            network.subspace_port,
+        );
+        if let Some(port) =
+            NetworkConfiguration::find_duplicate_port(&substrate_addresses, &dsn_addresses)
+        {
+            return Err(ConfigError::DuplicatePort { port });
+        }
+        if let Some(address) = substrate_addresses
+            .iter()
+            .chain(&dsn_addresses)
+            .find(|&&address| !NetworkConfiguration::is_address_available(address))
+        {
+            return Err(ConfigError::PortInUse {
+                port: address.port(),
+            });
+        }
+
         let mut farms = Vec::with_capacity(raw_config.farms().len());
 
         for farm in raw_config.farms() {
@@ -310,9 +1039,25 @@ impl Config {
                 target_size
             };
 
+            let free_space_warning_threshold = match &farm.free_space_warning_threshold {
+                Some(threshold) => ByteSize::from_str(threshold)
+                    .map_err(|error| ConfigError::InvalidSizeFormat {
+                        size: threshold.clone(),
+                        error,
+                    })?
+                    .as_u64(),
+                None => DEFAULT_FARM_FREE_SPACE_WARNING_THRESHOLD,
+            };
+            if free_space_warning_threshold == 0 {
+                return Err(ConfigError::InvalidFreeSpaceWarningThreshold {
+                    path: farm.path.display().to_string(),
+                });
+            }
+
             farms.push(DiskFarm {
                 directory: farm.path.clone(),
                 allocated_space: size,
+                free_space_warning_threshold,
             });
         }
 
@@ -321,7 +1066,9 @@ impl Config {
             node_path,
             farms,
             reduce_plotting_cpu_load: raw_config.reduce_plotting_cpu_load(),
-            network: raw_config.network(),
+            network,
+            bandwidth_caps,
+            node,
         })
     }
 }
@@ -1,8 +1,15 @@
 pub(super) mod direct_node_client;
+pub mod farm_info;
 pub(super) mod maybe_node_client;
+pub mod piece_downloads;
 
 use crate::PosTable;
+use crate::backend::farmer::farm_info::FarmLayout;
 use crate::backend::farmer::maybe_node_client::MaybeNodeClient;
+use crate::backend::farmer::piece_downloads::{
+    CountingPieceGetter, PieceDownloadCounters, PieceDownloadStats,
+};
+use crate::backend::networking::CountingPieceValidator;
 use crate::backend::utils::{Handler, HandlerFn};
 use anyhow::anyhow;
 use async_lock::{Mutex as AsyncMutex, RwLock as AsyncRwLock, Semaphore};
@@ -11,14 +18,14 @@ use event_listener_primitives::HandlerId;
 use futures::channel::{mpsc, oneshot};
 use futures::future::BoxFuture;
 use futures::stream::FuturesUnordered;
-use futures::{FutureExt, StreamExt, select};
+use futures::{FutureExt, StreamExt, future, select};
 use parking_lot::Mutex;
 use std::future::pending;
 use std::hash::Hash;
 use std::num::{NonZeroU8, NonZeroUsize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use std::{fmt, fs};
 use subspace_core_primitives::PublicKey;
 use subspace_core_primitives::pieces::Record;
@@ -26,7 +33,7 @@ use subspace_core_primitives::sectors::SectorIndex;
 use subspace_erasure_coding::ErasureCoding;
 use subspace_farmer::farm::plotted_pieces::PlottedPieces;
 use subspace_farmer::farm::{
-    FarmingNotification, PlottedSectors, SectorPlottingDetails, SectorUpdate,
+    FarmingNotification, PieceCache, PlotCache, PlottedSectors, SectorPlottingDetails, SectorUpdate,
 };
 use subspace_farmer::farmer_cache::{FarmerCache, FarmerCacheWorker};
 use subspace_farmer::farmer_piece_getter::FarmerPieceGetter;
@@ -37,7 +44,7 @@ use subspace_farmer::plotter::cpu::CpuPlotter;
 #[cfg(feature = "_gpu")]
 use subspace_farmer::plotter::gpu::GpuPlotter;
 use subspace_farmer::single_disk_farm::{
-    SingleDiskFarm, SingleDiskFarmError, SingleDiskFarmOptions,
+    SingleDiskFarm, SingleDiskFarmError, SingleDiskFarmInfo, SingleDiskFarmOptions,
 };
 use subspace_farmer::utils::{
     create_plotting_thread_pool_manager, recommended_number_of_farming_threads,
@@ -49,7 +56,7 @@ use subspace_kzg::Kzg;
 use subspace_process::{AsyncJoinOnDrop, run_future_in_dedicated_thread};
 use thread_priority::ThreadPriority;
 use tokio::sync::watch;
-use tracing::{Instrument, debug, error, info, info_span};
+use tracing::{Instrument, debug, error, info, info_span, warn};
 
 /// Minimal cache percentage, there is no need in setting it higher
 pub(super) const CACHE_PERCENTAGE: NonZeroU8 = NonZeroU8::MIN;
@@ -58,6 +65,20 @@ pub(super) const CACHE_PERCENTAGE: NonZeroU8 = NonZeroU8::MIN;
 const MAX_SPACE_PLEDGED_FOR_PLOT_CACHE_ON_WINDOWS: u64 = ByteSize::tib(7).as_u64();
 const FARM_ERROR_PRINT_INTERVAL: Duration = Duration::from_secs(30);
 const MAX_PLOTTING_SECTORS_PER_FARM: NonZeroUsize = NonZeroUsize::new(2).expect("Not zero; qed");
+/// Report plotted pieces collection progress after this many sectors were collected in a farm
+const PLOTTED_PIECES_COLLECTION_PROGRESS_SECTORS: SectorIndex = 10;
+/// Interval for checking farm files while farm is being initialized
+const FARM_INITIALIZATION_PROGRESS_INTERVAL: Duration = Duration::from_secs(1);
+/// Metadata file name inside farm directory, matches the one used by `SingleDiskFarm`
+const METADATA_FILE: &str = "metadata.bin";
+/// Plot file name inside farm directory, matches the one used by `SingleDiskFarm`
+const PLOT_FILE: &str = "plot.bin";
+/// Piece cache file name inside farm directory, matches the one used by `SingleDiskFarm`
+const PIECE_CACHE_FILE: &str = "piece_cache.bin";
+/// Farm info file name inside farm directory, matches the one used by `SingleDiskFarm`
+const FARM_INFO_FILE: &str = "single_disk_farm.json";
+/// Interval for checking whether farm disk is still present and writable
+const FARM_DISK_CHECK_INTERVAL: Duration = Duration::from_secs(5);
 
 #[derive(Debug, Default, Copy, Clone, PartialEq)]
 pub struct InitialFarmState {
@@ -65,6 +86,48 @@ pub struct InitialFarmState {
     pub plotted_sectors_count: SectorIndex,
 }
 
+/// Stage of farm initialization, estimated from farm files since `SingleDiskFarm` doesn't report it
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum FarmInitializationStage {
+    /// Reading or creating farm info and metadata
+    ReadingMetadata,
+    /// Allocating space for plot and piece cache files
+    Allocating {
+        allocated_bytes: u64,
+        total_bytes: u64,
+    },
+    /// Initializing piece cache and plot cache, these happen one after another once space is
+    /// allocated and can't be told apart from the outside
+    InitializingCaches,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct FarmInitializationProgress {
+    pub stage: FarmInitializationStage,
+    /// Time since farm initialization started
+    pub elapsed: Duration,
+    /// Estimated time remaining until space allocation is finished, based on allocation speed
+    pub eta: Option<Duration>,
+}
+
+/// Progress of collecting already plotted pieces of a farm on startup
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct PlottedPiecesCollectionProgress {
+    pub collected_sectors_count: SectorIndex,
+    pub plotted_sectors_count: SectorIndex,
+}
+
+/// State of the disk farm is stored on
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum FarmDiskState {
+    /// Farm directory exists and is writable
+    Available,
+    /// Farm directory or farm files are gone, most likely disk was unplugged or unmounted
+    Missing,
+    /// Farm directory exists, but can't be written to, most likely disk was remounted read-only
+    ReadOnly,
+}
+
 #[derive(Debug, Clone)]
 pub enum FarmerNotification<FarmIndex> {
     SectorUpdate {
@@ -84,6 +147,13 @@ pub enum FarmerNotification<FarmIndex> {
         farm_index: FarmIndex,
         error: Arc<anyhow::Error>,
     },
+    /// Farm is stopped while disk is not available and rejoins once disk is available again
+    FarmDiskStateChanged {
+        farm_index: FarmIndex,
+        disk_state: FarmDiskState,
+    },
+    /// Updated statistics of piece downloads
+    PieceDownloadStatsUpdate(PieceDownloadStats),
 }
 
 #[derive(Debug, Clone)]
@@ -184,36 +254,61 @@ impl<FarmIndex> fmt::Debug for Farmer<FarmIndex> {
 pub struct DiskFarm {
     pub directory: PathBuf,
     pub allocated_space: u64,
+    /// Free space on farm disk below which warning is shown
+    pub free_space_warning_threshold: u64,
 }
 
 /// Arguments for farmer
 #[derive(Debug)]
-pub(super) struct FarmerOptions<FarmIndex, OnFarmInitialized> {
+pub(super) struct FarmerOptions<
+    FarmIndex,
+    OnFarmInitializationProgress,
+    OnFarmInitialized,
+    OnPlottedPiecesCollectionProgress,
+> {
     pub(super) reward_address: PublicKey,
     pub(super) disk_farms: Vec<DiskFarm>,
     pub(super) node_client: MaybeNodeClient,
     pub(super) piece_getter: FarmerPieceGetter<
         FarmIndex,
-        SegmentCommitmentPieceValidator<MaybeNodeClient>,
+        CountingPieceValidator<SegmentCommitmentPieceValidator<MaybeNodeClient>>,
         MaybeNodeClient,
     >,
+    /// Counters of piece downloads, updated by plotting
+    pub(super) piece_download_counters: Arc<PieceDownloadCounters>,
 
     pub(super) plotted_pieces: Arc<AsyncRwLock<PlottedPieces<FarmIndex>>>,
     pub(super) farmer_cache: FarmerCache,
     pub(super) farmer_cache_worker: FarmerCacheWorker<MaybeNodeClient>,
     pub(super) kzg: Kzg,
     pub(super) reduce_plotting_cpu_load: bool,
+    /// Called periodically while farm is being initialized
+    pub(super) on_farm_initialization_progress: OnFarmInitializationProgress,
     pub(super) on_farm_initialized: OnFarmInitialized,
+    /// Called with progress of all farms whenever plotted pieces collection progress changes
+    pub(super) on_plotted_pieces_collection_progress: OnPlottedPiecesCollectionProgress,
 }
 
-pub(super) async fn create_farmer<FarmIndex, OnFarmInitialized>(
-    farmer_options: FarmerOptions<FarmIndex, OnFarmInitialized>,
+pub(super) async fn create_farmer<
+    FarmIndex,
+    OnFarmInitializationProgress,
+    OnFarmInitialized,
+    OnPlottedPiecesCollectionProgress,
+>(
+    farmer_options: FarmerOptions<
+        FarmIndex,
+        OnFarmInitializationProgress,
+        OnFarmInitialized,
+        OnPlottedPiecesCollectionProgress,
+    >,
 ) -> anyhow::Result<Farmer<FarmIndex>>
 where
     FarmIndex:
         Hash + Eq + Copy + fmt::Display + fmt::Debug + TryFrom<usize> + Send + Sync + 'static,
     usize: From<FarmIndex>,
+    OnFarmInitializationProgress: Fn(FarmIndex, FarmInitializationProgress),
     OnFarmInitialized: Fn(FarmIndex),
+    OnPlottedPiecesCollectionProgress: Fn(&[PlottedPiecesCollectionProgress]),
 {
     let span = info_span!("Farmer");
     let _enter = span.enter();
@@ -223,16 +318,19 @@ where
         disk_farms,
         node_client,
         piece_getter,
+        piece_download_counters,
         plotted_pieces,
         farmer_cache,
         farmer_cache_worker,
         kzg,
         reduce_plotting_cpu_load,
+        on_farm_initialization_progress,
         on_farm_initialized,
+        on_plotted_pieces_collection_progress,
     } = farmer_options;
 
     if disk_farms.is_empty() {
-        return Err(anyhow!("There must be at least one disk farm provided"));
+        info!("No farms configured, running farmer cache without farming");
     }
 
     for farm in &disk_farms {
@@ -267,7 +365,10 @@ where
 
     let farmer_cache_worker_fut = Box::pin(
         farmer_cache_worker
-            .run(piece_getter.downgrade())
+            .run(CountingPieceGetter::new(
+                piece_getter.downgrade(),
+                Arc::clone(&piece_download_counters),
+            ))
             .in_current_span(),
     );
 
@@ -295,6 +396,7 @@ where
 
     let downloading_semaphore =
         Arc::new(Semaphore::new(plotting_thread_pool_core_indices.len() + 1));
+    let plotting_piece_getter = CountingPieceGetter::new(piece_getter, piece_download_counters);
 
     let record_encoding_concurrency = {
         let cpu_cores = plotting_thread_pool_core_indices
@@ -338,7 +440,7 @@ where
             info!(?used_cuda_devices, "Using CUDA GPUs");
 
             let cuda_plotter = GpuPlotter::new(
-                piece_getter.clone(),
+                plotting_piece_getter.clone(),
                 Arc::new(Semaphore::new(cuda_devices.len() * 3)),
                 cuda_devices
                     .into_iter()
@@ -374,7 +476,7 @@ where
             info!(?used_rocm_devices, "Using ROCm GPUs");
 
             let rocm_plotter = GpuPlotter::new(
-                piece_getter.clone(),
+                plotting_piece_getter.clone(),
                 Arc::new(Semaphore::new(rocm_devices.len() * 3)),
                 rocm_devices
                     .into_iter()
@@ -402,7 +504,7 @@ where
         plotter
     } else {
         let cpu_plotter = Arc::new(CpuPlotter::<_, PosTable>::new(
-            piece_getter.clone(),
+            plotting_piece_getter.clone(),
             downloading_semaphore,
             plotting_thread_pool_manager.clone(),
             record_encoding_concurrency,
@@ -414,75 +516,106 @@ where
         Arc::new(cpu_plotter)
     };
 
+    let create_farm = {
+        let max_pieces_in_sector = farmer_app_info.protocol_info.max_pieces_in_sector;
+        let node_client = node_client.clone();
+        let farmer_app_info = farmer_app_info.clone();
+        let kzg = kzg.clone();
+        let erasure_coding = erasure_coding.clone();
+        let plotter = Arc::clone(&plotter);
+        let global_mutex = Arc::clone(&global_mutex);
+
+        move |farm_index: usize,
+              disk_farm: DiskFarm,
+              plotting_delay: Option<oneshot::Receiver<()>>| {
+            let farm_fut = SingleDiskFarm::new::<_, PosTable>(
+                SingleDiskFarmOptions {
+                    directory: disk_farm.directory,
+                    farmer_app_info: farmer_app_info.clone(),
+                    allocated_space: disk_farm.allocated_space,
+                    max_pieces_in_sector,
+                    node_client: node_client.clone(),
+                    reward_address,
+                    plotter: Arc::clone(&plotter),
+                    kzg: kzg.clone(),
+                    erasure_coding: erasure_coding.clone(),
+                    cache_percentage: CACHE_PERCENTAGE.get(),
+                    farming_thread_pool_size: recommended_number_of_farming_threads(),
+                    plotting_delay,
+                    global_mutex: Arc::clone(&global_mutex),
+                    max_plotting_sectors_per_farm: MAX_PLOTTING_SECTORS_PER_FARM,
+                    disable_farm_locking: false,
+                    read_sector_record_chunks_mode: ReadSectorRecordChunksMode::ConcurrentChunks,
+                    registry: None,
+                    create: true,
+                },
+                farm_index,
+            );
+
+            async move {
+                match farm_fut.await {
+                    Ok(farm) => Ok(farm),
+                    Err(SingleDiskFarmError::InsufficientAllocatedSpace {
+                        min_space,
+                        allocated_space,
+                    }) => Err(anyhow::anyhow!(
+                        "Allocated space {} ({}) is not enough, minimum is ~{} (~{}, {} bytes to \
+                        be exact)",
+                        bytesize::to_string(allocated_space, true),
+                        bytesize::to_string(allocated_space, false),
+                        bytesize::to_string(min_space, true),
+                        bytesize::to_string(min_space, false),
+                        min_space
+                    )),
+                    Err(error) => Err(error.into()),
+                }
+            }
+        }
+    };
+
     let (farms, plotting_delay_senders) = {
         let farms_total = disk_farms.len();
+        let max_pieces_in_sector = farmer_app_info.protocol_info.max_pieces_in_sector;
         let info_mutex = &AsyncMutex::new(());
         let (plotting_delay_senders, plotting_delay_receivers) = (0..farms_total)
             .map(|_| oneshot::channel())
             .unzip::<_, _, Vec<_>, Vec<_>>();
+        let on_farm_initialization_progress = &on_farm_initialization_progress;
         let on_farm_initialized = &on_farm_initialized;
 
         let mut farms = Vec::with_capacity(farms_total);
         let mut farms_stream = disk_farms
-            .into_iter()
+            .iter()
+            .cloned()
             .zip(plotting_delay_receivers)
             .enumerate()
             .map(|(farm_index, (disk_farm, plotting_delay_receiver))| {
-                let node_client = node_client.clone();
-                let farmer_app_info = farmer_app_info.clone();
-                let max_pieces_in_sector = farmer_app_info.protocol_info.max_pieces_in_sector;
-                let kzg = kzg.clone();
-                let erasure_coding = erasure_coding.clone();
-                let plotter = Arc::clone(&plotter);
-                let global_mutex = Arc::clone(&global_mutex);
+                let farm_fut =
+                    create_farm(farm_index, disk_farm.clone(), Some(plotting_delay_receiver));
 
                 async move {
-                    let farm_fut = SingleDiskFarm::new::<_, PosTable>(
-                        SingleDiskFarmOptions {
-                            directory: disk_farm.directory.clone(),
-                            farmer_app_info,
-                            allocated_space: disk_farm.allocated_space,
-                            max_pieces_in_sector,
-                            node_client,
-                            reward_address,
-                            plotter,
-                            kzg,
-                            erasure_coding,
-                            cache_percentage: CACHE_PERCENTAGE.get(),
-                            farming_thread_pool_size: recommended_number_of_farming_threads(),
-                            plotting_delay: Some(plotting_delay_receiver),
-                            global_mutex,
-                            max_plotting_sectors_per_farm: MAX_PLOTTING_SECTORS_PER_FARM,
-                            disable_farm_locking: false,
-                            read_sector_record_chunks_mode:
-                                ReadSectorRecordChunksMode::ConcurrentChunks,
-                            registry: None,
-                            create: true,
+                    let report_progress_fut = report_farm_initialization_progress(
+                        &disk_farm.directory,
+                        disk_farm.allocated_space,
+                        max_pieces_in_sector,
+                        |progress| {
+                            if let Ok(farm_index) = farm_index.try_into() {
+                                on_farm_initialization_progress(farm_index, progress);
+                            }
                         },
-                        farm_index,
                     );
 
-                    let farm = match farm_fut.await {
-                        Ok(farm) => farm,
-                        Err(SingleDiskFarmError::InsufficientAllocatedSpace {
-                            min_space,
-                            allocated_space,
-                        }) => {
-                            return (
-                                farm_index,
-                                Err(anyhow::anyhow!(
-                                    "Allocated space {} ({}) is not enough, minimum is ~{} (~{}, \
-                                    {} bytes to be exact)",
-                                    bytesize::to_string(allocated_space, true),
-                                    bytesize::to_string(allocated_space, false),
-                                    bytesize::to_string(min_space, true),
-                                    bytesize::to_string(min_space, false),
-                                    min_space
-                                )),
-                            );
+                    let farm_result = select! {
+                        farm_result = farm_fut.fuse() => farm_result,
+                        _ = report_progress_fut.fuse() => {
+                            unreachable!("Farm initialization progress is reported forever; qed");
                         }
+                    };
+
+                    let farm = match farm_result {
+                        Ok(farm) => farm,
                         Err(error) => {
-                            return (farm_index, Err(error.into()));
+                            return (farm_index, Err(error));
                         }
                     };
 
@@ -560,60 +693,69 @@ where
                 }
             })));
     }
-    farmer_cache
-        .replace_backing_caches(
-            farms
-                .iter()
-                .map(|farm| Arc::new(farm.piece_cache()) as Arc<_>)
-                .collect(),
-            if plot_cache {
-                farms
-                    .iter()
-                    .map(|farm| Arc::new(farm.plot_cache()) as Arc<_>)
-                    .collect()
-            } else {
-                Vec::new()
-            },
-        )
-        .await;
+    // Caches of farms whose disks are available, caches of farms with missing or read-only disks are
+    // removed until disk is available again
+    let farm_caches = Arc::new(Mutex::new(
+        farms
+            .iter()
+            .map(|farm| Some(caches_of_farm(farm)))
+            .collect::<Vec<_>>(),
+    ));
+    update_backing_caches(&farmer_cache, &farm_caches, plot_cache).await;
 
     info!("Collecting already plotted pieces (this will take some time)...");
 
-    // Collect already plotted pieces
-    let mut initial_farm_states = Vec::with_capacity(farms.len());
-
-    for (farm_index, farm) in farms.iter().enumerate() {
-        let mut plotted_pieces = plotted_pieces.write().await;
-        let farm_index = farm_index
-            .try_into()
-            .map_err(|_error| anyhow!("More than 256 plots are not supported by Space Acres"))?;
-
-        plotted_pieces.add_farm(farm_index, Arc::new(farm.piece_reader()));
-
-        let total_sectors_count = farm.total_sectors_count();
-        let mut plotted_sectors_count = 0;
-        let plotted_sectors = farm.plotted_sectors();
-        let mut plotted_sectors = plotted_sectors.get().await.map_err(|error| {
-            anyhow!("Failed to get plotted sectors for farm {farm_index}: {error}")
-        })?;
-
-        while let Some(plotted_sector_result) = plotted_sectors.next().await {
-            plotted_sectors_count += 1;
-            plotted_pieces.add_sector(
-                farm_index,
-                &plotted_sector_result.map_err(|error| {
-                    anyhow!(
-                        "Failed reading plotted sector on startup for farm {farm_index}: {error}"
-                    )
-                })?,
-            )
+    // Collect already plotted pieces, farms are processed concurrently
+    let initial_farm_states = {
+        let plotted_pieces = &plotted_pieces;
+        let collection_progress = &Mutex::new(vec![
+            PlottedPiecesCollectionProgress::default();
+            farms.len()
+        ]);
+        let on_plotted_pieces_collection_progress = &on_plotted_pieces_collection_progress;
+
+        let mut farm_indices = Vec::with_capacity(farms.len());
+        for (farm_index, farm) in farms.iter().enumerate() {
+            let farm_index = FarmIndex::try_from(farm_index).map_err(|_error| {
+                anyhow!("More than 256 plots are not supported by Space Acres")
+            })?;
+
+            plotted_pieces
+                .write()
+                .await
+                .add_farm(farm_index, Arc::new(farm.piece_reader()));
+
+            collection_progress.lock()[usize::from(farm_index)].plotted_sectors_count =
+                farm.plotted_sectors_count().await;
+            farm_indices.push(farm_index);
         }
 
-        initial_farm_states.push(InitialFarmState {
-            total_sectors_count,
-            plotted_sectors_count,
-        });
-    }
+        on_plotted_pieces_collection_progress(&collection_progress.lock());
+
+        future::try_join_all(farms.iter().zip(farm_indices).map(|(farm, farm_index)| {
+            async move {
+                let total_sectors_count = farm.total_sectors_count();
+                let plotted_sectors_count =
+                    collect_plotted_pieces(farm, farm_index, plotted_pieces, |collected_sectors| {
+                        let collection_progress = {
+                            let mut collection_progress = collection_progress.lock();
+                            collection_progress[usize::from(farm_index)].collected_sectors_count =
+                                collected_sectors;
+                            collection_progress.clone()
+                        };
+                        on_plotted_pieces_collection_progress(&collection_progress);
+                    })
+                    .await?;
+
+                anyhow::Ok(InitialFarmState {
+                    total_sectors_count,
+                    plotted_sectors_count,
+                })
+            }
+            .instrument(info_span!("", %farm_index))
+        }))
+        .await?
+    };
 
     info!("Finished collecting already plotted pieces successfully");
 
@@ -633,73 +775,109 @@ where
 
     let mut farms_stream = farms
         .into_iter()
+        .zip(disk_farms)
         .enumerate()
-        .map(|(farm_index, farm)| {
+        .map(|(farm_index, (farm, disk_farm))| {
             let Ok(farm_index) = FarmIndex::try_from(farm_index) else {
                 unreachable!(
                     "More than 256 plots are not supported, this is checked above already; qed"
                 );
             };
+            let create_farm = create_farm.clone();
+            let notifications = Arc::clone(&notifications);
             let plotted_pieces = Arc::clone(&plotted_pieces);
-            let span = info_span!("farm", %farm_index);
+            let farmer_cache = farmer_cache.clone();
+            let farm_caches = Arc::clone(&farm_caches);
 
-            farm.on_sector_update(Arc::new({
-                let notifications = Arc::clone(&notifications);
+            async move {
+                let mut farm = farm;
 
-                move |(sector_index, sector_update)| {
-                    notifications.call_simple(&FarmerNotification::SectorUpdate {
-                        farm_index,
-                        sector_index: *sector_index,
-                        update: sector_update.clone(),
-                    });
-                }
-            }))
-            .detach();
-            farm.on_farming_notification(Arc::new({
-                let notifications = Arc::clone(&notifications);
+                loop {
+                    register_farm_handlers(&farm, farm_index, &notifications, &plotted_pieces);
+
+                    // Farm is moved into this future, it is dropped explicitly below
+                    let mut farm_fut = Box::pin(farm.run()).fuse();
+
+                    let disk_state = select! {
+                        result = farm_fut => {
+                            // Farm may fail due to disk issues before they are noticed by
+                            // periodic disk checks
+                            let disk_state = farm_disk_state(&disk_farm.directory).await;
+                            if disk_state == FarmDiskState::Available {
+                                return (farm_index, result);
+                            }
 
-                move |notification| {
-                    notifications.call_simple(&FarmerNotification::FarmingNotification {
+                            disk_state
+                        }
+                        disk_state = wait_for_farm_disk_state(
+                            &disk_farm.directory,
+                            |disk_state| disk_state != FarmDiskState::Available,
+                        ).fuse() => {
+                            disk_state
+                        }
+                    };
+
+                    // Stop the farm right away, otherwise it keeps farm lock and files on the disk
+                    // that is gone open, such that farm can't be opened again once disk is back
+                    drop(farm_fut);
+
+                    warn!(
+                        ?disk_state,
+                        "Farm disk is not available, farm stopped until disk is available again"
+                    );
+                    notifications.call_simple(&FarmerNotification::FarmDiskStateChanged {
                         farm_index,
-                        notification: notification.clone(),
+                        disk_state,
                     });
-                }
-            }))
-            .detach();
 
-            // Collect newly plotted pieces
-            let on_plotted_sector_callback =
-                move |plotted_sector: &PlottedSector,
-                      maybe_old_plotted_sector: &Option<PlottedSector>| {
-                    let _span_guard = span.enter();
+                    plotted_pieces.write().await.delete_farm(farm_index);
+                    farm_caches.lock()[usize::from(farm_index)].take();
+                    update_backing_caches(&farmer_cache, &farm_caches, plot_cache).await;
+
+                    farm = loop {
+                        wait_for_farm_disk_state(&disk_farm.directory, |disk_state| {
+                            disk_state == FarmDiskState::Available
+                        })
+                        .await;
+
+                        match create_farm(usize::from(farm_index), disk_farm.clone(), None).await {
+                            Ok(farm) => {
+                                break farm;
+                            }
+                            Err(error) => {
+                                // Disk might not be fully mounted yet, try again later
+                                warn!(%error, "Failed to open farm after disk became available");
+
+                                tokio::time::sleep(FARM_DISK_CHECK_INTERVAL).await;
+                            }
+                        }
+                    };
 
+                    plotted_pieces
+                        .write()
+                        .await
+                        .add_farm(farm_index, Arc::new(farm.piece_reader()));
+                    if let Err(error) =
+                        collect_plotted_pieces(&farm, farm_index, &plotted_pieces, |_| {}).await
                     {
-                        let mut plotted_pieces = plotted_pieces.write_blocking();
-
-                        if let Some(old_plotted_sector) = &maybe_old_plotted_sector {
-                            plotted_pieces.delete_sector(farm_index, old_plotted_sector);
-                        }
-                        plotted_pieces.add_sector(farm_index, plotted_sector);
+                        return (farm_index, Err(error));
                     }
-                };
-            farm.on_sector_update(Arc::new(move |(_sector_index, sector_state)| {
-                if let SectorUpdate::Plotting(SectorPlottingDetails::Finished {
-                    plotted_sector,
-                    old_plotted_sector,
-                    ..
-                }) = sector_state
-                {
-                    on_plotted_sector_callback(plotted_sector, old_plotted_sector);
-                }
-            }))
-            .detach();
+                    farm_caches.lock()[usize::from(farm_index)].replace(caches_of_farm(&farm));
+                    update_backing_caches(&farmer_cache, &farm_caches, plot_cache).await;
 
-            farm.run().map(move |result| (farm_index, result))
+                    info!("Farm disk is available again, farm rejoined");
+                    notifications.call_simple(&FarmerNotification::FarmDiskStateChanged {
+                        farm_index,
+                        disk_state: FarmDiskState::Available,
+                    });
+                }
+            }
+            .instrument(info_span!("", %farm_index))
         })
         .collect::<FuturesUnordered<_>>();
 
     // Drop original instance such that the only remaining instances are in `SingleDiskFarm`
-    // event handlers
+    // event handlers and farm futures
     drop(plotted_pieces);
 
     let (action_sender, mut action_receiver) = mpsc::channel(1);
@@ -810,3 +988,268 @@ where
         action_sender,
     })
 }
+
+/// Periodically reports farm initialization progress, never returns and is supposed to be dropped
+/// once farm initialization is finished.
+///
+/// `SingleDiskFarm` doesn't expose initialization progress, so it is estimated from farm files:
+/// metadata file is created first, then space is allocated for plot and piece cache files, after
+/// which piece cache and plot cache are initialized. Allocation is instant on filesystems that
+/// support it, otherwise files grow gradually and their allocated size is compared with the size
+/// they will have once allocated.
+async fn report_farm_initialization_progress<OnProgress>(
+    directory: &Path,
+    allocated_space: u64,
+    max_pieces_in_sector: u16,
+    on_progress: OnProgress,
+) where
+    OnProgress: Fn(FarmInitializationProgress),
+{
+    let start = Instant::now();
+    // Point in time and number of allocated bytes when allocation was first observed
+    let mut maybe_allocation_start = None;
+
+    loop {
+        let stage = if directory.join(METADATA_FILE).exists() {
+            // Farm info is written before metadata, existing farm keeps its number of pieces in
+            // sector
+            let pieces_in_sector = SingleDiskFarmInfo::load_from(directory)
+                .ok()
+                .flatten()
+                .map_or(max_pieces_in_sector, |info| info.pieces_in_sector());
+            let layout = FarmLayout::new(allocated_space, pieces_in_sector, CACHE_PERCENTAGE.get());
+
+            let allocated_bytes = allocated_file_size(&directory.join(PLOT_FILE))
+                .min(layout.plot_file_size)
+                + allocated_file_size(&directory.join(PIECE_CACHE_FILE))
+                    .min(layout.piece_cache_file_size);
+            let total_bytes = layout.plot_file_size + layout.piece_cache_file_size;
+
+            if allocated_bytes < total_bytes {
+                FarmInitializationStage::Allocating {
+                    allocated_bytes,
+                    total_bytes,
+                }
+            } else {
+                FarmInitializationStage::InitializingCaches
+            }
+        } else {
+            FarmInitializationStage::ReadingMetadata
+        };
+
+        let eta = if let FarmInitializationStage::Allocating {
+            allocated_bytes,
+            total_bytes,
+        } = stage
+        {
+            let (allocation_start, initial_allocated_bytes) =
+                *maybe_allocation_start.get_or_insert((Instant::now(), allocated_bytes));
+
+            (allocated_bytes > initial_allocated_bytes).then(|| {
+                let bytes_per_second = (allocated_bytes - initial_allocated_bytes) as f64
+                    / allocation_start.elapsed().as_secs_f64();
+
+                Duration::from_secs_f64((total_bytes - allocated_bytes) as f64 / bytes_per_second)
+            })
+        } else {
+            None
+        };
+
+        on_progress(FarmInitializationProgress {
+            stage,
+            elapsed: start.elapsed(),
+            eta,
+        });
+
+        tokio::time::sleep(FARM_INITIALIZATION_PROGRESS_INTERVAL).await;
+    }
+}
+
+/// Size of the file that is actually allocated on disk, `0` if file doesn't exist yet
+fn allocated_file_size(path: &Path) -> u64 {
+    let Ok(metadata) = fs::metadata(path) else {
+        return 0;
+    };
+
+    // Sparse files have full size right away, only allocated blocks indicate allocation progress
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::MetadataExt;
+
+        metadata.len().min(metadata.blocks() * 512)
+    }
+    #[cfg(not(unix))]
+    {
+        metadata.len()
+    }
+}
+
+/// Piece cache and plot cache of a farm
+type FarmCaches = (Arc<dyn PieceCache>, Arc<dyn PlotCache>);
+
+fn caches_of_farm(farm: &SingleDiskFarm) -> FarmCaches {
+    (Arc::new(farm.piece_cache()), Arc::new(farm.plot_cache()))
+}
+
+/// Replace backing caches of farmer cache with caches of farms that are currently available
+async fn update_backing_caches(
+    farmer_cache: &FarmerCache,
+    farm_caches: &Mutex<Vec<Option<FarmCaches>>>,
+    plot_cache: bool,
+) {
+    let (piece_caches, plot_caches) = farm_caches
+        .lock()
+        .iter()
+        .flatten()
+        .cloned()
+        .unzip::<_, _, Vec<_>, Vec<_>>();
+
+    farmer_cache
+        .replace_backing_caches(
+            piece_caches,
+            if plot_cache { plot_caches } else { Vec::new() },
+        )
+        .await;
+}
+
+/// Subscribe to farm events, needs to be done every time farm is (re)created
+fn register_farm_handlers<FarmIndex>(
+    farm: &SingleDiskFarm,
+    farm_index: FarmIndex,
+    notifications: &Arc<Notifications<FarmIndex>>,
+    plotted_pieces: &Arc<AsyncRwLock<PlottedPieces<FarmIndex>>>,
+) where
+    FarmIndex: Hash + Eq + Copy + fmt::Display + fmt::Debug + Send + Sync + 'static,
+{
+    let span = info_span!("farm", %farm_index);
+
+    farm.on_sector_update(Arc::new({
+        let notifications = Arc::clone(notifications);
+
+        move |(sector_index, sector_update)| {
+            notifications.call_simple(&FarmerNotification::SectorUpdate {
+                farm_index,
+                sector_index: *sector_index,
+                update: sector_update.clone(),
+            });
+        }
+    }))
+    .detach();
+    farm.on_farming_notification(Arc::new({
+        let notifications = Arc::clone(notifications);
+
+        move |notification| {
+            notifications.call_simple(&FarmerNotification::FarmingNotification {
+                farm_index,
+                notification: notification.clone(),
+            });
+        }
+    }))
+    .detach();
+
+    // Collect newly plotted pieces
+    let on_plotted_sector_callback = {
+        let plotted_pieces = Arc::clone(plotted_pieces);
+
+        move |plotted_sector: &PlottedSector, maybe_old_plotted_sector: &Option<PlottedSector>| {
+            let _span_guard = span.enter();
+
+            {
+                let mut plotted_pieces = plotted_pieces.write_blocking();
+
+                if let Some(old_plotted_sector) = &maybe_old_plotted_sector {
+                    plotted_pieces.delete_sector(farm_index, old_plotted_sector);
+                }
+                plotted_pieces.add_sector(farm_index, plotted_sector);
+            }
+        }
+    };
+    farm.on_sector_update(Arc::new(move |(_sector_index, sector_state)| {
+        if let SectorUpdate::Plotting(SectorPlottingDetails::Finished {
+            plotted_sector,
+            old_plotted_sector,
+            ..
+        }) = sector_state
+        {
+            on_plotted_sector_callback(plotted_sector, old_plotted_sector);
+        }
+    }))
+    .detach();
+}
+
+/// Collect already plotted sectors of the farm into plotted pieces, `on_progress` is called with
+/// the number of sectors collected so far periodically and once collection is finished.
+///
+/// Returns the number of collected sectors.
+async fn collect_plotted_pieces<FarmIndex, OnProgress>(
+    farm: &SingleDiskFarm,
+    farm_index: FarmIndex,
+    plotted_pieces: &AsyncRwLock<PlottedPieces<FarmIndex>>,
+    on_progress: OnProgress,
+) -> anyhow::Result<SectorIndex>
+where
+    FarmIndex: Hash + Eq + Copy + fmt::Display + fmt::Debug + Send + Sync + 'static,
+    OnProgress: Fn(SectorIndex),
+{
+    let mut plotted_sectors_count = 0;
+    let plotted_sectors = farm.plotted_sectors();
+    let mut plotted_sectors = plotted_sectors
+        .get()
+        .await
+        .map_err(|error| anyhow!("Failed to get plotted sectors for farm {farm_index}: {error}"))?;
+
+    while let Some(plotted_sector_result) = plotted_sectors.next().await {
+        let plotted_sector = plotted_sector_result.map_err(|error| {
+            anyhow!("Failed reading plotted sector for farm {farm_index}: {error}")
+        })?;
+        plotted_pieces
+            .write()
+            .await
+            .add_sector(farm_index, &plotted_sector);
+        plotted_sectors_count += 1;
+
+        if plotted_sectors_count % PLOTTED_PIECES_COLLECTION_PROGRESS_SECTORS == 0 {
+            on_progress(plotted_sectors_count);
+        }
+    }
+
+    on_progress(plotted_sectors_count);
+
+    Ok(plotted_sectors_count)
+}
+
+/// Check whether farm disk is still present and writable
+async fn farm_disk_state(directory: &Path) -> FarmDiskState {
+    let directory = directory.to_path_buf();
+
+    tokio::task::spawn_blocking(move || {
+        if !directory.join(FARM_INFO_FILE).is_file() {
+            FarmDiskState::Missing
+        } else if tempfile::tempfile_in(&directory).is_err() {
+            FarmDiskState::ReadOnly
+        } else {
+            FarmDiskState::Available
+        }
+    })
+    .await
+    // Disk check panicking is treated the same way as missing disk
+    .unwrap_or(FarmDiskState::Missing)
+}
+
+/// Periodically check farm disk state until it matches `predicate`, returns matched state
+async fn wait_for_farm_disk_state<Predicate>(
+    directory: &Path,
+    predicate: Predicate,
+) -> FarmDiskState
+where
+    Predicate: Fn(FarmDiskState) -> bool,
+{
+    loop {
+        let disk_state = farm_disk_state(directory).await;
+        if predicate(disk_state) {
+            return disk_state;
+        }
+
+        tokio::time::sleep(FARM_DISK_CHECK_INTERVAL).await;
+    }
+}
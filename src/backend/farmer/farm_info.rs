@@ -0,0 +1,266 @@
+use crate::backend::farmer::{PIECE_CACHE_FILE, PLOT_FILE};
+use anyhow::anyhow;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::{fmt, fs, io};
+use subspace_farmer::disk_piece_cache::DiskPieceCache;
+use subspace_farmer::single_disk_farm::identity::Identity;
+use subspace_farmer::single_disk_farm::{
+    SingleDiskFarm, SingleDiskFarmInfo, SingleDiskFarmSummary,
+};
+use subspace_farmer_components::sector::{SectorMetadataChecksummed, sector_size};
+
+/// Space reserved for plot metadata, matches the one used by `SingleDiskFarm`
+const RESERVED_PLOT_METADATA: u64 = 1024 * 1024;
+/// Space reserved for farm info, matches the one used by `SingleDiskFarm`
+const RESERVED_FARM_INFO: u64 = 1024 * 1024;
+/// Disk page size that `SingleDiskFarm` leaves unused when fitting sectors into allocated space
+const DISK_PAGE_SIZE: u64 = 4096;
+
+/// Layout of farm files within allocated space, derived the same way `SingleDiskFarm` does it
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(super) struct FarmLayout {
+    pub(super) target_sector_count: u64,
+    /// Size of the plot file once fully allocated
+    pub(super) plot_file_size: u64,
+    /// Size of the piece cache file once fully allocated
+    pub(super) piece_cache_file_size: u64,
+}
+
+impl FarmLayout {
+    /// Layout of the farm with provided allocated space and cache percentage
+    pub(super) fn new(allocated_space: u64, pieces_in_sector: u16, cache_percentage: u8) -> Self {
+        let usable_space = usable_space(allocated_space);
+        let potentially_plottable_space = usable_space / 100 * (100 - u64::from(cache_percentage));
+        let target_sector_count = potentially_plottable_space.saturating_sub(DISK_PAGE_SIZE)
+            / single_sector_overhead(pieces_in_sector);
+
+        // Remaining space is used for piece cache
+        let piece_cache_file_size = if cache_percentage > 0 {
+            let element_size = u64::from(DiskPieceCache::element_size());
+            let cache_space = usable_space
+                .saturating_sub(target_sector_count * single_sector_overhead(pieces_in_sector));
+            cache_space / element_size * element_size
+        } else {
+            0
+        };
+
+        Self {
+            target_sector_count,
+            plot_file_size: target_sector_count * sector_size(pieces_in_sector) as u64,
+            piece_cache_file_size,
+        }
+    }
+
+    /// Layout of existing farm and cache percentage it was created with.
+    ///
+    /// Cache percentage is not stored in farm info, so it is the one that results in the same piece
+    /// cache size. Piece cache takes all the space not used by sectors, so if no cache percentage
+    /// matches exactly, the number of sectors still follows from piece cache size.
+    fn find(allocated_space: u64, pieces_in_sector: u16, piece_cache_file_size: u64) -> (Self, u8) {
+        let maybe_layout = (0..100).find_map(|cache_percentage| {
+            let layout = Self::new(allocated_space, pieces_in_sector, cache_percentage);
+            (layout.piece_cache_file_size == piece_cache_file_size)
+                .then_some((layout, cache_percentage))
+        });
+        if let Some(layout) = maybe_layout {
+            return layout;
+        }
+
+        let usable_space = usable_space(allocated_space);
+        let target_sector_count = usable_space.saturating_sub(piece_cache_file_size)
+            / single_sector_overhead(pieces_in_sector);
+        let cache_percentage = (piece_cache_file_size * 100)
+            .checked_div(usable_space)
+            .unwrap_or_default()
+            .min(100) as u8;
+
+        (
+            Self {
+                target_sector_count,
+                plot_file_size: target_sector_count * sector_size(pieces_in_sector) as u64,
+                piece_cache_file_size,
+            },
+            cache_percentage,
+        )
+    }
+}
+
+/// Allocated space without space that `SingleDiskFarm` uses regardless of plot size
+fn usable_space(allocated_space: u64) -> u64 {
+    allocated_space
+        .saturating_sub(RESERVED_PLOT_METADATA + RESERVED_FARM_INFO + Identity::file_size() as u64)
+}
+
+/// Space taken by a single sector in plot and plot metadata files
+fn single_sector_overhead(pieces_in_sector: u16) -> u64 {
+    (sector_size(pieces_in_sector) + SectorMetadataChecksummed::encoded_size()) as u64
+}
+
+/// Farm lock status at the moment of inspection
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum FarmLockStatus {
+    /// Farm is not used by any process
+    Unlocked,
+    /// Farm is locked, most likely it is used by another process
+    Locked,
+}
+
+/// Information about the farm collected without opening it for farming
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FarmInfo {
+    pub directory: PathBuf,
+    pub id: String,
+    pub genesis_hash: String,
+    pub public_key: String,
+    pub allocated_space: u64,
+    pub pieces_in_sector: u16,
+    /// Number of sectors that fit into allocated space, `None` if farm was not fully initialized
+    pub total_sectors_count: Option<u64>,
+    pub plotted_sectors_count: u64,
+    pub piece_cache_size: u64,
+    /// Space of sectors that are not plotted yet, which is used for caching pieces until then
+    pub plot_cache_size: Option<u64>,
+    /// Percentage of allocated space used by piece cache, `None` if farm was not fully initialized
+    pub cache_percentage: Option<u8>,
+    pub lock_status: FarmLockStatus,
+}
+
+impl fmt::Display for FarmInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Farm:")?;
+        writeln!(f, "  ID: {}", self.id)?;
+        writeln!(f, "  Genesis hash: {}", self.genesis_hash)?;
+        writeln!(f, "  Public key: {}", self.public_key)?;
+        writeln!(
+            f,
+            "  Allocated space: {} ({})",
+            bytesize::to_string(self.allocated_space, true),
+            bytesize::to_string(self.allocated_space, false)
+        )?;
+        writeln!(f, "  Directory: {}", self.directory.display())?;
+        match self.total_sectors_count {
+            Some(total_sectors_count) => writeln!(
+                f,
+                "  Sectors: {} plotted out of {total_sectors_count} ({} pieces in sector)",
+                self.plotted_sectors_count, self.pieces_in_sector
+            )?,
+            None => writeln!(
+                f,
+                "  Sectors: {} plotted ({} pieces in sector)",
+                self.plotted_sectors_count, self.pieces_in_sector
+            )?,
+        }
+        match self.cache_percentage {
+            Some(cache_percentage) => writeln!(
+                f,
+                "  Piece cache: {} ({cache_percentage}% of allocated space)",
+                bytesize::to_string(self.piece_cache_size, true)
+            )?,
+            None => writeln!(f, "  Piece cache: not allocated yet")?,
+        }
+        match self.plot_cache_size {
+            Some(plot_cache_size) => writeln!(
+                f,
+                "  Plot cache: {}",
+                bytesize::to_string(plot_cache_size, true)
+            )?,
+            None => writeln!(f, "  Plot cache: unknown until farm is fully initialized")?,
+        }
+        write!(
+            f,
+            "  Lock: {}",
+            match self.lock_status {
+                FarmLockStatus::Unlocked => "not locked",
+                FarmLockStatus::Locked => "locked, farm is likely used by another process",
+            }
+        )
+    }
+}
+
+/// Read farm information from the farm directory, farm files are only read and never modified.
+///
+/// Farm lock status is checked by taking the lock and releasing it right away, there is no way to
+/// check it without taking it. Farmer starting at that very moment will fail to lock the farm.
+pub fn read_farm_info(directory: PathBuf) -> anyhow::Result<FarmInfo> {
+    let (info, directory) = match SingleDiskFarm::collect_summary(directory) {
+        SingleDiskFarmSummary::Found { info, directory } => (info, directory),
+        SingleDiskFarmSummary::NotFound { directory } => {
+            return Err(anyhow!("No farm found in {}", directory.display()));
+        }
+        SingleDiskFarmSummary::Error { directory, error } => {
+            return Err(anyhow!(
+                "Failed to read farm info from {}: {error}",
+                directory.display()
+            ));
+        }
+    };
+
+    let lock_status = match SingleDiskFarmInfo::try_lock(&directory) {
+        Ok(lock) => {
+            drop(lock);
+            FarmLockStatus::Unlocked
+        }
+        Err(_error) => FarmLockStatus::Locked,
+    };
+
+    let plotted_sectors_count = SingleDiskFarm::read_all_sectors_metadata(&directory)
+        .map_err(|error| {
+            anyhow!(
+                "Failed to read sectors metadata from {}: {error}",
+                directory.display()
+            )
+        })?
+        .len() as u64;
+    let piece_cache_size = file_size(&directory.join(PIECE_CACHE_FILE))?;
+    // Both files are allocated during farm initialization, layout is unknown until then
+    let layout = if let Some(piece_cache_file_size) = piece_cache_size
+        && file_size(&directory.join(PLOT_FILE))?.is_some()
+    {
+        Some(FarmLayout::find(
+            info.allocated_space(),
+            info.pieces_in_sector(),
+            piece_cache_file_size,
+        ))
+    } else {
+        None
+    };
+    let piece_cache_size = piece_cache_size.unwrap_or_default();
+    // Plot cache uses the same element size as piece cache, see `DiskPlotCache`
+    let plot_cache_size = layout.map(|(layout, _cache_percentage)| {
+        let element_size = u64::from(DiskPieceCache::element_size());
+        let unplotted_sectors_count = layout
+            .target_sector_count
+            .saturating_sub(plotted_sectors_count);
+
+        unplotted_sectors_count * sector_size(info.pieces_in_sector()) as u64 / element_size
+            * element_size
+    });
+
+    Ok(FarmInfo {
+        directory,
+        id: info.id().to_string(),
+        genesis_hash: format!("0x{}", hex::encode(info.genesis_hash())),
+        public_key: format!("0x{}", hex::encode(info.public_key())),
+        allocated_space: info.allocated_space(),
+        pieces_in_sector: info.pieces_in_sector(),
+        total_sectors_count: layout.map(|(layout, _cache_percentage)| layout.target_sector_count),
+        plotted_sectors_count,
+        piece_cache_size,
+        plot_cache_size,
+        cache_percentage: layout.map(|(_layout, cache_percentage)| cache_percentage),
+        lock_status,
+    })
+}
+
+/// File size, `None` if file doesn't exist
+fn file_size(path: &Path) -> anyhow::Result<Option<u64>> {
+    match fs::metadata(path) {
+        Ok(metadata) => Ok(Some(metadata.len())),
+        // Files are created lazily, farm that was never started fully may not have them yet
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(error) => Err(anyhow!("Failed to read {}: {error}", path.display())),
+    }
+}
@@ -1,3 +1,4 @@
+use crate::backend::farmer::piece_downloads::PieceDownloadCounters;
 use arc_swap::ArcSwapOption;
 use futures::Stream;
 use std::pin::Pin;
@@ -10,9 +11,10 @@ use subspace_rpc_primitives::{
 };
 
 /// Wrapper node client that allows injecting real inner `NodeClientExt` implementation.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone)]
 pub(in super::super) struct MaybeNodeClient {
     inner: Arc<ArcSwapOption<Box<dyn NodeClientExt>>>,
+    piece_download_counters: Arc<PieceDownloadCounters>,
 }
 
 #[async_trait::async_trait]
@@ -82,10 +84,14 @@ impl NodeClient for MaybeNodeClient {
     }
 
     async fn piece(&self, piece_index: PieceIndex) -> anyhow::Result<Option<Piece>> {
-        match &*self.inner.load() {
+        let result = match &*self.inner.load() {
             Some(inner) => inner.piece(piece_index).await,
             None => Err(anyhow::anyhow!("Inner node client not injected yet")),
-        }
+        };
+        self.piece_download_counters
+            .record_node_request(piece_index, matches!(result, Ok(Some(_))));
+
+        result
     }
 
     async fn acknowledge_archived_segment_header(
@@ -124,6 +130,13 @@ impl NodeClientExt for MaybeNodeClient {
 }
 
 impl MaybeNodeClient {
+    pub(in super::super) fn new(piece_download_counters: Arc<PieceDownloadCounters>) -> Self {
+        Self {
+            inner: Arc::default(),
+            piece_download_counters,
+        }
+    }
+
     pub(in super::super) fn inject(&self, inner: Box<dyn NodeClientExt>) {
         self.inner.store(Some(Arc::new(inner)))
     }
@@ -0,0 +1,233 @@
+use futures::{Stream, StreamExt, stream};
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::task::Poll;
+use subspace_core_primitives::pieces::{Piece, PieceIndex};
+use subspace_data_retrieval::piece_getter::PieceGetter;
+
+tokio::task_local! {
+    /// Sources of pieces retrieved by the piece getter call that is currently in progress
+    static PIECE_SOURCES: Arc<PieceSources>;
+}
+
+/// Statistics of pieces downloaded for plotting, piece cache sync and node sync since application
+/// start
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct PieceDownloadStats {
+    /// Pieces found in local farmer cache or plots
+    pub local: u64,
+    /// Pieces downloaded from DSN cache of other farmers
+    pub dsn_cache: u64,
+    /// Pieces downloaded from archival storage (plots) of other farmers
+    pub archival: u64,
+    /// Pieces retrieved from node
+    pub node: u64,
+    /// Pieces received from DSN peers that failed validation
+    pub invalid: u64,
+    /// Pieces that could not be retrieved from any source
+    pub failed: u64,
+}
+
+#[derive(Debug, Copy, Clone)]
+enum PieceSource {
+    DsnCache,
+    Archival,
+    Node,
+}
+
+#[derive(Debug, Default)]
+struct PieceSourceState {
+    /// Number of times node was asked for the piece
+    node_requests: u32,
+    source: Option<PieceSource>,
+}
+
+/// Sources of pieces retrieved within a single piece getter call.
+///
+/// `FarmerPieceGetter` doesn't say where the piece came from, but it always tries sources in the
+/// same order: farmer cache, DSN cache and node (repeated according to `DsnCacheRetryPolicy`),
+/// then plots and archival storage. So a piece from DSN is from archival storage once node was
+/// asked for it as many times as fast path is attempted.
+#[derive(Debug)]
+struct PieceSources {
+    node_requests_before_archival: u32,
+    pieces: Mutex<HashMap<PieceIndex, PieceSourceState>>,
+}
+
+impl PieceSources {
+    fn new(node_requests_before_archival: u32) -> Arc<Self> {
+        Arc::new(Self {
+            node_requests_before_archival,
+            pieces: Mutex::default(),
+        })
+    }
+
+    /// Source of the piece, `None` means it was found locally
+    fn take(&self, piece_index: PieceIndex) -> Option<PieceSource> {
+        self.pieces
+            .lock()
+            .remove(&piece_index)
+            .and_then(|state| state.source)
+    }
+}
+
+/// Counters of piece downloads, shared between piece getter, piece validator and node client
+#[derive(Debug)]
+pub struct PieceDownloadCounters {
+    /// Max retries of `DsnCacheRetryPolicy`
+    dsn_cache_max_retries: u16,
+    local: AtomicU64,
+    dsn_cache: AtomicU64,
+    archival: AtomicU64,
+    node: AtomicU64,
+    invalid: AtomicU64,
+    failed: AtomicU64,
+    bytes_downloaded: AtomicU64,
+}
+
+impl PieceDownloadCounters {
+    pub fn new(dsn_cache_max_retries: u16) -> Self {
+        Self {
+            dsn_cache_max_retries,
+            local: AtomicU64::default(),
+            dsn_cache: AtomicU64::default(),
+            archival: AtomicU64::default(),
+            node: AtomicU64::default(),
+            invalid: AtomicU64::default(),
+            failed: AtomicU64::default(),
+            bytes_downloaded: AtomicU64::default(),
+        }
+    }
+
+    pub fn stats(&self) -> PieceDownloadStats {
+        PieceDownloadStats {
+            local: self.local.load(Ordering::Relaxed),
+            dsn_cache: self.dsn_cache.load(Ordering::Relaxed),
+            archival: self.archival.load(Ordering::Relaxed),
+            node: self.node.load(Ordering::Relaxed),
+            invalid: self.invalid.load(Ordering::Relaxed),
+            failed: self.failed.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Bytes of pieces received from DSN peers, including those that failed validation
+    pub fn bytes_downloaded(&self) -> u64 {
+        self.bytes_downloaded.load(Ordering::Relaxed)
+    }
+
+    pub(in super::super) fn record_dsn_piece(&self, piece_index: PieceIndex, valid: bool) {
+        self.bytes_downloaded
+            .fetch_add(Piece::SIZE as u64, Ordering::Relaxed);
+        if !valid {
+            self.invalid.fetch_add(1, Ordering::Relaxed);
+            return;
+        }
+
+        // Pieces downloaded outside of `CountingPieceGetter` are not counted
+        let _ = PIECE_SOURCES.try_with(|piece_sources| {
+            let mut pieces = piece_sources.pieces.lock();
+            let state = pieces.entry(piece_index).or_default();
+            state.source.replace(
+                if state.node_requests >= piece_sources.node_requests_before_archival {
+                    PieceSource::Archival
+                } else {
+                    PieceSource::DsnCache
+                },
+            );
+        });
+    }
+
+    pub(in super::super) fn record_node_request(&self, piece_index: PieceIndex, found: bool) {
+        let _ = PIECE_SOURCES.try_with(|piece_sources| {
+            let mut pieces = piece_sources.pieces.lock();
+            let state = pieces.entry(piece_index).or_default();
+            state.node_requests += 1;
+            if found {
+                state.source.replace(PieceSource::Node);
+            }
+        });
+    }
+
+    fn record_result(
+        &self,
+        piece_sources: &PieceSources,
+        piece_index: PieceIndex,
+        result: &anyhow::Result<Option<Piece>>,
+    ) {
+        let maybe_source = piece_sources.take(piece_index);
+        let counter = if matches!(result, Ok(Some(_))) {
+            match maybe_source {
+                Some(PieceSource::DsnCache) => &self.dsn_cache,
+                Some(PieceSource::Archival) => &self.archival,
+                Some(PieceSource::Node) => &self.node,
+                None => &self.local,
+            }
+        } else {
+            &self.failed
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Piece getter wrapper that counts piece downloads by source
+#[derive(Debug, Clone)]
+pub(in super::super) struct CountingPieceGetter<PG> {
+    inner: PG,
+    counters: Arc<PieceDownloadCounters>,
+}
+
+#[async_trait::async_trait]
+impl<PG> PieceGetter for CountingPieceGetter<PG>
+where
+    PG: PieceGetter + Send + Sync,
+{
+    async fn get_piece(&self, piece_index: PieceIndex) -> anyhow::Result<Option<Piece>> {
+        // Fast path is attempted once and then retried
+        let piece_sources = PieceSources::new(u32::from(self.counters.dsn_cache_max_retries) + 1);
+        let result = PIECE_SOURCES
+            .scope(
+                Arc::clone(&piece_sources),
+                self.inner.get_piece(piece_index),
+            )
+            .await;
+        self.counters
+            .record_result(&piece_sources, piece_index, &result);
+
+        result
+    }
+
+    async fn get_pieces<'a>(
+        &'a self,
+        piece_indices: Vec<PieceIndex>,
+    ) -> anyhow::Result<
+        Box<dyn Stream<Item = (PieceIndex, anyhow::Result<Option<Piece>>)> + Send + Unpin + 'a>,
+    > {
+        let counters = &self.counters;
+        // Multiple pieces are retrieved without retries
+        let piece_sources = PieceSources::new(1);
+        let mut stream = PIECE_SOURCES
+            .scope(
+                Arc::clone(&piece_sources),
+                self.inner.get_pieces(piece_indices),
+            )
+            .await?;
+
+        Ok(Box::new(stream::poll_fn(move |cx| {
+            let maybe_item =
+                PIECE_SOURCES.sync_scope(Arc::clone(&piece_sources), || stream.poll_next_unpin(cx));
+            if let Poll::Ready(Some((piece_index, result))) = &maybe_item {
+                counters.record_result(&piece_sources, *piece_index, result);
+            }
+
+            maybe_item
+        })))
+    }
+}
+
+impl<PG> CountingPieceGetter<PG> {
+    pub(in super::super) fn new(inner: PG, counters: Arc<PieceDownloadCounters>) -> Self {
+        Self { inner, counters }
+    }
+}
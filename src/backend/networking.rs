@@ -1,17 +1,24 @@
+use crate::backend::farmer::piece_downloads::PieceDownloadCounters;
+use crate::backend::utils::HandlerFn;
 use async_lock::RwLock as AsyncRwLock;
+use event_listener_primitives::HandlerId;
+use parity_scale_codec::Encode;
 use parking_lot::Mutex;
-use std::collections::HashSet;
-use std::fmt;
+use std::collections::{HashMap, HashSet};
 use std::hash::Hash;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Weak};
+use std::time::Duration;
+use std::{fmt, io};
+use subspace_core_primitives::pieces::{Piece, PieceIndex};
 use subspace_farmer::KNOWN_PEERS_CACHE_SIZE;
 use subspace_farmer::farm::plotted_pieces::PlottedPieces;
 use subspace_farmer::farmer_cache::FarmerCache;
 use subspace_farmer::node_client::NodeClientExt;
-use subspace_networking::libp2p::Multiaddr;
 use subspace_networking::libp2p::identity::ed25519::Keypair;
 use subspace_networking::libp2p::multiaddr::Protocol;
+use subspace_networking::libp2p::{Multiaddr, PeerId};
 use subspace_networking::protocols::request_response::handlers::cached_piece_by_index::{
     CachedPieceByIndexRequest, CachedPieceByIndexRequestHandler, CachedPieceByIndexResponse,
     PieceResult,
@@ -23,18 +30,290 @@ use subspace_networking::protocols::request_response::handlers::segment_header::
     SegmentHeaderBySegmentIndexesRequestHandler, SegmentHeaderRequest, SegmentHeaderResponse,
 };
 use subspace_networking::utils::multihash::ToMultihash;
+use subspace_networking::utils::piece_provider::PieceValidator;
 use subspace_networking::utils::strip_peer_id;
 use subspace_networking::{
-    Config, KademliaMode, KnownPeersManager, KnownPeersManagerConfig, Node, NodeRunner, WeakNode,
-    construct,
+    Config, KademliaMode, KnownPeersManager, KnownPeersManagerConfig, KnownPeersRegistry, Node,
+    NodeRunner, PeerAddressRemovedEvent, WeakNode, construct,
 };
 use subspace_rpc_primitives::MAX_SEGMENT_HEADERS_PER_REQUEST;
+use tokio::fs;
 use tracing::{Instrument, debug, error, info, info_span, warn};
 
 /// How many segment headers can be requested at a time.
 ///
 /// Must be the same as RPC limit since all requests go to the node anyway.
 const SEGMENT_HEADERS_LIMIT: u32 = MAX_SEGMENT_HEADERS_PER_REQUEST as u32;
+/// File in network directory that stores addresses of known DSN peers
+const KNOWN_PEERS_FILE: &str = "known_addresses.bin";
+/// Marker file in network directory requesting known peers cache to be cleared on next start
+const KNOWN_PEERS_RESET_FILE: &str = "known_addresses.reset";
+/// Known peers cache is updated continuously while running, not updated for this long means
+/// application was offline long enough for most of the addresses to be stale
+const KNOWN_PEERS_STALE_AFTER: Duration = Duration::from_secs(7 * 24 * 3600);
+
+/// Details about known peers cache on disk
+#[derive(Debug, Clone)]
+pub struct KnownPeersCacheInfo {
+    pub path: PathBuf,
+    /// Size of the cache file in bytes
+    pub size: u64,
+    /// Time since cache was last updated, if known
+    pub age: Option<Duration>,
+    /// Whether cache will be cleared on next start
+    pub reset_scheduled: bool,
+}
+
+/// Statistics about pieces and segment headers served to other DSN peers
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct PieceServingStats {
+    /// Piece requests served from farmer cache
+    pub cache_hits: u64,
+    /// Piece requests served from plots
+    pub plot_hits: u64,
+    /// Piece requests for pieces that are not stored locally
+    pub misses: u64,
+    /// Cached piece requests answered with closest peers instead of a piece
+    pub closest_peers_fallbacks: u64,
+    /// Segment headers served
+    pub segment_headers: u64,
+    /// Total size of all responses
+    pub bytes_uploaded: u64,
+}
+
+/// Counters updated by DSN request handlers, see [`PieceServingStats`] for details
+#[derive(Debug, Default)]
+pub struct PieceServingCounters {
+    cache_hits: AtomicU64,
+    plot_hits: AtomicU64,
+    misses: AtomicU64,
+    closest_peers_fallbacks: AtomicU64,
+    segment_headers: AtomicU64,
+    bytes_uploaded: AtomicU64,
+}
+
+impl PieceServingCounters {
+    pub fn stats(&self) -> PieceServingStats {
+        PieceServingStats {
+            cache_hits: self.cache_hits.load(Ordering::Relaxed),
+            plot_hits: self.plot_hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            closest_peers_fallbacks: self.closest_peers_fallbacks.load(Ordering::Relaxed),
+            segment_headers: self.segment_headers.load(Ordering::Relaxed),
+            bytes_uploaded: self.bytes_uploaded.load(Ordering::Relaxed),
+        }
+    }
+
+    fn record_response<T>(&self, response: &T)
+    where
+        T: Encode,
+    {
+        self.bytes_uploaded
+            .fetch_add(response.encoded_size() as u64, Ordering::Relaxed);
+    }
+}
+
+/// Direction of the connection to DSN peer
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum DsnPeerDirection {
+    /// Peer connected to us
+    Inbound,
+    /// We connected to peer
+    Outbound,
+}
+
+/// Details about peer connected to DSN
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DsnPeer {
+    pub peer_id: PeerId,
+    pub direction: DsnPeerDirection,
+    /// Dialed address of the connection, not available for inbound connections
+    pub address: Option<Multiaddr>,
+}
+
+/// Connected DSN peers along with direction and address of the connection
+#[derive(Debug, Default)]
+pub struct ConnectedDsnPeers {
+    /// Addresses successfully dialed by the swarm, reported through known peers registry right
+    /// before peer connection notification
+    dialed: Mutex<HashMap<PeerId, Multiaddr>>,
+    connected: Mutex<HashMap<PeerId, DsnPeer>>,
+}
+
+impl ConnectedDsnPeers {
+    pub fn peers(&self) -> Vec<DsnPeer> {
+        self.connected.lock().values().cloned().collect()
+    }
+
+    fn on_dialed(&self, peer_id: PeerId, address: Multiaddr) {
+        self.dialed.lock().insert(peer_id, address);
+    }
+
+    fn on_connected(&self, peer_id: PeerId) {
+        let address = self.dialed.lock().get(&peer_id).cloned();
+        let direction = if address.is_some() {
+            DsnPeerDirection::Outbound
+        } else {
+            DsnPeerDirection::Inbound
+        };
+
+        self.connected.lock().insert(
+            peer_id,
+            DsnPeer {
+                peer_id,
+                direction,
+                address,
+            },
+        );
+    }
+
+    fn on_disconnected(&self, peer_id: &PeerId) {
+        self.dialed.lock().remove(peer_id);
+        self.connected.lock().remove(peer_id);
+    }
+}
+
+/// Known peers registry wrapper that reports dialed addresses to [`ConnectedDsnPeers`].
+///
+/// Swarm adds successfully dialed address to known peers registry on connection establishment,
+/// which is the only place where direction and address of DSN connections are exposed.
+struct DialTrackingKnownPeersRegistry {
+    inner: KnownPeersManager,
+    connected_dsn_peers: Arc<ConnectedDsnPeers>,
+}
+
+#[async_trait::async_trait]
+impl KnownPeersRegistry for DialTrackingKnownPeersRegistry {
+    async fn add_known_peer(&mut self, peer_id: PeerId, addresses: Vec<Multiaddr>) {
+        if let Some(address) = addresses.first() {
+            self.connected_dsn_peers.on_dialed(peer_id, address.clone());
+        }
+        self.inner.add_known_peer(peer_id, addresses).await;
+    }
+
+    async fn remove_known_peer_addresses(&mut self, peer_id: PeerId, addresses: Vec<Multiaddr>) {
+        self.inner
+            .remove_known_peer_addresses(peer_id, addresses)
+            .await;
+    }
+
+    fn remove_all_known_peer_addresses(&mut self, peer_id: PeerId) {
+        self.inner.remove_all_known_peer_addresses(peer_id);
+    }
+
+    async fn all_known_peers(&self) -> Vec<(PeerId, Vec<Multiaddr>)> {
+        self.inner.all_known_peers().await
+    }
+
+    async fn run(&mut self) {
+        self.inner.run().await;
+    }
+
+    fn on_unreachable_address(
+        &mut self,
+        handler: HandlerFn<PeerAddressRemovedEvent>,
+    ) -> Option<HandlerId> {
+        self.inner.on_unreachable_address(handler)
+    }
+}
+
+/// Piece validator wrapper that counts pieces downloaded from DSN peers
+#[derive(Debug, Clone)]
+pub struct CountingPieceValidator<PV> {
+    inner: PV,
+    counters: Arc<PieceDownloadCounters>,
+}
+
+#[async_trait::async_trait]
+impl<PV> PieceValidator for CountingPieceValidator<PV>
+where
+    PV: PieceValidator,
+{
+    async fn validate_piece(
+        &self,
+        source_peer_id: PeerId,
+        piece_index: PieceIndex,
+        piece: Piece,
+    ) -> Option<Piece> {
+        let maybe_piece = self
+            .inner
+            .validate_piece(source_peer_id, piece_index, piece)
+            .await;
+        self.counters
+            .record_dsn_piece(piece_index, maybe_piece.is_some());
+
+        maybe_piece
+    }
+}
+
+impl<PV> CountingPieceValidator<PV> {
+    pub fn new(inner: PV, counters: Arc<PieceDownloadCounters>) -> Self {
+        Self { inner, counters }
+    }
+}
+
+/// Inspect known peers cache, returns `None` if there is no cache yet
+pub(super) async fn known_peers_cache_info(
+    network_path: &Path,
+) -> io::Result<Option<KnownPeersCacheInfo>> {
+    let path = network_path.join(KNOWN_PEERS_FILE);
+    let metadata = match fs::metadata(&path).await {
+        Ok(metadata) => metadata,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => {
+            return Ok(None);
+        }
+        Err(error) => {
+            return Err(error);
+        }
+    };
+    let reset_scheduled = fs::try_exists(network_path.join(KNOWN_PEERS_RESET_FILE)).await?;
+
+    Ok(Some(KnownPeersCacheInfo {
+        path,
+        size: metadata.len(),
+        age: metadata
+            .modified()
+            .ok()
+            .and_then(|modified| modified.elapsed().ok()),
+        reset_scheduled,
+    }))
+}
+
+/// Known peers cache is in use while networking is running, so it is cleared on next start instead
+pub(super) async fn schedule_known_peers_cache_reset(network_path: &Path) -> io::Result<()> {
+    fs::write(network_path.join(KNOWN_PEERS_RESET_FILE), b"").await
+}
+
+/// Remove known peers cache if reset was requested or cache is stale after long offline period,
+/// must be called before networking is created
+pub(super) async fn reset_known_peers_cache_if_needed(network_path: &Path) -> io::Result<()> {
+    let reset_path = network_path.join(KNOWN_PEERS_RESET_FILE);
+    let reset_requested = fs::try_exists(&reset_path).await?;
+    let stale = known_peers_cache_info(network_path)
+        .await?
+        .and_then(|known_peers_cache_info| known_peers_cache_info.age)
+        .is_some_and(|age| age >= KNOWN_PEERS_STALE_AFTER);
+
+    if !(reset_requested || stale) {
+        return Ok(());
+    }
+
+    match fs::remove_file(network_path.join(KNOWN_PEERS_FILE)).await {
+        Ok(()) => {}
+        Err(error) if error.kind() == io::ErrorKind::NotFound => {}
+        Err(error) => {
+            return Err(error);
+        }
+    }
+    if reset_requested {
+        fs::remove_file(&reset_path).await?;
+        info!("Known peers cache cleared as requested");
+    } else {
+        info!("Known peers cache cleared after long offline period");
+    }
+
+    Ok(())
+}
 
 /// Network options, mainly used for the DSN.
 /// Most substrate network options come from the chainspec, or node defaults constants.
@@ -99,6 +378,8 @@ pub fn create_network<FarmIndex, NC>(
     weak_plotted_pieces: Weak<AsyncRwLock<PlottedPieces<FarmIndex>>>,
     node_client: NC,
     farmer_cache: FarmerCache,
+    piece_serving_counters: Arc<PieceServingCounters>,
+    connected_dsn_peers: Arc<ConnectedDsnPeers>,
 ) -> Result<(Node, NodeRunner), anyhow::Error>
 where
     FarmIndex: Hash + Eq + Copy + fmt::Debug + Send + Sync + 'static,
@@ -109,7 +390,7 @@ where
     let _enter = span.enter();
 
     let known_peers_registry = KnownPeersManager::new(KnownPeersManagerConfig {
-        path: Some(base_path.join("known_addresses.bin").into_boxed_path()),
+        path: Some(base_path.join(KNOWN_PEERS_FILE).into_boxed_path()),
         ignore_peer_list: strip_peer_id(bootstrap_nodes.clone())
             .into_iter()
             .map(|(peer_id, _)| peer_id)
@@ -117,7 +398,12 @@ where
         cache_size: KNOWN_PEERS_CACHE_SIZE,
         ..Default::default()
     })
-    .map(Box::new)?;
+    .map(|known_peers_manager| {
+        Box::new(DialTrackingKnownPeersRegistry {
+            inner: known_peers_manager,
+            connected_dsn_peers: Arc::clone(&connected_dsn_peers),
+        })
+    })?;
 
     let maybe_weak_node = Arc::new(Mutex::new(None::<WeakNode>));
     let default_config = Config::new(protocol_prefix, keypair.into(), None);
@@ -130,6 +416,7 @@ where
             {
                 let maybe_weak_node = Arc::clone(&maybe_weak_node);
                 let farmer_cache = farmer_cache.clone();
+                let piece_serving_counters = Arc::clone(&piece_serving_counters);
 
                 CachedPieceByIndexRequestHandler::create(move |peer_id, request| {
                     let CachedPieceByIndexRequest {
@@ -140,6 +427,7 @@ where
 
                     let maybe_weak_node = Arc::clone(&maybe_weak_node);
                     let farmer_cache = farmer_cache.clone();
+                    let piece_serving_counters = Arc::clone(&piece_serving_counters);
                     let mut cached_pieces = Arc::unwrap_or_clone(cached_pieces);
 
                     async move {
@@ -148,10 +436,20 @@ where
                         cached_pieces.truncate(CachedPieceByIndexRequest::RECOMMENDED_LIMIT);
                         let cached_pieces = farmer_cache.has_pieces(cached_pieces).await;
 
-                        Some(CachedPieceByIndexResponse {
+                        let response = CachedPieceByIndexResponse {
                             result: match piece_from_cache {
-                                Some(piece) => PieceResult::Piece(piece),
+                                Some(piece) => {
+                                    piece_serving_counters
+                                        .cache_hits
+                                        .fetch_add(1, Ordering::Relaxed);
+
+                                    PieceResult::Piece(piece)
+                                }
                                 None => {
+                                    piece_serving_counters
+                                        .closest_peers_fallbacks
+                                        .fetch_add(1, Ordering::Relaxed);
+
                                     let maybe_node = maybe_weak_node
                                         .lock()
                                         .as_ref()
@@ -176,63 +474,93 @@ where
                                 }
                             },
                             cached_pieces,
-                        })
+                        };
+                        piece_serving_counters.record_response(&response);
+
+                        Some(response)
                     }
                     .in_current_span()
                 })
             },
-            PieceByIndexRequestHandler::create(move |_, request| {
-                let PieceByIndexRequest {
-                    piece_index,
-                    cached_pieces,
-                } = request;
-                debug!(?piece_index, "Piece request received. Trying cache...");
-
-                let weak_plotted_pieces = weak_plotted_pieces.clone();
-                let farmer_cache = farmer_cache.clone();
-                let mut cached_pieces = Arc::unwrap_or_clone(cached_pieces);
+            {
+                let piece_serving_counters = Arc::clone(&piece_serving_counters);
 
-                async move {
-                    let piece_from_cache = farmer_cache.get_piece(piece_index.to_multihash()).await;
-                    cached_pieces.truncate(PieceByIndexRequest::RECOMMENDED_LIMIT);
-                    let cached_pieces = farmer_cache.has_pieces(cached_pieces).await;
+                PieceByIndexRequestHandler::create(move |_, request| {
+                    let PieceByIndexRequest {
+                        piece_index,
+                        cached_pieces,
+                    } = request;
+                    debug!(?piece_index, "Piece request received. Trying cache...");
 
-                    if let Some(piece) = piece_from_cache {
-                        Some(PieceByIndexResponse {
-                            piece: Some(piece),
-                            cached_pieces,
-                        })
-                    } else {
-                        debug!(
-                            ?piece_index,
-                            "No piece in the cache. Trying archival storage..."
-                        );
-
-                        let read_piece_fut = match weak_plotted_pieces.upgrade() {
-                            Some(plotted_pieces) => plotted_pieces
-                                .try_read()?
-                                .read_piece(piece_index)?
-                                .in_current_span(),
-                            None => {
-                                debug!("A readers and pieces are already dropped");
+                    let weak_plotted_pieces = weak_plotted_pieces.clone();
+                    let farmer_cache = farmer_cache.clone();
+                    let piece_serving_counters = Arc::clone(&piece_serving_counters);
+                    let mut cached_pieces = Arc::unwrap_or_clone(cached_pieces);
+
+                    async move {
+                        let piece_from_cache =
+                            farmer_cache.get_piece(piece_index.to_multihash()).await;
+                        cached_pieces.truncate(PieceByIndexRequest::RECOMMENDED_LIMIT);
+                        let cached_pieces = farmer_cache.has_pieces(cached_pieces).await;
+
+                        let response = if let Some(piece) = piece_from_cache {
+                            piece_serving_counters
+                                .cache_hits
+                                .fetch_add(1, Ordering::Relaxed);
+
+                            PieceByIndexResponse {
+                                piece: Some(piece),
+                                cached_pieces,
+                            }
+                        } else {
+                            debug!(
+                                ?piece_index,
+                                "No piece in the cache. Trying archival storage..."
+                            );
+
+                            let maybe_read_piece_fut = match weak_plotted_pieces.upgrade() {
+                                Some(plotted_pieces) => {
+                                    plotted_pieces.try_read().and_then(|plotted_pieces| {
+                                        plotted_pieces.read_piece(piece_index)
+                                    })
+                                }
+                                None => {
+                                    debug!("A readers and pieces are already dropped");
+                                    return None;
+                                }
+                            };
+                            let Some(read_piece_fut) = maybe_read_piece_fut else {
+                                piece_serving_counters
+                                    .misses
+                                    .fetch_add(1, Ordering::Relaxed);
                                 return None;
+                            };
+
+                            let piece = read_piece_fut.in_current_span().await;
+                            let counter = if piece.is_some() {
+                                &piece_serving_counters.plot_hits
+                            } else {
+                                &piece_serving_counters.misses
+                            };
+                            counter.fetch_add(1, Ordering::Relaxed);
+
+                            PieceByIndexResponse {
+                                piece,
+                                cached_pieces,
                             }
                         };
+                        piece_serving_counters.record_response(&response);
 
-                        let piece = read_piece_fut.await;
-
-                        Some(PieceByIndexResponse {
-                            piece,
-                            cached_pieces,
-                        })
+                        Some(response)
                     }
-                }
-                .in_current_span()
-            }),
+                    .in_current_span()
+                })
+            },
             SegmentHeaderBySegmentIndexesRequestHandler::create(move |_, req| {
                 debug!(?req, "Segment headers request received.");
 
                 let node_client = node_client.clone();
+                let piece_serving_counters = Arc::clone(&piece_serving_counters);
 
                 async move {
                     let internal_result = match req {
@@ -280,7 +608,16 @@ where
                                 }
                             })
                             .collect::<Option<Vec<_>>>()
-                            .map(|segment_headers| SegmentHeaderResponse { segment_headers }),
+                            .map(|segment_headers| {
+                                let response = SegmentHeaderResponse { segment_headers };
+                                piece_serving_counters.segment_headers.fetch_add(
+                                    response.segment_headers.len() as u64,
+                                    Ordering::Relaxed,
+                                );
+                                piece_serving_counters.record_response(&response);
+
+                                response
+                            }),
                         Err(error) => {
                             error!(%error, "Failed to get segment headers from cache");
 
@@ -317,6 +654,20 @@ where
     }))
     .detach();
 
+    node.on_connected_peer(Arc::new({
+        let connected_dsn_peers = Arc::clone(&connected_dsn_peers);
+
+        move |peer_id| {
+            connected_dsn_peers.on_connected(*peer_id);
+        }
+    }))
+    .detach();
+
+    node.on_disconnected_peer(Arc::new(move |peer_id| {
+        connected_dsn_peers.on_disconnected(peer_id);
+    }))
+    .detach();
+
     // Consider returning HandlerId instead of each `detach()` calls for other usages.
     Ok((node, node_runner))
 }
@@ -1,10 +1,15 @@
+pub(super) mod external_node;
 mod utils;
 
 use crate::PosTable;
+use crate::backend::bandwidth::Traffic;
+use crate::backend::config::{
+    NetworkConfiguration, NodeConfiguration, NodePruning, NodeSyncMode, RpcMethods,
+};
 use crate::backend::farmer::direct_node_client::{DirectNodeClient, NodeClientConfig};
 use crate::backend::farmer::maybe_node_client::MaybeNodeClient;
 use crate::backend::node::utils::account_storage_key;
-use crate::backend::utils::{Handler, HandlerFn};
+use crate::backend::utils::{Handler, HandlerFn, parse_multiaddrs};
 use event_listener_primitives::HandlerId;
 use frame_system::AccountInfo;
 use futures::{FutureExt, StreamExt, select};
@@ -16,7 +21,11 @@ use sc_client_api::{HeaderBackend, StorageProvider};
 use sc_client_db::PruningMode;
 use sc_consensus_slots::SlotProportion;
 use sc_consensus_subspace::archiver::CreateObjectMappings;
-use sc_network::config::{Ed25519Secret, NodeKeyConfig, NonReservedPeerMode, SetConfig};
+use sc_network::NetworkStatusProvider;
+use sc_network::config::{
+    Ed25519Secret, MultiaddrWithPeerId, NodeKeyConfig, NonReservedPeerMode, SetConfig,
+};
+use sc_network::network_state::PeerEndpoint;
 use sc_service::{BlocksPruning, Configuration, GenericChainSpec, NoExtension};
 use sc_storage_monitor::{StorageMonitorParams, StorageMonitorService};
 use serde_json::Value;
@@ -26,6 +35,7 @@ use sp_core::H256;
 use sp_core::crypto::Ss58AddressFormat;
 use sp_core::storage::StorageKey;
 use sp_runtime::traits::Header;
+use std::collections::HashMap;
 use std::fmt;
 use std::net::{Ipv4Addr, Ipv6Addr};
 use std::path::PathBuf;
@@ -46,22 +56,27 @@ use subspace_service::config::{
 };
 use subspace_service::{FullClient, NewFull};
 use tokio::time::MissedTickBehavior;
-use tracing::{error, info, info_span};
+use tracing::{error, info, info_span, warn};
 
 pub(super) const GENESIS_HASH: &str =
     "66455a580aabff303720aa83adbe6c44502922251c03ba73686d5245da9e21bd";
 const SYNC_STATUS_EVENT_INTERVAL: Duration = Duration::from_secs(5);
 const CONNECTED_PEERS_EVENT_INTERVAL: Duration = Duration::from_secs(5);
+const NETWORK_TRAFFIC_EVENT_INTERVAL: Duration = Duration::from_secs(5);
+/// Details of connected peers are more expensive to collect, so they are updated less frequently
+pub(super) const PEERS_DETAILS_EVENT_INTERVAL: Duration = Duration::from_secs(10);
 /// Roughly 138k empty blocks can fit into one archived segment, hence we need to not allow to prune
 /// more blocks that this
-const MIN_STATE_PRUNING: BlockNumber = 140_000;
+pub const MIN_STATE_PRUNING: BlockNumber = 140_000;
+/// Number of blocks to keep with minimal pruning
+const MIN_BLOCKS_PRUNING: BlockNumber = 256;
 // Substrate's default
 pub const IN_PEERS: u32 = 32;
 // Substrate's default
 pub const OUT_PEERS: u32 = 8;
 
 /// The maximum number of characters for a node name.
-const NODE_NAME_MAX_LENGTH: usize = 64;
+pub const NODE_NAME_MAX_LENGTH: usize = 64;
 
 #[derive(Debug, thiserror::Error)]
 pub(super) enum ConsensusNodeCreationError {
@@ -134,10 +149,30 @@ pub struct BlockImportedNotification {
     pub voting_solution_range: SolutionRange,
 }
 
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum SubstratePeerRole {
+    Authority,
+    Full,
+    Light,
+}
+
+/// Details about peer connected to consensus node
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SubstratePeer {
+    pub peer_id: String,
+    pub role: SubstratePeerRole,
+    pub best_block: BlockNumber,
+    /// Address of the connection, not available for external node
+    pub address: Option<String>,
+}
+
 #[derive(Default, Debug)]
 struct Handlers {
     sync_state_change: Handler<SyncState>,
     connected_peers_change: Handler<u32>,
+    peers_update: Handler<Vec<SubstratePeer>>,
+    /// Total traffic since node start
+    network_traffic_update: Handler<Traffic>,
     block_imported: Handler<BlockImportedNotification>,
 }
 
@@ -272,6 +307,40 @@ impl ConsensusNode {
             }
         };
 
+        let peers_update_notification_fut = async {
+            let mut peers_details_interval = tokio::time::interval(PEERS_DETAILS_EVENT_INTERVAL);
+            peers_details_interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+            loop {
+                peers_details_interval.tick().await;
+
+                let peers = self.connected_peers().await;
+                self.handlers.peers_update.call_simple(&peers);
+            }
+        };
+
+        let network_traffic_notification_fut = async {
+            let mut network_traffic_interval =
+                tokio::time::interval(NETWORK_TRAFFIC_EVENT_INTERVAL);
+            network_traffic_interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+            loop {
+                network_traffic_interval.tick().await;
+
+                match self.full_node.network_service.status().await {
+                    Ok(network_status) => {
+                        self.handlers.network_traffic_update.call_simple(&Traffic {
+                            downloaded: network_status.total_bytes_inbound,
+                            uploaded: network_status.total_bytes_outbound,
+                        });
+                    }
+                    Err(()) => {
+                        warn!("Failed to query network status");
+                    }
+                }
+            }
+        };
+
         let task_manager = self.full_node.task_manager.future();
 
         select! {
@@ -284,6 +353,12 @@ impl ConsensusNode {
             _ = connected_peers_notification_fut.fuse() => {
                 // Nothing else to do
             }
+            _ = peers_update_notification_fut.fuse() => {
+                // Nothing else to do
+            }
+            _ = network_traffic_notification_fut.fuse() => {
+                // Nothing else to do
+            }
         }
 
         Ok(())
@@ -293,6 +368,54 @@ impl ConsensusNode {
         self.full_node.client.info().best_number
     }
 
+    async fn connected_peers(&self) -> Vec<SubstratePeer> {
+        let peers_info = match self.full_node.sync_service.peers_info().await {
+            Ok(peers_info) => peers_info,
+            Err(error) => {
+                warn!(%error, "Failed to query connected peers info");
+                return Vec::new();
+            }
+        };
+        let mut addresses = match self.full_node.network_service.network_state().await {
+            Ok(network_state) => network_state
+                .connected_peers
+                .into_iter()
+                .map(|(peer_id, peer)| {
+                    let address = match peer.endpoint {
+                        PeerEndpoint::Dialing(address, _role_override) => address,
+                        PeerEndpoint::Listening { send_back_addr, .. } => send_back_addr,
+                    };
+
+                    (peer_id, address.to_string())
+                })
+                .collect::<HashMap<_, _>>(),
+            Err(()) => {
+                warn!("Failed to query network state");
+                HashMap::new()
+            }
+        };
+
+        peers_info
+            .into_iter()
+            .map(|(peer_id, peer_info)| {
+                let peer_id = peer_id.to_base58();
+
+                SubstratePeer {
+                    address: addresses.remove(&peer_id),
+                    peer_id,
+                    role: if peer_info.roles.is_authority() {
+                        SubstratePeerRole::Authority
+                    } else if peer_info.roles.is_full() {
+                        SubstratePeerRole::Full
+                    } else {
+                        SubstratePeerRole::Light
+                    },
+                    best_block: peer_info.best_number,
+                }
+            })
+            .collect()
+    }
+
     pub(super) fn account_balance(&self, account: &PublicKey) -> Balance {
         let reward_address_storage_key = account_storage_key(account);
 
@@ -320,6 +443,14 @@ impl ConsensusNode {
         self.handlers.connected_peers_change.add(callback)
     }
 
+    pub(super) fn on_peers_update(&self, callback: HandlerFn<Vec<SubstratePeer>>) -> HandlerId {
+        self.handlers.peers_update.add(callback)
+    }
+
+    pub(super) fn on_network_traffic_update(&self, callback: HandlerFn<Traffic>) -> HandlerId {
+        self.handlers.network_traffic_update.add(callback)
+    }
+
     pub(super) fn on_block_imported(
         &self,
         callback: HandlerFn<BlockImportedNotification>,
@@ -341,20 +472,37 @@ fn get_total_account_balance(
         }
     };
 
-    let account_info = match AccountInfo::<Nonce, AccountData<Balance>>::decode(
-        &mut encoded_account_info.0.as_slice(),
-    ) {
-        Ok(account_info) => account_info,
-        Err(error) => {
-            error!(%error, "Failed to decode account info");
-            return None;
-        }
-    };
+    decode_total_account_balance(&encoded_account_info.0)
+}
+
+fn decode_total_account_balance(mut encoded_account_info: &[u8]) -> Option<Balance> {
+    let account_info =
+        match AccountInfo::<Nonce, AccountData<Balance>>::decode(&mut encoded_account_info) {
+            Ok(account_info) => account_info,
+            Err(error) => {
+                error!(%error, "Failed to decode account info");
+                return None;
+            }
+        };
 
     let account_data = account_info.data;
     Some(account_data.free + account_data.reserved + account_data.frozen)
 }
 
+fn chain_info(chain_spec: &ChainSpec) -> ChainInfo {
+    ChainInfo {
+        chain_name: chain_spec.0.name().to_string(),
+        protocol_id: chain_spec.0.protocol_id().unwrap_or_default().to_string(),
+        token_symbol: chain_spec
+            .0
+            .properties()
+            .get("tokenSymbol")
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .to_string(),
+    }
+}
+
 pub(super) fn load_chain_specification(chain_spec: &'static [u8]) -> Result<ChainSpec, String> {
     GenericChainSpec::<NoExtension, ()>::from_json_bytes(chain_spec)
         .map(|chain_spec| ChainSpec(Box::new(chain_spec)))
@@ -433,10 +581,43 @@ pub(super) fn generate_node_name() -> String {
 fn create_consensus_chain_config(
     keypair: &Keypair,
     base_path: PathBuf,
-    substrate_port: u16,
+    network_configuration: &NetworkConfiguration,
+    node_configuration: &NodeConfiguration,
     chain_spec: ChainSpec,
 ) -> SubstrateConfiguration {
-    let telemetry_endpoints = chain_spec.0.telemetry_endpoints().clone();
+    let substrate_port = network_configuration.substrate_port;
+    let connection_limits = network_configuration.connections.limits();
+    let listen_on = if network_configuration.substrate_listen_on.is_empty() {
+        vec![
+            sc_network::Multiaddr::from(sc_network::multiaddr::Protocol::Ip4(
+                Ipv4Addr::UNSPECIFIED,
+            ))
+            .with(sc_network::multiaddr::Protocol::Tcp(substrate_port)),
+            sc_network::Multiaddr::from(sc_network::multiaddr::Protocol::Ip6(
+                Ipv6Addr::UNSPECIFIED,
+            ))
+            .with(sc_network::multiaddr::Protocol::Tcp(substrate_port)),
+        ]
+    } else {
+        parse_multiaddrs(&network_configuration.substrate_listen_on)
+    };
+    let telemetry_endpoints = if node_configuration.telemetry {
+        chain_spec.0.telemetry_endpoints().clone()
+    } else {
+        None
+    };
+    let (state_pruning, blocks_pruning) = match node_configuration.pruning {
+        NodePruning::Minimum => (
+            PruningMode::blocks_pruning(MIN_STATE_PRUNING),
+            BlocksPruning::Some(MIN_BLOCKS_PRUNING),
+        ),
+        NodePruning::Blocks(blocks) => (
+            PruningMode::blocks_pruning(blocks.max(MIN_STATE_PRUNING)),
+            BlocksPruning::Some(blocks.max(MIN_STATE_PRUNING)),
+        ),
+        NodePruning::Archive => (PruningMode::ArchiveAll, BlocksPruning::KeepAll),
+    };
+    let rpc = &node_configuration.rpc;
 
     SubstrateConfiguration {
         impl_name: env!("CARGO_PKG_NAME").to_string(),
@@ -445,18 +626,17 @@ fn create_consensus_chain_config(
         base_path,
         transaction_pool: Default::default(),
         network: SubstrateNetworkConfiguration {
-            listen_on: vec![
-                sc_network::Multiaddr::from(sc_network::multiaddr::Protocol::Ip4(
-                    Ipv4Addr::UNSPECIFIED,
-                ))
-                .with(sc_network::multiaddr::Protocol::Tcp(substrate_port)),
-                sc_network::Multiaddr::from(sc_network::multiaddr::Protocol::Ip6(
-                    Ipv6Addr::UNSPECIFIED,
+            listen_on,
+            public_addresses: parse_multiaddrs(&network_configuration.substrate_external_addresses),
+            bootstrap_nodes: chain_spec
+                .0
+                .boot_nodes()
+                .iter()
+                .cloned()
+                .chain(parse_multiaddrs::<MultiaddrWithPeerId>(
+                    &network_configuration.substrate_bootstrap_nodes,
                 ))
-                .with(sc_network::multiaddr::Protocol::Tcp(substrate_port)),
-            ],
-            public_addresses: Vec::new(),
-            bootstrap_nodes: chain_spec.0.boot_nodes().to_vec(),
+                .collect(),
             node_key: NodeKeyConfig::Ed25519(Ed25519Secret::Input(
                 sc_network_types::ed25519::SecretKey::try_from_bytes(
                     keypair.secret().as_ref().to_vec(),
@@ -464,27 +644,40 @@ fn create_consensus_chain_config(
                 .expect("Correct secret; qed"),
             )),
             default_peers_set: SetConfig {
-                in_peers: IN_PEERS,
-                out_peers: OUT_PEERS,
-                reserved_nodes: Vec::new(),
+                in_peers: connection_limits.substrate_in_peers,
+                out_peers: connection_limits.substrate_out_peers,
+                reserved_nodes: parse_multiaddrs(&network_configuration.substrate_reserved_nodes),
                 non_reserved_mode: NonReservedPeerMode::Accept,
             },
-            node_name: generate_node_name(),
-            allow_private_ips: false,
-            sync_mode: ChainSyncMode::Snap,
+            node_name: node_configuration
+                .name
+                .clone()
+                .unwrap_or_else(generate_node_name),
+            allow_private_ips: network_configuration.allow_private_addresses,
+            sync_mode: match node_configuration.sync_mode {
+                NodeSyncMode::Snap => ChainSyncMode::Snap,
+                NodeSyncMode::Full => ChainSyncMode::Full,
+            },
             force_synced: false,
         },
-        state_pruning: PruningMode::blocks_pruning(MIN_STATE_PRUNING),
-        blocks_pruning: BlocksPruning::Some(256),
+        state_pruning,
+        blocks_pruning,
         // TODO: Make the whole `rpc_options` optional instead
         rpc_options: SubstrateRpcConfiguration {
-            listen_on: None,
+            listen_on: rpc.enabled.then_some(rpc.listen_on),
             // Substrate's default
             max_connections: 100,
-            cors: Some(Vec::new()),
-            methods: Default::default(),
-            // Substrate's default
-            rate_limit: None,
+            cors: if rpc.enabled {
+                // `*` means any origin is allowed, which is expressed with `None`
+                (!rpc.cors.iter().any(|origin| origin == "*")).then(|| rpc.cors.clone())
+            } else {
+                Some(Vec::new())
+            },
+            methods: match rpc.methods {
+                RpcMethods::Safe => sc_service::config::RpcMethods::Safe,
+                RpcMethods::Unsafe => sc_service::config::RpcMethods::Unsafe,
+            },
+            rate_limit: rpc.rate_limit,
             rate_limit_whitelisted_ips: Vec::new(),
             rate_limit_trust_proxy_headers: false,
             max_subscriptions_per_connection: 1024,
@@ -504,7 +697,8 @@ fn create_consensus_chain_config(
 pub(super) async fn create_consensus_node(
     keypair: &Keypair,
     base_path: PathBuf,
-    substrate_port: u16,
+    network_configuration: &NetworkConfiguration,
+    node_configuration: &NodeConfiguration,
     chain_spec: ChainSpec,
     piece_getter: Arc<dyn PieceGetter + Send + Sync + 'static>,
     node: Node,
@@ -513,22 +707,20 @@ pub(super) async fn create_consensus_node(
     set_default_ss58_version(&chain_spec);
 
     let pot_external_entropy = pot_external_entropy(&chain_spec)?;
-    let dsn_bootstrap_nodes = dsn_bootstrap_nodes(&chain_spec)?;
-
-    let chain_info = ChainInfo {
-        chain_name: chain_spec.0.name().to_string(),
-        protocol_id: chain_spec.0.protocol_id().unwrap_or_default().to_string(),
-        token_symbol: chain_spec
-            .0
-            .properties()
-            .get("tokenSymbol")
-            .and_then(|v| v.as_str())
-            .unwrap_or_default()
-            .to_string(),
-    };
-
-    let consensus_chain_config =
-        create_consensus_chain_config(keypair, base_path.clone(), substrate_port, chain_spec);
+    let mut dsn_bootstrap_nodes = dsn_bootstrap_nodes(&chain_spec)?;
+    dsn_bootstrap_nodes.extend(parse_multiaddrs::<Multiaddr>(
+        &network_configuration.dsn_bootstrap_nodes,
+    ));
+
+    let chain_info = chain_info(&chain_spec);
+
+    let consensus_chain_config = create_consensus_chain_config(
+        keypair,
+        base_path.clone(),
+        network_configuration,
+        node_configuration,
+        chain_spec,
+    );
 
     info!(
         "📋 Chain specification: {}",
@@ -536,6 +728,17 @@ pub(super) async fn create_consensus_node(
     );
     info!("🏷  Node name: {}", consensus_chain_config.network.node_name);
     info!("💾 Node path: {}", base_path.display());
+    if let Some(listen_on) = consensus_chain_config.rpc_options.listen_on {
+        info!("🔌 JSON-RPC server: {listen_on}");
+
+        if node_configuration.rpc.methods == RpcMethods::Unsafe && !listen_on.ip().is_loopback() {
+            warn!(
+                %listen_on,
+                "Unsafe JSON-RPC methods are exposed on non-loopback interface, make sure it is not \
+                reachable from untrusted networks"
+            );
+        }
+    }
 
     let sync = consensus_chain_config.network.sync_mode;
     let consensus_chain_config = Configuration::from(consensus_chain_config);
@@ -0,0 +1,413 @@
+use crate::backend::bandwidth::Traffic;
+use crate::backend::farmer::maybe_node_client::MaybeNodeClient;
+use crate::backend::node::utils::account_storage_key;
+use crate::backend::node::{
+    BlockImportedNotification, ChainInfo, ChainSpec, GENESIS_HASH, Handlers,
+    PEERS_DETAILS_EVENT_INTERVAL, SYNC_STATUS_EVENT_INTERVAL, SubstratePeer, SubstratePeerRole,
+    SyncKind, SyncState, chain_info, decode_total_account_balance, set_default_ss58_version,
+};
+use crate::backend::utils::HandlerFn;
+use event_listener_primitives::HandlerId;
+use futures::{FutureExt, StreamExt, future, select};
+use jsonrpsee::core::ClientError;
+use jsonrpsee::core::client::{ClientT, SubscriptionClientT};
+use jsonrpsee::rpc_params;
+use jsonrpsee::ws_client::{WsClient, WsClientBuilder};
+use parity_scale_codec::Decode;
+use serde::Deserialize;
+use sp_consensus_subspace::{ChainConstants, SolutionRanges};
+use sp_core::storage::{StorageData, StorageKey};
+use sp_core::{Bytes, H256};
+use sp_runtime::traits::Header as _;
+use std::fmt;
+use subspace_core_primitives::{BlockNumber, PublicKey};
+use subspace_farmer::node_client::rpc_node_client::RpcNodeClient;
+use subspace_runtime_primitives::Balance;
+use subspace_runtime_primitives::opaque::Header;
+use tokio::time::MissedTickBehavior;
+use tracing::{error, info, warn};
+
+/// Subset of `system_health` RPC response
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Health {
+    peers: u32,
+    is_syncing: bool,
+}
+
+/// Subset of `system_syncState` RPC response
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RpcSyncState {
+    highest_block: BlockNumber,
+}
+
+/// Subset of `system_peers` RPC response
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RpcPeerInfo {
+    peer_id: String,
+    roles: String,
+    best_number: BlockNumber,
+}
+
+/// Consensus node running elsewhere that farmer connects to over WebSocket RPC
+pub(in super::super) struct ExternalNode {
+    client: WsClient,
+    url: String,
+    chain_info: ChainInfo,
+    chain_constants: ChainConstants,
+    handlers: Handlers,
+}
+
+impl fmt::Debug for ExternalNode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ExternalNode")
+            .field("url", &self.url)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ExternalNode {
+    pub(in super::super) async fn run(self, reward_address: &PublicKey) -> anyhow::Result<()> {
+        let block_import_notifications_fut = async {
+            let reward_address_storage_key = account_storage_key(reward_address);
+            let mut new_heads = self
+                .client
+                .subscribe::<Header, _>(
+                    "chain_subscribeNewHeads",
+                    rpc_params![],
+                    "chain_unsubscribeNewHeads",
+                )
+                .await?;
+
+            while let Some(header) = new_heads.next().await {
+                let header = header?;
+                let block_hash = header.hash();
+                // Errors are logged already, notification is skipped rather than showing zeroes
+                let Some(solution_ranges) = self
+                    .runtime_api_call::<SolutionRanges>("SubspaceApi_solution_ranges", block_hash)
+                    .await
+                else {
+                    continue;
+                };
+                let Some(reward_address_balance) = self
+                    .total_account_balance(&reward_address_storage_key, Some(block_hash))
+                    .await
+                else {
+                    continue;
+                };
+
+                let block_imported_notification = BlockImportedNotification {
+                    number: *header.number(),
+                    reward_address_balance,
+                    solution_range: solution_ranges.current,
+                    voting_solution_range: solution_ranges.voting_current,
+                };
+                self.handlers
+                    .block_imported
+                    .call_simple(&block_imported_notification);
+            }
+
+            anyhow::Ok(())
+        };
+        let node_status_notifications_fut = async {
+            // Both sync state and connected peers are derived from the same RPC call
+            let mut node_status_interval = tokio::time::interval(SYNC_STATUS_EVENT_INTERVAL);
+            node_status_interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+            let mut last_sync_state = SyncState::Unknown;
+            self.handlers
+                .sync_state_change
+                .call_simple(&last_sync_state);
+            let mut last_connected_peers = 0;
+            self.handlers
+                .connected_peers_change
+                .call_simple(&last_connected_peers);
+
+            loop {
+                node_status_interval.tick().await;
+
+                let health = match self
+                    .client
+                    .request::<Health, _>("system_health", rpc_params![])
+                    .await
+                {
+                    Ok(health) => health,
+                    Err(error) => {
+                        warn!(%error, "Failed to query external node health");
+                        continue;
+                    }
+                };
+
+                let sync_state = if health.is_syncing {
+                    let target = self
+                        .client
+                        .request::<RpcSyncState, _>("system_syncState", rpc_params![])
+                        .await
+                        .map(|sync_state| sync_state.highest_block)
+                        .unwrap_or_default();
+
+                    SyncState::Syncing {
+                        kind: SyncKind::Regular,
+                        target,
+                    }
+                } else if health.peers > 0 {
+                    SyncState::Idle
+                } else {
+                    SyncState::Unknown
+                };
+
+                if sync_state != last_sync_state {
+                    self.handlers.sync_state_change.call_simple(&sync_state);
+
+                    last_sync_state = sync_state;
+                }
+
+                if health.peers != last_connected_peers {
+                    self.handlers
+                        .connected_peers_change
+                        .call_simple(&health.peers);
+
+                    last_connected_peers = health.peers;
+                }
+            }
+        };
+
+        let peers_update_notifications_fut = async {
+            let mut peers_details_interval = tokio::time::interval(PEERS_DETAILS_EVENT_INTERVAL);
+            peers_details_interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+            loop {
+                peers_details_interval.tick().await;
+
+                let peers_info = match self
+                    .client
+                    .request::<Vec<RpcPeerInfo>, _>("system_peers", rpc_params![])
+                    .await
+                {
+                    Ok(peers_info) => peers_info,
+                    Err(error) => {
+                        // `system_peers` is an unsafe RPC method, it will not be available unless
+                        // external node explicitly allows it, no need to retry
+                        warn!(
+                            %error,
+                            "Failed to query external node peers, peer details will not be \
+                            available"
+                        );
+                        return future::pending::<()>().await;
+                    }
+                };
+
+                let peers = peers_info
+                    .into_iter()
+                    .map(|peer_info| SubstratePeer {
+                        peer_id: peer_info.peer_id,
+                        role: match peer_info.roles.as_str() {
+                            "AUTHORITY" => SubstratePeerRole::Authority,
+                            "LIGHT" => SubstratePeerRole::Light,
+                            _ => SubstratePeerRole::Full,
+                        },
+                        best_block: peer_info.best_number,
+                        address: None,
+                    })
+                    .collect::<Vec<_>>();
+                self.handlers.peers_update.call_simple(&peers);
+            }
+        };
+
+        select! {
+            result = block_import_notifications_fut.fuse() => {
+                result?;
+            }
+            _ = node_status_notifications_fut.fuse() => {
+                // Nothing else to do
+            }
+            _ = peers_update_notifications_fut.fuse() => {
+                // Nothing else to do
+            }
+            _ = self.client.on_disconnect().fuse() => {
+                return Err(anyhow::anyhow!("Lost connection to external node at {}", self.url));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(in super::super) async fn best_block_number(&self) -> BlockNumber {
+        match self
+            .client
+            .request::<Header, _>("chain_getHeader", rpc_params![])
+            .await
+        {
+            Ok(header) => *header.number(),
+            Err(error) => {
+                error!(%error, "Failed to query best block from external node");
+                BlockNumber::default()
+            }
+        }
+    }
+
+    pub(in super::super) async fn account_balance(&self, account: &PublicKey) -> Balance {
+        let reward_address_storage_key = account_storage_key(account);
+
+        self.total_account_balance(&reward_address_storage_key, None)
+            .await
+            .unwrap_or_default()
+    }
+
+    pub(in super::super) fn chain_info(&self) -> &ChainInfo {
+        &self.chain_info
+    }
+
+    pub(in super::super) fn chain_constants(&self) -> &ChainConstants {
+        &self.chain_constants
+    }
+
+    pub(in super::super) fn on_sync_state_change(
+        &self,
+        callback: HandlerFn<SyncState>,
+    ) -> HandlerId {
+        self.handlers.sync_state_change.add(callback)
+    }
+
+    pub(in super::super) fn on_connected_peers_change(
+        &self,
+        callback: HandlerFn<u32>,
+    ) -> HandlerId {
+        self.handlers.connected_peers_change.add(callback)
+    }
+
+    pub(in super::super) fn on_peers_update(
+        &self,
+        callback: HandlerFn<Vec<SubstratePeer>>,
+    ) -> HandlerId {
+        self.handlers.peers_update.add(callback)
+    }
+
+    /// Never called, traffic of external node is not accounted for
+    pub(in super::super) fn on_network_traffic_update(
+        &self,
+        callback: HandlerFn<Traffic>,
+    ) -> HandlerId {
+        self.handlers.network_traffic_update.add(callback)
+    }
+
+    pub(in super::super) fn on_block_imported(
+        &self,
+        callback: HandlerFn<BlockImportedNotification>,
+    ) -> HandlerId {
+        self.handlers.block_imported.add(callback)
+    }
+
+    /// Total balance of the account, `None` if it could not be retrieved
+    async fn total_account_balance(
+        &self,
+        address_storage_key: &StorageKey,
+        block_hash: Option<H256>,
+    ) -> Option<Balance> {
+        let encoded_account_info = match self
+            .client
+            .request::<Option<StorageData>, _>(
+                "state_getStorage",
+                rpc_params![address_storage_key, block_hash],
+            )
+            .await
+        {
+            Ok(Some(encoded_account_info)) => encoded_account_info,
+            // Account doesn't exist until it receives something
+            Ok(None) => {
+                return Some(0);
+            }
+            Err(error) => {
+                error!(%error, "Failed to query account balance from external node");
+                return None;
+            }
+        };
+
+        decode_total_account_balance(&encoded_account_info.0)
+    }
+
+    /// Call runtime API method without arguments and decode its result
+    async fn runtime_api_call<T>(&self, method: &str, block_hash: H256) -> Option<T>
+    where
+        T: Decode,
+    {
+        let encoded_result = match self
+            .client
+            .request::<Bytes, _>("state_call", rpc_params![method, "0x", block_hash])
+            .await
+        {
+            Ok(encoded_result) => encoded_result,
+            Err(error) => {
+                error!(%error, %method, "Failed to call runtime API of external node");
+                return None;
+            }
+        };
+
+        match T::decode(&mut encoded_result.as_ref()) {
+            Ok(result) => Some(result),
+            Err(error) => {
+                error!(%error, %method, "Failed to decode runtime API result of external node");
+                None
+            }
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(in super::super) enum ExternalNodeConnectionError {
+    /// Incompatible chain
+    #[error("Incompatible chain, only {compatible_chain} is supported")]
+    IncompatibleChain { compatible_chain: String },
+    /// External node RPC error
+    #[error("External node RPC error: {0}")]
+    Rpc(#[from] ClientError),
+}
+
+/// Connect to external node, check that it is on the expected chain and inject its node client
+pub(in super::super) async fn connect_to_external_node(
+    url: &str,
+    chain_spec: &ChainSpec,
+    maybe_node_client: &MaybeNodeClient,
+) -> Result<ExternalNode, ExternalNodeConnectionError> {
+    set_default_ss58_version(chain_spec);
+
+    info!(%url, "🔗 Connecting to external node");
+
+    let client = WsClientBuilder::default().build(url).await?;
+
+    let genesis_hash = client
+        .request::<H256, _>("chain_getBlockHash", rpc_params![0])
+        .await?;
+    if hex::encode(genesis_hash) != GENESIS_HASH {
+        return Err(ExternalNodeConnectionError::IncompatibleChain {
+            compatible_chain: chain_spec.0.name().to_string(),
+        });
+    }
+
+    let chain_constants = client
+        .request::<Bytes, _>(
+            "state_call",
+            rpc_params!["SubspaceApi_chain_constants", "0x"],
+        )
+        .await?;
+    let chain_constants =
+        ChainConstants::decode(&mut chain_constants.as_ref()).map_err(|error| {
+            ClientError::Custom(format!(
+                "Failed to decode chain constants from external node: {error}"
+            ))
+        })?;
+
+    // Inject working node client into wrapper we have created before such that networking can
+    // respond to incoming requests properly and farmer can farm
+    maybe_node_client.inject(Box::new(RpcNodeClient::new(url).await?));
+
+    Ok(ExternalNode {
+        client,
+        url: url.to_string(),
+        chain_info: chain_info(chain_spec),
+        chain_constants,
+        handlers: Handlers::default(),
+    })
+}
@@ -1,5 +1,28 @@
 use event_listener_primitives::Bag;
+use std::fmt;
+use std::str::FromStr;
 use std::sync::Arc;
+use tracing::warn;
 
 pub(super) type HandlerFn<A> = Arc<dyn Fn(&A) + Send + Sync + 'static>;
 pub(super) type Handler<A> = Bag<HandlerFn<A>, A>;
+
+/// Parse multiaddrs that were already validated as part of configuration, the ones that still
+/// fail to parse (for example due to differences between networking stacks) are skipped
+pub(super) fn parse_multiaddrs<T>(multiaddrs: &[String]) -> Vec<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    multiaddrs
+        .iter()
+        .filter_map(|multiaddr| {
+            multiaddr
+                .parse()
+                .inspect_err(|error| {
+                    warn!(%error, %multiaddr, "Failed to parse multiaddr, ignoring");
+                })
+                .ok()
+        })
+        .collect()
+}
@@ -9,29 +9,40 @@ mod widgets;
 
 /// Known node data directories that should be migrated/deleted during node operations.
 pub(crate) const NODE_DATA_DIRS: &[&str] = &["db", "network"];
+/// Known node data files that should be migrated/deleted during node operations.
+pub(crate) const NODE_DATA_FILES: &[&str] = &[NODE_DATABASE_SETTINGS_FILE];
 
 use crate::AppStatusCode;
-use crate::backend::config::RawConfig;
+use crate::backend::config::{RawConfig, UpdatesConfiguration};
 use crate::backend::farmer::FarmerAction;
-use crate::backend::{BackendAction, BackendNotification, wipe};
+use crate::backend::{
+    BackendAction, BackendNotification, KnownPeersAction, KnownPeersUpdate,
+    NODE_DATABASE_SETTINGS_FILE, NetworkIdentityAction, NetworkIdentityUpdate, wipe,
+};
 use crate::frontend::configuration::node_migration::{
     MigrationMode, NodeMigrationDialog, NodeMigrationInit, NodeMigrationOutput, SyncMode,
 };
 use crate::frontend::configuration::{ConfigurationInput, ConfigurationOutput, ConfigurationView};
 use crate::frontend::loading::{LoadingInput, LoadingView};
 use crate::frontend::migration::{MigrationInput, MigrationOutput, MigrationView};
-use crate::frontend::new_version::NewVersion;
+use crate::frontend::new_version::{NewVersion, NewVersionInput, NewVersionOutput};
 use crate::frontend::running::{RunningInit, RunningInput, RunningOutput, RunningView};
 use crate::frontend::translations::{AsDefaultStr, T};
 use crate::icon_names::shipped as icon_names;
 use futures::channel::mpsc;
 use futures::{SinkExt, StreamExt};
-use gtk::glib;
 use gtk::prelude::*;
+use gtk::{gio, glib};
 use notify_rust::Notification;
 use relm4::actions::{RelmAction, RelmActionGroup};
 use relm4::prelude::*;
 use relm4::{Sender, ShutdownReceiver};
+use relm4_components::open_dialog::{
+    OpenDialog, OpenDialogMsg, OpenDialogResponse, OpenDialogSettings,
+};
+use relm4_components::save_dialog::{
+    SaveDialog, SaveDialogMsg, SaveDialogResponse, SaveDialogSettings,
+};
 use std::any::Any;
 use std::cell::{Cell, LazyCell};
 use std::future::Future;
@@ -49,6 +60,8 @@ const ABOUT_IMAGE: &[u8] = include_bytes!("../res/about.png");
 
 /// Free disk space below which warning must be shown (10 GiB)
 pub const NODE_FREE_SPACE_WARNING_THRESHOLD: u64 = ByteSize::gib(10).as_u64();
+/// Suggested file name for exported network identity
+const NETWORK_IDENTITY_EXPORT_FILE_NAME: &str = "space-acres-network-identity.key";
 
 #[cfg(all(unix, not(target_os = "macos")))]
 #[thread_local]
@@ -140,6 +153,9 @@ pub enum AppInput {
     ShutDown,
     NodeMigration(NodeMigrationOutput),
     Migration(MigrationOutput),
+    NetworkIdentity(NetworkIdentityAction),
+    NewVersion(NewVersionOutput),
+    Ignore,
 }
 
 #[allow(clippy::large_enum_variant)]
@@ -334,6 +350,10 @@ pub struct App {
     run_backend: fn() -> RunBackendResult,
     #[do_not_track]
     pending_migration: Option<PendingMigration>,
+    #[do_not_track]
+    network_identity_export_dialog: Controller<SaveDialog>,
+    #[do_not_track]
+    network_identity_import_dialog: Controller<OpenDialog>,
 }
 
 #[relm4::component(pub async)]
@@ -626,7 +646,9 @@ impl AsyncComponent for App {
                 .drop_on_shutdown()
         });
 
-        let new_version = NewVersion::builder().launch(()).detach();
+        let new_version = NewVersion::builder()
+            .launch(())
+            .forward(sender.input_sender(), AppInput::NewVersion);
 
         let loading_view = LoadingView::builder().launch(()).detach();
 
@@ -686,6 +708,34 @@ impl AsyncComponent for App {
             glib::Propagation::Stop
         });
 
+        let network_identity_export_dialog = SaveDialog::builder()
+            .transient_for_native(&root)
+            .launch(SaveDialogSettings {
+                accept_label: T.network_identity_dialog_button_export().to_string(),
+                cancel_label: T.configuration_dialog_button_cancel().to_string(),
+                ..SaveDialogSettings::default()
+            })
+            .forward(sender.input_sender(), |response| match response {
+                SaveDialogResponse::Accept(path) => {
+                    AppInput::NetworkIdentity(NetworkIdentityAction::Export { path })
+                }
+                SaveDialogResponse::Cancel => AppInput::Ignore,
+            });
+
+        let network_identity_import_dialog = OpenDialog::builder()
+            .transient_for_native(&root)
+            .launch(OpenDialogSettings {
+                accept_label: T.network_identity_dialog_button_import().to_string(),
+                cancel_label: T.configuration_dialog_button_cancel().to_string(),
+                ..OpenDialogSettings::default()
+            })
+            .forward(sender.input_sender(), |response| match response {
+                OpenDialogResponse::Accept(path) => {
+                    AppInput::NetworkIdentity(NetworkIdentityAction::Import { path })
+                }
+                OpenDialogResponse::Cancel => AppInput::Ignore,
+            });
+
         let tray_icon = tray_icon::spawn(&sender).await;
         let has_tray_icon = tray_icon.is_some();
 
@@ -719,6 +769,8 @@ impl AsyncComponent for App {
             migration_view,
             run_backend,
             pending_migration: None,
+            network_identity_export_dialog,
+            network_identity_import_dialog,
             tracker: u8::MAX,
         };
 
@@ -852,7 +904,8 @@ impl AsyncComponent for App {
                     .await;
             }
             AppInput::Running(running_output) => {
-                self.process_running_output(running_output).await;
+                self.process_running_output(running_output, root, &sender)
+                    .await;
             }
             AppInput::ChangeConfiguration => {
                 let configuration_already_opened = matches!(
@@ -964,6 +1017,33 @@ impl AsyncComponent for App {
             AppInput::Migration(migration_output) => {
                 self.process_migration_view_output(migration_output, &sender);
             }
+            AppInput::NetworkIdentity(network_identity_action) => {
+                if let Err(error) = self
+                    .backend_action_sender
+                    .send(BackendAction::NetworkIdentity(network_identity_action))
+                    .await
+                {
+                    self.set_current_view(View::Error(
+                        T.error_message_failed_to_send_network_identity_action_to_backend(
+                            error.to_string(),
+                        )
+                        .to_string(),
+                    ));
+                }
+            }
+            AppInput::NewVersion(new_version_output) => match new_version_output {
+                NewVersionOutput::Restart => {
+                    sender.input(AppInput::Restart);
+                }
+                NewVersionOutput::UpdateFailed(error) => {
+                    self.set_status_bar_contents(StatusBarContents::Error(
+                        T.status_bar_message_failed_to_update(error).to_string(),
+                    ));
+                }
+            },
+            AppInput::Ignore => {
+                // Ignore
+            }
         }
     }
 
@@ -1049,7 +1129,12 @@ impl App {
         }
     }
 
-    async fn process_running_output(&mut self, running_output: RunningOutput) {
+    async fn process_running_output(
+        &mut self,
+        running_output: RunningOutput,
+        root: &gtk::Window,
+        sender: &AsyncComponentSender<Self>,
+    ) {
         match running_output {
             RunningOutput::PausePlotting(pause_plotting) => {
                 if let Err(error) = self
@@ -1090,6 +1175,58 @@ impl App {
                     warn!(%error, "Failed to show low disk space notification");
                 }
             }
+            RunningOutput::ExportNetworkIdentity => {
+                self.network_identity_export_dialog
+                    .emit(SaveDialogMsg::SaveAs(
+                        NETWORK_IDENTITY_EXPORT_FILE_NAME.to_string(),
+                    ));
+            }
+            RunningOutput::ImportNetworkIdentity => {
+                self.network_identity_import_dialog
+                    .emit(OpenDialogMsg::Open);
+            }
+            RunningOutput::RotateNetworkIdentity => {
+                let alert_dialog = gtk::AlertDialog::builder()
+                    .modal(true)
+                    .message(T.network_identity_rotate_confirm_heading().to_string())
+                    .detail(T.network_identity_rotate_confirm_body().to_string())
+                    .buttons(vec![
+                        T.network_identity_rotate_confirm_button_cancel()
+                            .to_string(),
+                        T.network_identity_rotate_confirm_button_rotate()
+                            .to_string(),
+                    ])
+                    .cancel_button(0)
+                    .default_button(0)
+                    .build();
+
+                let sender = sender.clone();
+                alert_dialog.choose(Some(root), None::<&gio::Cancellable>, move |result| {
+                    if matches!(result, Ok(1)) {
+                        sender.input(AppInput::NetworkIdentity(NetworkIdentityAction::Rotate));
+                    }
+                });
+            }
+            RunningOutput::InspectKnownPeers => {
+                self.send_known_peers_action(KnownPeersAction::Inspect)
+                    .await;
+            }
+            RunningOutput::ClearKnownPeers => {
+                self.send_known_peers_action(KnownPeersAction::Clear).await;
+            }
+        }
+    }
+
+    async fn send_known_peers_action(&mut self, known_peers_action: KnownPeersAction) {
+        if let Err(error) = self
+            .backend_action_sender
+            .send(BackendAction::KnownPeers(known_peers_action))
+            .await
+        {
+            self.set_current_view(View::Error(
+                T.error_message_failed_to_send_known_peers_action_to_backend(error.to_string())
+                    .to_string(),
+            ));
         }
     }
 
@@ -1329,6 +1466,10 @@ impl App {
                 self.loading_view.emit(LoadingInput::BackendLoading(step));
             }
             BackendNotification::ConfigurationFound { raw_config } => {
+                self.new_version.emit(NewVersionInput::Configure {
+                    proxy: raw_config.network().proxy.clone(),
+                    updates: raw_config.updates().clone(),
+                });
                 self.get_mut_current_raw_config()
                     .replace(raw_config.clone());
             }
@@ -1339,6 +1480,10 @@ impl App {
             }
             BackendNotification::NotConfigured => {
                 if self.current_raw_config.is_none() {
+                    self.new_version.emit(NewVersionInput::Configure {
+                        proxy: None,
+                        updates: UpdatesConfiguration::default(),
+                    });
                     self.set_current_view(View::Welcome);
                 } else {
                     self.set_current_view(View::Configuration);
@@ -1382,9 +1527,96 @@ impl App {
                     ));
                 }
             },
+            BackendNotification::NetworkIdentityActionResult(result) => match result {
+                Ok(NetworkIdentityUpdate::Exported { path }) => {
+                    self.set_status_bar_contents(StatusBarContents::Warning {
+                        message: T
+                            .status_bar_message_network_identity_exported(
+                                path.display().to_string(),
+                            )
+                            .to_string(),
+                        buttons: StatusBarButtons {
+                            ok: true,
+                            ..Default::default()
+                        },
+                    });
+                }
+                Ok(NetworkIdentityUpdate::Replaced { peer_id }) => {
+                    self.set_status_bar_contents(StatusBarContents::Warning {
+                        message: T
+                            .status_bar_message_restart_is_needed_for_network_identity(
+                                peer_id.to_string(),
+                            )
+                            .to_string(),
+                        buttons: StatusBarButtons {
+                            restart: true,
+                            ..Default::default()
+                        },
+                    });
+                }
+                Err(error) => {
+                    self.set_status_bar_contents(StatusBarContents::Error(
+                        T.status_bar_message_failed_to_update_network_identity(error.to_string())
+                            .to_string(),
+                    ));
+                }
+            },
+            BackendNotification::KnownPeersActionResult(result) => match result {
+                Ok(KnownPeersUpdate::Inspected(maybe_known_peers_cache_info)) => {
+                    let message = match maybe_known_peers_cache_info {
+                        Some(known_peers_cache_info) if known_peers_cache_info.reset_scheduled => T
+                            .status_bar_message_known_peers_cache_reset_scheduled()
+                            .to_string(),
+                        Some(known_peers_cache_info) => {
+                            let path = known_peers_cache_info.path.display().to_string();
+                            let size = ByteSize::b(known_peers_cache_info.size).to_string_as(true);
+
+                            match known_peers_cache_info.age {
+                                Some(age) => T
+                                    .status_bar_message_known_peers_cache(
+                                        path,
+                                        size,
+                                        age.as_secs() / 3600,
+                                    )
+                                    .to_string(),
+                                None => T
+                                    .status_bar_message_known_peers_cache_unknown_age(path, size)
+                                    .to_string(),
+                            }
+                        }
+                        None => T.status_bar_message_known_peers_cache_empty().to_string(),
+                    };
+
+                    self.set_status_bar_contents(StatusBarContents::Warning {
+                        message,
+                        buttons: StatusBarButtons {
+                            ok: true,
+                            ..Default::default()
+                        },
+                    });
+                }
+                Ok(KnownPeersUpdate::ResetScheduled) => {
+                    self.set_status_bar_contents(StatusBarContents::Warning {
+                        message: T
+                            .status_bar_message_restart_is_needed_for_known_peers_cache()
+                            .to_string(),
+                        buttons: StatusBarButtons {
+                            restart: true,
+                            ..Default::default()
+                        },
+                    });
+                }
+                Err(error) => {
+                    self.set_status_bar_contents(StatusBarContents::Error(
+                        T.status_bar_message_failed_to_manage_known_peers_cache(error.to_string())
+                            .to_string(),
+                    ));
+                }
+            },
             BackendNotification::Running {
                 config,
                 raw_config,
+                peer_id,
                 best_block_number,
                 reward_address_balance,
                 initial_farm_states,
@@ -1403,6 +1635,7 @@ impl App {
                     cache_percentage,
                     config,
                     raw_config,
+                    peer_id,
                     chain_info,
                     chain_constants,
                 });
@@ -1415,6 +1648,17 @@ impl App {
                 self.running_view
                     .emit(RunningInput::FarmerNotification(farmer_notification));
             }
+            BackendNotification::BandwidthCapReached => {
+                self.set_status_bar_contents(StatusBarContents::Warning {
+                    message: T
+                        .status_bar_message_restart_is_needed_for_bandwidth_cap()
+                        .to_string(),
+                    buttons: StatusBarButtons {
+                        restart: true,
+                        ..Default::default()
+                    },
+                });
+            }
             BackendNotification::Stopped { error } => {
                 if let Some(pending) = self.pending_migration.take() {
                     debug!("Backend stopped, starting migration");
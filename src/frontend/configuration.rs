@@ -2,7 +2,12 @@ mod farm;
 pub mod node_migration;
 mod utils;
 
-use crate::backend::config::{NetworkConfiguration, RawConfig};
+use crate::backend::config::{
+    ConnectionLimits, NetworkConfiguration, NetworkConnections, NodeConfiguration, NodePruning,
+    NodeSyncMode, PieceDownloadConfiguration, ProxyConfiguration, RawConfig, RpcConfiguration,
+    RpcMethods, UpdatesConfiguration,
+};
+use crate::backend::node::{MIN_STATE_PRUNING, NODE_NAME_MAX_LENGTH};
 use crate::frontend::NODE_FREE_SPACE_WARNING_THRESHOLD;
 use crate::frontend::configuration::farm::{
     FarmWidget, FarmWidgetInit, FarmWidgetInput, FarmWidgetOutput,
@@ -20,17 +25,47 @@ use relm4::prelude::*;
 use relm4_components::open_dialog::{
     OpenDialog, OpenDialogMsg, OpenDialogResponse, OpenDialogSettings,
 };
+use relm4_components::simple_combo_box::{SimpleComboBox, SimpleComboBoxMsg};
+use std::fmt;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
 use std::ops::Deref;
 use std::path::PathBuf;
+use std::str::FromStr;
+use subspace_core_primitives::BlockNumber;
 use subspace_farmer::utils::ss58::parse_ss58_reward_address;
 use tracing::{debug, error, warn};
 
+/// Rough estimate of node disk usage that doesn't depend on pruning
+const NODE_BASE_DISK_USAGE_ESTIMATE: u64 = ByteSize::gib(1).as_u64();
+/// Rough estimate of disk usage by each block kept by the node
+const NODE_BLOCK_DISK_USAGE_ESTIMATE: u64 = ByteSize::kib(20).as_u64();
+/// Approximate number of blocks produced per day
+const BLOCKS_PER_DAY: u64 = 14_400;
+
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum DirectoryKind {
     NodePath,
     FarmPath(DynamicIndex),
 }
 
+#[derive(Debug, Copy, Clone)]
+pub enum ConnectionLimit {
+    DsnIn,
+    DsnOut,
+    DsnPendingIn,
+    DsnPendingOut,
+    SubstrateIn,
+    SubstrateOut,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum PieceDownloadSetting {
+    MaxRetries,
+    InitialRetryInterval,
+    MaxRetryInterval,
+    ConcurrencyMultiplier,
+}
+
 #[derive(Debug)]
 pub enum ConfigurationInput {
     AddFarm,
@@ -40,8 +75,43 @@ pub enum ConfigurationInput {
     DirectorySelected(PathBuf),
     SubstratePortChanged(u16),
     SubspacePortChanged(u16),
-    FasterNetworkingChanged(bool),
+    PickFreePorts,
+    NetworkConnectionsKindChanged(usize),
+    ConnectionLimitChanged {
+        limit: ConnectionLimit,
+        value: u32,
+    },
+    PieceDownloadSettingChanged {
+        setting: PieceDownloadSetting,
+        value: u64,
+    },
+    SubstrateBootstrapNodesChanged(String),
+    SubstrateReservedNodesChanged(String),
+    DsnBootstrapNodesChanged(String),
+    DsnReservedPeersChanged(String),
+    SubstrateExternalAddressesChanged(String),
+    DsnExternalAddressesChanged(String),
+    DailyDownloadCapChanged(String),
+    DailyUploadCapChanged(String),
+    ProxyUrlChanged(String),
+    ProxyUsernameChanged(String),
+    ProxyPasswordChanged(String),
+    AllowPrivateAddressesChanged(bool),
+    ListenOnKindChanged(usize),
+    ListenOnAddressChanged(String),
+    NodeNameChanged(String),
+    TelemetryChanged(bool),
+    NodeSyncModeChanged(usize),
+    NodePruningKindChanged(usize),
+    NodePruningBlocksChanged(BlockNumber),
+    RpcEnabledChanged(bool),
+    RpcListenOnChanged(String),
+    RpcUnsafeMethodsChanged(bool),
+    ExternalNodeRpcUrlChanged(String),
     ReducePlottingCpuLoadChanged(bool),
+    CheckUpdatesChanged(bool),
+    IncludePrereleasesChanged(bool),
+    UpdateSourceChanged(String),
     Delete(DynamicIndex),
     Reinitialize {
         raw_config: RawConfig,
@@ -120,31 +190,434 @@ where
     }
 }
 
+/// Split comma-separated list of multiaddrs as entered by user
+fn split_multiaddrs(multiaddrs: &str) -> Vec<String> {
+    multiaddrs
+        .split(',')
+        .map(str::trim)
+        .filter(|multiaddr| !multiaddr.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+fn is_valid_peer_multiaddrs(multiaddrs: &str) -> bool {
+    split_multiaddrs(multiaddrs)
+        .iter()
+        .all(|multiaddr| NetworkConfiguration::is_valid_peer_multiaddr(multiaddr))
+}
+
+fn is_valid_multiaddrs(multiaddrs: &str) -> bool {
+    split_multiaddrs(multiaddrs)
+        .iter()
+        .all(|multiaddr| NetworkConfiguration::is_valid_multiaddr(multiaddr))
+}
+
+/// Empty cap means no cap
+fn is_valid_daily_cap(cap: &str) -> bool {
+    cap.is_empty() || ByteSize::from_str(cap).is_ok()
+}
+
+/// Empty URL means no proxy
+fn is_valid_proxy_url(url: &str) -> bool {
+    url.is_empty() || ProxyConfiguration::is_valid_url(url)
+}
+
+/// Empty source means GitHub
+fn is_valid_update_source(source: &str) -> bool {
+    source.is_empty() || UpdatesConfiguration::is_valid_source(source)
+}
+
+/// Password can only be used together with username
+fn is_valid_proxy_password(password: &str, username: &str) -> bool {
+    password.is_empty() || !username.is_empty()
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum NetworkConnectionsKind {
+    Low,
+    Default,
+    High,
+    Custom,
+}
+
+impl fmt::Display for NetworkConnectionsKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&match self {
+            Self::Low => T.configuration_advanced_network_connections_low(),
+            Self::Default => T.configuration_advanced_network_connections_default(),
+            Self::High => T.configuration_advanced_network_connections_high(),
+            Self::Custom => T.configuration_advanced_network_connections_custom(),
+        })
+    }
+}
+
+impl NetworkConnectionsKind {
+    fn all() -> [NetworkConnectionsKind; std::mem::variant_count::<NetworkConnectionsKind>()] {
+        [Self::Low, Self::Default, Self::High, Self::Custom]
+    }
+}
+
+/// Problem with configured ports
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum PortProblem {
+    /// Port is already in use by another application
+    InUse(u16),
+    /// Same port is used by both Substrate and DSN
+    Duplicate(u16),
+}
+
+impl fmt::Display for PortProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&match *self {
+            Self::InUse(port) => T.configuration_advanced_network_error_port_in_use(port),
+            Self::Duplicate(port) => T.configuration_advanced_network_error_duplicate_port(port),
+        })
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum ListenOnKind {
+    /// All IPv4 and IPv6 interfaces
+    All,
+    /// All IPv4 interfaces
+    Ipv4,
+    /// Specific IP address
+    Specific,
+    /// Addresses from configuration file that can't be represented by other options, preserved
+    /// unless changed
+    Custom,
+}
+
+impl fmt::Display for ListenOnKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&match self {
+            Self::All => T.configuration_advanced_network_listen_on_all(),
+            Self::Ipv4 => T.configuration_advanced_network_listen_on_ipv4(),
+            Self::Specific => T.configuration_advanced_network_listen_on_specific(),
+            Self::Custom => T.configuration_advanced_network_listen_on_custom(),
+        })
+    }
+}
+
+impl ListenOnKind {
+    fn all() -> [ListenOnKind; std::mem::variant_count::<ListenOnKind>()] {
+        [Self::All, Self::Ipv4, Self::Specific, Self::Custom]
+    }
+
+    /// Options shown to user, [`Self::Custom`] is only shown when configuration file has custom
+    /// listen addresses since there is nothing to preserve otherwise
+    fn variants(with_custom: bool) -> Vec<ListenOnKind> {
+        Self::all()
+            .into_iter()
+            .filter(|kind| with_custom || *kind != Self::Custom)
+            .collect()
+    }
+}
+
+fn tcp_multiaddr(ip: IpAddr, port: u16) -> String {
+    match ip {
+        IpAddr::V4(ip) => format!("/ip4/{ip}/tcp/{port}"),
+        IpAddr::V6(ip) => format!("/ip6/{ip}/tcp/{port}"),
+    }
+}
+
+/// IP address of the only TCP listen address with provided port
+fn single_listen_ip(listen_on: &[String], port: u16) -> Option<IpAddr> {
+    let [multiaddr] = listen_on else {
+        return None;
+    };
+    let ip = multiaddr.split('/').nth(2)?.parse::<IpAddr>().ok()?;
+
+    (*multiaddr == tcp_multiaddr(ip, port)).then_some(ip)
+}
+
 #[tracker::track]
 #[derive(Debug)]
 struct NetworkConfigurationWrapper {
     substrate_port: u16,
     subspace_port: u16,
-    faster_networking: bool,
+    /// Port used by running instance of the app, not available for other applications
+    #[do_not_track]
+    original_substrate_port: u16,
+    /// Port used by running instance of the app, not available for other applications
+    #[do_not_track]
+    original_subspace_port: u16,
+    connections_kind: NetworkConnectionsKind,
+    /// Limits used with [`NetworkConnectionsKind::Custom`]
+    connection_limits: ConnectionLimits,
+    piece_download: PieceDownloadConfiguration,
+    allow_private_addresses: bool,
+    listen_on_kind: ListenOnKind,
+    /// Original listen addresses, preserved with [`ListenOnKind::Custom`]
+    #[do_not_track]
+    substrate_listen_on: Vec<String>,
+    /// Original listen addresses, preserved with [`ListenOnKind::Custom`]
+    #[do_not_track]
+    dsn_listen_on: Vec<String>,
 }
 
 impl Default for NetworkConfigurationWrapper {
     fn default() -> Self {
-        Self::from(NetworkConfiguration::default())
+        Self::from(&NetworkConfiguration::default())
     }
 }
 
-impl From<NetworkConfiguration> for NetworkConfigurationWrapper {
-    fn from(config: NetworkConfiguration) -> Self {
+impl From<&NetworkConfiguration> for NetworkConfigurationWrapper {
+    fn from(config: &NetworkConfiguration) -> Self {
+        let connections = config.connections;
+
         Self {
             substrate_port: config.substrate_port,
             subspace_port: config.subspace_port,
-            faster_networking: config.faster_networking,
+            original_substrate_port: config.substrate_port,
+            original_subspace_port: config.subspace_port,
+            connections_kind: match connections {
+                NetworkConnections::Low => NetworkConnectionsKind::Low,
+                NetworkConnections::Default => NetworkConnectionsKind::Default,
+                NetworkConnections::High => NetworkConnectionsKind::High,
+                NetworkConnections::Custom(_) => NetworkConnectionsKind::Custom,
+            },
+            connection_limits: connections.limits(),
+            piece_download: config.piece_download,
+            allow_private_addresses: config.allow_private_addresses,
+            listen_on_kind: Self::listen_on_kind(config).0,
+            substrate_listen_on: config.substrate_listen_on.clone(),
+            dsn_listen_on: config.dsn_listen_on.clone(),
+            tracker: u8::MAX,
+        }
+    }
+}
+
+impl NetworkConfigurationWrapper {
+    /// Derive listen option and specific address (if any) from configuration
+    fn listen_on_kind(config: &NetworkConfiguration) -> (ListenOnKind, Option<IpAddr>) {
+        if config.substrate_listen_on.is_empty() && config.dsn_listen_on.is_empty() {
+            return (ListenOnKind::All, None);
+        }
+
+        let substrate_ip = single_listen_ip(&config.substrate_listen_on, config.substrate_port);
+        let dsn_ip = single_listen_ip(&config.dsn_listen_on, config.subspace_port);
+        match (substrate_ip, dsn_ip) {
+            (Some(substrate_ip), Some(dsn_ip)) if substrate_ip == dsn_ip => {
+                if substrate_ip == IpAddr::V4(Ipv4Addr::UNSPECIFIED) {
+                    (ListenOnKind::Ipv4, None)
+                } else {
+                    (ListenOnKind::Specific, Some(substrate_ip))
+                }
+            }
+            _ => (ListenOnKind::Custom, None),
+        }
+    }
+
+    /// Substrate and DSN listen addresses with provided specific address, if it is valid
+    fn listen_on(&self, address: &str) -> Option<(Vec<String>, Vec<String>)> {
+        let ip = match self.listen_on_kind {
+            ListenOnKind::All => {
+                return Some((Vec::new(), Vec::new()));
+            }
+            ListenOnKind::Ipv4 => IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+            ListenOnKind::Specific => address.parse::<IpAddr>().ok()?,
+            ListenOnKind::Custom => {
+                return Some((self.substrate_listen_on.clone(), self.dsn_listen_on.clone()));
+            }
+        };
+
+        Some((
+            vec![tcp_multiaddr(ip, self.substrate_port)],
+            vec![tcp_multiaddr(ip, self.subspace_port)],
+        ))
+    }
+
+    /// IP address to listen on with provided specific address, `None` means all interfaces
+    fn listen_ip(&self, address: &str) -> Option<IpAddr> {
+        match self.listen_on_kind {
+            ListenOnKind::All | ListenOnKind::Custom => None,
+            ListenOnKind::Ipv4 => Some(IpAddr::V4(Ipv4Addr::UNSPECIFIED)),
+            ListenOnKind::Specific => address.parse::<IpAddr>().ok(),
+        }
+    }
+
+    /// Read-only view of custom listen addresses from configuration file
+    fn custom_listen_on_explanation(&self) -> String {
+        T.configuration_advanced_network_listen_on_custom_explanation(
+            self.substrate_listen_on.join(", "),
+            self.dsn_listen_on.join(", "),
+        )
+        .to_string()
+    }
+
+    fn connections(&self) -> NetworkConnections {
+        match self.connections_kind {
+            NetworkConnectionsKind::Low => NetworkConnections::Low,
+            NetworkConnectionsKind::Default => NetworkConnections::Default,
+            NetworkConnectionsKind::High => NetworkConnections::High,
+            NetworkConnectionsKind::Custom => NetworkConnections::Custom(self.connection_limits),
+        }
+    }
+}
+
+impl fmt::Display for NodeSyncMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&match self {
+            Self::Snap => T.configuration_advanced_node_sync_mode_snap(),
+            Self::Full => T.configuration_advanced_node_sync_mode_full(),
+        })
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum NodePruningKind {
+    Minimum,
+    Blocks,
+    Archive,
+}
+
+impl fmt::Display for NodePruningKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&match self {
+            Self::Minimum => T.configuration_advanced_node_pruning_kind_minimum(),
+            Self::Blocks => T.configuration_advanced_node_pruning_kind_blocks(),
+            Self::Archive => T.configuration_advanced_node_pruning_kind_archive(),
+        })
+    }
+}
+
+impl NodePruningKind {
+    fn all() -> [NodePruningKind; std::mem::variant_count::<NodePruningKind>()] {
+        [Self::Minimum, Self::Blocks, Self::Archive]
+    }
+}
+
+#[tracker::track]
+#[derive(Debug)]
+struct NodeConfigurationWrapper {
+    telemetry: bool,
+    sync_mode: NodeSyncMode,
+    /// Sync mode node database was created with, changing it results in database reset
+    #[do_not_track]
+    original_sync_mode: NodeSyncMode,
+    /// Pruning node database was created with, switching to or from archive results in database
+    /// reset
+    #[do_not_track]
+    original_pruning: NodePruning,
+    pruning_kind: NodePruningKind,
+    /// Number of blocks to keep with [`NodePruningKind::Blocks`]
+    pruning_blocks: BlockNumber,
+    rpc_enabled: bool,
+    rpc_unsafe_methods: bool,
+    /// Original RPC configuration, options not exposed in UI are preserved from here
+    #[do_not_track]
+    rpc: RpcConfiguration,
+}
+
+impl Default for NodeConfigurationWrapper {
+    fn default() -> Self {
+        Self::from(&NodeConfiguration::default())
+    }
+}
+
+impl From<&NodeConfiguration> for NodeConfigurationWrapper {
+    fn from(config: &NodeConfiguration) -> Self {
+        let (pruning_kind, pruning_blocks) = match config.pruning {
+            NodePruning::Minimum => (NodePruningKind::Minimum, MIN_STATE_PRUNING),
+            NodePruning::Blocks(blocks) => (NodePruningKind::Blocks, blocks),
+            NodePruning::Archive => (NodePruningKind::Archive, MIN_STATE_PRUNING),
+        };
+
+        Self {
+            telemetry: config.telemetry,
+            sync_mode: config.sync_mode,
+            original_sync_mode: config.sync_mode,
+            original_pruning: config.pruning,
+            pruning_kind,
+            pruning_blocks,
+            rpc_enabled: config.rpc.enabled,
+            rpc_unsafe_methods: config.rpc.methods == RpcMethods::Unsafe,
+            rpc: config.rpc.clone(),
             tracker: u8::MAX,
         }
     }
 }
 
+impl NodeConfigurationWrapper {
+    /// Explanation of trade-offs of selected sync mode
+    fn sync_mode_explanation(&self) -> String {
+        let mut explanation = match self.sync_mode {
+            NodeSyncMode::Snap => T.configuration_advanced_node_sync_mode_snap_explanation(),
+            NodeSyncMode::Full => T.configuration_advanced_node_sync_mode_full_explanation(),
+        }
+        .to_string();
+
+        if self.sync_mode != self.original_sync_mode {
+            explanation.push('\n');
+            explanation.push_str(&T.configuration_advanced_node_sync_mode_changed_warning());
+        }
+
+        explanation
+    }
+
+    fn pruning(&self) -> NodePruning {
+        match self.pruning_kind {
+            NodePruningKind::Minimum => NodePruning::Minimum,
+            NodePruningKind::Blocks => NodePruning::Blocks(self.pruning_blocks),
+            NodePruningKind::Archive => NodePruning::Archive,
+        }
+    }
+
+    /// RPC configuration with provided listen address, if it is valid
+    fn rpc(&self, listen_on: &str) -> Option<RpcConfiguration> {
+        let listen_on = match listen_on.parse::<SocketAddr>() {
+            Ok(listen_on) => listen_on,
+            // Invalid address doesn't matter if RPC server is disabled anyway
+            Err(_error) if !self.rpc_enabled => self.rpc.listen_on,
+            Err(_error) => {
+                return None;
+            }
+        };
+
+        Some(RpcConfiguration {
+            enabled: self.rpc_enabled,
+            listen_on,
+            methods: if self.rpc_unsafe_methods {
+                RpcMethods::Unsafe
+            } else {
+                RpcMethods::Safe
+            },
+            ..self.rpc.clone()
+        })
+    }
+
+    /// Rough estimate of node disk usage with selected pruning
+    fn disk_usage_estimate(&self) -> String {
+        let blocks_kept = match self.pruning() {
+            NodePruning::Minimum => Some(MIN_STATE_PRUNING),
+            NodePruning::Blocks(blocks) => Some(blocks),
+            NodePruning::Archive => None,
+        };
+
+        let mut estimate = match blocks_kept {
+            Some(blocks_kept) => T.configuration_advanced_node_pruning_estimate(
+                ByteSize::b(
+                    NODE_BASE_DISK_USAGE_ESTIMATE
+                        + u64::from(blocks_kept) * NODE_BLOCK_DISK_USAGE_ESTIMATE,
+                )
+                .to_string_as(true),
+            ),
+            None => T.configuration_advanced_node_pruning_estimate_archive(
+                ByteSize::b(BLOCKS_PER_DAY * NODE_BLOCK_DISK_USAGE_ESTIMATE).to_string_as(true),
+            ),
+        }
+        .to_string();
+
+        if self.pruning().is_archive() != self.original_pruning.is_archive() {
+            estimate.push('\n');
+            estimate.push_str(&T.configuration_advanced_node_pruning_changed_warning());
+        }
+
+        estimate
+    }
+}
+
 #[tracker::track]
 #[derive(Debug)]
 pub struct ConfigurationView {
@@ -158,7 +631,51 @@ pub struct ConfigurationView {
     farms: AsyncFactoryVecDeque<FarmWidget>,
     #[do_not_track]
     network_configuration: NetworkConfigurationWrapper,
+    port_problem: Option<PortProblem>,
+    #[do_not_track]
+    network_connections_kind_selector: Controller<SimpleComboBox<NetworkConnectionsKind>>,
+    #[do_not_track]
+    listen_on_kind_selector: Controller<SimpleComboBox<ListenOnKind>>,
+    #[do_not_track]
+    listen_on_address: MaybeValid<String>,
+    #[do_not_track]
+    substrate_bootstrap_nodes: MaybeValid<String>,
+    #[do_not_track]
+    substrate_reserved_nodes: MaybeValid<String>,
+    #[do_not_track]
+    dsn_bootstrap_nodes: MaybeValid<String>,
+    #[do_not_track]
+    dsn_reserved_peers: MaybeValid<String>,
+    #[do_not_track]
+    substrate_external_addresses: MaybeValid<String>,
+    #[do_not_track]
+    dsn_external_addresses: MaybeValid<String>,
+    #[do_not_track]
+    daily_download_cap: MaybeValid<String>,
+    #[do_not_track]
+    daily_upload_cap: MaybeValid<String>,
+    #[do_not_track]
+    proxy_url: MaybeValid<String>,
+    #[do_not_track]
+    proxy_username: MaybeValid<String>,
+    #[do_not_track]
+    proxy_password: MaybeValid<String>,
+    #[do_not_track]
+    node_configuration: NodeConfigurationWrapper,
+    #[do_not_track]
+    node_sync_mode_selector: Controller<SimpleComboBox<NodeSyncMode>>,
+    #[do_not_track]
+    node_pruning_kind_selector: Controller<SimpleComboBox<NodePruningKind>>,
+    #[do_not_track]
+    node_name: MaybeValid<String>,
+    #[do_not_track]
+    rpc_listen_on: MaybeValid<String>,
+    #[do_not_track]
+    external_node_rpc_url: MaybeValid<String>,
     reduce_plotting_cpu_load: bool,
+    updates_configuration: UpdatesConfiguration,
+    #[do_not_track]
+    update_source: MaybeValid<String>,
     #[do_not_track]
     pending_directory_selection: Option<DirectoryKind>,
     #[do_not_track]
@@ -385,6 +902,19 @@ impl AsyncComponent for ConfigurationView {
                     configuration_list_box -> gtk::ListBox {
                     },
 
+                    gtk::Label {
+                        add_css_class: "dim-label",
+                        set_halign: gtk::Align::Start,
+                        #[track = "model.external_node_rpc_url.changed_is_valid()"]
+                        set_label: &if model.external_node_rpc_url.is_empty() {
+                            T.configuration_no_farms_node_only()
+                        } else {
+                            T.configuration_no_farms_external_node()
+                        },
+                        #[track = "model.changed_farms()"]
+                        set_visible: model.farms.is_empty(),
+                    },
+
                     gtk::Expander {
                         set_label: Some(&T.configuration_advanced()),
 
@@ -431,7 +961,7 @@ impl AsyncComponent for ConfigurationView {
                                 gtk::Label {
                                     add_css_class: "heading",
                                     set_halign: gtk::Align::Start,
-                                    set_label: &T.configuration_advanced_network(),
+                                    set_label: &T.configuration_advanced_node(),
                                 },
 
                                 gtk::Box {
@@ -442,30 +972,41 @@ impl AsyncComponent for ConfigurationView {
                                         set_spacing: 10,
 
                                         gtk::Label {
-                                            set_label: &T.configuration_advanced_network_substrate_port()
+                                            set_label: &T.configuration_advanced_node_name(),
                                         },
-                                        gtk::SpinButton {
-                                            connect_value_changed[sender] => move |entry| {
-                                                sender.input(ConfigurationInput::SubstratePortChanged(
-                                                    entry.value().round() as u16
+                                        gtk::Entry {
+                                            connect_activate[sender] => move |entry| {
+                                                sender.input(ConfigurationInput::NodeNameChanged(
+                                                    entry.text().into()
                                                 ));
                                             },
-                                            set_adjustment: &gtk::Adjustment::new(
-                                                0.0,
-                                                0.0,
-                                                u16::MAX as f64,
-                                                1.0,
-                                                0.0,
-                                                0.0,
+                                            connect_changed[sender] => move |entry| {
+                                                sender.input(ConfigurationInput::NodeNameChanged(
+                                                    entry.text().into()
+                                                ));
+                                            },
+                                            #[track = "model.node_name.changed_is_valid()"]
+                                            set_css_classes: if model.node_name.is_valid {
+                                                &["valid-input"]
+                                            } else {
+                                                &["invalid-input"]
+                                            },
+                                            set_max_length: NODE_NAME_MAX_LENGTH as i32 - 1,
+                                            set_placeholder_text: Some(
+                                                T.configuration_advanced_node_name_placeholder().as_str(),
                                             ),
+                                            #[track = "model.node_name.changed_is_valid()"]
+                                            set_secondary_icon_name: model.node_name.icon(),
+                                            set_secondary_icon_activatable: false,
+                                            set_secondary_icon_sensitive: false,
+                                            #[track = "model.node_name.changed_value()"]
+                                            set_text: &model.node_name,
                                             set_tooltip: T
-                                                .configuration_advanced_network_default_port_number_tooltip(
-                                                    NetworkConfiguration::default().substrate_port
+                                                .configuration_advanced_node_name_tooltip(
+                                                    NODE_NAME_MAX_LENGTH - 1
                                                 )
                                                 .as_str(),
-                                            #[track = "model.network_configuration.changed_substrate_port()"]
-                                            set_value: model.network_configuration.substrate_port as f64,
-                                            set_width_chars: 5,
+                                            set_width_chars: 30,
                                         },
                                     },
 
@@ -473,56 +1014,1190 @@ impl AsyncComponent for ConfigurationView {
                                         set_spacing: 10,
 
                                         gtk::Label {
-                                            set_label: &T.configuration_advanced_network_subspace_port(),
+                                            set_label: &T.configuration_advanced_node_telemetry(),
                                         },
-                                        gtk::SpinButton {
-                                            connect_value_changed[sender] => move |entry| {
-                                                sender.input(ConfigurationInput::SubspacePortChanged(
-                                                    entry.value().round() as u16
+                                        gtk::Switch {
+                                            connect_state_set[sender] => move |_switch, state| {
+                                                sender.input(ConfigurationInput::TelemetryChanged(
+                                                    state
                                                 ));
+
+                                                glib::Propagation::Proceed
+                                            },
+                                            #[track = "model.node_configuration.changed_telemetry()"]
+                                            set_active: model.node_configuration.telemetry,
+                                            set_tooltip: &T.configuration_advanced_node_telemetry_tooltip(),
+                                        },
+                                    },
+
+                                    gtk::Box {
+                                        set_spacing: 10,
+
+                                        gtk::Label {
+                                            set_label: &T.configuration_advanced_node_sync_mode(),
+                                        },
+                                        gtk::Box {
+                                            set_tooltip: &T.configuration_advanced_node_sync_mode_tooltip(),
+
+                                            model.node_sync_mode_selector.widget().clone(),
+                                        },
+                                    },
+
+                                    gtk::Label {
+                                        add_css_class: "dim-label",
+                                        add_css_class: "caption",
+                                        set_halign: gtk::Align::Start,
+                                        #[track = "model.node_configuration.changed_sync_mode()"]
+                                        set_label: &model.node_configuration.sync_mode_explanation(),
+                                    },
+
+                                    gtk::Box {
+                                        set_spacing: 10,
+
+                                        gtk::Label {
+                                            set_label: &T.configuration_advanced_node_pruning(),
+                                        },
+                                        gtk::Box {
+                                            add_css_class: "linked",
+                                            set_tooltip: &T.configuration_advanced_node_pruning_tooltip(),
+
+                                            model.node_pruning_kind_selector.widget().clone(),
+
+                                            gtk::SpinButton {
+                                                connect_value_changed[sender] => move |entry| {
+                                                    sender.input(ConfigurationInput::NodePruningBlocksChanged(
+                                                        entry.value().round() as BlockNumber
+                                                    ));
+                                                },
+                                                set_adjustment: &gtk::Adjustment::new(
+                                                    MIN_STATE_PRUNING as f64,
+                                                    MIN_STATE_PRUNING as f64,
+                                                    BlockNumber::MAX as f64,
+                                                    1000.0,
+                                                    10_000.0,
+                                                    0.0,
+                                                ),
+                                                set_tooltip: T
+                                                    .configuration_advanced_node_pruning_blocks_tooltip(
+                                                        MIN_STATE_PRUNING
+                                                    )
+                                                    .as_str(),
+                                                #[track = "model.node_configuration.changed_pruning_blocks()"]
+                                                set_value: model.node_configuration.pruning_blocks as f64,
+                                                #[track = "model.node_configuration.changed_pruning_kind()"]
+                                                set_visible: model.node_configuration.pruning_kind == NodePruningKind::Blocks,
+                                                set_width_chars: 10,
                                             },
-                                            set_adjustment: &gtk::Adjustment::new(
-                                                0.0,
-                                                0.0,
-                                                u16::MAX as f64,
-                                                1.0,
-                                                0.0,
-                                                0.0,
-                                            ),
-                                            set_tooltip: T
-                                                .configuration_advanced_network_default_port_number_tooltip(
-                                                    NetworkConfiguration::default().subspace_port
-                                                )
-                                                .as_str(),
-                                            #[track = "model.network_configuration.changed_subspace_port()"]
-                                            set_value: model.network_configuration.subspace_port as f64,
-                                            set_width_chars: 5,
                                         },
                                     },
 
+                                    gtk::Label {
+                                        add_css_class: "dim-label",
+                                        add_css_class: "caption",
+                                        set_halign: gtk::Align::Start,
+                                        #[track = "model.node_configuration.changed_pruning_kind() || model.node_configuration.changed_pruning_blocks()"]
+                                        set_label: &model.node_configuration.disk_usage_estimate(),
+                                    },
+
                                     gtk::Box {
                                         set_spacing: 10,
 
                                         gtk::Label {
-                                            set_label: &T.configuration_advanced_network_faster_networking(),
+                                            set_label: &T.configuration_advanced_node_rpc(),
                                         },
                                         gtk::Switch {
                                             connect_state_set[sender] => move |_switch, state| {
-                                                sender.input(ConfigurationInput::FasterNetworkingChanged(
+                                                sender.input(ConfigurationInput::RpcEnabledChanged(
                                                     state
                                                 ));
 
                                                 glib::Propagation::Proceed
                                             },
-                                            #[track = "model.network_configuration.changed_faster_networking()"]
-                                            set_active: model.network_configuration.faster_networking,
-                                            set_tooltip: &T.configuration_advanced_network_faster_networking_tooltip(),
+                                            #[track = "model.node_configuration.changed_rpc_enabled()"]
+                                            set_active: model.node_configuration.rpc_enabled,
+                                            set_tooltip: &T.configuration_advanced_node_rpc_tooltip(),
                                         },
                                     },
 
-                                },
-                            },
-                        },
+                                    gtk::Box {
+                                        set_spacing: 10,
+                                        #[track = "model.node_configuration.changed_rpc_enabled()"]
+                                        set_visible: model.node_configuration.rpc_enabled,
+
+                                        gtk::Label {
+                                            set_label: &T.configuration_advanced_node_rpc_listen_on(),
+                                        },
+                                        gtk::Entry {
+                                            connect_activate[sender] => move |entry| {
+                                                sender.input(ConfigurationInput::RpcListenOnChanged(
+                                                    entry.text().into()
+                                                ));
+                                            },
+                                            connect_changed[sender] => move |entry| {
+                                                sender.input(ConfigurationInput::RpcListenOnChanged(
+                                                    entry.text().into()
+                                                ));
+                                            },
+                                            #[track = "model.rpc_listen_on.changed_is_valid()"]
+                                            set_css_classes: if model.rpc_listen_on.is_valid {
+                                                &["valid-input"]
+                                            } else {
+                                                &["invalid-input"]
+                                            },
+                                            #[track = "model.rpc_listen_on.changed_is_valid()"]
+                                            set_secondary_icon_name: model.rpc_listen_on.icon(),
+                                            set_secondary_icon_activatable: false,
+                                            set_secondary_icon_sensitive: false,
+                                            #[track = "model.rpc_listen_on.changed_value()"]
+                                            set_text: &model.rpc_listen_on,
+                                            set_tooltip: &T.configuration_advanced_node_rpc_listen_on_tooltip(),
+                                            set_width_chars: 22,
+                                        },
+                                    },
+
+                                    gtk::Box {
+                                        set_spacing: 10,
+                                        #[track = "model.node_configuration.changed_rpc_enabled()"]
+                                        set_visible: model.node_configuration.rpc_enabled,
+
+                                        gtk::Label {
+                                            set_label: &T.configuration_advanced_node_rpc_unsafe_methods(),
+                                        },
+                                        gtk::Switch {
+                                            connect_state_set[sender] => move |_switch, state| {
+                                                sender.input(ConfigurationInput::RpcUnsafeMethodsChanged(
+                                                    state
+                                                ));
+
+                                                glib::Propagation::Proceed
+                                            },
+                                            #[track = "model.node_configuration.changed_rpc_unsafe_methods()"]
+                                            set_active: model.node_configuration.rpc_unsafe_methods,
+                                            set_tooltip: &T.configuration_advanced_node_rpc_unsafe_methods_tooltip(),
+                                        },
+                                    },
+
+                                    gtk::Box {
+                                        set_spacing: 10,
+
+                                        gtk::Label {
+                                            set_label: &T.configuration_advanced_node_external_node(),
+                                        },
+                                        gtk::Entry {
+                                            connect_activate[sender] => move |entry| {
+                                                sender.input(ConfigurationInput::ExternalNodeRpcUrlChanged(
+                                                    entry.text().into()
+                                                ));
+                                            },
+                                            connect_changed[sender] => move |entry| {
+                                                sender.input(ConfigurationInput::ExternalNodeRpcUrlChanged(
+                                                    entry.text().into()
+                                                ));
+                                            },
+                                            #[track = "model.external_node_rpc_url.changed_is_valid()"]
+                                            set_css_classes: if model.external_node_rpc_url.is_valid {
+                                                &["valid-input"]
+                                            } else {
+                                                &["invalid-input"]
+                                            },
+                                            set_hexpand: true,
+                                            set_placeholder_text: Some(
+                                                T
+                                                    .configuration_advanced_node_external_node_placeholder(
+                                                        "ws://192.168.1.10:9944"
+                                                    )
+                                                    .as_str(),
+                                            ),
+                                            #[track = "model.external_node_rpc_url.changed_is_valid()"]
+                                            set_secondary_icon_name: model.external_node_rpc_url.icon(),
+                                            set_secondary_icon_activatable: false,
+                                            set_secondary_icon_sensitive: false,
+                                            #[track = "model.external_node_rpc_url.changed_value()"]
+                                            set_text: &model.external_node_rpc_url,
+                                            set_tooltip: &T.configuration_advanced_node_external_node_tooltip(),
+                                        },
+                                    },
+                                },
+
+                                gtk::Label {
+                                    add_css_class: "heading",
+                                    set_halign: gtk::Align::Start,
+                                    set_label: &T.configuration_advanced_network(),
+                                },
+
+                                gtk::Box {
+                                    set_orientation: gtk::Orientation::Vertical,
+                                    set_spacing: 10,
+
+                                    gtk::Box {
+                                        set_spacing: 10,
+
+                                        gtk::Label {
+                                            set_label: &T.configuration_advanced_network_listen_on(),
+                                        },
+                                        gtk::Box {
+                                            add_css_class: "linked",
+                                            set_tooltip: &T.configuration_advanced_network_listen_on_tooltip(),
+
+                                            model.listen_on_kind_selector.widget().clone(),
+
+                                            gtk::Entry {
+                                                connect_activate[sender] => move |entry| {
+                                                    sender.input(ConfigurationInput::ListenOnAddressChanged(
+                                                        entry.text().into()
+                                                    ));
+                                                },
+                                                connect_changed[sender] => move |entry| {
+                                                    sender.input(ConfigurationInput::ListenOnAddressChanged(
+                                                        entry.text().into()
+                                                    ));
+                                                },
+                                                #[track = "model.listen_on_address.changed_is_valid()"]
+                                                set_css_classes: if model.listen_on_address.is_valid {
+                                                    &["valid-input"]
+                                                } else {
+                                                    &["invalid-input"]
+                                                },
+                                                set_placeholder_text: Some(
+                                                    T.configuration_advanced_network_listen_on_address_placeholder().as_str(),
+                                                ),
+                                                #[track = "model.listen_on_address.changed_is_valid()"]
+                                                set_secondary_icon_name: model.listen_on_address.icon(),
+                                                set_secondary_icon_activatable: false,
+                                                set_secondary_icon_sensitive: false,
+                                                #[track = "model.listen_on_address.changed_value()"]
+                                                set_text: &model.listen_on_address,
+                                                #[track = "model.network_configuration.changed_listen_on_kind()"]
+                                                set_visible: model.network_configuration.listen_on_kind == ListenOnKind::Specific,
+                                                set_width_chars: 22,
+                                            },
+                                        },
+                                    },
+
+                                    gtk::Label {
+                                        add_css_class: "dim-label",
+                                        add_css_class: "caption",
+                                        set_halign: gtk::Align::Start,
+                                        set_selectable: true,
+                                        set_wrap: true,
+                                        #[track = "model.network_configuration.changed_listen_on_kind()"]
+                                        set_label: &model.network_configuration.custom_listen_on_explanation(),
+                                        #[track = "model.network_configuration.changed_listen_on_kind()"]
+                                        set_visible: model.network_configuration.listen_on_kind == ListenOnKind::Custom,
+                                    },
+
+                                    gtk::Box {
+                                        set_spacing: 10,
+
+                                        gtk::Label {
+                                            set_label: &T.configuration_advanced_network_substrate_port()
+                                        },
+                                        gtk::SpinButton {
+                                            connect_value_changed[sender] => move |entry| {
+                                                sender.input(ConfigurationInput::SubstratePortChanged(
+                                                    entry.value().round() as u16
+                                                ));
+                                            },
+                                            set_adjustment: &gtk::Adjustment::new(
+                                                0.0,
+                                                0.0,
+                                                u16::MAX as f64,
+                                                1.0,
+                                                0.0,
+                                                0.0,
+                                            ),
+                                            set_tooltip: T
+                                                .configuration_advanced_network_default_port_number_tooltip(
+                                                    NetworkConfiguration::default().substrate_port
+                                                )
+                                                .as_str(),
+                                            // Custom listen addresses include their own ports
+                                            #[track = "model.network_configuration.changed_listen_on_kind()"]
+                                            set_sensitive: model.network_configuration.listen_on_kind != ListenOnKind::Custom,
+                                            #[track = "model.network_configuration.changed_substrate_port()"]
+                                            set_value: model.network_configuration.substrate_port as f64,
+                                            set_width_chars: 5,
+                                        },
+                                    },
+
+                                    gtk::Box {
+                                        set_spacing: 10,
+
+                                        gtk::Label {
+                                            set_label: &T.configuration_advanced_network_substrate_external_addresses(),
+                                        },
+                                        gtk::Entry {
+                                            connect_activate[sender] => move |entry| {
+                                                sender.input(ConfigurationInput::SubstrateExternalAddressesChanged(
+                                                    entry.text().into()
+                                                ));
+                                            },
+                                            connect_changed[sender] => move |entry| {
+                                                sender.input(ConfigurationInput::SubstrateExternalAddressesChanged(
+                                                    entry.text().into()
+                                                ));
+                                            },
+                                            #[track = "model.substrate_external_addresses.changed_is_valid()"]
+                                            set_css_classes: if model.substrate_external_addresses.is_valid {
+                                                &["valid-input"]
+                                            } else {
+                                                &["invalid-input"]
+                                            },
+                                            set_hexpand: true,
+                                            set_placeholder_text: Some(
+                                                T.configuration_advanced_network_substrate_external_addresses_placeholder().as_str(),
+                                            ),
+                                            #[track = "model.substrate_external_addresses.changed_is_valid()"]
+                                            set_secondary_icon_name: model.substrate_external_addresses.icon(),
+                                            set_secondary_icon_activatable: false,
+                                            set_secondary_icon_sensitive: false,
+                                            #[track = "model.substrate_external_addresses.changed_value()"]
+                                            set_text: &model.substrate_external_addresses,
+                                            set_tooltip: &T.configuration_advanced_network_substrate_external_addresses_tooltip(),
+                                        },
+                                    },
+
+                                    gtk::Box {
+                                        set_spacing: 10,
+
+                                        gtk::Label {
+                                            set_label: &T.configuration_advanced_network_subspace_port(),
+                                        },
+                                        gtk::SpinButton {
+                                            connect_value_changed[sender] => move |entry| {
+                                                sender.input(ConfigurationInput::SubspacePortChanged(
+                                                    entry.value().round() as u16
+                                                ));
+                                            },
+                                            set_adjustment: &gtk::Adjustment::new(
+                                                0.0,
+                                                0.0,
+                                                u16::MAX as f64,
+                                                1.0,
+                                                0.0,
+                                                0.0,
+                                            ),
+                                            set_tooltip: T
+                                                .configuration_advanced_network_default_port_number_tooltip(
+                                                    NetworkConfiguration::default().subspace_port
+                                                )
+                                                .as_str(),
+                                            // Custom listen addresses include their own ports
+                                            #[track = "model.network_configuration.changed_listen_on_kind()"]
+                                            set_sensitive: model.network_configuration.listen_on_kind != ListenOnKind::Custom,
+                                            #[track = "model.network_configuration.changed_subspace_port()"]
+                                            set_value: model.network_configuration.subspace_port as f64,
+                                            set_width_chars: 5,
+                                        },
+                                    },
+
+                                    gtk::Box {
+                                        set_spacing: 10,
+
+                                        gtk::Button {
+                                            connect_clicked => ConfigurationInput::PickFreePorts,
+                                            set_label: &T.configuration_advanced_network_pick_free_ports(),
+                                            #[track = "model.network_configuration.changed_listen_on_kind()"]
+                                            set_sensitive: model.network_configuration.listen_on_kind != ListenOnKind::Custom,
+                                            set_tooltip: &T.configuration_advanced_network_pick_free_ports_tooltip(),
+                                        },
+                                        gtk::Label {
+                                            add_css_class: "error-label",
+                                            #[track = "model.changed_port_problem()"]
+                                            set_label: &model
+                                                .port_problem
+                                                .map(|port_problem| port_problem.to_string())
+                                                .unwrap_or_default(),
+                                            #[track = "model.changed_port_problem()"]
+                                            set_visible: model.port_problem.is_some(),
+                                        },
+                                    },
+
+                                    gtk::Box {
+                                        set_spacing: 10,
+
+                                        gtk::Label {
+                                            set_label: &T.configuration_advanced_network_dsn_external_addresses(),
+                                        },
+                                        gtk::Entry {
+                                            connect_activate[sender] => move |entry| {
+                                                sender.input(ConfigurationInput::DsnExternalAddressesChanged(
+                                                    entry.text().into()
+                                                ));
+                                            },
+                                            connect_changed[sender] => move |entry| {
+                                                sender.input(ConfigurationInput::DsnExternalAddressesChanged(
+                                                    entry.text().into()
+                                                ));
+                                            },
+                                            #[track = "model.dsn_external_addresses.changed_is_valid()"]
+                                            set_css_classes: if model.dsn_external_addresses.is_valid {
+                                                &["valid-input"]
+                                            } else {
+                                                &["invalid-input"]
+                                            },
+                                            set_hexpand: true,
+                                            set_placeholder_text: Some(
+                                                T.configuration_advanced_network_dsn_external_addresses_placeholder().as_str(),
+                                            ),
+                                            #[track = "model.dsn_external_addresses.changed_is_valid()"]
+                                            set_secondary_icon_name: model.dsn_external_addresses.icon(),
+                                            set_secondary_icon_activatable: false,
+                                            set_secondary_icon_sensitive: false,
+                                            #[track = "model.dsn_external_addresses.changed_value()"]
+                                            set_text: &model.dsn_external_addresses,
+                                            set_tooltip: &T.configuration_advanced_network_dsn_external_addresses_tooltip(),
+                                        },
+                                    },
+
+                                    gtk::Box {
+                                        set_spacing: 10,
+
+                                        gtk::Label {
+                                            set_label: &T.configuration_advanced_network_daily_download_cap(),
+                                        },
+                                        gtk::Entry {
+                                            connect_activate[sender] => move |entry| {
+                                                sender.input(ConfigurationInput::DailyDownloadCapChanged(
+                                                    entry.text().into()
+                                                ));
+                                            },
+                                            connect_changed[sender] => move |entry| {
+                                                sender.input(ConfigurationInput::DailyDownloadCapChanged(
+                                                    entry.text().into()
+                                                ));
+                                            },
+                                            #[track = "model.daily_download_cap.changed_is_valid()"]
+                                            set_css_classes: if model.daily_download_cap.is_valid {
+                                                &["valid-input"]
+                                            } else {
+                                                &["invalid-input"]
+                                            },
+                                            set_hexpand: true,
+                                            set_placeholder_text: Some(
+                                                T.configuration_advanced_network_daily_cap_placeholder().as_str(),
+                                            ),
+                                            #[track = "model.daily_download_cap.changed_is_valid()"]
+                                            set_secondary_icon_name: model.daily_download_cap.icon(),
+                                            set_secondary_icon_activatable: false,
+                                            set_secondary_icon_sensitive: false,
+                                            #[track = "model.daily_download_cap.changed_value()"]
+                                            set_text: &model.daily_download_cap,
+                                            set_tooltip: &T.configuration_advanced_network_daily_download_cap_tooltip(),
+                                        },
+                                    },
+
+                                    gtk::Box {
+                                        set_spacing: 10,
+
+                                        gtk::Label {
+                                            set_label: &T.configuration_advanced_network_daily_upload_cap(),
+                                        },
+                                        gtk::Entry {
+                                            connect_activate[sender] => move |entry| {
+                                                sender.input(ConfigurationInput::DailyUploadCapChanged(
+                                                    entry.text().into()
+                                                ));
+                                            },
+                                            connect_changed[sender] => move |entry| {
+                                                sender.input(ConfigurationInput::DailyUploadCapChanged(
+                                                    entry.text().into()
+                                                ));
+                                            },
+                                            #[track = "model.daily_upload_cap.changed_is_valid()"]
+                                            set_css_classes: if model.daily_upload_cap.is_valid {
+                                                &["valid-input"]
+                                            } else {
+                                                &["invalid-input"]
+                                            },
+                                            set_hexpand: true,
+                                            set_placeholder_text: Some(
+                                                T.configuration_advanced_network_daily_cap_placeholder().as_str(),
+                                            ),
+                                            #[track = "model.daily_upload_cap.changed_is_valid()"]
+                                            set_secondary_icon_name: model.daily_upload_cap.icon(),
+                                            set_secondary_icon_activatable: false,
+                                            set_secondary_icon_sensitive: false,
+                                            #[track = "model.daily_upload_cap.changed_value()"]
+                                            set_text: &model.daily_upload_cap,
+                                            set_tooltip: &T.configuration_advanced_network_daily_upload_cap_tooltip(),
+                                        },
+                                    },
+
+                                    gtk::Box {
+                                        set_spacing: 10,
+
+                                        gtk::Label {
+                                            set_label: &T.configuration_advanced_network_proxy_url(),
+                                        },
+                                        gtk::Entry {
+                                            connect_activate[sender] => move |entry| {
+                                                sender.input(ConfigurationInput::ProxyUrlChanged(
+                                                    entry.text().into()
+                                                ));
+                                            },
+                                            connect_changed[sender] => move |entry| {
+                                                sender.input(ConfigurationInput::ProxyUrlChanged(
+                                                    entry.text().into()
+                                                ));
+                                            },
+                                            #[track = "model.proxy_url.changed_is_valid()"]
+                                            set_css_classes: if model.proxy_url.is_valid {
+                                                &["valid-input"]
+                                            } else {
+                                                &["invalid-input"]
+                                            },
+                                            set_hexpand: true,
+                                            set_placeholder_text: Some(
+                                                T.configuration_advanced_network_proxy_url_placeholder().as_str(),
+                                            ),
+                                            #[track = "model.proxy_url.changed_is_valid()"]
+                                            set_secondary_icon_name: model.proxy_url.icon(),
+                                            set_secondary_icon_activatable: false,
+                                            set_secondary_icon_sensitive: false,
+                                            #[track = "model.proxy_url.changed_value()"]
+                                            set_text: &model.proxy_url,
+                                            set_tooltip: &T.configuration_advanced_network_proxy_url_tooltip(),
+                                        },
+                                    },
+
+                                    gtk::Box {
+                                        set_spacing: 10,
+
+                                        gtk::Label {
+                                            set_label: &T.configuration_advanced_network_proxy_username(),
+                                        },
+                                        gtk::Entry {
+                                            connect_activate[sender] => move |entry| {
+                                                sender.input(ConfigurationInput::ProxyUsernameChanged(
+                                                    entry.text().into()
+                                                ));
+                                            },
+                                            connect_changed[sender] => move |entry| {
+                                                sender.input(ConfigurationInput::ProxyUsernameChanged(
+                                                    entry.text().into()
+                                                ));
+                                            },
+                                            #[track = "model.proxy_username.changed_is_valid()"]
+                                            set_css_classes: if model.proxy_username.is_valid {
+                                                &["valid-input"]
+                                            } else {
+                                                &["invalid-input"]
+                                            },
+                                            set_hexpand: true,
+                                            set_placeholder_text: Some(
+                                                T.configuration_advanced_network_proxy_username_placeholder().as_str(),
+                                            ),
+                                            #[track = "model.proxy_username.changed_is_valid()"]
+                                            set_secondary_icon_name: model.proxy_username.icon(),
+                                            set_secondary_icon_activatable: false,
+                                            set_secondary_icon_sensitive: false,
+                                            #[track = "model.proxy_username.changed_value()"]
+                                            set_text: &model.proxy_username,
+                                            set_tooltip: &T.configuration_advanced_network_proxy_username_tooltip(),
+                                        },
+                                    },
+
+                                    gtk::Box {
+                                        set_spacing: 10,
+
+                                        gtk::Label {
+                                            set_label: &T.configuration_advanced_network_proxy_password(),
+                                        },
+                                        gtk::PasswordEntry {
+                                            connect_activate[sender] => move |entry| {
+                                                sender.input(ConfigurationInput::ProxyPasswordChanged(
+                                                    entry.text().into()
+                                                ));
+                                            },
+                                            connect_changed[sender] => move |entry| {
+                                                sender.input(ConfigurationInput::ProxyPasswordChanged(
+                                                    entry.text().into()
+                                                ));
+                                            },
+                                            #[track = "model.proxy_password.changed_is_valid()"]
+                                            set_css_classes: if model.proxy_password.is_valid {
+                                                &["valid-input"]
+                                            } else {
+                                                &["invalid-input"]
+                                            },
+                                            set_hexpand: true,
+                                            set_show_peek_icon: true,
+                                            #[track = "model.proxy_password.changed_value()"]
+                                            set_text: &model.proxy_password,
+                                            set_tooltip: &T.configuration_advanced_network_proxy_password_tooltip(),
+                                        },
+                                    },
+
+                                    gtk::Box {
+                                        set_spacing: 10,
+
+                                        gtk::Label {
+                                            set_label: &T.configuration_advanced_network_allow_private_addresses(),
+                                        },
+                                        gtk::Switch {
+                                            connect_state_set[sender] => move |_switch, state| {
+                                                sender.input(ConfigurationInput::AllowPrivateAddressesChanged(
+                                                    state
+                                                ));
+
+                                                glib::Propagation::Proceed
+                                            },
+                                            #[track = "model.network_configuration.changed_allow_private_addresses()"]
+                                            set_active: model.network_configuration.allow_private_addresses,
+                                            set_tooltip: &T.configuration_advanced_network_allow_private_addresses_tooltip(),
+                                        },
+                                    },
+
+                                    gtk::Box {
+                                        set_spacing: 10,
+
+                                        gtk::Label {
+                                            set_label: &T.configuration_advanced_network_connections(),
+                                        },
+                                        gtk::Box {
+                                            set_tooltip: &T.configuration_advanced_network_connections_tooltip(),
+
+                                            model.network_connections_kind_selector.widget().clone(),
+                                        },
+                                    },
+
+                                    gtk::Box {
+                                        set_margin_start: 20,
+                                        set_orientation: gtk::Orientation::Vertical,
+                                        set_spacing: 10,
+                                        #[track = "model.network_configuration.changed_connections_kind()"]
+                                        set_visible: model.network_configuration.connections_kind == NetworkConnectionsKind::Custom,
+
+                                        gtk::Box {
+                                            set_spacing: 10,
+
+                                            gtk::Label {
+                                                set_label: &T.configuration_advanced_network_connections_dsn_in(),
+                                            },
+                                            gtk::SpinButton {
+                                                connect_value_changed[sender] => move |entry| {
+                                                    sender.input(ConfigurationInput::ConnectionLimitChanged {
+                                                        limit: ConnectionLimit::DsnIn,
+                                                        value: entry.value().round() as u32,
+                                                    });
+                                                },
+                                                set_adjustment: &gtk::Adjustment::new(
+                                                    0.0,
+                                                    0.0,
+                                                    10_000.0,
+                                                    1.0,
+                                                    10.0,
+                                                    0.0,
+                                                ),
+                                                #[track = "model.network_configuration.changed_connection_limits()"]
+                                                set_value: model.network_configuration.connection_limits.dsn_in_connections as f64,
+                                                set_width_chars: 5,
+                                            },
+                                        },
+
+                                        gtk::Box {
+                                            set_spacing: 10,
+
+                                            gtk::Label {
+                                                set_label: &T.configuration_advanced_network_connections_dsn_out(),
+                                            },
+                                            gtk::SpinButton {
+                                                connect_value_changed[sender] => move |entry| {
+                                                    sender.input(ConfigurationInput::ConnectionLimitChanged {
+                                                        limit: ConnectionLimit::DsnOut,
+                                                        value: entry.value().round() as u32,
+                                                    });
+                                                },
+                                                set_adjustment: &gtk::Adjustment::new(
+                                                    1.0,
+                                                    1.0,
+                                                    10_000.0,
+                                                    1.0,
+                                                    10.0,
+                                                    0.0,
+                                                ),
+                                                #[track = "model.network_configuration.changed_connection_limits()"]
+                                                set_value: model.network_configuration.connection_limits.dsn_out_connections as f64,
+                                                set_width_chars: 5,
+                                            },
+                                        },
+
+                                        gtk::Box {
+                                            set_spacing: 10,
+
+                                            gtk::Label {
+                                                set_label: &T.configuration_advanced_network_connections_dsn_pending_in(),
+                                            },
+                                            gtk::SpinButton {
+                                                connect_value_changed[sender] => move |entry| {
+                                                    sender.input(ConfigurationInput::ConnectionLimitChanged {
+                                                        limit: ConnectionLimit::DsnPendingIn,
+                                                        value: entry.value().round() as u32,
+                                                    });
+                                                },
+                                                set_adjustment: &gtk::Adjustment::new(
+                                                    0.0,
+                                                    0.0,
+                                                    10_000.0,
+                                                    1.0,
+                                                    10.0,
+                                                    0.0,
+                                                ),
+                                                #[track = "model.network_configuration.changed_connection_limits()"]
+                                                set_value: model.network_configuration.connection_limits.dsn_pending_in_connections as f64,
+                                                set_width_chars: 5,
+                                            },
+                                        },
+
+                                        gtk::Box {
+                                            set_spacing: 10,
+
+                                            gtk::Label {
+                                                set_label: &T.configuration_advanced_network_connections_dsn_pending_out(),
+                                            },
+                                            gtk::SpinButton {
+                                                connect_value_changed[sender] => move |entry| {
+                                                    sender.input(ConfigurationInput::ConnectionLimitChanged {
+                                                        limit: ConnectionLimit::DsnPendingOut,
+                                                        value: entry.value().round() as u32,
+                                                    });
+                                                },
+                                                set_adjustment: &gtk::Adjustment::new(
+                                                    1.0,
+                                                    1.0,
+                                                    10_000.0,
+                                                    1.0,
+                                                    10.0,
+                                                    0.0,
+                                                ),
+                                                #[track = "model.network_configuration.changed_connection_limits()"]
+                                                set_value: model.network_configuration.connection_limits.dsn_pending_out_connections as f64,
+                                                set_width_chars: 5,
+                                            },
+                                        },
+
+                                        gtk::Box {
+                                            set_spacing: 10,
+
+                                            gtk::Label {
+                                                set_label: &T.configuration_advanced_network_connections_substrate_in(),
+                                            },
+                                            gtk::SpinButton {
+                                                connect_value_changed[sender] => move |entry| {
+                                                    sender.input(ConfigurationInput::ConnectionLimitChanged {
+                                                        limit: ConnectionLimit::SubstrateIn,
+                                                        value: entry.value().round() as u32,
+                                                    });
+                                                },
+                                                set_adjustment: &gtk::Adjustment::new(
+                                                    0.0,
+                                                    0.0,
+                                                    10_000.0,
+                                                    1.0,
+                                                    10.0,
+                                                    0.0,
+                                                ),
+                                                #[track = "model.network_configuration.changed_connection_limits()"]
+                                                set_value: model.network_configuration.connection_limits.substrate_in_peers as f64,
+                                                set_width_chars: 5,
+                                            },
+                                        },
+
+                                        gtk::Box {
+                                            set_spacing: 10,
+
+                                            gtk::Label {
+                                                set_label: &T.configuration_advanced_network_connections_substrate_out(),
+                                            },
+                                            gtk::SpinButton {
+                                                connect_value_changed[sender] => move |entry| {
+                                                    sender.input(ConfigurationInput::ConnectionLimitChanged {
+                                                        limit: ConnectionLimit::SubstrateOut,
+                                                        value: entry.value().round() as u32,
+                                                    });
+                                                },
+                                                set_adjustment: &gtk::Adjustment::new(
+                                                    1.0,
+                                                    1.0,
+                                                    10_000.0,
+                                                    1.0,
+                                                    10.0,
+                                                    0.0,
+                                                ),
+                                                #[track = "model.network_configuration.changed_connection_limits()"]
+                                                set_value: model.network_configuration.connection_limits.substrate_out_peers as f64,
+                                                set_width_chars: 5,
+                                            },
+                                        },
+                                    },
+
+                                    gtk::Box {
+                                        set_spacing: 10,
+
+                                        gtk::Label {
+                                            set_label: &T.configuration_advanced_network_piece_download(),
+                                            set_tooltip: &T.configuration_advanced_network_piece_download_tooltip(),
+                                        },
+                                    },
+
+                                    gtk::Box {
+                                        set_margin_start: 20,
+                                        set_orientation: gtk::Orientation::Vertical,
+                                        set_spacing: 10,
+
+                                        gtk::Box {
+                                            set_spacing: 10,
+
+                                            gtk::Label {
+                                                set_label: &T.configuration_advanced_network_piece_download_max_retries(),
+                                            },
+                                            gtk::SpinButton {
+                                                connect_value_changed[sender] => move |entry| {
+                                                    sender.input(ConfigurationInput::PieceDownloadSettingChanged {
+                                                        setting: PieceDownloadSetting::MaxRetries,
+                                                        value: entry.value().round() as u64,
+                                                    });
+                                                },
+                                                set_adjustment: &gtk::Adjustment::new(
+                                                    0.0,
+                                                    0.0,
+                                                    1_000.0,
+                                                    1.0,
+                                                    10.0,
+                                                    0.0,
+                                                ),
+                                                #[track = "model.network_configuration.changed_piece_download()"]
+                                                set_value: model.network_configuration.piece_download.max_retries as f64,
+                                                set_width_chars: 5,
+                                            },
+                                        },
+
+                                        gtk::Box {
+                                            set_spacing: 10,
+
+                                            gtk::Label {
+                                                set_label: &T.configuration_advanced_network_piece_download_initial_retry_interval(),
+                                            },
+                                            gtk::SpinButton {
+                                                connect_value_changed[sender] => move |entry| {
+                                                    sender.input(ConfigurationInput::PieceDownloadSettingChanged {
+                                                        setting: PieceDownloadSetting::InitialRetryInterval,
+                                                        value: entry.value().round() as u64,
+                                                    });
+                                                },
+                                                set_adjustment: &gtk::Adjustment::new(
+                                                    1.0,
+                                                    1.0,
+                                                    3_600.0,
+                                                    1.0,
+                                                    10.0,
+                                                    0.0,
+                                                ),
+                                                #[track = "model.network_configuration.changed_piece_download()"]
+                                                set_value: model.network_configuration.piece_download.initial_retry_interval as f64,
+                                                set_width_chars: 5,
+                                            },
+                                        },
+
+                                        gtk::Box {
+                                            set_spacing: 10,
+
+                                            gtk::Label {
+                                                set_label: &T.configuration_advanced_network_piece_download_max_retry_interval(),
+                                            },
+                                            gtk::SpinButton {
+                                                connect_value_changed[sender] => move |entry| {
+                                                    sender.input(ConfigurationInput::PieceDownloadSettingChanged {
+                                                        setting: PieceDownloadSetting::MaxRetryInterval,
+                                                        value: entry.value().round() as u64,
+                                                    });
+                                                },
+                                                set_adjustment: &gtk::Adjustment::new(
+                                                    1.0,
+                                                    1.0,
+                                                    3_600.0,
+                                                    1.0,
+                                                    10.0,
+                                                    0.0,
+                                                ),
+                                                #[track = "model.network_configuration.changed_piece_download()"]
+                                                set_value: model.network_configuration.piece_download.max_retry_interval as f64,
+                                                set_width_chars: 5,
+                                            },
+                                        },
+
+                                        gtk::Box {
+                                            set_spacing: 10,
+
+                                            gtk::Label {
+                                                set_label: &T.configuration_advanced_network_piece_download_concurrency_multiplier(),
+                                            },
+                                            gtk::SpinButton {
+                                                connect_value_changed[sender] => move |entry| {
+                                                    sender.input(ConfigurationInput::PieceDownloadSettingChanged {
+                                                        setting: PieceDownloadSetting::ConcurrencyMultiplier,
+                                                        value: entry.value().round() as u64,
+                                                    });
+                                                },
+                                                set_adjustment: &gtk::Adjustment::new(
+                                                    1.0,
+                                                    1.0,
+                                                    100.0,
+                                                    1.0,
+                                                    10.0,
+                                                    0.0,
+                                                ),
+                                                #[track = "model.network_configuration.changed_piece_download()"]
+                                                set_value: model.network_configuration.piece_download.concurrency_multiplier as f64,
+                                                set_width_chars: 5,
+                                            },
+                                        },
+                                    },
+
+                                    gtk::Box {
+                                        set_spacing: 10,
+
+                                        gtk::Label {
+                                            set_label: &T.configuration_advanced_network_substrate_bootstrap_nodes(),
+                                        },
+                                        gtk::Entry {
+                                            connect_activate[sender] => move |entry| {
+                                                sender.input(ConfigurationInput::SubstrateBootstrapNodesChanged(
+                                                    entry.text().into()
+                                                ));
+                                            },
+                                            connect_changed[sender] => move |entry| {
+                                                sender.input(ConfigurationInput::SubstrateBootstrapNodesChanged(
+                                                    entry.text().into()
+                                                ));
+                                            },
+                                            #[track = "model.substrate_bootstrap_nodes.changed_is_valid()"]
+                                            set_css_classes: if model.substrate_bootstrap_nodes.is_valid {
+                                                &["valid-input"]
+                                            } else {
+                                                &["invalid-input"]
+                                            },
+                                            set_hexpand: true,
+                                            set_placeholder_text: Some(
+                                                T.configuration_advanced_network_peer_multiaddrs_placeholder().as_str(),
+                                            ),
+                                            #[track = "model.substrate_bootstrap_nodes.changed_is_valid()"]
+                                            set_secondary_icon_name: model.substrate_bootstrap_nodes.icon(),
+                                            set_secondary_icon_activatable: false,
+                                            set_secondary_icon_sensitive: false,
+                                            #[track = "model.substrate_bootstrap_nodes.changed_value()"]
+                                            set_text: &model.substrate_bootstrap_nodes,
+                                            set_tooltip: &T.configuration_advanced_network_substrate_bootstrap_nodes_tooltip(),
+                                        },
+                                    },
+
+                                    gtk::Box {
+                                        set_spacing: 10,
+
+                                        gtk::Label {
+                                            set_label: &T.configuration_advanced_network_substrate_reserved_nodes(),
+                                        },
+                                        gtk::Entry {
+                                            connect_activate[sender] => move |entry| {
+                                                sender.input(ConfigurationInput::SubstrateReservedNodesChanged(
+                                                    entry.text().into()
+                                                ));
+                                            },
+                                            connect_changed[sender] => move |entry| {
+                                                sender.input(ConfigurationInput::SubstrateReservedNodesChanged(
+                                                    entry.text().into()
+                                                ));
+                                            },
+                                            #[track = "model.substrate_reserved_nodes.changed_is_valid()"]
+                                            set_css_classes: if model.substrate_reserved_nodes.is_valid {
+                                                &["valid-input"]
+                                            } else {
+                                                &["invalid-input"]
+                                            },
+                                            set_hexpand: true,
+                                            set_placeholder_text: Some(
+                                                T.configuration_advanced_network_peer_multiaddrs_placeholder().as_str(),
+                                            ),
+                                            #[track = "model.substrate_reserved_nodes.changed_is_valid()"]
+                                            set_secondary_icon_name: model.substrate_reserved_nodes.icon(),
+                                            set_secondary_icon_activatable: false,
+                                            set_secondary_icon_sensitive: false,
+                                            #[track = "model.substrate_reserved_nodes.changed_value()"]
+                                            set_text: &model.substrate_reserved_nodes,
+                                            set_tooltip: &T.configuration_advanced_network_substrate_reserved_nodes_tooltip(),
+                                        },
+                                    },
+
+                                    gtk::Box {
+                                        set_spacing: 10,
+
+                                        gtk::Label {
+                                            set_label: &T.configuration_advanced_network_dsn_bootstrap_nodes(),
+                                        },
+                                        gtk::Entry {
+                                            connect_activate[sender] => move |entry| {
+                                                sender.input(ConfigurationInput::DsnBootstrapNodesChanged(
+                                                    entry.text().into()
+                                                ));
+                                            },
+                                            connect_changed[sender] => move |entry| {
+                                                sender.input(ConfigurationInput::DsnBootstrapNodesChanged(
+                                                    entry.text().into()
+                                                ));
+                                            },
+                                            #[track = "model.dsn_bootstrap_nodes.changed_is_valid()"]
+                                            set_css_classes: if model.dsn_bootstrap_nodes.is_valid {
+                                                &["valid-input"]
+                                            } else {
+                                                &["invalid-input"]
+                                            },
+                                            set_hexpand: true,
+                                            set_placeholder_text: Some(
+                                                T.configuration_advanced_network_peer_multiaddrs_placeholder().as_str(),
+                                            ),
+                                            #[track = "model.dsn_bootstrap_nodes.changed_is_valid()"]
+                                            set_secondary_icon_name: model.dsn_bootstrap_nodes.icon(),
+                                            set_secondary_icon_activatable: false,
+                                            set_secondary_icon_sensitive: false,
+                                            #[track = "model.dsn_bootstrap_nodes.changed_value()"]
+                                            set_text: &model.dsn_bootstrap_nodes,
+                                            set_tooltip: &T.configuration_advanced_network_dsn_bootstrap_nodes_tooltip(),
+                                        },
+                                    },
+
+                                    gtk::Box {
+                                        set_spacing: 10,
+
+                                        gtk::Label {
+                                            set_label: &T.configuration_advanced_network_dsn_reserved_peers(),
+                                        },
+                                        gtk::Entry {
+                                            connect_activate[sender] => move |entry| {
+                                                sender.input(ConfigurationInput::DsnReservedPeersChanged(
+                                                    entry.text().into()
+                                                ));
+                                            },
+                                            connect_changed[sender] => move |entry| {
+                                                sender.input(ConfigurationInput::DsnReservedPeersChanged(
+                                                    entry.text().into()
+                                                ));
+                                            },
+                                            #[track = "model.dsn_reserved_peers.changed_is_valid()"]
+                                            set_css_classes: if model.dsn_reserved_peers.is_valid {
+                                                &["valid-input"]
+                                            } else {
+                                                &["invalid-input"]
+                                            },
+                                            set_hexpand: true,
+                                            set_placeholder_text: Some(
+                                                T.configuration_advanced_network_peer_multiaddrs_placeholder().as_str(),
+                                            ),
+                                            #[track = "model.dsn_reserved_peers.changed_is_valid()"]
+                                            set_secondary_icon_name: model.dsn_reserved_peers.icon(),
+                                            set_secondary_icon_activatable: false,
+                                            set_secondary_icon_sensitive: false,
+                                            #[track = "model.dsn_reserved_peers.changed_value()"]
+                                            set_text: &model.dsn_reserved_peers,
+                                            set_tooltip: &T.configuration_advanced_network_dsn_reserved_peers_tooltip(),
+                                        },
+                                    },
+
+                                },
+
+                                gtk::Label {
+                                    add_css_class: "heading",
+                                    set_halign: gtk::Align::Start,
+                                    set_label: &T.configuration_advanced_updates(),
+                                },
+
+                                gtk::Box {
+                                    set_orientation: gtk::Orientation::Vertical,
+                                    set_spacing: 10,
+
+                                    gtk::Box {
+                                        set_spacing: 10,
+
+                                        gtk::Label {
+                                            set_label: &T.configuration_advanced_updates_check(),
+                                        },
+                                        gtk::Switch {
+                                            connect_state_set[sender] => move |_switch, state| {
+                                                sender.input(ConfigurationInput::CheckUpdatesChanged(
+                                                    state
+                                                ));
+
+                                                glib::Propagation::Proceed
+                                            },
+                                            #[track = "model.changed_updates_configuration()"]
+                                            set_active: model.updates_configuration.enabled,
+                                            set_tooltip: &T.configuration_advanced_updates_check_tooltip(),
+                                        },
+                                    },
+
+                                    gtk::Box {
+                                        set_spacing: 10,
+                                        #[track = "model.changed_updates_configuration()"]
+                                        set_sensitive: model.updates_configuration.enabled,
+
+                                        gtk::Label {
+                                            set_label: &T.configuration_advanced_updates_include_prereleases(),
+                                        },
+                                        gtk::Switch {
+                                            connect_state_set[sender] => move |_switch, state| {
+                                                sender.input(ConfigurationInput::IncludePrereleasesChanged(
+                                                    state
+                                                ));
+
+                                                glib::Propagation::Proceed
+                                            },
+                                            #[track = "model.changed_updates_configuration()"]
+                                            set_active: model.updates_configuration.include_prereleases,
+                                            set_tooltip: &T.configuration_advanced_updates_include_prereleases_tooltip(),
+                                        },
+                                    },
+
+                                    gtk::Box {
+                                        set_spacing: 10,
+                                        #[track = "model.changed_updates_configuration()"]
+                                        set_sensitive: model.updates_configuration.enabled,
+
+                                        gtk::Label {
+                                            set_label: &T.configuration_advanced_updates_source(),
+                                        },
+                                        gtk::Entry {
+                                            connect_activate[sender] => move |entry| {
+                                                sender.input(ConfigurationInput::UpdateSourceChanged(
+                                                    entry.text().into()
+                                                ));
+                                            },
+                                            connect_changed[sender] => move |entry| {
+                                                sender.input(ConfigurationInput::UpdateSourceChanged(
+                                                    entry.text().into()
+                                                ));
+                                            },
+                                            #[track = "model.update_source.changed_is_valid()"]
+                                            set_css_classes: if model.update_source.is_valid {
+                                                &["valid-input"]
+                                            } else {
+                                                &["invalid-input"]
+                                            },
+                                            set_hexpand: true,
+                                            set_placeholder_text: Some(
+                                                T.configuration_advanced_updates_source_placeholder().as_str(),
+                                            ),
+                                            #[track = "model.update_source.changed_is_valid()"]
+                                            set_secondary_icon_name: model.update_source.icon(),
+                                            set_secondary_icon_activatable: false,
+                                            set_secondary_icon_sensitive: false,
+                                            #[track = "model.update_source.changed_value()"]
+                                            set_text: &model.update_source,
+                                            set_tooltip: &T.configuration_advanced_updates_source_tooltip(),
+                                        },
+                                    },
+                                },
+                            },
+                        },
                     },
 
                     gtk::Box {
@@ -567,12 +2242,8 @@ impl AsyncComponent for ConfigurationView {
                                 gtk::Button {
                                     add_css_class: "suggested-action",
                                     connect_clicked => ConfigurationInput::Save,
-                                    #[track = "model.reward_address.changed_is_valid() || model.node_path.changed_is_valid() || model.changed_farms()"]
-                                    set_sensitive:
-                                        model.reward_address.is_valid
-                                            && model.node_path.is_valid
-                                            && !model.farms.is_empty()
-                                            && model.farms.iter().all(|maybe_farm| maybe_farm.map(FarmWidget::valid).unwrap_or_default()),
+                                    #[track = "model.changed_validity()"]
+                                    set_sensitive: model.is_valid(),
 
                                     gtk::Label {
                                         set_label: &T.configuration_button_save(),
@@ -606,12 +2277,8 @@ impl AsyncComponent for ConfigurationView {
                                 gtk::Button {
                                     add_css_class: "suggested-action",
                                     connect_clicked => ConfigurationInput::Start,
-                                    #[track = "model.reward_address.changed_is_valid() || model.node_path.changed_is_valid() || model.changed_farms()"]
-                                    set_sensitive:
-                                        model.reward_address.is_valid
-                                            && model.node_path.is_valid
-                                            && !model.farms.is_empty()
-                                            && model.farms.iter().all(|maybe_farm| maybe_farm.map(FarmWidget::valid).unwrap_or_default()),
+                                    #[track = "model.changed_validity()"]
+                                    set_sensitive: model.is_valid(),
 
                                     gtk::Label {
                                         set_label: &T.configuration_button_start(),
@@ -656,14 +2323,107 @@ impl AsyncComponent for ConfigurationView {
 
         farms.guard().push_back(FarmWidgetInit::default());
 
+        let network_configuration = NetworkConfigurationWrapper::default();
+        let network_connections_kind_selector = SimpleComboBox::builder()
+            .launch({
+                let variants = NetworkConnectionsKind::all().to_vec();
+                let active_index = variants
+                    .iter()
+                    .position(|candidate| *candidate == network_configuration.connections_kind);
+
+                SimpleComboBox {
+                    variants,
+                    active_index,
+                }
+            })
+            .forward(
+                sender.input_sender(),
+                ConfigurationInput::NetworkConnectionsKindChanged,
+            );
+        let listen_on_kind_selector = SimpleComboBox::builder()
+            .launch({
+                let variants = ListenOnKind::variants(
+                    network_configuration.listen_on_kind == ListenOnKind::Custom,
+                );
+                let active_index = variants
+                    .iter()
+                    .position(|candidate| *candidate == network_configuration.listen_on_kind);
+
+                SimpleComboBox {
+                    variants,
+                    active_index,
+                }
+            })
+            .forward(
+                sender.input_sender(),
+                ConfigurationInput::ListenOnKindChanged,
+            );
+        let node_configuration = NodeConfigurationWrapper::default();
+        let rpc_listen_on = MaybeValid::yes(node_configuration.rpc.listen_on.to_string());
+        let node_sync_mode_selector = SimpleComboBox::builder()
+            .launch({
+                let variants = NodeSyncMode::all().to_vec();
+                let active_index = variants
+                    .iter()
+                    .position(|candidate| *candidate == node_configuration.sync_mode);
+
+                SimpleComboBox {
+                    variants,
+                    active_index,
+                }
+            })
+            .forward(
+                sender.input_sender(),
+                ConfigurationInput::NodeSyncModeChanged,
+            );
+        let node_pruning_kind_selector = SimpleComboBox::builder()
+            .launch({
+                let variants = NodePruningKind::all().to_vec();
+                let active_index = variants
+                    .iter()
+                    .position(|candidate| *candidate == node_configuration.pruning_kind);
+
+                SimpleComboBox {
+                    variants,
+                    active_index,
+                }
+            })
+            .forward(
+                sender.input_sender(),
+                ConfigurationInput::NodePruningKindChanged,
+            );
+
         let model = Self {
             reward_address: MaybeValid::no(String::new()),
             node_path: MaybeValid::no(PathBuf::new()),
             node_space_used: None,
             node_free_space: None,
             farms,
-            network_configuration: Default::default(),
+            network_configuration,
+            port_problem: None,
+            network_connections_kind_selector,
+            listen_on_kind_selector,
+            listen_on_address: MaybeValid::no(String::new()),
+            substrate_bootstrap_nodes: MaybeValid::yes(String::new()),
+            substrate_reserved_nodes: MaybeValid::yes(String::new()),
+            dsn_bootstrap_nodes: MaybeValid::yes(String::new()),
+            dsn_reserved_peers: MaybeValid::yes(String::new()),
+            substrate_external_addresses: MaybeValid::yes(String::new()),
+            dsn_external_addresses: MaybeValid::yes(String::new()),
+            daily_download_cap: MaybeValid::yes(String::new()),
+            daily_upload_cap: MaybeValid::yes(String::new()),
+            proxy_url: MaybeValid::yes(String::new()),
+            proxy_username: MaybeValid::yes(String::new()),
+            proxy_password: MaybeValid::yes(String::new()),
+            node_configuration,
+            node_sync_mode_selector,
+            node_pruning_kind_selector,
+            node_name: MaybeValid::yes(String::new()),
+            rpc_listen_on,
+            external_node_rpc_url: MaybeValid::yes(String::new()),
             reduce_plotting_cpu_load: false,
+            updates_configuration: UpdatesConfiguration::default(),
+            update_source: MaybeValid::yes(String::new()),
             pending_directory_selection: Default::default(),
             open_dialog,
             reconfiguration: false,
@@ -687,6 +2447,17 @@ impl AsyncComponent for ConfigurationView {
         self.reward_address.reset();
         self.node_path.reset();
         self.network_configuration.reset();
+        self.listen_on_address.reset();
+        self.substrate_bootstrap_nodes.reset();
+        self.substrate_reserved_nodes.reset();
+        self.dsn_bootstrap_nodes.reset();
+        self.dsn_reserved_peers.reset();
+        self.substrate_external_addresses.reset();
+        self.dsn_external_addresses.reset();
+        self.node_configuration.reset();
+        self.node_name.reset();
+        self.rpc_listen_on.reset();
+        self.external_node_rpc_url.reset();
 
         self.process_input(input, sender).await;
     }
@@ -753,16 +2524,212 @@ impl ConfigurationView {
             }
             ConfigurationInput::SubstratePortChanged(port) => {
                 self.network_configuration.substrate_port = port;
+                self.set_port_problem(None);
             }
             ConfigurationInput::SubspacePortChanged(port) => {
                 self.network_configuration.subspace_port = port;
+                self.set_port_problem(None);
+            }
+            ConfigurationInput::PickFreePorts => {
+                let listen_ip = self
+                    .network_configuration
+                    .listen_ip(&self.listen_on_address);
+
+                match NetworkConfiguration::pick_free_ports(listen_ip) {
+                    Some((substrate_port, subspace_port)) => {
+                        self.network_configuration
+                            .set_substrate_port(substrate_port);
+                        self.network_configuration.set_subspace_port(subspace_port);
+                        self.set_port_problem(None);
+                    }
+                    None => {
+                        error!("Failed to pick free ports");
+                    }
+                }
+            }
+            ConfigurationInput::NetworkConnectionsKindChanged(index) => {
+                let connections_kind = NetworkConnectionsKind::all()[index];
+                if connections_kind == NetworkConnectionsKind::Custom {
+                    // Start customization from limits of previously selected preset
+                    let connection_limits = self.network_configuration.connections().limits();
+                    self.network_configuration
+                        .set_connection_limits(connection_limits);
+                }
+                self.network_configuration
+                    .set_connections_kind(connections_kind);
+            }
+            ConfigurationInput::ConnectionLimitChanged { limit, value } => {
+                let connection_limits = self.network_configuration.get_mut_connection_limits();
+                match limit {
+                    ConnectionLimit::DsnIn => connection_limits.dsn_in_connections = value,
+                    ConnectionLimit::DsnOut => connection_limits.dsn_out_connections = value,
+                    ConnectionLimit::DsnPendingIn => {
+                        connection_limits.dsn_pending_in_connections = value
+                    }
+                    ConnectionLimit::DsnPendingOut => {
+                        connection_limits.dsn_pending_out_connections = value
+                    }
+                    ConnectionLimit::SubstrateIn => connection_limits.substrate_in_peers = value,
+                    ConnectionLimit::SubstrateOut => connection_limits.substrate_out_peers = value,
+                }
+            }
+            ConfigurationInput::PieceDownloadSettingChanged { setting, value } => {
+                let piece_download = self.network_configuration.get_mut_piece_download();
+                // Intervals are adjusted together such that initial interval never exceeds max
+                match setting {
+                    PieceDownloadSetting::MaxRetries => {
+                        piece_download.max_retries = value.try_into().unwrap_or(u16::MAX);
+                    }
+                    PieceDownloadSetting::InitialRetryInterval => {
+                        piece_download.initial_retry_interval = value;
+                        piece_download.max_retry_interval =
+                            piece_download.max_retry_interval.max(value);
+                    }
+                    PieceDownloadSetting::MaxRetryInterval => {
+                        piece_download.max_retry_interval = value;
+                        piece_download.initial_retry_interval =
+                            piece_download.initial_retry_interval.min(value);
+                    }
+                    PieceDownloadSetting::ConcurrencyMultiplier => {
+                        piece_download.concurrency_multiplier =
+                            value.try_into().unwrap_or(u16::MAX);
+                    }
+                }
+            }
+            ConfigurationInput::SubstrateBootstrapNodesChanged(multiaddrs) => {
+                // Force change so it is possible to render updates as the input changes
+                *self.substrate_bootstrap_nodes.get_mut_is_valid() =
+                    is_valid_peer_multiaddrs(&multiaddrs);
+                self.substrate_bootstrap_nodes.value = multiaddrs;
+            }
+            ConfigurationInput::SubstrateReservedNodesChanged(multiaddrs) => {
+                // Force change so it is possible to render updates as the input changes
+                *self.substrate_reserved_nodes.get_mut_is_valid() =
+                    is_valid_peer_multiaddrs(&multiaddrs);
+                self.substrate_reserved_nodes.value = multiaddrs;
+            }
+            ConfigurationInput::DsnBootstrapNodesChanged(multiaddrs) => {
+                // Force change so it is possible to render updates as the input changes
+                *self.dsn_bootstrap_nodes.get_mut_is_valid() =
+                    is_valid_peer_multiaddrs(&multiaddrs);
+                self.dsn_bootstrap_nodes.value = multiaddrs;
+            }
+            ConfigurationInput::DsnReservedPeersChanged(multiaddrs) => {
+                // Force change so it is possible to render updates as the input changes
+                *self.dsn_reserved_peers.get_mut_is_valid() = is_valid_peer_multiaddrs(&multiaddrs);
+                self.dsn_reserved_peers.value = multiaddrs;
+            }
+            ConfigurationInput::SubstrateExternalAddressesChanged(multiaddrs) => {
+                // Force change so it is possible to render updates as the input changes
+                *self.substrate_external_addresses.get_mut_is_valid() =
+                    is_valid_multiaddrs(&multiaddrs);
+                self.substrate_external_addresses.value = multiaddrs;
+            }
+            ConfigurationInput::DsnExternalAddressesChanged(multiaddrs) => {
+                // Force change so it is possible to render updates as the input changes
+                *self.dsn_external_addresses.get_mut_is_valid() = is_valid_multiaddrs(&multiaddrs);
+                self.dsn_external_addresses.value = multiaddrs;
+            }
+            ConfigurationInput::DailyDownloadCapChanged(cap) => {
+                let cap = cap.trim();
+                // Force change so it is possible to render updates as the input changes
+                *self.daily_download_cap.get_mut_is_valid() = is_valid_daily_cap(cap);
+                self.daily_download_cap.value = cap.to_string();
+            }
+            ConfigurationInput::DailyUploadCapChanged(cap) => {
+                let cap = cap.trim();
+                // Force change so it is possible to render updates as the input changes
+                *self.daily_upload_cap.get_mut_is_valid() = is_valid_daily_cap(cap);
+                self.daily_upload_cap.value = cap.to_string();
+            }
+            ConfigurationInput::ProxyUrlChanged(url) => {
+                let url = url.trim();
+                // Force change so it is possible to render updates as the input changes
+                *self.proxy_url.get_mut_is_valid() = is_valid_proxy_url(url);
+                self.proxy_url.value = url.to_string();
+            }
+            ConfigurationInput::ProxyUsernameChanged(username) => {
+                // Force change so it is possible to render updates as the input changes
+                *self.proxy_username.get_mut_is_valid() = true;
+                self.proxy_username.value = username;
+                *self.proxy_password.get_mut_is_valid() =
+                    is_valid_proxy_password(&self.proxy_password, &self.proxy_username);
+            }
+            ConfigurationInput::ProxyPasswordChanged(password) => {
+                // Force change so it is possible to render updates as the input changes
+                *self.proxy_password.get_mut_is_valid() =
+                    is_valid_proxy_password(&password, &self.proxy_username);
+                self.proxy_password.value = password;
+            }
+            ConfigurationInput::AllowPrivateAddressesChanged(allow_private_addresses) => {
+                self.network_configuration.allow_private_addresses = allow_private_addresses;
+            }
+            ConfigurationInput::ListenOnKindChanged(index) => {
+                self.network_configuration
+                    .set_listen_on_kind(ListenOnKind::all()[index]);
+            }
+            ConfigurationInput::ListenOnAddressChanged(address) => {
+                let address = address.trim();
+                // Force change so it is possible to render updates as the input changes
+                *self.listen_on_address.get_mut_is_valid() = address.parse::<IpAddr>().is_ok();
+                self.listen_on_address.value = address.to_string();
+            }
+            ConfigurationInput::NodeNameChanged(node_name) => {
+                let node_name = node_name.trim();
+                // Force change so it is possible to render updates as the input changes
+                *self.node_name.get_mut_is_valid() =
+                    node_name.is_empty() || NodeConfiguration::is_valid_node_name(node_name);
+                self.node_name.value = node_name.to_string();
+            }
+            ConfigurationInput::TelemetryChanged(telemetry) => {
+                self.node_configuration.telemetry = telemetry;
+            }
+            ConfigurationInput::NodeSyncModeChanged(index) => {
+                self.node_configuration
+                    .set_sync_mode(NodeSyncMode::all()[index]);
+            }
+            ConfigurationInput::NodePruningKindChanged(index) => {
+                self.node_configuration
+                    .set_pruning_kind(NodePruningKind::all()[index]);
+            }
+            ConfigurationInput::NodePruningBlocksChanged(blocks) => {
+                self.node_configuration.set_pruning_blocks(blocks);
+            }
+            ConfigurationInput::RpcEnabledChanged(enabled) => {
+                self.node_configuration.set_rpc_enabled(enabled);
+            }
+            ConfigurationInput::RpcListenOnChanged(listen_on) => {
+                let listen_on = listen_on.trim();
+                // Force change so it is possible to render updates as the input changes
+                *self.rpc_listen_on.get_mut_is_valid() = listen_on.parse::<SocketAddr>().is_ok();
+                self.rpc_listen_on.value = listen_on.to_string();
             }
-            ConfigurationInput::FasterNetworkingChanged(faster_networking) => {
-                self.network_configuration.faster_networking = faster_networking;
+            ConfigurationInput::RpcUnsafeMethodsChanged(unsafe_methods) => {
+                self.node_configuration.rpc_unsafe_methods = unsafe_methods;
+            }
+            ConfigurationInput::ExternalNodeRpcUrlChanged(url) => {
+                let url = url.trim();
+                // Force change so it is possible to render updates as the input changes
+                *self.external_node_rpc_url.get_mut_is_valid() =
+                    url.is_empty() || NodeConfiguration::is_valid_external_node_rpc_url(url);
+                self.external_node_rpc_url.value = url.to_string();
             }
             ConfigurationInput::ReducePlottingCpuLoadChanged(reduce_plotting_cpu_load) => {
                 self.reduce_plotting_cpu_load = reduce_plotting_cpu_load;
             }
+            ConfigurationInput::CheckUpdatesChanged(enabled) => {
+                // Tracked to update sensitivity of other update settings
+                self.get_mut_updates_configuration().enabled = enabled;
+            }
+            ConfigurationInput::IncludePrereleasesChanged(include_prereleases) => {
+                self.updates_configuration.include_prereleases = include_prereleases;
+            }
+            ConfigurationInput::UpdateSourceChanged(source) => {
+                let source = source.trim();
+                // Force change so it is possible to render updates as the input changes
+                *self.update_source.get_mut_is_valid() = is_valid_update_source(source);
+                self.update_source.value = source.to_string();
+            }
             ConfigurationInput::Delete(index) => {
                 let mut farms = self.get_mut_farms().guard();
                 farms.remove(index.current_index());
@@ -826,12 +2793,163 @@ impl ConfigurationView {
                         farms.push_back(FarmWidgetInit {
                             path: farm.path.clone(),
                             size: farm.size.clone(),
+                            free_space_warning_threshold: farm.free_space_warning_threshold.clone(),
                         });
                     }
                 }
                 self.set_reduce_plotting_cpu_load(raw_config.reduce_plotting_cpu_load());
+                let updates_configuration = raw_config.updates().clone();
+                let update_source = updates_configuration.source.clone().unwrap_or_default();
+                self.update_source = if is_valid_update_source(&update_source) {
+                    MaybeValid::yes(update_source)
+                } else {
+                    MaybeValid::no(update_source)
+                };
+                self.set_updates_configuration(updates_configuration);
                 self.network_configuration =
                     NetworkConfigurationWrapper::from(raw_config.network());
+                let (listen_on_kind, listen_on_ip) =
+                    NetworkConfigurationWrapper::listen_on_kind(raw_config.network());
+                // Custom option is only available when configuration has custom listen addresses
+                let listen_on_kind_variants =
+                    ListenOnKind::variants(listen_on_kind == ListenOnKind::Custom);
+                self.listen_on_kind_selector
+                    .emit(SimpleComboBoxMsg::UpdateData(SimpleComboBox {
+                        active_index: listen_on_kind_variants
+                            .iter()
+                            .position(|kind| *kind == listen_on_kind),
+                        variants: listen_on_kind_variants,
+                    }));
+                self.listen_on_address = match listen_on_ip {
+                    Some(ip) => MaybeValid::yes(ip.to_string()),
+                    None => MaybeValid::no(String::new()),
+                };
+                if let Some(index) = NetworkConnectionsKind::all()
+                    .iter()
+                    .position(|kind| *kind == self.network_configuration.connections_kind)
+                {
+                    self.network_connections_kind_selector
+                        .emit(SimpleComboBoxMsg::SetActiveIdx(index));
+                }
+                let substrate_bootstrap_nodes =
+                    raw_config.network().substrate_bootstrap_nodes.join(", ");
+                self.substrate_bootstrap_nodes =
+                    if is_valid_peer_multiaddrs(&substrate_bootstrap_nodes) {
+                        MaybeValid::yes(substrate_bootstrap_nodes)
+                    } else {
+                        MaybeValid::no(substrate_bootstrap_nodes)
+                    };
+                let substrate_reserved_nodes =
+                    raw_config.network().substrate_reserved_nodes.join(", ");
+                self.substrate_reserved_nodes =
+                    if is_valid_peer_multiaddrs(&substrate_reserved_nodes) {
+                        MaybeValid::yes(substrate_reserved_nodes)
+                    } else {
+                        MaybeValid::no(substrate_reserved_nodes)
+                    };
+                let dsn_bootstrap_nodes = raw_config.network().dsn_bootstrap_nodes.join(", ");
+                self.dsn_bootstrap_nodes = if is_valid_peer_multiaddrs(&dsn_bootstrap_nodes) {
+                    MaybeValid::yes(dsn_bootstrap_nodes)
+                } else {
+                    MaybeValid::no(dsn_bootstrap_nodes)
+                };
+                let dsn_reserved_peers = raw_config.network().dsn_reserved_peers.join(", ");
+                self.dsn_reserved_peers = if is_valid_peer_multiaddrs(&dsn_reserved_peers) {
+                    MaybeValid::yes(dsn_reserved_peers)
+                } else {
+                    MaybeValid::no(dsn_reserved_peers)
+                };
+                let substrate_external_addresses =
+                    raw_config.network().substrate_external_addresses.join(", ");
+                self.substrate_external_addresses =
+                    if is_valid_multiaddrs(&substrate_external_addresses) {
+                        MaybeValid::yes(substrate_external_addresses)
+                    } else {
+                        MaybeValid::no(substrate_external_addresses)
+                    };
+                let dsn_external_addresses = raw_config.network().dsn_external_addresses.join(", ");
+                self.dsn_external_addresses = if is_valid_multiaddrs(&dsn_external_addresses) {
+                    MaybeValid::yes(dsn_external_addresses)
+                } else {
+                    MaybeValid::no(dsn_external_addresses)
+                };
+                let daily_download_cap = raw_config
+                    .network()
+                    .daily_download_cap
+                    .clone()
+                    .unwrap_or_default();
+                self.daily_download_cap = if is_valid_daily_cap(&daily_download_cap) {
+                    MaybeValid::yes(daily_download_cap)
+                } else {
+                    MaybeValid::no(daily_download_cap)
+                };
+                let daily_upload_cap = raw_config
+                    .network()
+                    .daily_upload_cap
+                    .clone()
+                    .unwrap_or_default();
+                self.daily_upload_cap = if is_valid_daily_cap(&daily_upload_cap) {
+                    MaybeValid::yes(daily_upload_cap)
+                } else {
+                    MaybeValid::no(daily_upload_cap)
+                };
+                let proxy = raw_config.network().proxy.clone();
+                let proxy_url = proxy
+                    .as_ref()
+                    .map(|proxy| proxy.url.clone())
+                    .unwrap_or_default();
+                self.proxy_url = if is_valid_proxy_url(&proxy_url) {
+                    MaybeValid::yes(proxy_url)
+                } else {
+                    MaybeValid::no(proxy_url)
+                };
+                let proxy_username = proxy
+                    .as_ref()
+                    .and_then(|proxy| proxy.username.clone())
+                    .unwrap_or_default();
+                let proxy_password = proxy.and_then(|proxy| proxy.password).unwrap_or_default();
+                self.proxy_password = if is_valid_proxy_password(&proxy_password, &proxy_username) {
+                    MaybeValid::yes(proxy_password)
+                } else {
+                    MaybeValid::no(proxy_password)
+                };
+                self.proxy_username = MaybeValid::yes(proxy_username);
+                self.node_configuration = NodeConfigurationWrapper::from(raw_config.node());
+                if let Some(index) = NodeSyncMode::all()
+                    .iter()
+                    .position(|sync_mode| *sync_mode == self.node_configuration.sync_mode)
+                {
+                    self.node_sync_mode_selector
+                        .emit(SimpleComboBoxMsg::SetActiveIdx(index));
+                }
+                if let Some(index) = NodePruningKind::all()
+                    .iter()
+                    .position(|kind| *kind == self.node_configuration.pruning_kind)
+                {
+                    self.node_pruning_kind_selector
+                        .emit(SimpleComboBoxMsg::SetActiveIdx(index));
+                }
+                let node_name = raw_config.node().name.clone().unwrap_or_default();
+                self.node_name =
+                    if node_name.is_empty() || NodeConfiguration::is_valid_node_name(&node_name) {
+                        MaybeValid::yes(node_name)
+                    } else {
+                        MaybeValid::no(node_name)
+                    };
+                self.rpc_listen_on = MaybeValid::yes(raw_config.node().rpc.listen_on.to_string());
+                let external_node_rpc_url = raw_config
+                    .node()
+                    .external_node_rpc_url
+                    .clone()
+                    .unwrap_or_default();
+                self.external_node_rpc_url = if external_node_rpc_url.is_empty()
+                    || NodeConfiguration::is_valid_external_node_rpc_url(&external_node_rpc_url)
+                {
+                    MaybeValid::yes(external_node_rpc_url)
+                } else {
+                    MaybeValid::no(external_node_rpc_url)
+                };
+                self.set_port_problem(None);
                 self.reconfiguration = reconfiguration;
             }
             ConfigurationInput::Help => {
@@ -842,7 +2960,9 @@ impl ConfigurationView {
                 }
             }
             ConfigurationInput::Start => {
-                if let Some(raw_config) = self.create_raw_config()
+                self.set_port_problem(self.find_port_problem());
+                if self.port_problem.is_none()
+                    && let Some(raw_config) = self.create_raw_config()
                     && sender
                         .output(ConfigurationOutput::StartWithNewConfig(raw_config))
                         .is_err()
@@ -861,7 +2981,9 @@ impl ConfigurationView {
                 }
             }
             ConfigurationInput::Save => {
-                if let Some(raw_config) = self.create_raw_config()
+                self.set_port_problem(self.find_port_problem());
+                if self.port_problem.is_none()
+                    && let Some(raw_config) = self.create_raw_config()
                     && sender
                         .output(ConfigurationOutput::ConfigUpdate(raw_config))
                         .is_err()
@@ -896,8 +3018,109 @@ impl ConfigurationView {
         }
     }
 
+    /// Find problem with configured ports, like port that is already in use by another
+    /// application.
+    ///
+    /// Ports that are used by running instance of the app are not checked for availability during
+    /// reconfiguration.
+    fn find_port_problem(&self) -> Option<PortProblem> {
+        let network_configuration = &self.network_configuration;
+        // Invalid listen address is reported separately
+        let (substrate_listen_on, dsn_listen_on) =
+            network_configuration.listen_on(&self.listen_on_address)?;
+        // Substrate port is not used when farming with external node
+        let substrate_addresses = if self.external_node_rpc_url.is_empty() {
+            NetworkConfiguration::tcp_listen_addresses(
+                &substrate_listen_on,
+                network_configuration.substrate_port,
+            )
+        } else {
+            Vec::new()
+        };
+        let dsn_addresses = NetworkConfiguration::tcp_listen_addresses(
+            &dsn_listen_on,
+            network_configuration.subspace_port,
+        );
+
+        if let Some(port) =
+            NetworkConfiguration::find_duplicate_port(&substrate_addresses, &dsn_addresses)
+        {
+            return Some(PortProblem::Duplicate(port));
+        }
+
+        substrate_addresses
+            .into_iter()
+            .map(|address| (address, network_configuration.original_substrate_port))
+            .chain(
+                dsn_addresses
+                    .into_iter()
+                    .map(|address| (address, network_configuration.original_subspace_port)),
+            )
+            .filter(|(address, original_port)| {
+                !(self.reconfiguration && address.port() == *original_port)
+            })
+            .find(|(address, _original_port)| !NetworkConfiguration::is_address_available(*address))
+            .map(|(address, _original_port)| PortProblem::InUse(address.port()))
+    }
+
+    /// Whether validity of any of the inputs might have changed
+    fn changed_validity(&self) -> bool {
+        self.reward_address.changed_is_valid()
+            || self.node_path.changed_is_valid()
+            || self.changed_farms()
+            || self.node_configuration.changed_rpc_enabled()
+            || self.rpc_listen_on.changed_is_valid()
+            || self.external_node_rpc_url.changed_is_valid()
+            || self.node_name.changed_is_valid()
+            || self.substrate_bootstrap_nodes.changed_is_valid()
+            || self.substrate_reserved_nodes.changed_is_valid()
+            || self.dsn_bootstrap_nodes.changed_is_valid()
+            || self.dsn_reserved_peers.changed_is_valid()
+            || self.substrate_external_addresses.changed_is_valid()
+            || self.dsn_external_addresses.changed_is_valid()
+            || self.daily_download_cap.changed_is_valid()
+            || self.daily_upload_cap.changed_is_valid()
+            || self.proxy_url.changed_is_valid()
+            || self.proxy_password.changed_is_valid()
+            || self.update_source.changed_is_valid()
+            || self.network_configuration.changed_listen_on_kind()
+            || self.listen_on_address.changed_is_valid()
+    }
+
+    /// Whether all inputs are valid and configuration can be used
+    fn is_valid(&self) -> bool {
+        self.reward_address.is_valid
+            && self.node_path.is_valid
+            && (!self.node_configuration.rpc_enabled || self.rpc_listen_on.is_valid)
+            && self.external_node_rpc_url.is_valid
+            // Neither node nor farmer would run otherwise
+            && (self.external_node_rpc_url.is_empty() || !self.farms.is_empty())
+            && self.node_name.is_valid
+            && self.substrate_bootstrap_nodes.is_valid
+            && self.substrate_reserved_nodes.is_valid
+            && self.dsn_bootstrap_nodes.is_valid
+            && self.dsn_reserved_peers.is_valid
+            && self.substrate_external_addresses.is_valid
+            && self.dsn_external_addresses.is_valid
+            && self.daily_download_cap.is_valid
+            && self.daily_upload_cap.is_valid
+            && self.proxy_url.is_valid
+            && self.proxy_password.is_valid
+            && self.update_source.is_valid
+            && (self.network_configuration.listen_on_kind != ListenOnKind::Specific
+                || self.listen_on_address.is_valid)
+            && self
+                .farms
+                .iter()
+                .all(|maybe_farm| maybe_farm.map(FarmWidget::valid).unwrap_or_default())
+    }
+
     /// Create raw config from own state
     fn create_raw_config(&self) -> Option<RawConfig> {
+        let (substrate_listen_on, dsn_listen_on) = self
+            .network_configuration
+            .listen_on(&self.listen_on_address)?;
+
         Some(RawConfig::V0 {
             reward_address: String::clone(&self.reward_address),
             node_path: PathBuf::clone(&self.node_path),
@@ -910,7 +3133,44 @@ impl ConfigurationView {
             network: NetworkConfiguration {
                 substrate_port: self.network_configuration.substrate_port,
                 subspace_port: self.network_configuration.subspace_port,
-                faster_networking: self.network_configuration.faster_networking,
+                faster_networking: false,
+                connections: self.network_configuration.connections(),
+                substrate_bootstrap_nodes: split_multiaddrs(&self.substrate_bootstrap_nodes),
+                substrate_reserved_nodes: split_multiaddrs(&self.substrate_reserved_nodes),
+                dsn_bootstrap_nodes: split_multiaddrs(&self.dsn_bootstrap_nodes),
+                dsn_reserved_peers: split_multiaddrs(&self.dsn_reserved_peers),
+                substrate_external_addresses: split_multiaddrs(&self.substrate_external_addresses),
+                dsn_external_addresses: split_multiaddrs(&self.dsn_external_addresses),
+                allow_private_addresses: self.network_configuration.allow_private_addresses,
+                substrate_listen_on,
+                dsn_listen_on,
+                daily_download_cap: (!self.daily_download_cap.is_empty())
+                    .then(|| String::clone(&self.daily_download_cap)),
+                daily_upload_cap: (!self.daily_upload_cap.is_empty())
+                    .then(|| String::clone(&self.daily_upload_cap)),
+                piece_download: self.network_configuration.piece_download,
+                proxy: (!self.proxy_url.is_empty()).then(|| ProxyConfiguration {
+                    url: String::clone(&self.proxy_url),
+                    username: (!self.proxy_username.is_empty())
+                        .then(|| String::clone(&self.proxy_username)),
+                    password: (!self.proxy_password.is_empty())
+                        .then(|| String::clone(&self.proxy_password)),
+                }),
+            },
+            node: NodeConfiguration {
+                name: (!self.node_name.is_empty()).then(|| String::clone(&self.node_name)),
+                telemetry: self.node_configuration.telemetry,
+                sync_mode: self.node_configuration.sync_mode,
+                pruning: self.node_configuration.pruning(),
+                rpc: self.node_configuration.rpc(&self.rpc_listen_on)?,
+                external_node_rpc_url: (!self.external_node_rpc_url.is_empty())
+                    .then(|| String::clone(&self.external_node_rpc_url)),
+            },
+            updates: UpdatesConfiguration {
+                enabled: self.updates_configuration.enabled,
+                include_prereleases: self.updates_configuration.include_prereleases,
+                source: (!self.update_source.is_empty())
+                    .then(|| String::clone(&self.update_source)),
             },
         })
     }
@@ -1,4 +1,4 @@
-use crate::backend::config::{Farm, MIN_FARM_SIZE};
+use crate::backend::config::{DEFAULT_FARM_FREE_SPACE_WARNING_THRESHOLD, Farm, MIN_FARM_SIZE};
 use crate::frontend::configuration::MaybeValid;
 use crate::icon_names::shipped as icon_names;
 use bytesize::ByteSize;
@@ -30,6 +30,14 @@ fn is_free_percentage_size_valid(size: &str) -> bool {
             .unwrap_or_default()
 }
 
+/// Empty threshold means default is used
+fn is_free_space_warning_threshold_valid(threshold: &str) -> bool {
+    threshold.is_empty()
+        || ByteSize::from_str(threshold)
+            .map(|threshold| threshold.as_u64() > 0)
+            .unwrap_or_default()
+}
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 enum SizeKind {
     Fixed,
@@ -55,6 +63,7 @@ impl SizeKind {
 pub(super) struct FarmWidgetInit {
     pub(super) path: PathBuf,
     pub(super) size: String,
+    pub(super) free_space_warning_threshold: Option<String>,
 }
 
 impl Default for FarmWidgetInit {
@@ -62,6 +71,7 @@ impl Default for FarmWidgetInit {
         Self {
             path: PathBuf::new(),
             size: String::new(),
+            free_space_warning_threshold: None,
         }
     }
 }
@@ -72,6 +82,7 @@ pub(super) enum FarmWidgetInput {
     SizeKindChanged(usize),
     FarmFixedSizeChanged(String),
     FarmFreePercentageSizeChanged(String),
+    FreeSpaceWarningThresholdChanged(String),
 }
 
 #[derive(Debug)]
@@ -97,6 +108,9 @@ pub(super) struct FarmWidget {
     /// 0.0%..=100.0%
     #[do_not_track]
     free_percentage_size: MaybeValid<String>,
+    /// Empty when default is used
+    #[do_not_track]
+    free_space_warning_threshold: MaybeValid<String>,
 }
 
 #[relm4::factory(pub(super) async)]
@@ -248,6 +262,38 @@ impl AsyncFactoryComponent for FarmWidget {
                         },
                     },
 
+                    gtk::Entry {
+                        connect_activate[sender] => move |entry| {
+                            sender.input(FarmWidgetInput::FreeSpaceWarningThresholdChanged(entry.text().into()));
+                        },
+                        connect_changed[sender] => move |entry| {
+                            sender.input(FarmWidgetInput::FreeSpaceWarningThresholdChanged(entry.text().into()));
+                        },
+                        #[track = "self.free_space_warning_threshold.changed_is_valid()"]
+                        set_css_classes: if self.free_space_warning_threshold.is_valid {
+                            &["valid-input"]
+                        } else {
+                            &["invalid-input"]
+                        },
+                        set_placeholder_text: Some(
+                            &T.configuration_farm_free_space_warning_threshold_placeholder(
+                                bytesize::to_string(DEFAULT_FARM_FREE_SPACE_WARNING_THRESHOLD, true),
+                            ),
+                        ),
+                        set_primary_icon_name: Some(icon_names::WARNING_OUTLINE),
+                        set_primary_icon_activatable: false,
+                        set_primary_icon_sensitive: false,
+                        #[track = "self.free_space_warning_threshold.changed_is_valid()"]
+                        set_secondary_icon_name: self.free_space_warning_threshold.icon(),
+                        set_secondary_icon_activatable: false,
+                        set_secondary_icon_sensitive: false,
+                        #[track = "self.free_space_warning_threshold.changed_value()"]
+                        set_text: self.free_space_warning_threshold.as_str(),
+                        set_tooltip_markup: Some(
+                            &T.configuration_farm_free_space_warning_threshold_tooltip()
+                        ),
+                    },
+
                     gtk::Button {
                         connect_clicked[sender, index] => move |_| {
                             if sender.output(FarmWidgetOutput::Delete(index.clone())).is_err() {
@@ -297,6 +343,14 @@ impl AsyncFactoryComponent for FarmWidget {
             )
         };
 
+        let free_space_warning_threshold = value.free_space_warning_threshold.unwrap_or_default();
+        let free_space_warning_threshold =
+            if is_free_space_warning_threshold_valid(&free_space_warning_threshold) {
+                MaybeValid::yes(free_space_warning_threshold)
+            } else {
+                MaybeValid::no(free_space_warning_threshold)
+            };
+
         let size_kind_selector = SimpleComboBox::builder()
             .launch({
                 let variants = SizeKind::all().to_vec();
@@ -322,6 +376,7 @@ impl AsyncFactoryComponent for FarmWidget {
             size_kind_selector,
             fixed_size,
             free_percentage_size,
+            free_space_warning_threshold,
             tracker: u8::MAX,
         };
 
@@ -340,6 +395,7 @@ impl AsyncFactoryComponent for FarmWidget {
         self.path.reset();
         self.fixed_size.reset();
         self.free_percentage_size.reset();
+        self.free_space_warning_threshold.reset();
 
         let was_valid = self.valid();
 
@@ -361,6 +417,11 @@ impl AsyncFactoryComponent for FarmWidget {
                     .set_is_valid(is_free_percentage_size_valid(&size));
                 self.free_percentage_size.value = size;
             }
+            FarmWidgetInput::FreeSpaceWarningThresholdChanged(threshold) => {
+                self.free_space_warning_threshold
+                    .set_is_valid(is_free_space_warning_threshold_valid(&threshold));
+                self.free_space_warning_threshold.value = threshold;
+            }
         }
 
         let is_valid = self.valid();
@@ -376,7 +437,7 @@ impl AsyncFactoryComponent for FarmWidget {
 
 impl FarmWidget {
     pub(super) fn valid(&self) -> bool {
-        if !self.path.is_valid {
+        if !(self.path.is_valid && self.free_space_warning_threshold.is_valid) {
             return false;
         }
 
@@ -393,6 +454,8 @@ impl FarmWidget {
                 SizeKind::Fixed => &self.fixed_size,
                 SizeKind::FreePercentage => &self.free_percentage_size,
             }),
+            free_space_warning_threshold: Some(self.free_space_warning_threshold.value.clone())
+                .filter(|threshold| !threshold.is_empty()),
         }
     }
 }
@@ -560,7 +560,7 @@ impl AsyncComponent for NodeMigrationDialog {
     }
 }
 
-use crate::frontend::NODE_DATA_DIRS;
+use crate::frontend::{NODE_DATA_DIRS, NODE_DATA_FILES};
 
 /// Check if the source directory contains any files/directories other than known node data
 async fn check_for_non_node_data(path: &std::path::Path) -> bool {
@@ -570,7 +570,9 @@ async fn check_for_non_node_data(path: &std::path::Path) -> bool {
             for entry in entries.flatten() {
                 let file_name = entry.file_name();
                 let name = file_name.to_string_lossy();
-                if !NODE_DATA_DIRS.contains(&name.as_ref()) {
+                if !(NODE_DATA_DIRS.contains(&name.as_ref())
+                    || NODE_DATA_FILES.contains(&name.as_ref()))
+                {
                     return true;
                 }
             }
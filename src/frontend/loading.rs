@@ -1,7 +1,10 @@
-use crate::backend::LoadingStep;
+use crate::backend::farmer::{FarmInitializationProgress, FarmInitializationStage};
+use crate::backend::{FarmIndex, LoadingStep};
 use crate::frontend::translations::{AsDefaultStr, T};
 use gtk::prelude::*;
 use relm4::prelude::*;
+use std::collections::BTreeMap;
+use std::time::Duration;
 
 #[derive(Debug)]
 pub enum LoadingInput {
@@ -15,6 +18,11 @@ pub struct LoadingView {
     message: String,
     /// Progress in %: 0.0..=100.0
     progress: f32,
+    /// Whether there is ongoing work with unknown progress
+    in_progress_indeterminate: bool,
+    /// Farms that are being initialized right now
+    #[do_not_track]
+    farms_initialization_progress: BTreeMap<FarmIndex, FarmInitializationProgress>,
 }
 
 #[relm4::component(pub)]
@@ -43,9 +51,22 @@ impl Component for LoadingView {
                 set_fraction: f64::from(model.progress / 100.0),
             },
 
-            gtk::Label {
-                #[track = "model.changed_message()"]
-                set_markup: &format!("<span color=\"grey\">{}</span>", &model.message),
+            gtk::Box {
+                set_halign: gtk::Align::Center,
+                set_spacing: 10,
+
+                gtk::Spinner {
+                    set_valign: gtk::Align::Start,
+                    #[track = "model.changed_in_progress_indeterminate()"]
+                    set_spinning: model.in_progress_indeterminate,
+                    #[track = "model.changed_in_progress_indeterminate()"]
+                    set_visible: model.in_progress_indeterminate,
+                },
+
+                gtk::Label {
+                    #[track = "model.changed_message()"]
+                    set_markup: &format!("<span color=\"grey\">{}</span>", &model.message),
+                },
             },
         }
     }
@@ -59,6 +80,8 @@ impl Component for LoadingView {
             title: String::new(),
             message: String::new(),
             progress: 0.0,
+            in_progress_indeterminate: false,
+            farms_initialization_progress: BTreeMap::new(),
             tracker: u8::MAX,
         };
 
@@ -91,6 +114,7 @@ impl LoadingView {
                     }
                     LoadingStep::CheckingNodePath
                     | LoadingStep::CreatingNodePath
+                    | LoadingStep::ResettingNodeDatabase
                     | LoadingStep::NodePathReady
                     | LoadingStep::PreparingNetworkingStack
                     | LoadingStep::ReadingNetworkKeypair
@@ -101,11 +125,15 @@ impl LoadingView {
                         T.loading_networking_stack_title()
                     }
                     LoadingStep::CreatingConsensusNode
-                    | LoadingStep::ConsensusNodeCreatedSuccessfully => {
+                    | LoadingStep::ConsensusNodeCreatedSuccessfully
+                    | LoadingStep::ConnectingToExternalNode { .. }
+                    | LoadingStep::ConnectedToExternalNodeSuccessfully => {
                         T.loading_consensus_node_title()
                     }
                     LoadingStep::InitializingFarms { .. }
+                    | LoadingStep::FarmInitializationProgress { .. }
                     | LoadingStep::FarmInitialized { .. }
+                    | LoadingStep::CollectingPlottedPieces { .. }
                     | LoadingStep::FarmerCreatedSuccessfully => T.loading_farmer_title(),
                     LoadingStep::WipingFarm { .. } | LoadingStep::WipedFarmsSuccessfully => {
                         T.loading_wiping_farmer_data_title()
@@ -115,7 +143,19 @@ impl LoadingView {
                     }
                 };
                 self.set_title(title.to_string());
-                self.set_progress(step.progress());
+                if let LoadingStep::FarmInitializationProgress { .. }
+                | LoadingStep::FarmInitialized { .. } = step
+                {
+                    // Farms are initialized concurrently, don't let progress jump back and forth
+                    self.set_progress(self.progress.max(step.progress()));
+                } else {
+                    self.set_progress(step.progress());
+                }
+                let initializing_farms = matches!(
+                    step,
+                    LoadingStep::FarmInitializationProgress { .. }
+                        | LoadingStep::FarmInitialized { .. }
+                );
                 let message = match step {
                     LoadingStep::LoadingConfiguration => {
                         T.loading_configuration_step_loading().to_string()
@@ -152,6 +192,9 @@ impl LoadingView {
                     LoadingStep::CreatingNodePath => T
                         .loading_networking_stack_step_creating_node_path()
                         .to_string(),
+                    LoadingStep::ResettingNodeDatabase => T
+                        .loading_networking_stack_step_resetting_node_database()
+                        .to_string(),
                     LoadingStep::NodePathReady => T
                         .loading_networking_stack_step_node_path_ready()
                         .to_string(),
@@ -179,15 +222,55 @@ impl LoadingView {
                     LoadingStep::ConsensusNodeCreatedSuccessfully => T
                         .loading_consensus_node_step_created_successfully()
                         .to_string(),
-                    LoadingStep::InitializingFarms { farms_total } => T
-                        .loading_farmer_step_initializing(0, farms_total)
+                    LoadingStep::ConnectingToExternalNode { url } => T
+                        .loading_consensus_node_step_connecting_to_external_node(url)
+                        .to_string(),
+                    LoadingStep::ConnectedToExternalNodeSuccessfully => T
+                        .loading_consensus_node_step_connected_to_external_node_successfully()
                         .to_string(),
+                    LoadingStep::InitializingFarms { farms_total } => {
+                        self.farms_initialization_progress.clear();
+
+                        T.loading_farmer_step_initializing(0, farms_total)
+                            .to_string()
+                    }
+                    LoadingStep::FarmInitializationProgress {
+                        farm_index,
+                        farms_initialized,
+                        farms_total,
+                        progress,
+                    } => {
+                        self.farms_initialization_progress
+                            .insert(farm_index, progress);
+
+                        self.farms_initialization_message(farms_initialized, farms_total)
+                    }
                     LoadingStep::FarmInitialized {
                         farm_index,
+                        farms_initialized,
                         farms_total,
-                    } => T
-                        .loading_farmer_step_initializing(u16::from(farm_index) + 1, farms_total)
-                        .to_string(),
+                    } => {
+                        self.farms_initialization_progress.remove(&farm_index);
+
+                        self.farms_initialization_message(farms_initialized, farms_total)
+                    }
+                    LoadingStep::CollectingPlottedPieces { farms } => {
+                        let mut message = T
+                            .loading_farmer_step_collecting_plotted_pieces()
+                            .to_string();
+                        for (farm_index, farm) in farms.into_iter().enumerate() {
+                            message.push('\n');
+                            message.push_str(
+                                T.loading_farmer_step_collecting_plotted_pieces_farm(
+                                    farm_index + 1,
+                                    farm.collected_sectors_count,
+                                    farm.plotted_sectors_count,
+                                )
+                                .as_str(),
+                            );
+                        }
+                        message
+                    }
                     LoadingStep::FarmerCreatedSuccessfully => {
                         T.loading_farmer_step_created_successfully().to_string()
                     }
@@ -213,7 +296,80 @@ impl LoadingView {
                     }
                 };
                 self.set_message(message);
+                // Only space allocation progress is known, the rest of farm initialization is not
+                self.set_in_progress_indeterminate(
+                    initializing_farms
+                        && self.farms_initialization_progress.values().any(|progress| {
+                            !matches!(progress.stage, FarmInitializationStage::Allocating { .. })
+                        }),
+                );
             }
         }
     }
+
+    fn farms_initialization_message(&self, farms_initialized: u16, farms_total: u16) -> String {
+        let mut message = T
+            .loading_farmer_step_initializing(farms_initialized, farms_total)
+            .to_string();
+
+        for (farm_index, progress) in &self.farms_initialization_progress {
+            let farm_index = u16::from(*farm_index) + 1;
+            let time_elapsed = format_duration(progress.elapsed);
+
+            message.push('\n');
+            message.push_str(
+                match progress.stage {
+                    FarmInitializationStage::ReadingMetadata => T
+                        .loading_farmer_step_initializing_farm_reading_metadata(
+                            farm_index,
+                            time_elapsed,
+                        ),
+                    FarmInitializationStage::Allocating {
+                        allocated_bytes,
+                        total_bytes,
+                    } => {
+                        let space_allocated = bytesize::to_string(allocated_bytes, true);
+                        let space_total = bytesize::to_string(total_bytes, true);
+
+                        if let Some(eta) = progress.eta {
+                            T.loading_farmer_step_initializing_farm_allocating(
+                                farm_index,
+                                space_allocated,
+                                space_total,
+                                time_elapsed,
+                                format_duration(eta),
+                            )
+                        } else {
+                            T.loading_farmer_step_initializing_farm_allocating_no_eta(
+                                farm_index,
+                                space_allocated,
+                                space_total,
+                                time_elapsed,
+                            )
+                        }
+                    }
+                    FarmInitializationStage::InitializingCaches => T
+                        .loading_farmer_step_initializing_farm_initializing_caches(
+                            farm_index,
+                            time_elapsed,
+                        ),
+                }
+                .as_str(),
+            );
+        }
+
+        message
+    }
+}
+
+/// Formats duration as `HH:MM:SS`
+fn format_duration(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+
+    format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
 }
@@ -257,7 +257,7 @@ impl Component for MigrationView {
     }
 }
 
-use super::NODE_DATA_DIRS;
+use super::{NODE_DATA_DIRS, NODE_DATA_FILES};
 
 impl MigrationView {
     /// Delete only known node data from the given path, leaving other files untouched.
@@ -268,6 +268,12 @@ impl MigrationView {
                 tokio::fs::remove_dir_all(&dir_path).await?;
             }
         }
+        for file_name in NODE_DATA_FILES {
+            let file_path = path.join(file_name);
+            if tokio::fs::try_exists(&file_path).await.unwrap_or(false) {
+                tokio::fs::remove_file(&file_path).await?;
+            }
+        }
         Ok(())
     }
 
@@ -534,6 +540,18 @@ impl MigrationView {
             }
         }
 
+        // Files are tiny and don't contribute to progress
+        for file_name in NODE_DATA_FILES {
+            let src_file = source.join(file_name);
+            if src_file.exists()
+                && let Err(e) = std::fs::copy(&src_file, destination.join(file_name))
+            {
+                copied_bytes.store(total_size, Ordering::Relaxed);
+                let _ = progress_handle.join();
+                return Err(e);
+            }
+        }
+
         // Signal completion to the progress thread
         copied_bytes.store(total_size, Ordering::Relaxed);
         let _ = progress_handle.join();
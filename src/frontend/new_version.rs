@@ -1,10 +1,22 @@
+pub mod update;
+
+use crate::backend::config::{ProxyConfiguration, UpdatesConfiguration};
+use crate::frontend::new_version::update::{
+    DownloadedUpdate, InstallationKind, ReleaseAsset, UpdateAssets, UpdateError,
+    download_and_verify, stage_update,
+};
 use crate::frontend::translations::{AsDefaultStr, T};
+use futures::channel::mpsc;
+use futures::{FutureExt, StreamExt, select};
+use gtk::gio;
 use gtk::prelude::*;
 use relm4::prelude::*;
 use relm4::{Sender, ShutdownReceiver};
 use reqwest::Client;
 use semver::Version;
 use serde::Deserialize;
+use std::path::Path;
+use std::sync::Arc;
 use std::time::Duration;
 use tracing::{debug, warn};
 
@@ -12,56 +24,150 @@ use tracing::{debug, warn};
 const NEW_VERSION_CHECK_INTERVAL: Duration = Duration::from_secs(3600);
 /// Retry failed check every 5 minutes
 const NEW_VERSION_CHECK_RETRY_INTERVAL: Duration = Duration::from_secs(5 * 60);
+/// Number of most recent GitHub releases to look through when pre-releases are included
+const RECENT_RELEASES_LIMIT: usize = 20;
 
+/// Release in GitHub releases API format
 #[derive(Debug, Deserialize)]
-struct LatestRelease {
+struct GitHubRelease {
     tag_name: String,
+    #[serde(default)]
+    body: Option<String>,
+    #[serde(default)]
+    html_url: Option<String>,
+    #[serde(default)]
+    prerelease: bool,
+    #[serde(default)]
+    draft: bool,
+    #[serde(default)]
+    assets: Vec<GitHubAsset>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubAsset {
+    name: String,
+    browser_download_url: String,
+}
+
+/// Either `releases/latest` or `releases` response
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum GitHubReleases {
+    Single(GitHubRelease),
+    List(Vec<GitHubRelease>),
+}
+
+impl GitHubReleases {
+    fn into_vec(self) -> Vec<GitHubRelease> {
+        match self {
+            Self::Single(release) => vec![release],
+            Self::List(releases) => releases,
+        }
+    }
+}
+
+/// New release that is available
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct NewRelease {
+    version: Version,
+    /// Release notes (changelog), might be empty
+    notes: String,
+    /// Release page, releases page of the repository is used if not provided by the source
+    url: Option<String>,
+    /// Assets for in-app update, if supported for this installation
+    update_assets: Option<UpdateAssets>,
+}
+
+#[derive(Debug, Default, Clone)]
+enum UpdateState {
+    #[default]
+    None,
+    Downloading,
+    Downloaded(Arc<DownloadedUpdate>),
+    Installing,
+}
+
+#[derive(Debug, Clone)]
+struct CheckSettings {
+    client: Client,
+    updates: UpdatesConfiguration,
+}
+
+#[derive(Debug)]
+pub enum NewVersionInput {
+    /// Configure how checks are done, checks start after the first configuration
+    Configure {
+        proxy: Option<ProxyConfiguration>,
+        updates: UpdatesConfiguration,
+    },
+    /// New version button was clicked
+    Activate,
+    DownloadUpdate,
+    InstallUpdate,
+}
+
+#[derive(Debug)]
+pub enum NewVersionOutput {
+    /// Update was staged, application needs to restart
+    Restart,
+    UpdateFailed(String),
 }
 
 #[derive(Debug)]
 pub enum NewVersionCommandOutput {
-    NewVersion(Version),
+    NewVersion(NewRelease),
+    UpToDate,
+    UpdateDownloaded(Result<DownloadedUpdate, UpdateError>),
+    UpdateStaged(Result<(), UpdateError>),
 }
 
 #[tracker::track]
 #[derive(Debug)]
 pub struct NewVersion {
-    new_version: Option<Version>,
+    new_version: Option<NewRelease>,
+    #[no_eq]
+    update_state: UpdateState,
+    /// Client for update downloads, replaced when proxy configuration changes
+    #[do_not_track]
+    client: Client,
+    /// Sender of settings to the checking loop, created on the first configuration
+    #[do_not_track]
+    settings_sender: Option<mpsc::UnboundedSender<CheckSettings>>,
 }
 
 #[relm4::component(pub)]
 impl Component for NewVersion {
     type Init = ();
-    type Input = ();
-    type Output = ();
+    type Input = NewVersionInput;
+    type Output = NewVersionOutput;
     type CommandOutput = NewVersionCommandOutput;
 
     view! {
         #[root]
-        gtk::LinkButton {
+        gtk::Button {
             add_css_class: "suggested-action",
-            remove_css_class: "link",
-            set_has_frame: true,
-            #[track = "model.changed_new_version()"]
-            set_label: T
-                .new_version_available(
-                    model.new_version.as_ref().map(Version::to_string).unwrap_or_default()
-                )
-                .as_str(),
-            set_tooltip: &T.new_version_available_button_open(),
-            set_uri: &{
-                let repository = env!("CARGO_PKG_REPOSITORY");
-
-                if repository.starts_with("https://github.com") {
-                    // Turn:
-                    // https://github.com/autonomys/space-acres
-                    // Into:
-                    // https://github.com/autonomys/space-acres/releases
-                    format!("{}/releases", env!("CARGO_PKG_REPOSITORY"))
-                } else {
-                    repository.to_string()
+            connect_clicked => NewVersionInput::Activate,
+            #[track = "model.changed_new_version() || model.changed_update_state()"]
+            set_label: &{
+                let version = model
+                    .new_version
+                    .as_ref()
+                    .map(|new_release| new_release.version.to_string())
+                    .unwrap_or_default();
+
+                match model.update_state {
+                    UpdateState::None => T.new_version_available(version),
+                    UpdateState::Downloading => T.new_version_update_downloading(version),
+                    UpdateState::Downloaded(_) => T.new_version_update_ready(version),
+                    UpdateState::Installing => T.new_version_update_installing(version),
                 }
             },
+            #[track = "model.changed_update_state()"]
+            set_sensitive: !matches!(
+                model.update_state,
+                UpdateState::Downloading | UpdateState::Installing
+            ),
+            set_tooltip: &T.new_version_available_button_release_notes(),
             set_use_underline: false,
             #[track = "model.changed_new_version()"]
             set_visible: model.new_version.is_some(),
@@ -71,51 +177,137 @@ impl Component for NewVersion {
     fn init(
         _init: Self::Init,
         _root: Self::Root,
-        sender: ComponentSender<Self>,
+        _sender: ComponentSender<Self>,
     ) -> ComponentParts<Self> {
         let model = Self {
             new_version: None,
+            update_state: UpdateState::None,
+            client: Client::new(),
+            settings_sender: None,
             tracker: u8::MAX,
         };
 
         let widgets = view_output!();
 
-        sender.command(Self::check_new_version);
-
         ComponentParts { model, widgets }
     }
 
+    fn update(&mut self, input: Self::Input, sender: ComponentSender<Self>, root: &Self::Root) {
+        // Reset changes
+        self.reset();
+
+        self.process_input(input, sender, root);
+    }
+
     fn update_cmd(
         &mut self,
         input: Self::CommandOutput,
-        _sender: ComponentSender<Self>,
-        _root: &Self::Root,
+        sender: ComponentSender<Self>,
+        root: &Self::Root,
     ) {
         // Reset changes
         self.reset();
 
-        self.process_command(input);
+        self.process_command(input, sender, root);
     }
 }
 
 impl NewVersion {
-    async fn check_new_version(
-        sender: Sender<NewVersionCommandOutput>,
-        shutdown_receiver: ShutdownReceiver,
+    fn process_input(
+        &mut self,
+        input: NewVersionInput,
+        sender: ComponentSender<Self>,
+        root: &gtk::Button,
     ) {
-        let url = env!("CARGO_PKG_REPOSITORY");
+        match input {
+            NewVersionInput::Configure { proxy, updates } => {
+                let client = match create_client(proxy.as_ref()) {
+                    Ok(client) => client,
+                    Err(error) => {
+                        warn!(%error, "Failed to configure proxy, checking new version without it");
+                        Client::new()
+                    }
+                };
+
+                self.client = client.clone();
+
+                if !updates.enabled && matches!(self.update_state, UpdateState::None) {
+                    self.set_new_version(None);
+                }
 
-        if !url.starts_with("https://github.com") {
-            warn!(%url, "Unexpected repository URL, not checking for new version");
-            return;
+                let settings_sender = self.settings_sender.get_or_insert_with(|| {
+                    let (settings_sender, settings_receiver) = mpsc::unbounded();
+                    sender.command(move |sender, shutdown_receiver| {
+                        Self::check_new_version(settings_receiver, sender, shutdown_receiver)
+                    });
+                    settings_sender
+                });
+
+                if settings_sender
+                    .unbounded_send(CheckSettings { client, updates })
+                    .is_err()
+                {
+                    debug!("Failed to send new version check settings");
+                }
+            }
+            NewVersionInput::Activate => {
+                if let Some(new_release) = &self.new_version {
+                    if let UpdateState::Downloaded(_) = self.update_state {
+                        Self::confirm_update(new_release, &sender, root);
+                    } else {
+                        Self::show_release_notes(new_release, &sender, root);
+                    }
+                }
+            }
+            NewVersionInput::DownloadUpdate => {
+                let Some(update_assets) = self
+                    .new_version
+                    .as_ref()
+                    .and_then(|new_release| new_release.update_assets.clone())
+                else {
+                    return;
+                };
+                if !matches!(self.update_state, UpdateState::None) {
+                    return;
+                }
+
+                self.set_update_state(UpdateState::Downloading);
+                let client = self.client.clone();
+                sender.oneshot_command(async move {
+                    NewVersionCommandOutput::UpdateDownloaded(
+                        download_and_verify(&client, &update_assets).await,
+                    )
+                });
+            }
+            NewVersionInput::InstallUpdate => {
+                let Some(installation_kind) = self
+                    .new_version
+                    .as_ref()
+                    .and_then(|new_release| new_release.update_assets.as_ref())
+                    .map(|update_assets| update_assets.installation_kind)
+                else {
+                    return;
+                };
+                let UpdateState::Downloaded(downloaded_update) = self.update_state.clone() else {
+                    return;
+                };
+
+                self.set_update_state(UpdateState::Installing);
+                sender.spawn_oneshot_command(move || {
+                    NewVersionCommandOutput::UpdateStaged(stage_update(
+                        installation_kind,
+                        &downloaded_update,
+                    ))
+                });
+            }
         }
-        // Turn:
-        // https://github.com/autonomys/space-acres
-        // Into:
-        // https://api.github.com/repos/autonomys/space-acres/releases/latest
-        let mut url = url.replace("https://github.com", "https://api.github.com/repos");
-        url.push_str("/releases/latest");
+    }
 
+    async fn check_new_version(
+        mut settings_receiver: mpsc::UnboundedReceiver<CheckSettings>,
+        sender: Sender<NewVersionCommandOutput>,
+        shutdown_receiver: ShutdownReceiver,
+    ) {
         let current_version = env!("CARGO_PKG_VERSION");
         let current_version = match Version::parse(current_version) {
             Ok(current_version) => current_version,
@@ -124,60 +316,349 @@ impl NewVersion {
                 return;
             }
         };
-        let user_agent = format!("{}/{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
 
         shutdown_receiver
             .register(async move {
-                let client = Client::new();
+                let Some(mut settings) = settings_receiver.next().await else {
+                    return;
+                };
+
                 loop {
-                    let response: reqwest::Result<LatestRelease> = try {
-                        client
-                            .get(&url)
-                            .header("User-Agent", &user_agent)
-                            .send()
-                            .await?
-                            .json()
-                            .await?
-                    };
+                    let mut check_interval = NEW_VERSION_CHECK_INTERVAL;
 
-                    let tag_name = match response {
-                        Ok(latest_release) => latest_release.tag_name,
-                        Err(error) => {
-                            warn!(%error, %url, "Failed to check new release");
-                            tokio::time::sleep(NEW_VERSION_CHECK_RETRY_INTERVAL).await;
-                            continue;
+                    if settings.updates.enabled {
+                        match fetch_new_release(&settings, &current_version).await {
+                            Ok(maybe_new_release) => {
+                                let output = match maybe_new_release {
+                                    Some(new_release) => {
+                                        NewVersionCommandOutput::NewVersion(new_release)
+                                    }
+                                    None => NewVersionCommandOutput::UpToDate,
+                                };
+                                if sender.send(output).is_err() {
+                                    break;
+                                }
+                            }
+                            Err(error) => {
+                                warn!(%error, "Failed to check new release");
+                                check_interval = NEW_VERSION_CHECK_RETRY_INTERVAL;
+                            }
                         }
-                    };
+                    }
 
-                    let new_version = match Version::parse(&tag_name) {
-                        Ok(new_version) => new_version,
-                        Err(error) => {
-                            debug!(%error, %tag_name, "Failed to parse new version");
-                            tokio::time::sleep(NEW_VERSION_CHECK_RETRY_INTERVAL).await;
-                            continue;
+                    // Check again after interval or right away when settings change, while
+                    // disabled only wait for settings
+                    let maybe_new_settings = if settings.updates.enabled {
+                        select! {
+                            _ = tokio::time::sleep(check_interval).fuse() => {
+                                None
+                            }
+                            maybe_new_settings = settings_receiver.next() => {
+                                Some(maybe_new_settings)
+                            }
                         }
+                    } else {
+                        Some(settings_receiver.next().await)
                     };
 
-                    if new_version > current_version
-                        && sender
-                            .send(NewVersionCommandOutput::NewVersion(new_version))
-                            .is_err()
-                    {
-                        break;
+                    match maybe_new_settings {
+                        Some(Some(new_settings)) => {
+                            settings = new_settings;
+                        }
+                        Some(None) => {
+                            break;
+                        }
+                        None => {
+                            // Time for the next check
+                        }
                     }
-
-                    tokio::time::sleep(NEW_VERSION_CHECK_INTERVAL).await;
                 }
             })
             .drop_on_shutdown()
             .await
     }
 
-    fn process_command(&mut self, command_output: NewVersionCommandOutput) {
+    fn process_command(
+        &mut self,
+        command_output: NewVersionCommandOutput,
+        sender: ComponentSender<Self>,
+        root: &gtk::Button,
+    ) {
         match command_output {
-            NewVersionCommandOutput::NewVersion(version) => {
-                self.get_mut_new_version().replace(version);
+            NewVersionCommandOutput::NewVersion(new_release) => {
+                // Keep release that is being updated to
+                if matches!(self.update_state, UpdateState::None) {
+                    self.set_new_version(Some(new_release));
+                }
             }
+            NewVersionCommandOutput::UpToDate => {
+                if matches!(self.update_state, UpdateState::None) {
+                    self.set_new_version(None);
+                }
+            }
+            NewVersionCommandOutput::UpdateDownloaded(result) => match result {
+                Ok(downloaded_update) => {
+                    self.set_update_state(UpdateState::Downloaded(Arc::new(downloaded_update)));
+                    if let Some(new_release) = &self.new_version {
+                        Self::confirm_update(new_release, &sender, root);
+                    }
+                }
+                Err(error) => {
+                    warn!(%error, "Failed to download update");
+                    self.set_update_state(UpdateState::None);
+                    if sender
+                        .output(NewVersionOutput::UpdateFailed(error.to_string()))
+                        .is_err()
+                    {
+                        debug!("Failed to send NewVersionOutput::UpdateFailed");
+                    }
+                }
+            },
+            NewVersionCommandOutput::UpdateStaged(result) => match result {
+                Ok(()) => {
+                    if sender.output(NewVersionOutput::Restart).is_err() {
+                        debug!("Failed to send NewVersionOutput::Restart");
+                    }
+                }
+                Err(error) => {
+                    warn!(%error, "Failed to install update");
+                    self.set_update_state(UpdateState::None);
+                    if sender
+                        .output(NewVersionOutput::UpdateFailed(error.to_string()))
+                        .is_err()
+                    {
+                        debug!("Failed to send NewVersionOutput::UpdateFailed");
+                    }
+                }
+            },
         }
     }
+
+    fn confirm_update(
+        new_release: &NewRelease,
+        sender: &ComponentSender<Self>,
+        root: &gtk::Button,
+    ) {
+        let alert_dialog = gtk::AlertDialog::builder()
+            .modal(true)
+            .message(
+                T.new_version_update_confirm_heading(new_release.version.to_string())
+                    .to_string(),
+            )
+            .detail(T.new_version_update_confirm_body().to_string())
+            .buttons(vec![
+                T.new_version_update_confirm_button_cancel().to_string(),
+                T.new_version_update_confirm_button_install().to_string(),
+            ])
+            .cancel_button(0)
+            .default_button(1)
+            .build();
+
+        let sender = sender.clone();
+        alert_dialog.choose(
+            root.root().and_downcast::<gtk::Window>().as_ref(),
+            None::<&gio::Cancellable>,
+            move |result| {
+                if matches!(result, Ok(1)) {
+                    sender.input(NewVersionInput::InstallUpdate);
+                }
+            },
+        );
+    }
+
+    fn show_release_notes(
+        new_release: &NewRelease,
+        sender: &ComponentSender<Self>,
+        root: &gtk::Button,
+    ) {
+        // Create a custom header bar with only a close button (no minimize/maximize)
+        let header_bar = gtk::HeaderBar::builder().show_title_buttons(false).build();
+        let close_button = gtk::Button::builder()
+            .icon_name("window-close-symbolic")
+            .build();
+        header_bar.pack_end(&close_button);
+
+        let dialog_window = gtk::Window::builder()
+            .title(
+                T.new_version_release_notes_title(new_release.version.to_string())
+                    .to_string(),
+            )
+            .titlebar(&header_bar)
+            .modal(true)
+            .default_width(600)
+            .default_height(500)
+            .build();
+        if let Some(parent_window) = root.root().and_downcast::<gtk::Window>() {
+            dialog_window.set_transient_for(Some(&parent_window));
+        }
+
+        let window_clone = dialog_window.clone();
+        close_button.connect_clicked(move |_| {
+            window_clone.close();
+        });
+
+        let notes = if new_release.notes.trim().is_empty() {
+            T.new_version_release_notes_empty().to_string()
+        } else {
+            new_release.notes.clone()
+        };
+        let text_view = gtk::TextView::builder()
+            .editable(false)
+            .cursor_visible(false)
+            .wrap_mode(gtk::WrapMode::WordChar)
+            .top_margin(10)
+            .bottom_margin(10)
+            .left_margin(10)
+            .right_margin(10)
+            .build();
+        text_view.buffer().set_text(&notes);
+
+        let scrolled_window = gtk::ScrolledWindow::builder()
+            .vexpand(true)
+            .child(&text_view)
+            .build();
+
+        let release_page_button = gtk::LinkButton::builder()
+            .label(T.new_version_available_button_open().to_string())
+            .uri(
+                new_release
+                    .url
+                    .clone()
+                    .unwrap_or_else(|| format!("{}/releases", env!("CARGO_PKG_REPOSITORY"))),
+            )
+            .build();
+
+        let content = gtk::Box::builder()
+            .orientation(gtk::Orientation::Vertical)
+            .spacing(10)
+            .margin_top(10)
+            .margin_bottom(10)
+            .margin_start(10)
+            .margin_end(10)
+            .build();
+        content.append(&scrolled_window);
+
+        let buttons = gtk::Box::builder()
+            .halign(gtk::Align::End)
+            .spacing(10)
+            .build();
+        buttons.append(&release_page_button);
+        if new_release.update_assets.is_some() {
+            let install_button = gtk::Button::builder()
+                .label(T.new_version_update_button_install().to_string())
+                .css_classes(["suggested-action"])
+                .build();
+            let sender = sender.clone();
+            let window_clone = dialog_window.clone();
+            install_button.connect_clicked(move |_| {
+                sender.input(NewVersionInput::DownloadUpdate);
+                window_clone.close();
+            });
+            buttons.append(&install_button);
+        }
+        content.append(&buttons);
+
+        dialog_window.set_child(Some(&content));
+        dialog_window.present();
+    }
+}
+
+fn user_agent() -> String {
+    format!("{}/{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"))
+}
+
+/// Create HTTP client that uses provided proxy, if any
+fn create_client(maybe_proxy: Option<&ProxyConfiguration>) -> reqwest::Result<Client> {
+    let mut client_builder = Client::builder();
+    if let Some(proxy) = maybe_proxy {
+        client_builder = client_builder.proxy(proxy.reqwest_proxy()?);
+    }
+
+    client_builder.build()
+}
+
+/// Fetch releases from configured source and return the newest one if it is newer than current
+/// version
+async fn fetch_new_release(
+    settings: &CheckSettings,
+    current_version: &Version,
+) -> anyhow::Result<Option<NewRelease>> {
+    let include_prereleases = settings.updates.include_prereleases;
+
+    let releases = match &settings.updates.source {
+        Some(source) if Path::new(source).is_absolute() => {
+            serde_json::from_slice::<GitHubReleases>(&tokio::fs::read(source).await?)?
+        }
+        maybe_source => {
+            let url = match maybe_source {
+                Some(url) => url.clone(),
+                None => {
+                    let url = env!("CARGO_PKG_REPOSITORY");
+
+                    if !url.starts_with("https://github.com") {
+                        anyhow::bail!("Unexpected repository URL {url}");
+                    }
+                    // Turn:
+                    // https://github.com/autonomys/space-acres
+                    // Into:
+                    // https://api.github.com/repos/autonomys/space-acres/releases/latest
+                    // Or, since `latest` never points to a pre-release, into:
+                    // https://api.github.com/repos/autonomys/space-acres/releases?per_page=20
+                    let mut url = url.replace("https://github.com", "https://api.github.com/repos");
+                    if include_prereleases {
+                        url.push_str(&format!("/releases?per_page={RECENT_RELEASES_LIMIT}"));
+                    } else {
+                        url.push_str("/releases/latest");
+                    }
+                    url
+                }
+            };
+            settings
+                .client
+                .get(&url)
+                .header("User-Agent", user_agent())
+                .send()
+                .await?
+                .error_for_status()?
+                .json::<GitHubReleases>()
+                .await?
+        }
+    };
+
+    let newest_release = releases
+        .into_vec()
+        .into_iter()
+        .filter(|release| !release.draft && (include_prereleases || !release.prerelease))
+        .filter_map(|release| match Version::parse(&release.tag_name) {
+            Ok(version) => Some((version, release)),
+            Err(error) => {
+                debug!(%error, tag_name = %release.tag_name, "Failed to parse release version");
+                None
+            }
+        })
+        .filter(|(version, _release)| include_prereleases || version.pre.is_empty())
+        .max_by(|(a, _), (b, _)| a.cmp(b));
+
+    Ok(newest_release
+        .filter(|(version, _release)| version > current_version)
+        .map(|(version, release)| {
+            let assets = release
+                .assets
+                .into_iter()
+                .map(|asset| ReleaseAsset {
+                    name: asset.name,
+                    url: asset.browser_download_url,
+                })
+                .collect::<Vec<_>>();
+            let update_assets = InstallationKind::detect().and_then(|installation_kind| {
+                UpdateAssets::find(installation_kind, &version, &assets)
+            });
+
+            NewRelease {
+                version,
+                notes: release.body.unwrap_or_default(),
+                url: release.html_url,
+                update_assets,
+            }
+        }))
 }
@@ -0,0 +1,434 @@
+//! In-app updates of Linux installations, both AppImage and Debian package are supported
+
+use reqwest::Client;
+use semver::Version;
+use sha2::{Digest, Sha256};
+use sp_core::{Pair, ed25519};
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+use std::process::ExitStatus;
+use std::{env, fs, io};
+use tempfile::TempDir;
+use tokio::io::AsyncWriteExt;
+use tracing::{error, info};
+
+/// Hex-encoded Ed25519 public key that checksum files of release assets are signed with, in-app
+/// updates are not available if it was not provided at build time (empty when not configured in
+/// CI)
+const UPDATE_SIGNING_PUBLIC_KEY: Option<&str> = option_env!("SPACE_ACRES_UPDATE_PUBLIC_KEY");
+/// Suffix of SHA-256 checksum file published next to each release asset, in `sha256sum` format
+const CHECKSUM_SUFFIX: &str = ".sha256";
+/// Suffix of Ed25519 signature of the checksum file, either raw or hex-encoded
+const SIGNATURE_SUFFIX: &str = ".sha256.sig";
+/// Suffix of AppImage staged next to the current one, applied by supervisor on restart
+const STAGED_APPIMAGE_SUFFIX: &str = ".update";
+/// File that exists when application was installed from Debian package
+const DPKG_INFO_FILE: &str = "/var/lib/dpkg/info/space-acres.list";
+
+/// In-app update error
+#[derive(Debug, thiserror::Error)]
+pub enum UpdateError {
+    /// Public key for signature verification was not provided at build time
+    #[error("Update signing public key is not available in this build")]
+    NoPublicKey,
+    /// Invalid public key for signature verification
+    #[error("Invalid update signing public key")]
+    InvalidPublicKey,
+    /// Failed to download release asset
+    #[error("Failed to download {name}: {error}")]
+    Download { name: String, error: reqwest::Error },
+    /// Invalid release asset name
+    #[error("Invalid release asset name \"{name}\"")]
+    InvalidAssetName { name: String },
+    /// Invalid signature format
+    #[error("Invalid signature format of {name}")]
+    InvalidSignatureFormat { name: String },
+    /// Signature verification failed
+    #[error("Signature of {name} is invalid")]
+    InvalidSignature { name: String },
+    /// Invalid checksum format
+    #[error("Invalid checksum format of {name}")]
+    InvalidChecksumFormat { name: String },
+    /// Signed checksum file is for a different file than the installer
+    #[error("Checksum file {name} is for {file_name} rather than {installer_name}")]
+    ChecksumFileMismatch {
+        name: String,
+        file_name: String,
+        installer_name: String,
+    },
+    /// Downloaded file doesn't match published checksum
+    #[error("Checksum of {name} doesn't match, expected {expected}, got {actual}")]
+    ChecksumMismatch {
+        name: String,
+        expected: String,
+        actual: String,
+    },
+    /// AppImage location is unknown
+    #[error("AppImage location is unknown")]
+    AppImageLocationUnknown,
+    /// Package installation failed
+    #[error("Package installation failed: {status}")]
+    InstallationFailed { status: ExitStatus },
+    /// I/O error
+    #[error("I/O error: {0}")]
+    Io(#[from] io::Error),
+}
+
+/// How application was installed
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum InstallationKind {
+    AppImage,
+    Deb,
+}
+
+impl InstallationKind {
+    /// Detect how application was installed, `None` if in-app updates are not supported for it
+    pub fn detect() -> Option<Self> {
+        if !cfg!(target_os = "linux") {
+            return None;
+        }
+
+        if env::var_os("APPIMAGE").is_some() {
+            return Some(Self::AppImage);
+        }
+
+        let current_exe = env::current_exe().ok()?;
+        (current_exe.starts_with("/usr/bin") && Path::new(DPKG_INFO_FILE).exists())
+            .then_some(Self::Deb)
+    }
+
+    fn matches_asset(self, name: &str) -> bool {
+        match self {
+            Self::AppImage => name.ends_with(&format!("-{}.AppImage", env::consts::ARCH)),
+            Self::Deb => {
+                let arch = match env::consts::ARCH {
+                    "x86_64" => "amd64",
+                    "aarch64" => "arm64",
+                    arch => arch,
+                };
+                name.ends_with(&format!("_{arch}.deb"))
+            }
+        }
+    }
+}
+
+/// Release asset
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ReleaseAsset {
+    pub name: String,
+    pub url: String,
+}
+
+/// Release assets necessary for in-app update
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct UpdateAssets {
+    pub installation_kind: InstallationKind,
+    installer: ReleaseAsset,
+    checksum: ReleaseAsset,
+    signature: ReleaseAsset,
+}
+
+impl UpdateAssets {
+    /// Find assets of provided version matching installation kind, `None` if in-app update is not
+    /// possible
+    pub fn find(
+        installation_kind: InstallationKind,
+        version: &Version,
+        assets: &[ReleaseAsset],
+    ) -> Option<Self> {
+        public_key().ok()?;
+
+        let version = version.to_string();
+        let installer = assets.iter().find(|asset| {
+            installation_kind.matches_asset(&asset.name) && contains_version(&asset.name, &version)
+        })?;
+        let find_by_name = |name: String| assets.iter().find(|asset| asset.name == name).cloned();
+
+        Some(Self {
+            installation_kind,
+            checksum: find_by_name(format!("{}{CHECKSUM_SUFFIX}", installer.name))?,
+            signature: find_by_name(format!("{}{SIGNATURE_SUFFIX}", installer.name))?,
+            installer: installer.clone(),
+        })
+    }
+}
+
+/// Installer downloaded into a private temporary directory, which is removed on drop
+#[derive(Debug)]
+pub struct DownloadedUpdate {
+    installer_name: String,
+    installer_path: PathBuf,
+    /// Checksum from signed checksum file, installer is verified against it again right before
+    /// staging
+    checksum: [u8; 32],
+    _download_dir: TempDir,
+}
+
+impl DownloadedUpdate {
+    /// Verify that file at provided path still matches signed checksum of the installer
+    fn verify(&self, path: &Path) -> Result<(), UpdateError> {
+        let mut file = fs::File::open(path)?;
+        let mut hasher = Sha256::new();
+        io::copy(&mut file, &mut hasher)?;
+
+        verify_checksum(
+            &self.installer_name,
+            self.checksum,
+            hasher.finalize().into(),
+        )
+    }
+}
+
+/// Download installer, verify its checksum and signature of the checksum.
+///
+/// Installer is streamed into a newly created temporary directory that is only accessible by the
+/// current user, such that other users of the machine can't replace it before installation.
+pub async fn download_and_verify(
+    client: &Client,
+    assets: &UpdateAssets,
+) -> Result<DownloadedUpdate, UpdateError> {
+    let public_key = public_key()?;
+
+    let checksum = download(client, &assets.checksum).await?;
+    let signature = download(client, &assets.signature).await?;
+    let signature =
+        parse_signature(&signature).ok_or_else(|| UpdateError::InvalidSignatureFormat {
+            name: assets.signature.name.clone(),
+        })?;
+    if !ed25519::Pair::verify(&signature, &checksum, &public_key) {
+        return Err(UpdateError::InvalidSignature {
+            name: assets.checksum.name.clone(),
+        });
+    }
+    let (expected_checksum, file_name) =
+        parse_checksum(&checksum).ok_or_else(|| UpdateError::InvalidChecksumFormat {
+            name: assets.checksum.name.clone(),
+        })?;
+    // Otherwise signed checksum file of an older release could be served for the installer
+    if file_name != assets.installer.name {
+        return Err(UpdateError::ChecksumFileMismatch {
+            name: assets.checksum.name.clone(),
+            file_name: file_name.to_string(),
+            installer_name: assets.installer.name.clone(),
+        });
+    }
+
+    let file_name = Path::new(&assets.installer.name)
+        .file_name()
+        .ok_or_else(|| UpdateError::InvalidAssetName {
+            name: assets.installer.name.clone(),
+        })?;
+    // Created with `0700` permissions on Unix
+    let download_dir = tempfile::Builder::new()
+        .prefix(concat!(env!("CARGO_PKG_NAME"), "-update-"))
+        .tempdir()?;
+    let installer_path = download_dir.path().join(file_name);
+
+    let actual_checksum = download_to_file(client, &assets.installer, &installer_path).await?;
+    verify_checksum(&assets.installer.name, expected_checksum, actual_checksum)?;
+
+    info!(path = %installer_path.display(), "Update downloaded and verified");
+
+    Ok(DownloadedUpdate {
+        installer_name: assets.installer.name.clone(),
+        installer_path,
+        checksum: expected_checksum,
+        _download_dir: download_dir,
+    })
+}
+
+/// Stage downloaded update such that it is used after restart.
+///
+/// AppImage is placed next to the current one and swapped by supervisor on restart, Debian
+/// package is installed right away (with `pkexec` asking for permission) since running process is
+/// not affected by it. In both cases exact bytes that will be used are verified against signed
+/// checksum right before.
+pub fn stage_update(
+    installation_kind: InstallationKind,
+    downloaded_update: &DownloadedUpdate,
+) -> Result<(), UpdateError> {
+    let installer_path = downloaded_update.installer_path.as_path();
+
+    match installation_kind {
+        InstallationKind::AppImage => {
+            let appimage = env::var_os("APPIMAGE").ok_or(UpdateError::AppImageLocationUnknown)?;
+            let staged_appimage = staged_appimage_path(Path::new(&appimage));
+
+            fs::copy(installer_path, &staged_appimage)?;
+            if let Err(error) = downloaded_update.verify(&staged_appimage) {
+                let _ = fs::remove_file(&staged_appimage);
+                return Err(error);
+            }
+            #[cfg(unix)]
+            {
+                use std::os::unix::fs::PermissionsExt;
+
+                fs::set_permissions(&staged_appimage, fs::Permissions::from_mode(0o755))?;
+            }
+        }
+        InstallationKind::Deb => {
+            downloaded_update.verify(installer_path)?;
+
+            let output = duct::cmd(
+                "pkexec",
+                [
+                    OsStr::new("dpkg"),
+                    OsStr::new("-i"),
+                    installer_path.as_os_str(),
+                ],
+            )
+            .unchecked()
+            .run()?;
+
+            if !output.status.success() {
+                return Err(UpdateError::InstallationFailed {
+                    status: output.status,
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Replace current AppImage with staged update if there is one, returns path to the AppImage that
+/// should be started instead of the current process
+pub fn apply_staged_update() -> Option<PathBuf> {
+    let appimage = PathBuf::from(env::var_os("APPIMAGE")?);
+    let staged_appimage = staged_appimage_path(&appimage);
+
+    if !staged_appimage.exists() {
+        return None;
+    }
+
+    if let Err(error) = fs::rename(&staged_appimage, &appimage) {
+        error!(
+            %error,
+            path = %staged_appimage.display(),
+            "Failed to apply staged update"
+        );
+        let _ = fs::remove_file(&staged_appimage);
+        return None;
+    }
+
+    info!(path = %appimage.display(), "Applied staged update");
+
+    Some(appimage)
+}
+
+fn staged_appimage_path(appimage: &Path) -> PathBuf {
+    let mut staged_appimage = appimage.as_os_str().to_os_string();
+    staged_appimage.push(STAGED_APPIMAGE_SUFFIX);
+    PathBuf::from(staged_appimage)
+}
+
+fn public_key() -> Result<ed25519::Public, UpdateError> {
+    let public_key = UPDATE_SIGNING_PUBLIC_KEY
+        .filter(|public_key| !public_key.is_empty())
+        .ok_or(UpdateError::NoPublicKey)?;
+    let public_key = hex::decode(public_key.trim())
+        .ok()
+        .and_then(|public_key| <[u8; 32]>::try_from(public_key).ok())
+        .ok_or(UpdateError::InvalidPublicKey)?;
+
+    Ok(ed25519::Public::from_raw(public_key))
+}
+
+async fn download(client: &Client, asset: &ReleaseAsset) -> Result<Vec<u8>, UpdateError> {
+    let result: reqwest::Result<Vec<u8>> = try {
+        client
+            .get(&asset.url)
+            .header("User-Agent", super::user_agent())
+            .send()
+            .await?
+            .error_for_status()?
+            .bytes()
+            .await?
+            .to_vec()
+    };
+
+    result.map_err(|error| UpdateError::Download {
+        name: asset.name.clone(),
+        error,
+    })
+}
+
+/// Download release asset into a file, returns SHA-256 checksum of downloaded contents
+async fn download_to_file(
+    client: &Client,
+    asset: &ReleaseAsset,
+    path: &Path,
+) -> Result<[u8; 32], UpdateError> {
+    let download_error = |error| UpdateError::Download {
+        name: asset.name.clone(),
+        error,
+    };
+
+    let mut response = client
+        .get(&asset.url)
+        .header("User-Agent", super::user_agent())
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .map_err(download_error)?;
+
+    let mut file = tokio::fs::File::create(path).await?;
+    let mut hasher = Sha256::new();
+    while let Some(chunk) = response.chunk().await.map_err(download_error)? {
+        hasher.update(&chunk);
+        file.write_all(&chunk).await?;
+    }
+    file.flush().await?;
+
+    Ok(hasher.finalize().into())
+}
+
+fn verify_checksum(name: &str, expected: [u8; 32], actual: [u8; 32]) -> Result<(), UpdateError> {
+    if actual != expected {
+        return Err(UpdateError::ChecksumMismatch {
+            name: name.to_string(),
+            expected: hex::encode(expected),
+            actual: hex::encode(actual),
+        });
+    }
+
+    Ok(())
+}
+
+/// Signature is either raw 64 bytes or hex-encoded
+fn parse_signature(signature: &[u8]) -> Option<ed25519::Signature> {
+    let signature = match <[u8; 64]>::try_from(signature) {
+        Ok(signature) => signature,
+        Err(_) => {
+            let signature = hex::decode(std::str::from_utf8(signature).ok()?.trim()).ok()?;
+            <[u8; 64]>::try_from(signature).ok()?
+        }
+    };
+
+    Some(ed25519::Signature::from_raw(signature))
+}
+
+/// Checksum file is in `sha256sum` format: hex-encoded checksum followed by file name, optionally
+/// prefixed with `*` in binary mode
+fn parse_checksum(checksum: &[u8]) -> Option<([u8; 32], &str)> {
+    let line = std::str::from_utf8(checksum).ok()?.trim_end();
+    if line.contains('\n') {
+        return None;
+    }
+    let (checksum, file_name) = line.split_once(' ')?;
+    let file_name = file_name.trim_start();
+    let file_name = file_name.strip_prefix('*').unwrap_or(file_name);
+
+    Some((
+        <[u8; 32]>::try_from(hex::decode(checksum).ok()?).ok()?,
+        file_name,
+    ))
+}
+
+/// Whether asset name contains provided version as a separate `-` or `_` delimited part, such
+/// that `0.2.1` doesn't match `space-acres-0.2.19-x86_64.AppImage`
+fn contains_version(name: &str, version: &str) -> bool {
+    name.match_indices(version).any(|(index, _)| {
+        name[..index].ends_with(['-', '_']) && name[index + version.len()..].starts_with(['-', '_'])
+    })
+}
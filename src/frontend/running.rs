@@ -2,6 +2,7 @@ mod farm;
 mod node;
 
 use crate::backend::config::{Config, RawConfig};
+use crate::backend::farmer::piece_downloads::PieceDownloadStats;
 use crate::backend::farmer::{FarmerNotification, InitialFarmState};
 use crate::backend::node::ChainInfo;
 use crate::backend::{FarmIndex, NodeNotification};
@@ -27,6 +28,7 @@ use subspace_core_primitives::solutions::{SolutionRange, solution_range_to_piece
 use subspace_farmer::farm::{
     FarmingNotification, ProvingResult, SectorPlottingDetails, SectorUpdate,
 };
+use subspace_networking::libp2p::PeerId;
 use subspace_runtime_primitives::{AI3, Balance};
 use tracing::{debug, warn};
 
@@ -45,6 +47,7 @@ pub enum RunningInput {
         cache_percentage: NonZeroU8,
         config: Config,
         raw_config: RawConfig,
+        peer_id: PeerId,
         chain_info: ChainInfo,
         chain_constants: ChainConstants,
     },
@@ -60,6 +63,11 @@ pub enum RunningInput {
 pub enum RunningOutput {
     PausePlotting(bool),
     LowDiskSpace { free_space: ByteSize },
+    ExportNetworkIdentity,
+    ImportNetworkIdentity,
+    RotateNetworkIdentity,
+    InspectKnownPeers,
+    ClearKnownPeers,
 }
 
 #[tracker::track]
@@ -78,6 +86,7 @@ struct FarmerState {
     slot_probability: (u64, u64),
     slot_duration: Duration,
     last_reward_received_time: Instant,
+    piece_download_stats: PieceDownloadStats,
     #[do_not_track]
     reward_eta_progress_circle: Controller<ProgressCircle>,
 }
@@ -88,6 +97,8 @@ pub struct RunningView {
     #[do_not_track]
     node_view: Controller<NodeView>,
     node_synced: bool,
+    /// Running without any farms, only node is shown
+    node_only: bool,
     #[do_not_track]
     farmer_state: FarmerState,
     #[do_not_track]
@@ -112,11 +123,15 @@ impl Component for RunningView {
 
             gtk::Separator {
                 set_margin_all: 10,
+                #[track = "model.changed_node_only()"]
+                set_visible: !model.node_only,
             },
 
             gtk::Box {
                 set_orientation: gtk::Orientation::Vertical,
                 set_spacing: 10,
+                #[track = "model.changed_node_only()"]
+                set_visible: !model.node_only,
 
                 gtk::Box {
                     set_spacing: 10,
@@ -233,6 +248,34 @@ impl Component for RunningView {
                             },
                         },
 
+                        gtk::Label {
+                            add_css_class: "dim-label",
+                            set_halign: gtk::Align::Start,
+                            #[track = "model.farmer_state.changed_piece_download_stats()"]
+                            set_visible: model.farmer_state.piece_download_stats != PieceDownloadStats::default(),
+                            #[track = "model.farmer_state.changed_piece_download_stats()"]
+                            set_label: &T
+                                .running_farmer_piece_downloads(
+                                    model.farmer_state.piece_download_stats.local,
+                                    model.farmer_state.piece_download_stats.dsn_cache,
+                                    model.farmer_state.piece_download_stats.archival,
+                                    model.farmer_state.piece_download_stats.node,
+                                    model.farmer_state.piece_download_stats.failed,
+                                )
+                                .to_string(),
+                            #[track = "model.farmer_state.changed_piece_download_stats()"]
+                            set_tooltip: &T
+                                .running_farmer_piece_downloads_tooltip(
+                                    model.farmer_state.piece_download_stats.local,
+                                    model.farmer_state.piece_download_stats.dsn_cache,
+                                    model.farmer_state.piece_download_stats.archival,
+                                    model.farmer_state.piece_download_stats.node,
+                                    model.farmer_state.piece_download_stats.invalid,
+                                    model.farmer_state.piece_download_stats.failed,
+                                )
+                                .to_string(),
+                        },
+
                         #[local_ref]
                         farms_box -> gtk::Box {
                             set_orientation: gtk::Orientation::Vertical,
@@ -266,6 +309,7 @@ impl Component for RunningView {
         let model = Self {
             node_view,
             node_synced: false,
+            node_only: false,
             farmer_state: FarmerState {
                 initial_reward_address_balance: 0,
                 reward_address_balance: 0,
@@ -280,6 +324,7 @@ impl Component for RunningView {
                 slot_probability: (1, 1),
                 slot_duration: Duration::from_secs(1),
                 last_reward_received_time: Instant::now(),
+                piece_download_stats: PieceDownloadStats::default(),
                 reward_eta_progress_circle,
                 tracker: u16::MAX,
             },
@@ -313,9 +358,11 @@ impl RunningView {
                 cache_percentage,
                 config,
                 raw_config,
+                peer_id,
                 chain_info,
                 chain_constants,
             } => {
+                self.set_node_only(config.farms.is_empty());
                 for (farm_index, (initial_farm_state, farm)) in initial_farm_states
                     .iter()
                     .copied()
@@ -373,10 +420,14 @@ impl RunningView {
                 self.farmer_state.cache_percentage = cache_percentage;
                 self.farmer_state.slot_probability = chain_constants.slot_probability();
                 self.farmer_state.slot_duration = chain_constants.slot_duration().as_duration();
+                let connection_limits = config.network.connections.limits();
                 self.node_view.emit(NodeInput::Initialize {
                     best_block_number,
                     chain_info,
                     node_path: raw_config.node_path().clone(),
+                    peer_id,
+                    in_peers: connection_limits.substrate_in_peers,
+                    out_peers: connection_limits.substrate_out_peers,
                 });
             }
             RunningInput::NodeNotification(node_notification) => {
@@ -392,7 +443,11 @@ impl RunningView {
                         }
                         self.set_node_synced(new_synced);
                     }
-                    NodeNotification::ConnectedPeersUpdate(_) => {
+                    NodeNotification::ConnectedPeersUpdate(_)
+                    | NodeNotification::SubstratePeersUpdate(_)
+                    | NodeNotification::DsnPeersUpdate(_)
+                    | NodeNotification::PieceServingStatsUpdate(_)
+                    | NodeNotification::BandwidthUsageUpdate(_) => {
                         // Ignore
                     }
                     NodeNotification::BlockImported(imported_block) => {
@@ -493,10 +548,21 @@ impl RunningView {
                 FarmerNotification::FarmerCacheSyncProgress { progress } => {
                     self.farmer_state.set_piece_cache_sync_progress(progress);
                 }
+                FarmerNotification::PieceDownloadStatsUpdate(piece_download_stats) => {
+                    self.farmer_state
+                        .set_piece_download_stats(piece_download_stats);
+                }
                 FarmerNotification::FarmError { farm_index, error } => {
                     self.farms
                         .send(&farm_index, FarmWidgetInput::Error { error });
                 }
+                FarmerNotification::FarmDiskStateChanged {
+                    farm_index,
+                    disk_state,
+                } => {
+                    self.farms
+                        .send(&farm_index, FarmWidgetInput::DiskState(disk_state));
+                }
             },
             RunningInput::ToggleFarmDetails => {
                 self.farms.broadcast(FarmWidgetInput::ToggleFarmDetails);
@@ -524,6 +590,31 @@ impl RunningView {
                         debug!("Failed to send RunningOutput::LowDiskSpace");
                     }
                 }
+                NodeOutput::ExportNetworkIdentity => {
+                    if sender.output(RunningOutput::ExportNetworkIdentity).is_err() {
+                        debug!("Failed to send RunningOutput::ExportNetworkIdentity");
+                    }
+                }
+                NodeOutput::ImportNetworkIdentity => {
+                    if sender.output(RunningOutput::ImportNetworkIdentity).is_err() {
+                        debug!("Failed to send RunningOutput::ImportNetworkIdentity");
+                    }
+                }
+                NodeOutput::RotateNetworkIdentity => {
+                    if sender.output(RunningOutput::RotateNetworkIdentity).is_err() {
+                        debug!("Failed to send RunningOutput::RotateNetworkIdentity");
+                    }
+                }
+                NodeOutput::InspectKnownPeers => {
+                    if sender.output(RunningOutput::InspectKnownPeers).is_err() {
+                        debug!("Failed to send RunningOutput::InspectKnownPeers");
+                    }
+                }
+                NodeOutput::ClearKnownPeers => {
+                    if sender.output(RunningOutput::ClearKnownPeers).is_err() {
+                        debug!("Failed to send RunningOutput::ClearKnownPeers");
+                    }
+                }
             },
         }
     }
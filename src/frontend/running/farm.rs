@@ -1,4 +1,4 @@
-use crate::backend::farmer::DiskFarm;
+use crate::backend::farmer::{DiskFarm, FarmDiskState};
 use crate::frontend::NotificationExt;
 use crate::frontend::translations::{AsDefaultStr, T};
 use crate::icon_names::shipped as icon_names;
@@ -6,7 +6,7 @@ use bytesize::ByteSize;
 use gtk::prelude::*;
 use notify_rust::Notification;
 use relm4::prelude::*;
-use relm4::{RelmIterChildrenExt, RelmRemoveAllExt};
+use relm4::{RelmIterChildrenExt, RelmRemoveAllExt, Sender, ShutdownReceiver};
 use simple_moving_average::{SMA, SingleSumSMA};
 use std::collections::HashMap;
 use std::path::PathBuf;
@@ -17,7 +17,7 @@ use subspace_farmer::farm::{
     FarmingError, FarmingNotification, ProvingResult, SectorExpirationDetails,
     SectorPlottingDetails, SectorUpdate,
 };
-use tracing::{error, warn};
+use tracing::{debug, error, warn};
 
 const INVALID_SCORE_VALUE: f64 = -1.0;
 /// Number of samples over which to track auditing time, 1 minute in slots
@@ -30,6 +30,8 @@ const PROVING_TIME_TRACKING_WINDOW: usize = 10;
 const EXCELLENT_PROVING_TIME: Duration = Duration::from_millis(1800);
 /// Number of samples over which to track sector plotting time
 const SECTOR_PLOTTING_TIME_TRACKING_WINDOW: usize = 10;
+/// How frequently to check for free disk space
+const FREE_DISK_SPACE_CHECK_INTERVAL: Duration = Duration::from_secs(5);
 
 fn format_size(bytes: u64) -> String {
     ByteSize::b(bytes).to_string_as(!bytes.is_multiple_of(ByteSize::mb(1).as_u64()))
@@ -98,15 +100,23 @@ pub(super) enum FarmWidgetInput {
     Error {
         error: Arc<anyhow::Error>,
     },
+    DiskState(FarmDiskState),
     WindowResized,
     RecalculateSectorRows,
 }
 
+#[derive(Debug)]
+pub(super) enum FarmWidgetCommandOutput {
+    FreeDiskSpace(ByteSize),
+}
+
 #[tracker::track]
 #[derive(Debug)]
 pub(super) struct FarmWidget {
     path: PathBuf,
     size: String,
+    free_disk_space: Option<ByteSize>,
+    free_space_warning_threshold: u64,
     #[do_not_track]
     auditing_time: SingleSumSMA<Duration, u32, AUDITING_TIME_TRACKING_WINDOW>,
     auditing_time_average: Duration,
@@ -135,6 +145,7 @@ pub(super) struct FarmWidget {
     block_authoring_delay: Duration,
     #[no_eq]
     error: Option<Arc<anyhow::Error>>,
+    disk_state: FarmDiskState,
 }
 
 #[relm4::factory(pub(super))]
@@ -142,7 +153,7 @@ impl FactoryComponent for FarmWidget {
     type Init = FarmWidgetInit;
     type Input = FarmWidgetInput;
     type Output = ();
-    type CommandOutput = ();
+    type CommandOutput = FarmWidgetCommandOutput;
     type ParentWidget = gtk::Box;
     type Index = u8;
 
@@ -161,8 +172,8 @@ impl FactoryComponent for FarmWidget {
                     set_tooltip: &T.running_farmer_farm_tooltip(),
 
                     gtk::Label {
-                        #[track = "self.changed_error()"]
-                        set_css_classes: if self.error.is_some() {
+                        #[track = "self.changed_error() || self.changed_disk_state()"]
+                        set_css_classes: if self.error.is_some() || self.disk_state != FarmDiskState::Available {
                             &["farm-error"]
                         } else {
                             &[]
@@ -171,8 +182,8 @@ impl FactoryComponent for FarmWidget {
                     },
                 },
 
-                match &self.error {
-                    Some(_error) => gtk::Box {
+                match (&self.error, self.disk_state) {
+                    (Some(_), _) | (None, FarmDiskState::Missing | FarmDiskState::ReadOnly) => gtk::Box {
                         add_css_class: "farm-error",
                         set_halign: gtk::Align::End,
                         set_hexpand: true,
@@ -181,12 +192,46 @@ impl FactoryComponent for FarmWidget {
                             set_icon_name: Some(icon_names::WARNING_OUTLINE),
                         }
                     },
-                    None => {
+                    (None, FarmDiskState::Available) => {
                         gtk::Box {
                             set_halign: gtk::Align::End,
                             set_hexpand: true,
                             set_margin_top: 5,
 
+                            // Center vertically so space meter doesn't stretch to fill row height
+                            gtk::Box {
+                                set_margin_end: 10,
+                                set_spacing: 5,
+                                set_valign: gtk::Align::Center,
+                                #[track = "self.changed_free_disk_space()"]
+                                set_tooltip: T
+                                    .running_farmer_farm_free_disk_space_tooltip(
+                                        self.free_disk_space
+                                            .map(|bytes| bytes.to_string_as(true))
+                                            .unwrap_or_default()
+                                    )
+                                    .as_str(),
+                                #[track = "self.changed_free_disk_space()"]
+                                set_visible: self.is_low_disk_space(),
+
+                                gtk::Image {
+                                    set_icon_name: Some(icon_names::SSD),
+                                },
+
+                                gtk::LevelBar {
+                                    add_css_class: "free-disk-space",
+                                    set_min_value: 0.1,
+                                    #[track = "self.changed_free_disk_space()"]
+                                    set_value: {
+                                        let free_space = self.free_disk_space
+                                            .map(|bytes| bytes.as_u64())
+                                            .unwrap_or_default();
+                                        free_space as f64 / self.free_space_warning_threshold as f64
+                                    },
+                                    set_width_request: 70,
+                                },
+                            },
+
                             gtk::Box {
                                 set_spacing: 10,
                                 #[track = "self.changed_is_node_synced()"]
@@ -293,8 +338,8 @@ impl FactoryComponent for FarmWidget {
             },
 
             #[transition = "SlideUpDown"]
-            match (&self.error, self.plotting_state) {
-                (Some(error), _) => gtk::Box {
+            match (&self.error, self.disk_state, self.plotting_state) {
+                (Some(error), _, _) => gtk::Box {
                     gtk::Label {
                         add_css_class: "farm-error",
                         set_halign: gtk::Align::Start,
@@ -302,7 +347,21 @@ impl FactoryComponent for FarmWidget {
                         set_label: T.running_farmer_farm_crashed(error.to_string()).as_str(),
                     }
                 },
-                (_, PlottingState::Plotting { kind, progress }) => gtk::Box {
+                (_, FarmDiskState::Missing, _) => gtk::Box {
+                    gtk::Label {
+                        add_css_class: "farm-error",
+                        set_halign: gtk::Align::Start,
+                        set_label: &T.running_farmer_farm_disk_missing(),
+                    }
+                },
+                (_, FarmDiskState::ReadOnly, _) => gtk::Box {
+                    gtk::Label {
+                        add_css_class: "farm-error",
+                        set_halign: gtk::Align::Start,
+                        set_label: &T.running_farmer_farm_disk_read_only(),
+                    }
+                },
+                (_, _, PlottingState::Plotting { kind, progress }) => gtk::Box {
                     set_orientation: gtk::Orientation::Vertical,
                     set_spacing: 10,
 
@@ -367,7 +426,7 @@ impl FactoryComponent for FarmWidget {
                         set_fraction: progress as f64 / 100.0,
                     },
                 },
-                (_, PlottingState::Idle) => gtk::Box {
+                (_, _, PlottingState::Idle) => gtk::Box {
                     gtk::Label {
                         #[track = "self.changed_is_node_synced()"]
                         set_label: &if self.is_node_synced {
@@ -384,14 +443,16 @@ impl FactoryComponent for FarmWidget {
                 set_vscrollbar_policy: gtk::PolicyType::Never,
 
                 self.sector_rows.clone() -> gtk::Box {
-                    #[track = "self.changed_farm_details() || self.changed_error()"]
-                    set_visible: self.farm_details && self.error.is_none(),
+                    #[track = "self.changed_farm_details() || self.changed_error() || self.changed_disk_state()"]
+                    set_visible: self.farm_details
+                        && self.error.is_none()
+                        && self.disk_state == FarmDiskState::Available,
                 },
             },
         },
     }
 
-    fn init_model(init: Self::Init, _index: &Self::Index, _sender: FactorySender<Self>) -> Self {
+    fn init_model(init: Self::Init, _index: &Self::Index, sender: FactorySender<Self>) -> Self {
         let mut sectors = Vec::with_capacity(usize::from(init.total_sectors));
         for sector_index in 0..init.total_sectors {
             let sector = gtk::Box::builder()
@@ -408,9 +469,19 @@ impl FactoryComponent for FarmWidget {
 
         let sector_rows = gtk::Box::new(gtk::Orientation::Vertical, 0);
 
+        sender.command({
+            let farm_path = init.farm.directory.clone();
+
+            move |sender, shutdown_receiver| async move {
+                Self::check_free_disk_space(sender, shutdown_receiver, farm_path).await;
+            }
+        });
+
         Self {
             path: init.farm.directory,
             size: format_size(init.farm.allocated_space),
+            free_disk_space: None,
+            free_space_warning_threshold: init.farm.free_space_warning_threshold,
             auditing_time: SingleSumSMA::from_zero(Duration::ZERO),
             auditing_time_average: Duration::ZERO,
             auditing_time_score: INVALID_SCORE_VALUE,
@@ -431,6 +502,7 @@ impl FactoryComponent for FarmWidget {
             slot_duration: init.slot_duration,
             block_authoring_delay: init.block_authoring_delay,
             error: None,
+            disk_state: FarmDiskState::Available,
             tracker: u32::MAX,
         }
     }
@@ -441,6 +513,13 @@ impl FactoryComponent for FarmWidget {
 
         self.process_input(input, sender);
     }
+
+    fn update_cmd(&mut self, input: Self::CommandOutput, sender: FactorySender<Self>) {
+        // Reset changes
+        self.reset();
+
+        self.process_command(input, sender);
+    }
 }
 
 impl FarmWidget {
@@ -599,6 +678,60 @@ impl FarmWidget {
 
                 self.get_mut_error().replace(error);
             }
+            FarmWidgetInput::DiskState(disk_state) => {
+                if disk_state == self.disk_state {
+                    return;
+                }
+
+                let path = self.path.display().to_string();
+                sender.spawn_command(move |_sender| {
+                    let mut notification = Notification::new();
+                    match disk_state {
+                        FarmDiskState::Available => {
+                            notification
+                                .summary(&T.notification_farm_disk_available())
+                                .body(&T.notification_farm_disk_available_body(path));
+                        }
+                        FarmDiskState::Missing => {
+                            notification
+                                .summary(&T.notification_farm_disk_missing())
+                                .body(&T.notification_farm_disk_missing_body(path));
+                        }
+                        FarmDiskState::ReadOnly => {
+                            notification
+                                .summary(&T.notification_farm_disk_read_only())
+                                .body(&T.notification_farm_disk_read_only_body(path));
+                        }
+                    }
+                    notification.with_typical_options();
+                    #[cfg(all(unix, not(target_os = "macos")))]
+                    if disk_state != FarmDiskState::Available {
+                        notification.urgency(notify_rust::Urgency::Critical);
+                    }
+                    if let Err(error) = notification.show() {
+                        warn!(%error, "Failed to show desktop notification");
+                    }
+                });
+
+                if disk_state != FarmDiskState::Available {
+                    // Farm is stopped, sectors that were being plotted will not be finished
+                    for (&sector_index, sector) in &self.sectors {
+                        for sector_state in [
+                            SectorState::Downloading,
+                            SectorState::Encoding,
+                            SectorState::Writing,
+                        ] {
+                            sector.remove_css_class(sector_state.css_class());
+                        }
+                        Self::update_sector_tooltip(sector, sector_index);
+                    }
+                    self.set_encoding_sectors(0);
+                    self.last_sector_plotted.take();
+                    self.set_plotting_state(PlottingState::Idle);
+                }
+
+                self.set_disk_state(disk_state);
+            }
             FarmWidgetInput::WindowResized => {
                 sender.input(FarmWidgetInput::RecalculateSectorRows);
             }
@@ -640,6 +773,82 @@ impl FarmWidget {
         }
     }
 
+    fn process_command(
+        &mut self,
+        command_output: FarmWidgetCommandOutput,
+        sender: FactorySender<Self>,
+    ) {
+        match command_output {
+            FarmWidgetCommandOutput::FreeDiskSpace(bytes) => {
+                let was_low = self.is_low_disk_space();
+
+                self.get_mut_free_disk_space().replace(bytes);
+
+                // Only notify when transitioning from normal to low space to avoid spamming
+                // notifications
+                if self.is_low_disk_space() && !was_low {
+                    let path = self.path.display().to_string();
+                    sender.spawn_command(move |_sender| {
+                        let mut notification = Notification::new();
+                        notification
+                            .summary(&T.notification_farm_low_disk_space())
+                            .body(&T.notification_farm_low_disk_space_body(
+                                path,
+                                bytes.to_string_as(true),
+                            ))
+                            .with_typical_options();
+                        if let Err(error) = notification.show() {
+                            warn!(%error, "Failed to show desktop notification");
+                        }
+                    });
+                }
+            }
+        }
+    }
+
+    async fn check_free_disk_space(
+        sender: Sender<FarmWidgetCommandOutput>,
+        shutdown_receiver: ShutdownReceiver,
+        farm_path: PathBuf,
+    ) {
+        shutdown_receiver
+            .register(async move {
+                loop {
+                    let path = farm_path.clone();
+                    match tokio::task::spawn_blocking(move || fs4::available_space(path)).await {
+                        Ok(Ok(free_disk_space)) => {
+                            if sender
+                                .send(FarmWidgetCommandOutput::FreeDiskSpace(ByteSize::b(
+                                    free_disk_space,
+                                )))
+                                .is_err()
+                            {
+                                break;
+                            }
+                        }
+                        Ok(Err(error)) => {
+                            // Disk may be missing, which is reported separately
+                            debug!(%error, "Failed to check free farm disk space");
+                        }
+                        Err(error) => {
+                            error!(%error, "Free disk space task panicked");
+                            break;
+                        }
+                    }
+
+                    tokio::time::sleep(FREE_DISK_SPACE_CHECK_INTERVAL).await;
+                }
+            })
+            .drop_on_shutdown()
+            .await
+    }
+
+    fn is_low_disk_space(&self) -> bool {
+        self.free_disk_space
+            .map(|bytes| bytes.as_u64() <= self.free_space_warning_threshold)
+            .unwrap_or_default()
+    }
+
     fn update_sector_state(&self, sector_index: SectorIndex, sector_state: SectorState) {
         if let Some(sector) = self.sectors.get(&sector_index) {
             match sector_state {
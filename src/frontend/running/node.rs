@@ -1,5 +1,9 @@
 use crate::backend::NodeNotification;
-use crate::backend::node::{ChainInfo, IN_PEERS, OUT_PEERS, SyncState};
+use crate::backend::bandwidth::{BandwidthUsage, Traffic};
+use crate::backend::networking::{DsnPeer, DsnPeerDirection, PieceServingStats};
+use crate::backend::node::{
+    ChainInfo, IN_PEERS, OUT_PEERS, SubstratePeer, SubstratePeerRole, SyncState,
+};
 use crate::frontend::NODE_FREE_SPACE_WARNING_THRESHOLD;
 use crate::frontend::translations::{AsDefaultStr, T};
 use crate::icon_names::shipped as icon_names;
@@ -8,12 +12,14 @@ use gtk::prelude::*;
 use relm4::prelude::*;
 use relm4::{Sender, ShutdownReceiver};
 use simple_moving_average::{SMA, SingleSumSMA};
+use std::fmt;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::{Duration, Instant};
 use subspace_core_primitives::BlockNumber;
-use tracing::{error, warn};
+use subspace_networking::libp2p::PeerId;
+use tracing::{debug, error, warn};
 
 /// Maximum blocks to store in the import queue.
 // HACK: This constant comes from Substrate's sync, but it is not public in there
@@ -22,8 +28,25 @@ const MAX_IMPORTING_BLOCKS: BlockNumber = 2048;
 const FREE_DISK_SPACE_CHECK_INTERVAL: Duration = Duration::from_secs(5);
 /// Number of samples over which to track block import time, 1 minute in slots
 const BLOCK_IMPORT_TIME_TRACKING_WINDOW: usize = 1000;
-const ALL_PEERS: u32 = OUT_PEERS + IN_PEERS;
-const ALMOST_ALL_PEERS: u32 = OUT_PEERS + IN_PEERS - IN_PEERS / 5;
+
+impl fmt::Display for SubstratePeerRole {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&match self {
+            Self::Authority => T.running_node_peers_role_authority(),
+            Self::Full => T.running_node_peers_role_full(),
+            Self::Light => T.running_node_peers_role_light(),
+        })
+    }
+}
+
+impl fmt::Display for DsnPeerDirection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&match self {
+            Self::Inbound => T.running_node_peers_direction_inbound(),
+            Self::Outbound => T.running_node_peers_direction_outbound(),
+        })
+    }
+}
 
 #[derive(Debug)]
 pub enum NodeInput {
@@ -31,9 +54,17 @@ pub enum NodeInput {
         best_block_number: BlockNumber,
         chain_info: ChainInfo,
         node_path: PathBuf,
+        peer_id: PeerId,
+        in_peers: u32,
+        out_peers: u32,
     },
     NodeNotification(NodeNotification),
     OpenNodeFolder,
+    ExportNetworkIdentity,
+    ImportNetworkIdentity,
+    RotateNetworkIdentity,
+    InspectKnownPeers,
+    ClearKnownPeers,
 }
 
 #[derive(Debug)]
@@ -44,6 +75,11 @@ pub enum NodeCommandOutput {
 #[derive(Debug, Clone)]
 pub enum NodeOutput {
     LowDiskSpace { free_space: ByteSize },
+    ExportNetworkIdentity,
+    ImportNetworkIdentity,
+    RotateNetworkIdentity,
+    InspectKnownPeers,
+    ClearKnownPeers,
 }
 
 #[tracker::track]
@@ -52,8 +88,19 @@ pub struct NodeView {
     best_block_number: BlockNumber,
     sync_state: SyncState,
     connected_peers: u32,
+    in_peers: u32,
+    out_peers: u32,
     free_disk_space: Option<ByteSize>,
     chain_name: String,
+    peer_id: Option<PeerId>,
+    substrate_peers: Vec<SubstratePeer>,
+    dsn_peers: Vec<DsnPeer>,
+    piece_serving_stats: PieceServingStats,
+    /// Upload rate in bytes per second
+    upload_rate: u64,
+    bandwidth_usage: BandwidthUsage,
+    #[do_not_track]
+    last_piece_serving_stats_time: Option<Instant>,
     #[do_not_track]
     node_path: PathBuf,
     #[do_not_track]
@@ -135,20 +182,13 @@ impl Component for NodeView {
                 gtk::LinkButton {
                     remove_css_class: "link",
                     set_cursor_from_name: Some("pointer"),
-                    #[track = "model.changed_connected_peers()"]
-                    set_css_classes: &[
-                        "flat",
-                        match model.connected_peers {
-                            ALMOST_ALL_PEERS.. => "success-label",
-                            ..OUT_PEERS => "error-label",
-                            _ => "warning-label",
-                        },
-                    ],
-                    #[track = "model.changed_connected_peers()"]
+                    #[track = "model.changed_connected_peers() || model.changed_in_peers() || model.changed_out_peers()"]
+                    set_css_classes: &["flat", model.connected_peers_css_class()],
+                    #[track = "model.changed_connected_peers() || model.changed_in_peers() || model.changed_out_peers()"]
                     set_tooltip: T
                         .running_node_connections_tooltip(
                             model.connected_peers,
-                            IN_PEERS + OUT_PEERS,
+                            model.in_peers + model.out_peers,
                         )
                         .as_str(),
                     set_uri: "https://docs.autonomys.xyz/farming/guides/port-config",
@@ -158,22 +198,194 @@ impl Component for NodeView {
                         set_spacing: 5,
 
                         gtk::Label {
-                            #[track = "model.changed_connected_peers()"]
-                            set_label: &format!("{}/{}", model.connected_peers, IN_PEERS + OUT_PEERS),
+                            #[track = "model.changed_connected_peers() || model.changed_in_peers() || model.changed_out_peers()"]
+                            set_label: &format!("{}/{}", model.connected_peers, model.in_peers + model.out_peers),
                         },
 
                         gtk::Image {
-                            #[track = "model.changed_connected_peers()"]
-                            set_icon_name:
-                                #[expect(clippy::match_overlapping_arm, reason = "Intentional for improved readability")]
-                                Some(match model.connected_peers {
-                                    ALL_PEERS => icon_names::STRENGTH_BARS_1,
-                                    0 => icon_names::STRENGTH_BARS_6,
-                                    ..OUT_PEERS => icon_names::STRENGTH_BARS_5,
-                                    OUT_PEERS => icon_names::STRENGTH_BARS_4,
-                                    ..ALMOST_ALL_PEERS => icon_names::STRENGTH_BARS_3,
-                                    _ => icon_names::STRENGTH_NARS_2,
-                                }),
+                            #[track = "model.changed_connected_peers() || model.changed_in_peers() || model.changed_out_peers()"]
+                            set_icon_name: Some(model.connected_peers_icon()),
+                        },
+                    },
+                },
+            },
+
+            gtk::Box {
+                set_spacing: 5,
+
+                gtk::Label {
+                    add_css_class: "dim-label",
+                    set_halign: gtk::Align::Start,
+                    set_selectable: true,
+                    #[track = "model.changed_peer_id()"]
+                    set_label: &T
+                        .running_node_peer_id(
+                            model.peer_id.map(|peer_id| peer_id.to_string()).unwrap_or_default()
+                        )
+                        .to_string(),
+                },
+
+                gtk::MenuButton {
+                    set_has_frame: false,
+                    set_icon_name: icon_names::MENU_LARGE,
+                    set_tooltip: &T.running_node_network_identity_tooltip(),
+
+                    #[wrap(Some)]
+                    set_popover = &gtk::Popover {
+                        gtk::Box {
+                            set_orientation: gtk::Orientation::Vertical,
+                            set_spacing: 10,
+
+                            gtk::Button {
+                                connect_clicked => NodeInput::ExportNetworkIdentity,
+                                set_label: &T.running_node_network_identity_export(),
+                            },
+                            gtk::Label {
+                                add_css_class: "warning-label",
+                                set_label: &T.running_node_network_identity_export_warning(),
+                                set_max_width_chars: 50,
+                                set_wrap: true,
+                            },
+
+                            gtk::Separator {},
+
+                            gtk::Button {
+                                connect_clicked => NodeInput::ImportNetworkIdentity,
+                                set_label: &T.running_node_network_identity_import(),
+                            },
+                            gtk::Button {
+                                add_css_class: "destructive-action",
+                                connect_clicked => NodeInput::RotateNetworkIdentity,
+                                set_label: &T.running_node_network_identity_rotate(),
+                            },
+                            gtk::Label {
+                                add_css_class: "warning-label",
+                                set_label: &T.running_node_network_identity_replace_warning(),
+                                set_max_width_chars: 50,
+                                set_wrap: true,
+                            },
+                        },
+                    },
+                },
+
+                gtk::Label {
+                    add_css_class: "dim-label",
+                    set_halign: gtk::Align::End,
+                    set_hexpand: true,
+                    #[track = "model.changed_piece_serving_stats() || model.changed_upload_rate()"]
+                    set_label: &T
+                        .running_node_piece_serving(
+                            model.piece_serving_stats.cache_hits
+                                + model.piece_serving_stats.plot_hits,
+                            ByteSize::b(model.upload_rate).to_string_as(true),
+                        )
+                        .to_string(),
+                    #[track = "model.changed_piece_serving_stats()"]
+                    set_tooltip: &model.piece_serving_details(),
+                },
+
+                gtk::Label {
+                    #[track = "model.changed_bandwidth_usage()"]
+                    set_css_classes: &[
+                        if model.bandwidth_usage.download_cap_reached()
+                            || model.bandwidth_usage.upload_cap_reached()
+                        {
+                            "warning-label"
+                        } else {
+                            "dim-label"
+                        },
+                    ],
+                    #[track = "model.changed_bandwidth_usage()"]
+                    set_label: &{
+                        let Traffic { downloaded, uploaded } = model.bandwidth_usage.day();
+
+                        T.running_node_bandwidth(
+                            ByteSize::b(downloaded).to_string_as(true),
+                            ByteSize::b(uploaded).to_string_as(true),
+                        )
+                        .to_string()
+                    },
+                    #[track = "model.changed_bandwidth_usage()"]
+                    set_tooltip: &model.bandwidth_details(),
+                },
+
+                gtk::MenuButton {
+                    set_has_frame: false,
+                    set_label: &T.running_node_peers_button(),
+                    set_tooltip: &T.running_node_peers_button_tooltip(),
+
+                    #[wrap(Some)]
+                    set_popover = &gtk::Popover {
+                        gtk::ScrolledWindow {
+                            set_max_content_height: 500,
+                            set_min_content_width: 600,
+                            set_propagate_natural_height: true,
+                            set_propagate_natural_width: true,
+
+                            gtk::Box {
+                                set_orientation: gtk::Orientation::Vertical,
+                                set_spacing: 10,
+
+                                gtk::Label {
+                                    add_css_class: "heading",
+                                    set_halign: gtk::Align::Start,
+                                    #[track = "model.changed_substrate_peers()"]
+                                    set_label: &T
+                                        .running_node_peers_substrate(model.substrate_peers.len())
+                                        .to_string(),
+                                },
+                                gtk::Label {
+                                    add_css_class: "monospace",
+                                    set_halign: gtk::Align::Start,
+                                    set_selectable: true,
+                                    #[track = "model.changed_substrate_peers()"]
+                                    set_label: &model.substrate_peers_details(),
+                                },
+
+                                gtk::Label {
+                                    add_css_class: "heading",
+                                    set_halign: gtk::Align::Start,
+                                    #[track = "model.changed_dsn_peers()"]
+                                    set_label: &T
+                                        .running_node_peers_dsn(model.dsn_peers.len())
+                                        .to_string(),
+                                },
+                                gtk::Label {
+                                    add_css_class: "monospace",
+                                    set_halign: gtk::Align::Start,
+                                    set_selectable: true,
+                                    #[track = "model.changed_dsn_peers()"]
+                                    set_label: &model.dsn_peers_details(),
+                                },
+
+                                gtk::Separator {},
+
+                                gtk::Label {
+                                    add_css_class: "heading",
+                                    set_halign: gtk::Align::Start,
+                                    set_label: &T.running_node_known_peers(),
+                                },
+                                gtk::Label {
+                                    add_css_class: "dim-label",
+                                    set_halign: gtk::Align::Start,
+                                    set_label: &T.running_node_known_peers_description(),
+                                    set_max_width_chars: 80,
+                                    set_wrap: true,
+                                },
+                                gtk::Box {
+                                    set_spacing: 10,
+
+                                    gtk::Button {
+                                        connect_clicked => NodeInput::InspectKnownPeers,
+                                        set_label: &T.running_node_known_peers_inspect(),
+                                    },
+                                    gtk::Button {
+                                        add_css_class: "destructive-action",
+                                        connect_clicked => NodeInput::ClearKnownPeers,
+                                        set_label: &T.running_node_known_peers_clear(),
+                                    },
+                                },
+                            },
                         },
                     },
                 },
@@ -283,13 +495,22 @@ impl Component for NodeView {
             best_block_number: 0,
             sync_state: SyncState::default(),
             connected_peers: 0,
+            in_peers: IN_PEERS,
+            out_peers: OUT_PEERS,
             free_disk_space: None,
             chain_name: String::new(),
+            peer_id: None,
+            substrate_peers: Vec::new(),
+            dsn_peers: Vec::new(),
+            piece_serving_stats: PieceServingStats::default(),
+            upload_rate: 0,
+            bandwidth_usage: BandwidthUsage::default(),
+            last_piece_serving_stats_time: None,
             node_path: PathBuf::new(),
             disk_space_check_cancel: Arc::new(AtomicBool::new(false)),
             block_import_time: SingleSumSMA::from_zero(Duration::ZERO),
             last_block_import_time: None,
-            tracker: u8::MAX,
+            tracker: u16::MAX,
         };
 
         let widgets = view_output!();
@@ -317,12 +538,155 @@ impl Component for NodeView {
 }
 
 impl NodeView {
+    /// Peers count at which connectivity is considered to be good
+    fn almost_all_peers(&self) -> u32 {
+        self.out_peers + self.in_peers - self.in_peers / 5
+    }
+
+    fn connected_peers_css_class(&self) -> &'static str {
+        if self.connected_peers >= self.almost_all_peers() {
+            "success-label"
+        } else if self.connected_peers < self.out_peers {
+            "error-label"
+        } else {
+            "warning-label"
+        }
+    }
+
+    fn connected_peers_icon(&self) -> &'static str {
+        if self.connected_peers == self.in_peers + self.out_peers {
+            icon_names::STRENGTH_BARS_1
+        } else if self.connected_peers == 0 {
+            icon_names::STRENGTH_BARS_6
+        } else if self.connected_peers < self.out_peers {
+            icon_names::STRENGTH_BARS_5
+        } else if self.connected_peers == self.out_peers {
+            icon_names::STRENGTH_BARS_4
+        } else if self.connected_peers < self.almost_all_peers() {
+            icon_names::STRENGTH_BARS_3
+        } else {
+            icon_names::STRENGTH_NARS_2
+        }
+    }
+
+    fn substrate_peers_details(&self) -> String {
+        if self.substrate_peers.is_empty() {
+            return T.running_node_peers_none().to_string();
+        }
+
+        self.substrate_peers
+            .iter()
+            .map(|peer| {
+                let address = match &peer.address {
+                    Some(address) => address.clone(),
+                    None => T.running_node_peers_address_unknown().to_string(),
+                };
+
+                format!(
+                    "{}  {:<10}  #{:<9}  {address}",
+                    peer.peer_id,
+                    peer.role.to_string(),
+                    peer.best_block,
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn dsn_peers_details(&self) -> String {
+        if self.dsn_peers.is_empty() {
+            return T.running_node_peers_none().to_string();
+        }
+
+        self.dsn_peers
+            .iter()
+            .map(|peer| {
+                let address = match &peer.address {
+                    Some(address) => address.to_string(),
+                    None => T.running_node_peers_address_unknown().to_string(),
+                };
+
+                format!(
+                    "{}  {:<10}  {address}",
+                    peer.peer_id,
+                    peer.direction.to_string(),
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn piece_serving_details(&self) -> String {
+        let PieceServingStats {
+            cache_hits,
+            plot_hits,
+            misses,
+            closest_peers_fallbacks,
+            segment_headers,
+            bytes_uploaded,
+        } = self.piece_serving_stats;
+
+        T.running_node_piece_serving_tooltip(
+            cache_hits,
+            plot_hits,
+            misses,
+            closest_peers_fallbacks,
+            segment_headers,
+            ByteSize::b(bytes_uploaded).to_string_as(true),
+        )
+        .to_string()
+    }
+
+    fn bandwidth_details(&self) -> String {
+        let format_traffic = |traffic: Traffic| {
+            (
+                ByteSize::b(traffic.downloaded).to_string_as(true),
+                ByteSize::b(traffic.uploaded).to_string_as(true),
+            )
+        };
+        let format_cap = |cap: Option<u64>| {
+            cap.map(|cap| ByteSize::b(cap).to_string_as(true))
+                .unwrap_or_else(|| T.running_node_bandwidth_no_cap().to_string())
+        };
+
+        let (hour_downloaded, hour_uploaded) = format_traffic(self.bandwidth_usage.hour);
+        let (substrate_downloaded, substrate_uploaded) =
+            format_traffic(self.bandwidth_usage.substrate_day);
+        let (dsn_downloaded, dsn_uploaded) = format_traffic(self.bandwidth_usage.dsn_day);
+
+        let mut details = T
+            .running_node_bandwidth_tooltip(
+                hour_downloaded,
+                hour_uploaded,
+                substrate_downloaded,
+                substrate_uploaded,
+                dsn_downloaded,
+                dsn_uploaded,
+                format_cap(self.bandwidth_usage.caps.download),
+                format_cap(self.bandwidth_usage.caps.upload),
+            )
+            .to_string();
+        if self.bandwidth_usage.download_cap_reached() {
+            details.push('\n');
+            details.push_str(&T.running_node_bandwidth_download_cap_reached());
+        }
+        if self.bandwidth_usage.upload_cap_reached() {
+            details.push('\n');
+            details.push_str(&T.running_node_bandwidth_upload_cap_reached());
+        }
+
+        details
+    }
+
     fn process_input(&mut self, input: NodeInput, sender: &ComponentSender<Self>) {
         match input {
             NodeInput::Initialize {
                 best_block_number,
                 chain_info,
                 node_path,
+                peer_id,
+                in_peers,
+                out_peers,
             } => {
                 // Cancel any existing disk space check task
                 self.disk_space_check_cancel.store(true, Ordering::Relaxed);
@@ -337,6 +701,9 @@ impl NodeView {
                         }),
                 );
                 self.node_path = node_path.clone();
+                self.set_peer_id(Some(peer_id));
+                self.set_in_peers(in_peers);
+                self.set_out_peers(out_peers);
 
                 // Create new cancellation token and start disk space check task
                 self.disk_space_check_cancel = Arc::new(AtomicBool::new(false));
@@ -380,6 +747,30 @@ impl NodeView {
                 NodeNotification::ConnectedPeersUpdate(connected_peers) => {
                     self.set_connected_peers(connected_peers);
                 }
+                NodeNotification::SubstratePeersUpdate(mut peers) => {
+                    peers.sort_by(|a, b| a.peer_id.cmp(&b.peer_id));
+                    self.set_substrate_peers(peers);
+                }
+                NodeNotification::DsnPeersUpdate(mut peers) => {
+                    peers.sort_by(|a, b| a.peer_id.cmp(&b.peer_id));
+                    self.set_dsn_peers(peers);
+                }
+                NodeNotification::PieceServingStatsUpdate(piece_serving_stats) => {
+                    let now = Instant::now();
+                    if let Some(last_piece_serving_stats_time) =
+                        self.last_piece_serving_stats_time.replace(now)
+                    {
+                        let uploaded = piece_serving_stats
+                            .bytes_uploaded
+                            .saturating_sub(self.piece_serving_stats.bytes_uploaded);
+                        let elapsed = now.duration_since(last_piece_serving_stats_time);
+                        self.set_upload_rate((uploaded as f64 / elapsed.as_secs_f64()) as u64);
+                    }
+                    self.set_piece_serving_stats(piece_serving_stats);
+                }
+                NodeNotification::BandwidthUsageUpdate(bandwidth_usage) => {
+                    self.set_bandwidth_usage(bandwidth_usage);
+                }
                 NodeNotification::BlockImported(imported_block) => {
                     self.set_best_block_number(imported_block.number);
                     // Ensure target is never below current block
@@ -402,6 +793,31 @@ impl NodeView {
                     error!(%error, path = %self.node_path.display(), "Failed to open node folder");
                 }
             }
+            NodeInput::ExportNetworkIdentity => {
+                if sender.output(NodeOutput::ExportNetworkIdentity).is_err() {
+                    debug!("Failed to send NodeOutput::ExportNetworkIdentity");
+                }
+            }
+            NodeInput::ImportNetworkIdentity => {
+                if sender.output(NodeOutput::ImportNetworkIdentity).is_err() {
+                    debug!("Failed to send NodeOutput::ImportNetworkIdentity");
+                }
+            }
+            NodeInput::RotateNetworkIdentity => {
+                if sender.output(NodeOutput::RotateNetworkIdentity).is_err() {
+                    debug!("Failed to send NodeOutput::RotateNetworkIdentity");
+                }
+            }
+            NodeInput::InspectKnownPeers => {
+                if sender.output(NodeOutput::InspectKnownPeers).is_err() {
+                    debug!("Failed to send NodeOutput::InspectKnownPeers");
+                }
+            }
+            NodeInput::ClearKnownPeers => {
+                if sender.output(NodeOutput::ClearKnownPeers).is_err() {
+                    debug!("Failed to send NodeOutput::ClearKnownPeers");
+                }
+            }
         }
     }
 
@@ -12,9 +12,10 @@ mod icon_names {
     include!(concat!(env!("OUT_DIR"), "/icon_names.rs"));
 }
 
+use crate::backend::farmer::farm_info::read_farm_info;
 use crate::frontend::{App, AppInit, GLOBAL_CSS, RunBackendResult};
 use bytesize::ByteSize;
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use duct::{Expression, cmd};
 use file_rotate::compression::Compression;
 use file_rotate::suffix::AppendCount;
@@ -137,9 +138,70 @@ impl AppStatusCode {
     }
 }
 
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Farm-related commands
+    #[command(subcommand)]
+    Farm(FarmCommand),
+}
+
+impl Command {
+    fn run(self) -> ExitCode {
+        match self {
+            Self::Farm(farm_command) => farm_command.run(),
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum FarmCommand {
+    /// Print information about the farm without starting it, farm files are not modified
+    Info {
+        /// Path to the farm directory
+        path: PathBuf,
+        /// Print information in JSON format
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+impl FarmCommand {
+    fn run(self) -> ExitCode {
+        match self {
+            Self::Info { path, json } => {
+                let farm_info = match read_farm_info(path) {
+                    Ok(farm_info) => farm_info,
+                    Err(error) => {
+                        eprintln!("{error}");
+                        return ExitCode::FAILURE;
+                    }
+                };
+
+                if json {
+                    match serde_json::to_string_pretty(&farm_info) {
+                        Ok(farm_info) => {
+                            println!("{farm_info}");
+                        }
+                        Err(error) => {
+                            eprintln!("Failed to serialize farm info: {error}");
+                            return ExitCode::FAILURE;
+                        }
+                    }
+                } else {
+                    println!("{farm_info}");
+                }
+
+                ExitCode::SUCCESS
+            }
+        }
+    }
+}
+
 #[derive(Debug, Parser)]
-#[clap(about, version)]
+#[clap(about, version, args_conflicts_with_subcommands = true)]
 struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
     /// Used for startup to minimize the window
     #[arg(long)]
     startup: bool,
@@ -161,7 +223,9 @@ struct Cli {
 
 impl Cli {
     fn run(self) -> ExitCode {
-        if self.uninstall {
+        if let Some(command) = self.command {
+            command.run()
+        } else if self.uninstall {
             #[cfg(windows)]
             {
                 let dirs_to_remove = env::var_os("SystemDrive")
@@ -504,6 +568,21 @@ impl Cli {
                         break;
                     }
                     AppStatusCode::Restart => {
+                        #[cfg(unix)]
+                        if let Some(program) = frontend::new_version::update::apply_staged_update()
+                        {
+                            use std::os::unix::process::CommandExt;
+
+                            error!(program = %program.display(), "Starting updated application");
+                            // Updated AppImage has its own supervisor, replace current process
+                            // with it
+                            let error = process::Command::new(program)
+                                .arg("--")
+                                .args(&self.gtk_arguments)
+                                .exec();
+                            error!(%error, "Failed to start updated application");
+                        }
+
                         error!("Restarting application");
                         continue;
                     }